@@ -29,6 +29,7 @@ where
     window_description: WindowDescription,
     window_scale_policy: WindowScalePolicy,
     on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+    event_tracer: Option<Box<dyn Fn(&Event, EventPhase, Entity) + Send>>,
     ignore_default_theme: bool,
     text_config: TextConfig,
 }
@@ -44,6 +45,7 @@ where
             window_description: WindowDescription::new(),
             window_scale_policy: WindowScalePolicy::SystemScaleFactor,
             on_idle: None,
+            event_tracer: None,
             ignore_default_theme: false,
             text_config: TextConfig::default(),
         }
@@ -88,6 +90,13 @@ where
         self
     }
 
+    /// Sets how the window is cleared before each frame is drawn.
+    pub fn clear_behavior(mut self, clear_behavior: ClearBehavior) -> Self {
+        self.window_description.clear_behavior = clear_behavior;
+
+        self
+    }
+
     /// Open a new window that blocks the current thread until the window is destroyed.
     ///
     /// Do **not** use this in the context of audio plugins, unless it is compiled as a
@@ -100,6 +109,7 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.event_tracer,
             self.ignore_default_theme,
             self.text_config,
         )
@@ -119,6 +129,7 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.event_tracer,
             self.ignore_default_theme,
             self.text_config,
         )
@@ -136,6 +147,7 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.event_tracer,
             self.ignore_default_theme,
             self.text_config,
         )
@@ -164,6 +176,18 @@ where
 
         self
     }
+
+    /// Sets a callback which is called as every event is dispatched, for debugging event propagation.
+    ///
+    /// See [`Context::set_event_tracer`] for details.
+    pub fn trace_events<T>(mut self, tracer: T) -> Self
+    where
+        T: 'static + Fn(&Event, EventPhase, Entity) + Send,
+    {
+        self.event_tracer = Some(Box::new(tracer));
+
+        self
+    }
 }
 
 pub(crate) struct ApplicationRunner {
@@ -383,7 +407,13 @@ impl ApplicationRunner {
                 }
             }
             baseview::Event::Window(event) => match event {
-                baseview::WindowEvent::Focused => cx.needs_refresh(),
+                baseview::WindowEvent::Focused => {
+                    cx.set_window_focus(Entity::root(), true);
+                    cx.needs_refresh();
+                }
+                baseview::WindowEvent::Unfocused => {
+                    cx.set_window_focus(Entity::root(), false);
+                }
                 baseview::WindowEvent::Resized(window_info) => {
                     // We keep track of the current size before applying the user scale factor while
                     // baseview's logical size includes that factor so we need to compensate for it
@@ -418,7 +448,6 @@ impl ApplicationRunner {
                 baseview::WindowEvent::WillClose => {
                     cx.send_event(Event::new(WindowEvent::WindowClose));
                 }
-                _ => {}
             },
         }
     }
@@ -459,7 +488,7 @@ fn translate_mouse_button(button: baseview::MouseButton) -> MouseButton {
         baseview::MouseButton::Right => MouseButton::Right,
         baseview::MouseButton::Middle => MouseButton::Middle,
         baseview::MouseButton::Other(id) => MouseButton::Other(id as u16),
-        baseview::MouseButton::Back => MouseButton::Other(4),
-        baseview::MouseButton::Forward => MouseButton::Other(5),
+        baseview::MouseButton::Back => MouseButton::Back,
+        baseview::MouseButton::Forward => MouseButton::Forward,
     }
 }
@@ -31,6 +31,11 @@ impl ViziaWindow {
 
         let canvas = Canvas::new(renderer).expect("Cannot create canvas");
 
+        let renderer_info =
+            unsafe { RendererInfo::from_gl_loader(|s| context.get_proc_address(s) as *const _) };
+        let shader_context =
+            unsafe { ShaderContext::from_gl_loader(|s| context.get_proc_address(s) as *const _) };
+
         // Assume scale for now until there is an event with a new one.
         // Assume scale for now until there is an event with a new one.
         // Scaling is a combination of the window's scale factor (which is usually determined by the
@@ -44,7 +49,10 @@ impl ViziaWindow {
         };
         let dpi_factor = window_scale_factor * win_desc.user_scale_factor;
 
-        BackendContext::new(&mut cx).add_main_window(&win_desc, canvas, dpi_factor as f32);
+        let mut backend_cx = BackendContext::new(&mut cx);
+        backend_cx.add_main_window(&win_desc, canvas, dpi_factor as f32);
+        backend_cx.set_renderer_info(renderer_info);
+        backend_cx.set_shader_context(Entity::root(), shader_context);
 
         cx.remove_user_themes();
         if let Some(builder) = builder {
@@ -67,6 +75,7 @@ impl ViziaWindow {
         scale_policy: WindowScalePolicy,
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        event_tracer: Option<Box<dyn Fn(&vizia_core::events::Event, EventPhase, Entity) + Send>>,
         ignore_default_theme: bool,
         text_config: TextConfig,
     ) -> WindowHandle
@@ -95,6 +104,9 @@ impl ViziaWindow {
 
                 context.ignore_default_theme = ignore_default_theme;
                 context.remove_user_themes();
+                if let Some(tracer) = event_tracer {
+                    context.set_event_tracer(tracer);
+                }
 
                 let mut cx = BackendContext::new(&mut context);
                 cx.set_text_config(text_config);
@@ -120,6 +132,7 @@ impl ViziaWindow {
         scale_policy: WindowScalePolicy,
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        event_tracer: Option<Box<dyn Fn(&vizia_core::events::Event, EventPhase, Entity) + Send>>,
         ignore_default_theme: bool,
         text_config: TextConfig,
     ) -> WindowHandle
@@ -144,6 +157,9 @@ impl ViziaWindow {
 
                 context.ignore_default_theme = ignore_default_theme;
                 context.remove_user_themes();
+                if let Some(tracer) = event_tracer {
+                    context.set_event_tracer(tracer);
+                }
 
                 let mut cx = BackendContext::new(&mut context);
                 cx.set_text_config(text_config);
@@ -169,6 +185,7 @@ impl ViziaWindow {
         scale_policy: WindowScalePolicy,
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        event_tracer: Option<Box<dyn Fn(&vizia_core::events::Event, EventPhase, Entity) + Send>>,
         ignore_default_theme: bool,
         text_config: TextConfig,
     ) where
@@ -192,6 +209,9 @@ impl ViziaWindow {
 
                 context.ignore_default_theme = ignore_default_theme;
                 context.remove_user_themes();
+                if let Some(tracer) = event_tracer {
+                    context.set_event_tracer(tracer);
+                }
 
                 let mut cx = BackendContext::new(&mut context);
                 cx.set_text_config(text_config);
@@ -2,7 +2,8 @@ use crate::prelude::*;
 // use instant::Duration;
 use morphorm::Units;
 use vizia_style::{
-    BackgroundSize, BorderWidth, BoxShadow, FontSize, Position, Property, Scale, Translate,
+    BackgroundSize, BorderColor, BorderWidth, BoxShadow, FontSize, Position, Property, Scale,
+    Translate,
 };
 
 // pub struct AnimationDescription {
@@ -12,6 +13,7 @@ use vizia_style::{
 
 pub struct AnimationBuilder<'a> {
     pub(crate) keyframes: Vec<KeyframeBuilder<'a>>,
+    pub(crate) easing: EasingFunction,
 }
 
 impl<'a> Default for AnimationBuilder<'a> {
@@ -22,7 +24,18 @@ impl<'a> Default for AnimationBuilder<'a> {
 
 impl<'a> AnimationBuilder<'a> {
     pub fn new() -> Self {
-        Self { keyframes: Vec::new() }
+        Self { keyframes: Vec::new(), easing: EasingFunction::Linear }
+    }
+
+    /// Sets the easing function used to interpolate between the keyframes of the animation, which is
+    /// linear by default.
+    ///
+    /// An animation eased with [`EasingFunction::Spring`] runs until the spring comes to rest, rather
+    /// than for the duration it is played with.
+    pub fn easing(mut self, easing: EasingFunction) -> Self {
+        self.easing = easing;
+
+        self
     }
 
     pub fn keyframe(
@@ -108,7 +121,7 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
-    pub fn border_color(mut self, val: impl Into<Color>) -> Self {
+    pub fn border_color(mut self, val: impl Into<BorderColor>) -> Self {
         self.properties.push(Property::BorderColor(val.into()));
 
         self
@@ -166,6 +179,12 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    pub fn image_tint(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::ImageTint(val.into()));
+
+        self
+    }
+
     pub fn background_image(mut self, val: impl Into<Vec<BackgroundImage<'a>>>) -> Self {
         self.properties.push(Property::BackgroundImage(val.into()));
 
@@ -279,7 +298,7 @@ impl<'a> KeyframeBuilder<'a> {
     // SIZE
 
     pub fn width(mut self, val: impl Into<Units>) -> Self {
-        self.properties.push(Property::Width(val.into()));
+        self.properties.push(Property::Width(SizeUnits::Units(val.into())));
 
         self
     }
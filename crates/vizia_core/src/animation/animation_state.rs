@@ -40,6 +40,10 @@ pub(crate) struct AnimationState<T: Interpolator> {
 
     pub active: bool,
 
+    /// Whether the animation is paused at a progress set with [`seek`](AnimationState::seek)
+    /// instead of advancing with time.
+    pub paused: bool,
+
     /// For transitions. The starting rule for this transition.
     pub from_rule: usize,
     /// For tansitions. The ending rule for this transition.
@@ -65,6 +69,7 @@ where
             persistent: false,
             t: 0.0,
             active: false,
+            paused: false,
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
@@ -91,6 +96,15 @@ where
         self
     }
 
+    /// Returns the duration the animation plays for, which for an animation eased with a spring is
+    /// the time it takes for the spring to come to rest.
+    pub(crate) fn play_duration(&self) -> Duration {
+        self.keyframes
+            .first()
+            .and_then(|keyframe| keyframe.timing_function.settle_duration())
+            .unwrap_or(self.duration)
+    }
+
     pub(crate) fn get_output(&self) -> Option<&T> {
         self.output.as_ref()
     }
@@ -102,6 +116,29 @@ where
         self.entities.insert(entity);
     }
 
+    /// Sets how far through the animation the output is, between 0.0 and 1.0.
+    pub(crate) fn seek(&mut self, t: f32) {
+        let t = t.clamp(0.0, 1.0);
+        self.t = t;
+
+        if self.keyframes.len() == 1 {
+            self.output = Some(self.keyframes[0].value.clone());
+            return;
+        }
+
+        let mut i = 0;
+        while i < self.keyframes.len() - 1 && self.keyframes[i + 1].time < t {
+            i += 1;
+        }
+        let start = &self.keyframes[i];
+        let end = &self.keyframes[i + 1];
+
+        let normalised_elapsed_time = (t - start.time) / (end.time - start.time);
+
+        let timing_t = start.timing_function.value(normalised_elapsed_time);
+        self.output = Some(T::interpolate(&start.value, &end.value, timing_t));
+    }
+
     pub(crate) fn is_transition(&self) -> bool {
         !(self.from_rule == usize::MAX && self.to_rule == usize::MAX)
     }
@@ -122,6 +159,7 @@ where
             persistent: true,
             t: 0.0,
             active: false,
+            paused: false,
             entities: HashSet::new(),
             from_rule: std::usize::MAX,
             to_rule: std::usize::MAX,
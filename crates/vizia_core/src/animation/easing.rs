@@ -0,0 +1,184 @@
+//! Named easing functions, which map the progress of an animation from 0.0 to 1.0 to an eased
+//! progress.
+//!
+//! These can be selected with the corresponding variant of [`EasingFunction`](crate::prelude::EasingFunction),
+//! or combined into a custom easing function with [`EasingFunction::custom`](crate::prelude::EasingFunction::custom).
+
+use std::f32::consts::PI;
+
+// The overshoot of the back easing functions, which overshoot their target by about 10%.
+const BACK_OVERSHOOT: f32 = 1.70158;
+const BACK_OVERSHOOT_IN_OUT: f32 = BACK_OVERSHOOT * 1.525;
+
+// The period of the elastic easing functions.
+const ELASTIC_PERIOD: f32 = 2.0 * PI / 3.0;
+const ELASTIC_PERIOD_IN_OUT: f32 = 2.0 * PI / 4.5;
+
+// Returns the mirror image of an easing function, which turns an ease in into an ease out and the
+// other way around.
+fn mirror(ease: fn(f32) -> f32, t: f32) -> f32 {
+    1.0 - ease(1.0 - t)
+}
+
+// Eases in with an ease in function for the first half of the animation, and out with its mirror
+// image for the second half.
+fn in_out(ease_in: fn(f32) -> f32, t: f32) -> f32 {
+    if t < 0.5 {
+        ease_in(2.0 * t) / 2.0
+    } else {
+        1.0 - ease_in(2.0 - 2.0 * t) / 2.0
+    }
+}
+
+pub fn ease_in_sine(t: f32) -> f32 {
+    1.0 - (t * PI / 2.0).cos()
+}
+
+pub fn ease_out_sine(t: f32) -> f32 {
+    (t * PI / 2.0).sin()
+}
+
+pub fn ease_in_out_sine(t: f32) -> f32 {
+    -((PI * t).cos() - 1.0) / 2.0
+}
+
+pub fn ease_in_quad(t: f32) -> f32 {
+    t.powi(2)
+}
+
+pub fn ease_out_quad(t: f32) -> f32 {
+    mirror(ease_in_quad, t)
+}
+
+pub fn ease_in_out_quad(t: f32) -> f32 {
+    in_out(ease_in_quad, t)
+}
+
+pub fn ease_in_cubic(t: f32) -> f32 {
+    t.powi(3)
+}
+
+pub fn ease_out_cubic(t: f32) -> f32 {
+    mirror(ease_in_cubic, t)
+}
+
+pub fn ease_in_out_cubic(t: f32) -> f32 {
+    in_out(ease_in_cubic, t)
+}
+
+pub fn ease_in_quart(t: f32) -> f32 {
+    t.powi(4)
+}
+
+pub fn ease_out_quart(t: f32) -> f32 {
+    mirror(ease_in_quart, t)
+}
+
+pub fn ease_in_out_quart(t: f32) -> f32 {
+    in_out(ease_in_quart, t)
+}
+
+pub fn ease_in_quint(t: f32) -> f32 {
+    t.powi(5)
+}
+
+pub fn ease_out_quint(t: f32) -> f32 {
+    mirror(ease_in_quint, t)
+}
+
+pub fn ease_in_out_quint(t: f32) -> f32 {
+    in_out(ease_in_quint, t)
+}
+
+pub fn ease_in_expo(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2f32.powf(10.0 * t - 10.0)
+    }
+}
+
+pub fn ease_out_expo(t: f32) -> f32 {
+    mirror(ease_in_expo, t)
+}
+
+pub fn ease_in_out_expo(t: f32) -> f32 {
+    in_out(ease_in_expo, t)
+}
+
+pub fn ease_in_circ(t: f32) -> f32 {
+    1.0 - (1.0 - t.clamp(0.0, 1.0).powi(2)).sqrt()
+}
+
+pub fn ease_out_circ(t: f32) -> f32 {
+    mirror(ease_in_circ, t)
+}
+
+pub fn ease_in_out_circ(t: f32) -> f32 {
+    in_out(ease_in_circ, t)
+}
+
+pub fn ease_in_back(t: f32) -> f32 {
+    (BACK_OVERSHOOT + 1.0) * t.powi(3) - BACK_OVERSHOOT * t.powi(2)
+}
+
+pub fn ease_out_back(t: f32) -> f32 {
+    mirror(ease_in_back, t)
+}
+
+pub fn ease_in_out_back(t: f32) -> f32 {
+    // Eased with a larger overshoot so that each half overshoots as much as the other back functions.
+    in_out(|t| (BACK_OVERSHOOT_IN_OUT + 1.0) * t.powi(3) - BACK_OVERSHOOT_IN_OUT * t.powi(2), t)
+}
+
+pub fn ease_in_elastic(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+
+    -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * ELASTIC_PERIOD).sin()
+}
+
+pub fn ease_out_elastic(t: f32) -> f32 {
+    mirror(ease_in_elastic, t)
+}
+
+pub fn ease_in_out_elastic(t: f32) -> f32 {
+    // Eased with a shorter period so that each half oscillates as often as the other elastic functions.
+    in_out(
+        |t| {
+            if t <= 0.0 || t >= 1.0 {
+                return t.clamp(0.0, 1.0);
+            }
+
+            -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 11.125) * ELASTIC_PERIOD_IN_OUT).sin()
+        },
+        t,
+    )
+}
+
+pub fn ease_in_bounce(t: f32) -> f32 {
+    mirror(ease_out_bounce, t)
+}
+
+pub fn ease_out_bounce(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+pub fn ease_in_out_bounce(t: f32) -> f32 {
+    in_out(ease_in_bounce, t)
+}
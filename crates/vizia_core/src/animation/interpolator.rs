@@ -9,9 +9,12 @@ use femtovg::Transform2D;
 
 use crate::style::ImageOrGradient;
 
-/// A trait which describes how a property is interpolated for animations.
-/// TODO: Make this crate private when `AnimatableSet` can be made crate private.
-pub(crate) trait Interpolator {
+/// A trait which describes how a value is interpolated for animations.
+///
+/// This is implemented for animatable style properties, and can be implemented for custom types
+/// which are animated with [`EventContext::animate`](crate::prelude::EventContext::animate).
+pub trait Interpolator {
+    /// Returns the value a proportion `t`, between 0.0 and 1.0, of the way from `start` to `end`.
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self;
 }
 
@@ -89,6 +92,23 @@ impl Interpolator for Filter {
             (Filter::Blur(start), Filter::Blur(end)) => {
                 Filter::Blur(Length::interpolate(start, end, t))
             }
+
+            (
+                Filter::DropShadow { x_offset, y_offset, blur_radius, color },
+                Filter::DropShadow {
+                    x_offset: end_x_offset,
+                    y_offset: end_y_offset,
+                    blur_radius: end_blur_radius,
+                    color: end_color,
+                },
+            ) => Filter::DropShadow {
+                x_offset: Length::interpolate(x_offset, end_x_offset, t),
+                y_offset: Length::interpolate(y_offset, end_y_offset, t),
+                blur_radius: Option::<Length>::interpolate(blur_radius, end_blur_radius, t),
+                color: Option::<Color>::interpolate(color, end_color, t),
+            },
+
+            _ => end.clone(),
         }
     }
 }
@@ -352,6 +372,21 @@ impl Interpolator for ClipPath {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         match (start, end) {
             (ClipPath::Shape(s), ClipPath::Shape(e)) => ClipPath::Shape(Rect::interpolate(s, e, t)),
+            (ClipPath::RoundedShape(s, sr), ClipPath::RoundedShape(e, er)) => {
+                ClipPath::RoundedShape(
+                    Rect::interpolate(s, e, t),
+                    LengthOrPercentage::interpolate(sr, er, t),
+                )
+            }
+            // A shape without rounded corners has a corner radius of zero.
+            (ClipPath::Shape(s), ClipPath::RoundedShape(e, er)) => ClipPath::RoundedShape(
+                Rect::interpolate(s, e, t),
+                LengthOrPercentage::interpolate(&LengthOrPercentage::px(0.0), er, t),
+            ),
+            (ClipPath::RoundedShape(s, sr), ClipPath::Shape(e)) => ClipPath::RoundedShape(
+                Rect::interpolate(s, e, t),
+                LengthOrPercentage::interpolate(sr, &LengthOrPercentage::px(0.0), t),
+            ),
             _ => end.clone(),
         }
     }
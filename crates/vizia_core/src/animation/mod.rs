@@ -4,8 +4,10 @@ pub use animation_id::{AnimId, Animation};
 mod animation_state;
 pub(crate) use animation_state::{AnimationState, Keyframe};
 
+pub mod easing;
+
 mod interpolator;
-pub(crate) use interpolator::Interpolator;
+pub use interpolator::Interpolator;
 
 mod timing_function;
 pub(crate) use timing_function::TimingFunction;
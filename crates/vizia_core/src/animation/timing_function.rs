@@ -1,9 +1,22 @@
+use instant::Duration;
+use vizia_style::{EasingFunction, StepPosition};
+
+use super::easing;
+
+// The time step used to find when a spring comes to rest, in seconds.
+const SPRING_STEP: f32 = 1.0 / 120.0;
+// The longest a spring is allowed to run for, in seconds, so that an undamped spring still ends.
+const SPRING_MAX_DURATION: f32 = 10.0;
+// A spring is at rest once both its distance from the target and its velocity are below these.
+const SPRING_REST_DISPLACEMENT: f32 = 0.001;
+const SPRING_REST_VELOCITY: f32 = 0.01;
+
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct TimingFunction {
-    x1: f32,
-    x2: f32,
-    y1: f32,
-    y2: f32,
+pub(crate) enum TimingFunction {
+    CubicBezier { x1: f32, x2: f32, y1: f32, y2: f32 },
+    Spring(Spring),
+    Steps { count: u32, position: StepPosition },
+    Function(fn(f32) -> f32),
 }
 
 impl Default for TimingFunction {
@@ -28,20 +41,66 @@ impl TimingFunction {
     pub fn ease_in_out() -> Self {
         Self::new(0.42, 0., 0.58, 1.)
     }
+    pub fn spring(stiffness: f32, damping: f32) -> Self {
+        Self::Spring(Spring::new(stiffness, damping))
+    }
+    pub fn steps(count: u32, position: StepPosition) -> Self {
+        Self::Steps { count, position }
+    }
 }
 
 impl TimingFunction {
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
-        Self { x1, y1, x2, y2 }
+        Self::CubicBezier { x1, y1, x2, y2 }
     }
 
     pub fn value(&self, x: f32) -> f32 {
-        // Linear
-        if self.x1 == self.y1 && self.x2 == self.y2 {
-            return x;
+        match *self {
+            TimingFunction::CubicBezier { x1, y1, x2, y2 } => {
+                // Linear
+                if x1 == y1 && x2 == y2 {
+                    return x;
+                }
+
+                Self::calc_bezier(Self::find_t_for_x(x, x1, x2), y1, y2)
+            }
+
+            TimingFunction::Spring(spring) => spring.value(x),
+
+            TimingFunction::Steps { count, position } => Self::calc_steps(x, count, position),
+
+            TimingFunction::Function(function) => function(x),
+        }
+    }
+
+    /// Returns how long the spring takes to come to rest, which replaces the duration of an animation
+    /// eased with a spring, or `None` for other timing functions.
+    pub fn settle_duration(&self) -> Option<Duration> {
+        match self {
+            TimingFunction::Spring(spring) => Some(Duration::from_secs_f32(spring.settle_time)),
+            _ => None,
+        }
+    }
+
+    fn calc_steps(x: f32, count: u32, position: StepPosition) -> f32 {
+        // The value holds at the last step once the animation has finished.
+        if x >= 1.0 {
+            return 1.0;
         }
 
-        Self::calc_bezier(self.find_t_for_x(x), self.y1, self.y2)
+        let count = count.max(1) as f32;
+        let jumps = match position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => count,
+            StepPosition::JumpNone => (count - 1.0).max(1.0),
+            StepPosition::JumpBoth => count + 1.0,
+        };
+
+        let mut step = (x * count).floor();
+        if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+            step += 1.0;
+        }
+
+        (step / jumps).clamp(0.0, 1.0)
     }
 
     fn calc_bezier(t: f32, a1: f32, a2: f32) -> f32 {
@@ -60,16 +119,16 @@ impl TimingFunction {
         3.0 * a(a1, a2) * t * t + 2.0 * b(a1, a2) * t + c(a1)
     }
 
-    fn find_t_for_x(&self, x: f32) -> f32 {
+    fn find_t_for_x(x: f32, x1: f32, x2: f32) -> f32 {
         let mut guess = x;
         let mut error = f32::MAX;
         for _ in 0..8 {
-            let pos = Self::calc_bezier(guess, self.x1, self.x2);
+            let pos = Self::calc_bezier(guess, x1, x2);
             error = pos - x;
             if error.abs() <= 0.0000001 {
                 return guess;
             }
-            let slope = Self::calc_bezier_slope(guess, self.x1, self.x2);
+            let slope = Self::calc_bezier_slope(guess, x1, x2);
             guess -= error / slope;
         }
         if error.abs() <= 0.0000001 {
@@ -80,9 +139,85 @@ impl TimingFunction {
     }
 }
 
+/// A damped spring with a unit mass, moving from 0.0 to 1.0 from rest.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Spring {
+    stiffness: f32,
+    damping: f32,
+    // The time it takes for the spring to come to rest, in seconds.
+    settle_time: f32,
+}
+
+impl Spring {
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        let mut spring = Self {
+            stiffness: stiffness.max(f32::EPSILON),
+            damping: damping.max(0.0),
+            settle_time: 0.0,
+        };
+
+        let mut time = 0.0;
+        while time < SPRING_MAX_DURATION {
+            time += SPRING_STEP;
+            let (position, velocity) = spring.state(time);
+            if (1.0 - position).abs() < SPRING_REST_DISPLACEMENT
+                && velocity.abs() < SPRING_REST_VELOCITY
+            {
+                break;
+            }
+        }
+        spring.settle_time = time.min(SPRING_MAX_DURATION);
+
+        spring
+    }
+
+    // Returns the position of the spring at the given proportion of the time it takes to settle.
+    fn value(&self, x: f32) -> f32 {
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        self.state(x.max(0.0) * self.settle_time).0
+    }
+
+    // Returns the position and velocity of the spring after the given time in seconds.
+    fn state(&self, time: f32) -> (f32, f32) {
+        let omega = self.stiffness.sqrt();
+        let zeta = self.damping / (2.0 * omega);
+
+        // The displacement from the target starts at -1.0 with no velocity.
+        let (displacement, velocity) = if zeta < 1.0 {
+            // Underdamped, so the spring oscillates around the target.
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let envelope = (-zeta * omega * time).exp();
+            let (sin, cos) = (omega_d * time).sin_cos();
+            (
+                -envelope * (cos + zeta * omega / omega_d * sin),
+                envelope * omega * omega / omega_d * sin,
+            )
+        } else if zeta == 1.0 {
+            // Critically damped.
+            let envelope = (-omega * time).exp();
+            (-(1.0 + omega * time) * envelope, omega * omega * time * envelope)
+        } else {
+            // Overdamped.
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            let c1 = -r2 / (r2 - r1);
+            let c2 = r1 / (r2 - r1);
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        };
+
+        (1.0 + displacement, velocity)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::TimingFunction;
+    use vizia_style::{EasingFunction, StepPosition};
 
     #[test]
     fn linear() {
@@ -95,4 +230,87 @@ mod tests {
         let timing_func = TimingFunction::ease();
         assert_eq!(timing_func.value(0.25), 0.4085106);
     }
+
+    #[test]
+    fn spring() {
+        let timing_func = TimingFunction::spring(170.0, 10.0);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(1.0), 1.0);
+        // An underdamped spring overshoots its target.
+        assert!((0..100).any(|i| timing_func.value(i as f32 / 100.0) > 1.0));
+        assert!(timing_func.settle_duration().is_some());
+    }
+
+    #[test]
+    fn steps() {
+        let timing_func = TimingFunction::steps(4, StepPosition::JumpEnd);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(0.3), 0.25);
+        assert_eq!(timing_func.value(1.0), 1.0);
+
+        let timing_func = TimingFunction::steps(4, StepPosition::JumpStart);
+        assert_eq!(timing_func.value(0.0), 0.25);
+
+        let timing_func = TimingFunction::steps(3, StepPosition::JumpNone);
+        assert_eq!(timing_func.value(0.5), 0.5);
+    }
+
+    #[test]
+    fn named() {
+        let timing_func = TimingFunction::from(EasingFunction::EaseOutBack);
+        assert!((timing_func.value(0.0)).abs() < 0.0001);
+        assert!((timing_func.value(1.0) - 1.0).abs() < 0.0001);
+        // The back easing functions overshoot their target.
+        assert!(timing_func.value(0.6) > 1.0);
+    }
+}
+
+impl From<EasingFunction> for TimingFunction {
+    fn from(easing: EasingFunction) -> Self {
+        match easing {
+            EasingFunction::Linear => TimingFunction::linear(),
+            EasingFunction::Ease => TimingFunction::ease(),
+            EasingFunction::EaseIn => TimingFunction::ease_in(),
+            EasingFunction::EaseOut => TimingFunction::ease_out(),
+            EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
+            EasingFunction::EaseInSine => TimingFunction::Function(easing::ease_in_sine),
+            EasingFunction::EaseOutSine => TimingFunction::Function(easing::ease_out_sine),
+            EasingFunction::EaseInOutSine => TimingFunction::Function(easing::ease_in_out_sine),
+            EasingFunction::EaseInQuad => TimingFunction::Function(easing::ease_in_quad),
+            EasingFunction::EaseOutQuad => TimingFunction::Function(easing::ease_out_quad),
+            EasingFunction::EaseInOutQuad => TimingFunction::Function(easing::ease_in_out_quad),
+            EasingFunction::EaseInCubic => TimingFunction::Function(easing::ease_in_cubic),
+            EasingFunction::EaseOutCubic => TimingFunction::Function(easing::ease_out_cubic),
+            EasingFunction::EaseInOutCubic => TimingFunction::Function(easing::ease_in_out_cubic),
+            EasingFunction::EaseInQuart => TimingFunction::Function(easing::ease_in_quart),
+            EasingFunction::EaseOutQuart => TimingFunction::Function(easing::ease_out_quart),
+            EasingFunction::EaseInOutQuart => TimingFunction::Function(easing::ease_in_out_quart),
+            EasingFunction::EaseInQuint => TimingFunction::Function(easing::ease_in_quint),
+            EasingFunction::EaseOutQuint => TimingFunction::Function(easing::ease_out_quint),
+            EasingFunction::EaseInOutQuint => TimingFunction::Function(easing::ease_in_out_quint),
+            EasingFunction::EaseInExpo => TimingFunction::Function(easing::ease_in_expo),
+            EasingFunction::EaseOutExpo => TimingFunction::Function(easing::ease_out_expo),
+            EasingFunction::EaseInOutExpo => TimingFunction::Function(easing::ease_in_out_expo),
+            EasingFunction::EaseInCirc => TimingFunction::Function(easing::ease_in_circ),
+            EasingFunction::EaseOutCirc => TimingFunction::Function(easing::ease_out_circ),
+            EasingFunction::EaseInOutCirc => TimingFunction::Function(easing::ease_in_out_circ),
+            EasingFunction::EaseInBack => TimingFunction::Function(easing::ease_in_back),
+            EasingFunction::EaseOutBack => TimingFunction::Function(easing::ease_out_back),
+            EasingFunction::EaseInOutBack => TimingFunction::Function(easing::ease_in_out_back),
+            EasingFunction::EaseInElastic => TimingFunction::Function(easing::ease_in_elastic),
+            EasingFunction::EaseOutElastic => TimingFunction::Function(easing::ease_out_elastic),
+            EasingFunction::EaseInOutElastic => {
+                TimingFunction::Function(easing::ease_in_out_elastic)
+            }
+            EasingFunction::EaseInBounce => TimingFunction::Function(easing::ease_in_bounce),
+            EasingFunction::EaseOutBounce => TimingFunction::Function(easing::ease_out_bounce),
+            EasingFunction::EaseInOutBounce => TimingFunction::Function(easing::ease_in_out_bounce),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
+            EasingFunction::Spring { stiffness, damping } => {
+                TimingFunction::spring(stiffness, damping)
+            }
+            EasingFunction::Steps(count, position) => TimingFunction::steps(count, position),
+            EasingFunction::Custom(custom) => TimingFunction::Function(custom.0),
+        }
+    }
 }
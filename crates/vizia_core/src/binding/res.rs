@@ -1,7 +1,12 @@
-use vizia_style::{BoxShadow, FontStretch, FontStyle, FontWeight, FontWeightKeyword};
+use vizia_style::{
+    BoxShadow, FontHinting, FontSmoothing, FontStretch, FontStyle, FontWeight, FontWeightKeyword,
+};
 
 use crate::{
-    modifiers::{BoxShadowBuilder, LinearGradientBuilder},
+    modifiers::{
+        Background, BoxShadowBuilder, ConicGradientBuilder, LinearGradientBuilder,
+        RadialGradientBuilder,
+    },
     prelude::*,
 };
 
@@ -85,10 +90,32 @@ impl_res_simple!(Angle);
 impl_res_simple!(TextAlign);
 impl_res_clone!(BoxShadow);
 impl_res_clone!(LinearGradientBuilder);
+impl_res_clone!(RadialGradientBuilder);
+impl_res_clone!(ConicGradientBuilder);
+impl_res_clone!(Gradient);
+impl_res_clone!(CursorImage);
+impl_res_clone!(Cursor);
+impl_res_clone!(Background);
 impl_res_clone!(BoxShadowBuilder);
 impl_res_clone!(Filter);
+impl_res_clone!(ClipPath);
 impl_res_simple!(Opacity);
 impl_res_simple!(FontStretch);
+impl_res_simple!(FontSmoothing);
+impl_res_simple!(FontHinting);
+impl_res_simple!(Direction);
+impl_res_simple!(ImageRendering);
+impl_res_simple!(MaskMode);
+impl_res_simple!(BoxSizing);
+impl_res_simple!(SizeUnits);
+impl_res_simple!(FlexWrap);
+impl_res_simple!(AlignContent);
+impl_res_simple!(JustifyContent);
+impl_res_simple!(AlignItems);
+impl_res_simple!(WillChange);
+impl_res_simple!(CaretShape);
+impl_res_simple!(instant::Duration);
+impl_res_simple!(Fullscreen);
 impl_res_clone!(Translate);
 impl_res_clone!(Scale);
 impl_res_clone!(Position);
@@ -2,8 +2,10 @@
 //! results. The main type here is CachedData, usually accessed via `cx.cache`.
 
 use crate::{layout::cache::GeoChanged, prelude::*};
-use femtovg::ImageId;
+use femtovg::{ImageFlags, ImageId, Transform2D};
+use fnv::FnvHashMap;
 use vizia_storage::SparseSet;
+use vizia_style::Gradient;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub(crate) struct Pos {
@@ -11,6 +13,35 @@ pub(crate) struct Pos {
     pub(crate) y: f32,
 }
 
+/// The state of a view which is notified when it enters or leaves the visible area of the window.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct ViewportObserver {
+    /// The proportion of the view which must be visible for it to be considered in the viewport.
+    pub(crate) threshold: f32,
+    /// The distance, in physical pixels, by which the visible area is expanded so that views near
+    /// the viewport are considered to be in it.
+    pub(crate) margin: f32,
+    /// Whether the view was in the viewport after the last layout.
+    pub(crate) visible: bool,
+}
+
+/// A list of images of a view, which can contain gradients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ImageLayer {
+    Background,
+    Mask,
+}
+
+/// A gradient drawn into an image, along with the values it was drawn with, so that it is only drawn
+/// again when they change.
+pub(crate) struct GradientImage {
+    pub(crate) image: ImageId,
+    pub(crate) gradient: Gradient,
+    pub(crate) luminance: bool,
+    pub(crate) scale_factor: f32,
+    pub(crate) parent_width: f32,
+}
+
 /// Stores data which can be cached between system runs.
 ///
 /// When an event occurs or style data is changed systems run to determine the new state of the UI.
@@ -19,10 +50,34 @@ pub(crate) struct Pos {
 pub struct CachedData {
     pub(crate) bounds: SparseSet<BoundingBox>,
     pub(crate) relative_position: SparseSet<Pos>,
+    /// The transform of each view, composed with the transforms of its ancestors.
+    pub(crate) transform: SparseSet<Transform2D>,
     pub(crate) shadow_images: SparseSet<Vec<Option<(ImageId, ImageId)>>>,
     pub(crate) filter_image: SparseSet<Option<(ImageId, ImageId)>>,
     pub(crate) screenshot_image: SparseSet<Option<ImageId>>,
+    pub(crate) effect_images: SparseSet<(ImageId, ImageId)>,
+    /// Images which gradients that can't be drawn with a paint directly, such as conic gradients,
+    /// are drawn into, by the list of images of the view and the index of the gradient in the list.
+    pub(crate) gradient_images: SparseSet<FnvHashMap<(ImageLayer, usize), GradientImage>>,
     pub(crate) geo_changed: SparseSet<GeoChanged>,
+    pub(crate) viewport_observers: SparseSet<ViewportObserver>,
+    /// Images which are only loaded near the viewport, and whether they are unloaded when not.
+    pub(crate) lazy_images: SparseSet<bool>,
+    /// The image the window is drawn into when partial redraw is enabled, which keeps the content of
+    /// the previous frame.
+    pub(crate) frame_image: Option<ImageId>,
+    /// The area of the window covered by each view when it was last drawn.
+    pub(crate) redraw_regions: SparseSet<BoundingBox>,
+    /// The distance the children of each scrolled view are moved up and to the left by, in physical
+    /// pixels.
+    pub(crate) scroll_offset: SparseSet<(f32, f32)>,
+    /// The copies of the images of the main window loaded into the canvas of each window, by the id
+    /// of the image in the main window and the flags of the copy. The main window only has copies of
+    /// images which are drawn with other flags, e.g. with another image rendering mode.
+    pub(crate) window_images: FnvHashMap<Entity, Vec<(ImageId, ImageFlags, ImageId)>>,
+    /// Images of removed views in each window other than the main window, which are deleted when the
+    /// window is next drawn, while its rendering context is current.
+    pub(crate) removed_images: FnvHashMap<Entity, Vec<ImageId>>,
 }
 
 impl CachedData {
@@ -35,10 +90,33 @@ impl CachedData {
     pub(crate) fn remove(&mut self, entity: Entity) {
         self.bounds.remove(entity);
         self.relative_position.remove(entity);
+        self.transform.remove(entity);
         self.filter_image.remove(entity);
         self.screenshot_image.remove(entity);
         self.shadow_images.remove(entity);
+        self.effect_images.remove(entity);
+        self.gradient_images.remove(entity);
         self.geo_changed.remove(entity);
+        self.viewport_observers.remove(entity);
+        self.lazy_images.remove(entity);
+        self.redraw_regions.remove(entity);
+        self.scroll_offset.remove(entity);
+    }
+
+    /// Removes the cached images of the views in the given window. Used when the images have been
+    /// invalidated by a renderer reset of the window.
+    pub(crate) fn clear_images(&mut self, tree: &Tree<Entity>, window: Entity) {
+        for entity in window.branch_iter(tree).filter(|entity| tree.window(*entity) == window) {
+            self.shadow_images.remove(entity);
+            self.filter_image.remove(entity);
+            self.screenshot_image.remove(entity);
+            self.effect_images.remove(entity);
+            self.gradient_images.remove(entity);
+        }
+
+        if window == Entity::root() {
+            self.frame_image = None;
+        }
     }
 
     /// Returns the bounding box of the entity, determined by the layout system.
@@ -66,6 +144,11 @@ impl CachedData {
         self.bounds.get(entity).cloned().unwrap_or_default().h
     }
 
+    /// Returns the transform of the entity, composed with the transforms of its ancestors.
+    pub fn get_transform(&self, entity: Entity) -> Transform2D {
+        self.transform.get(entity).copied().unwrap_or_else(Transform2D::identity)
+    }
+
     pub fn set_bounds(&mut self, entity: Entity, bounds: BoundingBox) {
         if let Some(b) = self.bounds.get_mut(entity) {
             *b = bounds;
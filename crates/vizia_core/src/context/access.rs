@@ -18,6 +18,24 @@ impl<'a> AccessContext<'a> {
     }
 }
 
+/// How urgently a screen reader announces a message, see [`EventContext::announce`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Politeness {
+    /// The message is announced once the screen reader is idle.
+    Polite,
+    /// The message interrupts anything the screen reader is currently announcing.
+    Assertive,
+}
+
+impl From<Politeness> for Live {
+    fn from(politeness: Politeness) -> Self {
+        match politeness {
+            Politeness::Polite => Live::Polite,
+            Politeness::Assertive => Live::Assertive,
+        }
+    }
+}
+
 /// Wrapper around an accesskit node builder, a node id, and a list of children to be added to the node.
 #[derive(Debug)]
 pub struct AccessNode {
@@ -1,18 +1,22 @@
+use instant::Instant;
 use std::any::Any;
 
 use femtovg::{renderer::OpenGl, Canvas};
 use vizia_window::WindowDescription;
 
-use super::EventProxy;
+use super::{EventProxy, RendererInfo};
+use crate::accessibility::IntoNode;
 use crate::events::EventManager;
+use crate::resource::ImageOrId;
 use crate::style::SystemFlags;
+use crate::view::ShaderContext;
 use crate::{cache::CachedData, environment::Environment, prelude::*, style::Style, systems::*};
 use vizia_id::GenerationalId;
 
 pub use crate::text::cosmic::TextConfig;
 
 #[cfg(feature = "clipboard")]
-use copypasta::ClipboardProvider;
+use super::ClipboardProvider;
 
 /// Context used to integrate vizia with windowing backends such as winit and baseview.
 pub struct BackendContext<'a>(pub &'a mut Context, Option<EventManager>);
@@ -39,16 +43,90 @@ impl<'a> BackendContext<'a> {
         }
     }
 
+    /// Helper function for mutating the state of the window with the given root view.
+    pub fn mutate_window_of<W: Any, F: Fn(&mut BackendContext, &W)>(
+        &mut self,
+        entity: Entity,
+        f: F,
+    ) {
+        if let Some(window_event_handler) = self.0.views.remove(&entity) {
+            if let Some(window) = window_event_handler.downcast_ref::<W>() {
+                f(self, window);
+            }
+
+            self.0.views.insert(entity, window_event_handler);
+        }
+    }
+
     /// Adds a root window view to the context.
     pub fn add_window<W: View>(&mut self, window: W) {
         self.0.views.insert(Entity::root(), Box::new(window));
     }
 
+    /// Takes the windows opened with [`Context::spawn_window`] which are yet to be created by the
+    /// windowing backend.
+    pub fn take_spawned_windows(&mut self) -> Vec<(Entity, WindowDescription)> {
+        std::mem::take(&mut self.0.spawned_windows)
+    }
+
+    /// Adds the window view and canvas of a window opened with [`Context::spawn_window`], replacing
+    /// the placeholder root view of the window.
+    pub fn add_secondary_window<W: View>(
+        &mut self,
+        entity: Entity,
+        window: W,
+        mut canvas: Canvas<OpenGl>,
+    ) {
+        let physical_width = self.0.cache.get_width(entity);
+        let physical_height = self.0.cache.get_height(entity);
+        canvas.set_size(physical_width as u32, physical_height as u32, 1.0);
+
+        self.0.views.insert(entity, Box::new(window));
+        self.0.canvases.insert(entity, canvas);
+
+        self.needs_refresh();
+    }
+
+    /// Removes the window with the given root view, along with its views.
+    pub fn close_window(&mut self, entity: Entity) {
+        self.0.remove(entity);
+    }
+
+    /// Drops the windows which were removed from the tree. The `make_current` closure is called with
+    /// the window view of each window to make its rendering context current before its canvas is
+    /// dropped. Returns true if any windows were dropped.
+    pub fn dispose_removed_windows<W: Any, F: FnMut(&W)>(&mut self, mut make_current: F) -> bool {
+        let removed_windows = std::mem::take(&mut self.0.removed_windows);
+        let disposed = !removed_windows.is_empty();
+        for (window, canvas, shader_context) in removed_windows {
+            if let Some(window) = window.downcast_ref::<W>() {
+                make_current(window);
+            }
+
+            drop(canvas);
+            drop(shader_context);
+        }
+
+        disposed
+    }
+
+    /// Returns true if there are windows other than the main window.
+    pub fn has_secondary_windows(&self) -> bool {
+        self.0.canvases.len() > 1 || !self.0.spawned_windows.is_empty()
+    }
+
     /// Returns a mutable reference to the style data.
     pub fn style(&mut self) -> &mut Style {
         &mut self.0.style
     }
 
+    /// Sets the background color of a window, which it is cleared with before each frame. This
+    /// overrides any background color given to the window by a stylesheet.
+    pub fn set_window_background_color(&mut self, window: Entity, color: Color) {
+        self.0.style.background_color.insert(window, color);
+        self.0.style.needs_redraw();
+    }
+
     /// Returns a mutable reference to the cache of computed properties data.
     pub fn cache(&mut self) -> &mut CachedData {
         &mut self.0.cache
@@ -64,6 +142,19 @@ impl<'a> BackendContext<'a> {
         self.0.focused
     }
 
+    /// Returns true if the given view accepts text input, in which case the windowing backend should
+    /// enable input method editor (IME) support while the view is focused.
+    pub fn accepts_text_input(&self, entity: Entity) -> bool {
+        matches!(self.0.style.role.get(entity), Some(Role::TextField))
+            && !self.0.style.disabled.get(entity).copied().unwrap_or_default()
+    }
+
+    /// Returns true if the focused view accepts text input and a keyboard should be made available,
+    /// for example by showing the platform's soft keyboard on touch devices.
+    pub fn keyboard_requested(&self) -> bool {
+        self.0.keyboard_requested
+    }
+
     /// The window's size in logical pixels, before
     /// [`user_scale_factor()`][Self::user_scale_factor()] gets applied to it. If this value changed
     /// during a frame then the window will be resized and a [`WindowEvent::GeometryChanged`] will be
@@ -101,9 +192,11 @@ impl<'a> BackendContext<'a> {
         );
 
         self.0.style.dpi_factor = dpi_factor as f64;
+        self.0.clear_behavior = window_description.clear_behavior;
 
         self.0.cache.set_width(Entity::root(), physical_width);
         self.0.cache.set_height(Entity::root(), physical_height);
+        self.0.style.needs_redraw();
 
         self.0
             .style
@@ -119,6 +212,77 @@ impl<'a> BackendContext<'a> {
         self.0.canvases.insert(Entity::root(), canvas);
     }
 
+    /// Replaces the canvas of the root window after the rendering context has been lost.
+    ///
+    /// See [`reset_window_renderer`](Self::reset_window_renderer).
+    pub fn reset_renderer(&mut self, canvas: Canvas<OpenGl>) {
+        self.reset_window_renderer(Entity::root(), canvas);
+    }
+
+    /// Replaces the canvas of the window with the given root view after its rendering context has
+    /// been lost.
+    ///
+    /// Any images uploaded to the previous canvas are discarded and will be uploaded again when the
+    /// window is next drawn, and a [`WindowEvent::RendererReset`] event is sent to every view in the
+    /// window.
+    pub fn reset_window_renderer(&mut self, window: Entity, mut canvas: Canvas<OpenGl>) {
+        let width = self.0.cache.get_width(window);
+        let height = self.0.cache.get_height(window);
+        canvas.set_size(width as u32, height as u32, 1.0);
+
+        // The old canvas is dropped without deleting its images as they belong to the lost context.
+        self.0.canvases.insert(window, canvas);
+        if let Some(shader_context) = self.0.shader_contexts.remove(&window) {
+            shader_context.discard();
+        }
+
+        self.0.cache.clear_images(&self.0.tree, window);
+
+        if window == Entity::root() {
+            self.0.text_context.clear_glyph_cache();
+
+            // The decoded images are kept along with the images uploaded to the old canvas, so they
+            // are uploaded again to the new canvas when next drawn.
+            for stored_image in self.0.resource_manager.images.values_mut() {
+                let (image, flags) = match &mut stored_image.image {
+                    ImageOrId::Id(_, _, image, flags) => {
+                        (std::mem::replace(image, image::DynamicImage::new_rgba8(0, 0)), *flags)
+                    }
+                    ImageOrId::Image(..) => continue,
+                };
+
+                stored_image.image = ImageOrId::Image(image, flags);
+            }
+
+            // The copies of the images are keyed by ids of the old canvas, which may be reused by the
+            // new canvas, so they are deleted and copied again. Copies in the main window belong to
+            // the lost context.
+            for (other_window, images) in self.0.cache.window_images.drain() {
+                if other_window != Entity::root() {
+                    self.0
+                        .cache
+                        .removed_images
+                        .entry(other_window)
+                        .or_default()
+                        .extend(images.into_iter().map(|(_, _, copy)| copy));
+                }
+            }
+        } else {
+            self.0.glyph_caches.remove(&window);
+            self.0.cache.window_images.remove(&window);
+            self.0.cache.removed_images.remove(&window);
+        }
+
+        self.0.event_queue.push_back(
+            Event::new(WindowEvent::RendererReset)
+                .target(window)
+                .origin(window)
+                .propagate(Propagation::Subtree),
+        );
+
+        self.needs_refresh();
+    }
+
     /// Returns a reference to the [`Environment`] model.
     pub fn environment(&self) -> &Environment {
         self.0.data::<Environment>().unwrap()
@@ -131,7 +295,22 @@ impl<'a> BackendContext<'a> {
 
     /// Calls the draw system.
     pub fn draw(&mut self) {
-        draw_system(self.0);
+        draw_system(self.0, Entity::root());
+    }
+
+    /// Calls the draw system for the window with the given root view.
+    pub fn draw_window(&mut self, entity: Entity) {
+        if entity == Entity::root() {
+            self.draw();
+            return;
+        }
+
+        // Glyphs are rendered into textures of the canvas of each window.
+        let mut glyph_cache = self.0.glyph_caches.remove(&entity).unwrap_or_default();
+        self.0.text_context.swap_glyph_cache(&mut glyph_cache);
+        self.0.with_window_scale_factor(entity, |cx| draw_system(cx, entity));
+        self.0.text_context.swap_glyph_cache(&mut glyph_cache);
+        self.0.glyph_caches.insert(entity, glyph_cache);
     }
 
     /// Set the current entity. This is useful in user code when you're performing black magic and
@@ -146,9 +325,40 @@ impl<'a> BackendContext<'a> {
         self.0.text_config = text_config;
     }
 
+    /// Sets the information about the graphics driver used to render the application.
+    pub fn set_renderer_info(&mut self, renderer_info: RendererInfo) {
+        self.0.renderer_info = renderer_info;
+    }
+
+    /// Sets the context used to run the fragment shaders of views in the window with the given root
+    /// view. Without one, views with a [`FragmentShader`] effect are drawn without the effect.
+    pub fn set_shader_context(&mut self, window: Entity, shader_context: ShaderContext) {
+        self.0.shader_contexts.insert(window, shader_context);
+    }
+
+    /// Returns the scale factor used by the application.
+    pub fn scale_factor(&self) -> f32 {
+        self.0.scale_factor()
+    }
+
     /// Sets the scale factor used by the application.
     pub fn set_scale_factor(&mut self, scale: f64) {
         self.0.style.dpi_factor = scale;
+        self.0.style.needs_redraw();
+    }
+
+    /// Sets the scale factor of the window with the given root view, such as when it moves to a
+    /// display with a different scale factor. The views of each window are laid out and drawn with
+    /// the scale factor of their window.
+    pub fn set_window_scale_factor(&mut self, entity: Entity, scale: f64) {
+        if entity == Entity::root() {
+            self.set_scale_factor(scale);
+            return;
+        }
+
+        self.0.style.window_dpi_factors.insert(entity, scale);
+        self.0.style.needs_relayout();
+        self.0.style.needs_redraw();
     }
 
     /// Sets the size of the root window.
@@ -162,6 +372,52 @@ impl<'a> BackendContext<'a> {
         self.0.style.height.insert(Entity::root(), Units::Pixels(logical_height));
     }
 
+    /// Sets the size of the window with the given root view.
+    pub fn set_window_size_of(
+        &mut self,
+        entity: Entity,
+        physical_width: f32,
+        physical_height: f32,
+    ) {
+        if entity == Entity::root() {
+            self.set_window_size(physical_width, physical_height);
+            return;
+        }
+
+        self.0.cache.set_width(entity, physical_width);
+        self.0.cache.set_height(entity, physical_height);
+
+        let scale_factor = self.0.style.window_scale_factor(entity) as f32;
+        let logical_width = physical_width / scale_factor;
+        let logical_height = physical_height / scale_factor;
+        self.0.style.width.insert(entity, Units::Pixels(logical_width));
+        self.0.style.height.insert(entity, Units::Pixels(logical_height));
+    }
+
+    /// Sets the window which the mouse cursor is in, within which views are hovered.
+    pub fn set_cursor_window(&mut self, entity: Entity) {
+        self.0.cursor_window = entity;
+    }
+
+    /// Sets whether the window with the given root view is focused. When a window gains focus while
+    /// a view in another window is focused, focus moves to the root view of the window. When a window
+    /// loses focus, held keys are released, as their key up events are not received.
+    pub fn set_window_focus(&mut self, entity: Entity, focused: bool) {
+        self.0.window_has_focus = focused;
+        if !focused {
+            self.0.keys_down.clear();
+            stop_all_key_repeats(self.0);
+        }
+        if focused && self.0.tree.window(self.0.focused) != entity {
+            self.0.with_current(entity, |cx| cx.focus_with_visibility(false));
+        }
+    }
+
+    /// Sends a message directly to the root view of a window.
+    pub fn emit_window_event<M: Send + Any>(&mut self, entity: Entity, message: M) {
+        self.0.event_queue.push_back(Event::new(message).direct(entity));
+    }
+
     /// Temporarily sets the current entity, calls the provided closure, and then resets the current entity back to previous.
     pub fn with_current(&mut self, e: Entity, f: impl FnOnce(&mut Context)) {
         let prev = self.0.current;
@@ -197,6 +453,18 @@ impl<'a> BackendContext<'a> {
         !self.0.event_queue.is_empty()
     }
 
+    /// Returns the type names of the messages of the events in the queue, for diagnosing events which
+    /// are repeatedly re-queued.
+    pub fn queued_event_types(&self) -> Vec<&'static str> {
+        self.0.event_queue.iter().map(|event| event.type_name).collect()
+    }
+
+    /// Returns the nodes of the initial accessibility tree of the main window, which are the root
+    /// node and the live regions used to announce messages.
+    pub fn initial_access_nodes(&mut self) -> Vec<(accesskit::NodeId, accesskit::Node)> {
+        initial_access_nodes(self.0)
+    }
+
     /// Returns a mutable reference to the accesskit node classes.
     pub fn accesskit_node_classes(&mut self) -> &mut accesskit::NodeClassSet {
         &mut self.style().accesskit_node_classes
@@ -204,8 +472,42 @@ impl<'a> BackendContext<'a> {
 
     /// Calls the event manager to process any queued events.
     pub fn process_events(&mut self) {
+        // Focus any view which was marked to be autofocused when it was built.
+        if let Some(entity) = self.0.autofocus.take() {
+            self.0.with_current(entity, |cx| cx.focus_with_visibility(false));
+            self.0.tree_updates.push(accesskit::TreeUpdate {
+                nodes: vec![],
+                tree: None,
+                focus: self.0.window_has_focus.then_some(entity.accesskit_id()),
+            });
+        }
+
+        // The number of events which can still be dispatched in this frame.
+        let mut budget = self.0.max_events_per_frame;
+
         if let Some(event_manager) = &mut self.1 {
-            while event_manager.flush_events(self.0) {}
+            while event_manager.flush_events(self.0, &mut budget) {}
+        }
+
+        // Notify views when focus moves into or out of a view which accepts text input.
+        let keyboard_requested = self.accepts_text_input(self.0.focused);
+        if keyboard_requested != self.0.keyboard_requested {
+            self.0.keyboard_requested = keyboard_requested;
+            self.0.event_queue.push_back(
+                Event::new(WindowEvent::KeyboardRequested(keyboard_requested))
+                    .target(Entity::root())
+                    .origin(Entity::root())
+                    .propagate(Propagation::Subtree),
+            );
+
+            if let Some(event_manager) = &mut self.1 {
+                while event_manager.flush_events(self.0, &mut budget) {}
+            }
+        }
+
+        // Events left in the queue once the budget is used up are dispatched in the next frame.
+        if budget == Some(0) {
+            self.0.deferred_events += self.0.event_queue.len();
         }
     }
 
@@ -218,6 +520,7 @@ impl<'a> BackendContext<'a> {
     /// Calls the accessibility system and updates the accesskit node tree.
     pub fn process_tree_updates(&mut self, process: impl Fn(&Vec<accesskit::TreeUpdate>)) {
         accessibility_system(self.0);
+        announcement_system(self.0);
 
         (process)(&self.0.tree_updates);
 
@@ -239,21 +542,54 @@ impl<'a> BackendContext<'a> {
 
     // Returns true if animations are playing
     pub fn process_animations(&mut self) -> bool {
-        animation_system(self.0)
+        let animating = animation_system(self.0);
+        let repeating = key_repeat_system(self.0);
+        let tweening = tween_system(self.0);
+
+        // Remove any views which have finished fading out.
+        for entity in self.0.style.finish_fades() {
+            self.0.remove(entity);
+        }
+
+        animating || repeating || tweening
+    }
+
+    /// Returns the time at which the caret of the focused text input next blinks, or `None` if no
+    /// caret is blinking.
+    ///
+    /// A blinking caret does not cause [`process_animations`](Self::process_animations) to return
+    /// true. Instead, backends should wake up at this time, e.g. with `ControlFlow::WaitUntil`.
+    pub fn next_caret_blink(&self) -> Option<Instant> {
+        self.0.next_caret_blink
     }
 
     /// Massages the style system until everything is coherent
     pub fn process_visual_updates(&mut self) {
+        // Warn about a leak if the number of entities keeps growing.
+        self.0.check_entity_growth();
+
         // Perform layout.
         layout_system(self.0);
+
+        // Compose the transforms of views for drawing and hit-testing.
+        transform_system(self.0);
+
+        // Views may have moved, or been hidden, under a cursor which hasn't, so hovering is updated
+        // whenever the window is redrawn.
+        if self.0.style.system_flags.intersects(SystemFlags::REDRAW | SystemFlags::REDRAW_PARTIAL) {
+            hover_system(self.0);
+        }
     }
 
+    /// Emits a message from the root view, capturing the current keyboard modifiers in the
+    /// [`EventMeta`](crate::events::EventMeta) of the event.
     pub fn emit_origin<M: Send + Any>(&mut self, message: M) {
         self.0.event_queue.push_back(
             Event::new(message)
                 .target(self.0.current)
                 .origin(Entity::root())
-                .propagate(Propagation::Up),
+                .propagate(Propagation::Up)
+                .modifiers(self.0.modifiers),
         );
     }
 
@@ -0,0 +1,111 @@
+use std::error::Error;
+
+/// The error returned by clipboard operations.
+pub type ClipboardError = Box<dyn Error + Send + Sync + 'static>;
+
+/// A raster image stored on the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+    /// The pixels of the image, as rows of 8-bit RGBA values from top to bottom.
+    pub rgba: Vec<u8>,
+    /// The width of the image in pixels.
+    pub width: u32,
+    /// The height of the image in pixels.
+    pub height: u32,
+}
+
+/// Provides access to the system clipboard.
+///
+/// By default the clipboard is provided by [`ArboardClipboard`], which supports both text and
+/// images, or by [`LocalClipboard`] where the system clipboard is not available. Windowing backends
+/// can replace the provider with
+/// [`set_clipboard_provider`](crate::backend::BackendContext::set_clipboard_provider).
+pub trait ClipboardProvider: Send {
+    /// Returns the text contents of the clipboard.
+    fn get_contents(&mut self) -> Result<String, ClipboardError>;
+
+    /// Replaces the contents of the clipboard with the given text.
+    fn set_contents(&mut self, text: String) -> Result<(), ClipboardError>;
+
+    /// Returns the image stored on the clipboard.
+    ///
+    /// The default implementation returns an error for providers without image support.
+    fn get_image(&mut self) -> Result<ClipboardImage, ClipboardError> {
+        Err("reading images is not supported by this clipboard provider".into())
+    }
+
+    /// Replaces the contents of the clipboard with an image made of `width * height` RGBA pixels.
+    ///
+    /// The default implementation returns an error for providers without image support.
+    fn set_image(&mut self, rgba: Vec<u8>, width: u32, height: u32) -> Result<(), ClipboardError> {
+        let _ = (rgba, width, height);
+        Err("writing images is not supported by this clipboard provider".into())
+    }
+}
+
+/// A clipboard provider which keeps its text in memory, so that it is only shared within the
+/// application.
+#[derive(Debug, Default)]
+pub struct LocalClipboard {
+    contents: String,
+}
+
+impl ClipboardProvider for LocalClipboard {
+    fn get_contents(&mut self) -> Result<String, ClipboardError> {
+        Ok(self.contents.clone())
+    }
+
+    fn set_contents(&mut self, text: String) -> Result<(), ClipboardError> {
+        self.contents = text;
+        Ok(())
+    }
+}
+
+/// A clipboard provider backed by `arboard`, which supports both text and images.
+///
+/// On Wayland the clipboard is accessed with the data-control protocol when the `wayland` feature
+/// is enabled, falling back to X11 through XWayland for compositors which do not support it.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct ArboardClipboard {
+    clipboard: arboard::Clipboard,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ArboardClipboard {
+    /// Connects to the system clipboard.
+    pub fn new() -> Result<Self, ClipboardError> {
+        Ok(Self { clipboard: arboard::Clipboard::new()? })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ClipboardProvider for ArboardClipboard {
+    fn get_contents(&mut self) -> Result<String, ClipboardError> {
+        Ok(self.clipboard.get_text()?)
+    }
+
+    fn set_contents(&mut self, text: String) -> Result<(), ClipboardError> {
+        Ok(self.clipboard.set_text(text)?)
+    }
+
+    fn get_image(&mut self) -> Result<ClipboardImage, ClipboardError> {
+        let image = self.clipboard.get_image()?;
+        Ok(ClipboardImage {
+            rgba: image.bytes.into_owned(),
+            width: image.width as u32,
+            height: image.height as u32,
+        })
+    }
+
+    fn set_image(&mut self, rgba: Vec<u8>, width: u32, height: u32) -> Result<(), ClipboardError> {
+        if rgba.len() != width as usize * height as usize * 4 {
+            return Err("the image data does not match the given width and height".into());
+        }
+
+        Ok(self.clipboard.set_image(arboard::ImageData {
+            width: width as usize,
+            height: height as usize,
+            bytes: rgba.into(),
+        })?)
+    }
+}
@@ -1,12 +1,12 @@
 use cosmic_text::FamilyOwned;
-use femtovg::{ImageId, Transform2D};
+use femtovg::{ImageId, Solidity, Transform2D};
 use std::any::{Any, TypeId};
 
 use fnv::FnvHashMap;
 use morphorm::Units;
 
 use crate::animation::Interpolator;
-use crate::cache::CachedData;
+use crate::cache::{CachedData, GradientImage, ImageLayer};
 use crate::events::ViewHandler;
 use crate::model::ModelDataStore;
 use crate::prelude::*;
@@ -14,11 +14,13 @@ use crate::resource::{ImageOrId, ResourceManager};
 use crate::style::{ImageOrGradient, IntoTransform, Style};
 use crate::text::{TextConfig, TextContext};
 use crate::vg::{Paint, Path};
+use crate::view::ShaderContext;
 use vizia_input::{Modifiers, MouseState};
 use vizia_storage::SparseSet;
 use vizia_style::{
-    BackgroundSize, BoxShadow, ClipPath, Filter, Gradient, HorizontalPositionKeyword,
-    LengthPercentageOrAuto, LineDirection, VerticalPositionKeyword,
+    BackgroundSize, BoxShadow, ClipPath, DimensionPercentage, Filter, Gradient,
+    HorizontalPositionKeyword, ImageRendering, LengthPercentageOrAuto, LineDirection, Rect,
+    VerticalPositionKeyword,
 };
 
 /// A context used when drawing.
@@ -64,6 +66,16 @@ pub struct DrawContext<'a> {
     pub(crate) modifiers: &'a Modifiers,
     pub(crate) mouse: &'a MouseState<Entity>,
     pub(crate) opacity: f32,
+    // The render target views are drawn to, an offscreen layer within views with a shader effect.
+    pub(crate) render_target: femtovg::RenderTarget,
+    // The area of the window being redrawn when only part of the window is redrawn.
+    pub(crate) dirty_rect: Option<BoundingBox>,
+    // The root view of the window being drawn.
+    pub(crate) window: Entity,
+    // Used to run the fragment shaders of views, if supported by the window.
+    pub(crate) shader_context: Option<&'a mut ShaderContext>,
+    // Whether a view is being drawn as the mask of another view, rather than in its own place.
+    pub(crate) drawing_mask: bool,
 }
 
 macro_rules! get_units_property {
@@ -98,6 +110,29 @@ macro_rules! get_color_property {
             }
         }
     };
+
+    // A property of one side of the view, which is read from the opposite side when the view has a
+    // right-to-left direction.
+    (
+        $(#[$meta:meta])*
+        $name:ident, mirrored: $mirrored:ident
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> Color {
+            let opacity = self.opacity();
+            let color = if self.is_rtl() {
+                self.style.$mirrored.get(self.current)
+            } else {
+                self.style.$name.get(self.current)
+            };
+
+            if let Some(col) = color {
+                Color::rgba(col.r(), col.g(), col.b(), (opacity * col.a() as f32) as u8)
+            } else {
+                Color::rgba(0, 0, 0, 0)
+            }
+        }
+    };
 }
 
 macro_rules! get_length_property {
@@ -117,6 +152,31 @@ macro_rules! get_length_property {
             0.0
         }
     };
+
+    // A property of one side or corner of the view, which is read from the opposite side or corner
+    // when the view has a right-to-left direction.
+    (
+        $(#[$meta:meta])*
+        $name:ident, mirrored: $mirrored:ident
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> f32 {
+            let length = if self.is_rtl() {
+                self.style.$mirrored.get(self.current)
+            } else {
+                self.style.$name.get(self.current)
+            };
+
+            if let Some(length) = length {
+                let bounds = self.bounds();
+
+                let px = length.to_pixels(bounds.w.min(bounds.h), self.scale_factor());
+                return px.round();
+            }
+
+            0.0
+        }
+    };
 }
 
 impl<'a> DrawContext<'a> {
@@ -130,6 +190,12 @@ impl<'a> DrawContext<'a> {
         self.style.dpi_factor as f32
     }
 
+    // Returns whether the current view has a right-to-left direction, in which case its horizontal
+    // layout is mirrored along with its left and right borders and corners.
+    fn is_rtl(&self) -> bool {
+        self.style.direction.get(self.current).copied() == Some(Direction::Rtl)
+    }
+
     /// Returns a reference to the keyboard modifiers state.
     pub fn modifiers(&self) -> &Modifiers {
         self.modifiers
@@ -148,46 +214,45 @@ impl<'a> DrawContext<'a> {
 
         // let root_bounds = self.cache.get_bounds(Entity::root());
 
-        let scale = self.scale_factor();
-
-        let clip_bounds = self
-            .style
-            .clip_path
-            .get(self.current)
-            .map(|clip| match clip {
-                ClipPath::Auto => bounds,
-                ClipPath::Shape(rect) => bounds.shrink_sides(
-                    rect.3.to_pixels(bounds.w, scale),
-                    rect.0.to_pixels(bounds.h, scale),
-                    rect.1.to_pixels(bounds.w, scale),
-                    rect.2.to_pixels(bounds.h, scale),
-                ),
-            })
-            .unwrap_or(bounds);
+        let clip_shape = self.clip_shape();
+        let clip_bounds = clip_shape.map_or(bounds, |(shape, _)| shape);
 
         let root_bounds: BoundingBox =
             BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
 
-        match (overflowx, overflowy) {
+        let region = match (overflowx, overflowy) {
             (Overflow::Visible, Overflow::Visible) => root_bounds,
-            (Overflow::Hidden, Overflow::Visible) => {
+            (Overflow::Hidden | Overflow::Scroll, Overflow::Visible) => {
                 let left = clip_bounds.left();
                 let right = clip_bounds.right();
                 let top = root_bounds.top();
                 let bottom = root_bounds.bottom();
                 BoundingBox::from_min_max(left, top, right, bottom)
             }
-            (Overflow::Visible, Overflow::Hidden) => {
+            (Overflow::Visible, Overflow::Hidden | Overflow::Scroll) => {
                 let left = root_bounds.left();
                 let right = root_bounds.right();
                 let top = clip_bounds.top();
                 let bottom = clip_bounds.bottom();
                 BoundingBox::from_min_max(left, top, right, bottom)
             }
-            (Overflow::Hidden, Overflow::Hidden) => clip_bounds,
+            _ => clip_bounds,
+        };
+
+        // A clip path clips the view regardless of its overflow.
+        match clip_shape {
+            Some((shape, _)) => region.intersection(&shape),
+            None => region,
         }
     }
 
+    /// Returns the bounds of the clip path of the current view, along with the radius of its corners,
+    /// or `None` if the view has no clip path.
+    pub(crate) fn clip_shape(&self) -> Option<(BoundingBox, f32)> {
+        let clip_path = self.style.clip_path.get(self.current)?;
+        clip_shape(clip_path, self.bounds(), self.scale_factor())
+    }
+
     /// Returns the 2D transform of the current view.
     pub fn transform(&self) -> Transform2D {
         let mut transform = Transform2D::identity();
@@ -235,7 +300,7 @@ impl<'a> DrawContext<'a> {
                     if let Some(end) = animation_state.keyframes.last() {
                         let start_transform = start.value.as_transform(bounds, scale_factor);
                         let end_transform = end.value.as_transform(bounds, scale_factor);
-                        let t = animation_state.t;
+                        let t = start.timing_function.value(animation_state.t);
                         let animated_transform =
                             Transform2D::interpolate(&start_transform, &end_transform, t);
                         transform.premultiply(&animated_transform);
@@ -251,6 +316,66 @@ impl<'a> DrawContext<'a> {
         transform
     }
 
+    // Returns the area of the window drawn to by the current view, including its outline, any outer
+    // shadows, and any blur, after the view is transformed.
+    pub(crate) fn draw_region(&self) -> BoundingBox {
+        let scale_factor = self.scale_factor();
+        let shadow_extent =
+            |x: &Length, y: &Length, blur: Option<&Length>, spread: Option<&Length>| {
+                let px = |length: &Length| length.to_px().unwrap_or_default() * scale_factor;
+                px(x).abs().max(px(y).abs())
+                    + 2.0 * blur.map(px).unwrap_or_default()
+                    + spread.map(px).unwrap_or_default()
+            };
+
+        let mut extent = self.outline_width() + self.outline_offset().max(0.0);
+
+        if let Some(box_shadows) = self.box_shadows() {
+            for box_shadow in box_shadows.iter().filter(|box_shadow| !box_shadow.inset) {
+                extent = extent.max(shadow_extent(
+                    &box_shadow.x_offset,
+                    &box_shadow.y_offset,
+                    box_shadow.blur_radius.as_ref(),
+                    box_shadow.spread_radius.as_ref(),
+                ));
+            }
+        }
+
+        match self.style.filter.get(self.current) {
+            Some(Filter::DropShadow { x_offset, y_offset, blur_radius, .. }) => {
+                extent = extent.max(shadow_extent(x_offset, y_offset, blur_radius.as_ref(), None));
+            }
+
+            Some(Filter::Blur(blur_radius)) => {
+                extent = extent.max(shadow_extent(
+                    &Length::px(0.0),
+                    &Length::px(0.0),
+                    Some(blur_radius),
+                    None,
+                ));
+            }
+
+            None => {}
+        }
+
+        // Antialiasing can reach just past the edges of the view.
+        let bounds = self.bounds().expand(extent + 2.0);
+        let transform = self.cache.get_transform(self.current);
+        let corners =
+            [bounds.top_left(), bounds.top_right(), bounds.bottom_left(), bounds.bottom_right()];
+
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+        for (x, y) in corners {
+            let (x, y) = transform.transform_point(x, y);
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+
+        BoundingBox::from_min_max(min_x, min_y, max_x, max_y)
+    }
+
     /// Returns the visibility of the current view.
     pub fn visibility(&self) -> Option<Visibility> {
         self.style.visibility.get(self.current).copied()
@@ -289,10 +414,37 @@ impl<'a> DrawContext<'a> {
     }
 
     get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+        /// Returns the width of the top border of the current view in physical pixels.
+        border_top_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the right border of the current view in physical pixels.
+        ///
+        /// For a view with a right-to-left direction this is the width of the left border.
+        border_right_width,
+        mirrored: border_left_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the bottom border of the current view in physical pixels.
+        border_bottom_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the left border of the current view in physical pixels.
+        ///
+        /// For a view with a right-to-left direction this is the width of the right border.
+        border_left_width,
+        mirrored: border_right_width
     );
 
+    /// Returns the width of the top border of the current view in physical pixels.
+    #[deprecated(note = "Use the per-side accessors such as `border_top_width` instead")]
+    pub fn border_width(&self) -> f32 {
+        self.border_top_width()
+    }
+
     get_color_property!(
         /// Returns the outline color of the current view.
         outline_color
@@ -310,42 +462,70 @@ impl<'a> DrawContext<'a> {
 
     get_length_property!(
         /// Returns the border radius for the top-left corner of the current view.
-        border_top_left_radius
+        ///
+        /// For a view with a right-to-left direction this is the radius of the top-right corner.
+        border_top_left_radius,
+        mirrored: border_top_right_radius
     );
 
     get_length_property!(
         /// Returns the border radius for the top-right corner of the current view.
-        border_top_right_radius
+        ///
+        /// For a view with a right-to-left direction this is the radius of the top-left corner.
+        border_top_right_radius,
+        mirrored: border_top_left_radius
     );
 
     get_length_property!(
         /// Returns the border radius for the bottom-left corner of the current view.    
-        border_bottom_left_radius
+        ///
+        /// For a view with a right-to-left direction this is the radius of the bottom-right corner.
+        border_bottom_left_radius,
+        mirrored: border_bottom_right_radius
     );
 
     get_length_property!(
         /// Returns the border radius for the bottom-right corner of the current view.
-        border_bottom_right_radius
+        ///
+        /// For a view with a right-to-left direction this is the radius of the bottom-left corner.
+        border_bottom_right_radius,
+        mirrored: border_bottom_left_radius
     );
 
     /// Returns the border corner shape for the top-left corner of the current view.
     pub fn border_top_left_shape(&self) -> BorderCornerShape {
-        self.style.border_top_left_shape.get(self.current).copied().unwrap_or_default()
+        if self.is_rtl() {
+            self.style.border_top_right_shape.get(self.current).copied().unwrap_or_default()
+        } else {
+            self.style.border_top_left_shape.get(self.current).copied().unwrap_or_default()
+        }
     }
 
     /// Returns the border corner shape for the top-left corner of the current view.
     pub fn border_top_right_shape(&self) -> BorderCornerShape {
-        self.style.border_top_right_shape.get(self.current).copied().unwrap_or_default()
+        if self.is_rtl() {
+            self.style.border_top_left_shape.get(self.current).copied().unwrap_or_default()
+        } else {
+            self.style.border_top_right_shape.get(self.current).copied().unwrap_or_default()
+        }
     }
 
     /// Returns the border corner shape for the top-left corner of the current view.
     pub fn border_bottom_left_shape(&self) -> BorderCornerShape {
-        self.style.border_bottom_left_shape.get(self.current).copied().unwrap_or_default()
+        if self.is_rtl() {
+            self.style.border_bottom_right_shape.get(self.current).copied().unwrap_or_default()
+        } else {
+            self.style.border_bottom_left_shape.get(self.current).copied().unwrap_or_default()
+        }
     }
 
     /// Returns the border corner shape for the top-left corner of the current view.
     pub fn border_bottom_right_shape(&self) -> BorderCornerShape {
-        self.style.border_bottom_right_shape.get(self.current).copied().unwrap_or_default()
+        if self.is_rtl() {
+            self.style.border_bottom_left_shape.get(self.current).copied().unwrap_or_default()
+        } else {
+            self.style.border_bottom_right_shape.get(self.current).copied().unwrap_or_default()
+        }
     }
 
     get_units_property!(
@@ -369,7 +549,16 @@ impl<'a> DrawContext<'a> {
     );
 
     get_color_property!(background_color);
-    get_color_property!(border_color);
+    get_color_property!(border_top_color);
+    get_color_property!(border_right_color, mirrored: border_left_color);
+    get_color_property!(border_bottom_color);
+    get_color_property!(border_left_color, mirrored: border_right_color);
+
+    /// Returns the color of the top border of the current view.
+    #[deprecated(note = "Use the per-side accessors such as `border_top_color` instead")]
+    pub fn border_color(&self) -> Color {
+        self.border_top_color()
+    }
 
     get_color_property!(selection_color);
     get_color_property!(caret_color);
@@ -407,7 +596,10 @@ impl<'a> DrawContext<'a> {
 
         let bounds = self.bounds();
 
-        let border_width = self.border_width();
+        let border_top_width = self.border_top_width();
+        let border_right_width = self.border_right_width();
+        let border_bottom_width = self.border_bottom_width();
+        let border_left_width = self.border_left_width();
 
         let border_top_left_radius = self.border_top_left_radius();
         let border_top_right_radius = self.border_top_right_radius();
@@ -427,13 +619,21 @@ impl<'a> DrawContext<'a> {
             && border_bottom_right_radius == bounds.w / 2.0
             && border_top_left_radius == bounds.h / 2.0
             && border_top_right_radius == bounds.h / 2.0
+            && border_top_width == border_right_width
+            && border_top_width == border_bottom_width
+            && border_top_width == border_left_width
         {
-            path.circle(bounds.center().0, bounds.center().1, bounds.w / 2.0 - border_width / 2.0);
+            path.circle(
+                bounds.center().0,
+                bounds.center().1,
+                bounds.w / 2.0 - border_top_width / 2.0,
+            );
         } else {
-            let x = bounds.x + border_width / 2.0;
-            let y = bounds.y + border_width / 2.0;
-            let w = bounds.w - border_width;
-            let h = bounds.h - border_width;
+            // The path runs through the middle of each border.
+            let x = bounds.x + border_left_width / 2.0;
+            let y = bounds.y + border_top_width / 2.0;
+            let w = bounds.w - (border_left_width + border_right_width) / 2.0;
+            let h = bounds.h - (border_top_width + border_bottom_width) / 2.0;
             let halfw = w.abs() * 0.5;
             let halfh = h.abs() * 0.5;
 
@@ -525,12 +725,13 @@ impl<'a> DrawContext<'a> {
 
     /// Draw backdrop filters for the current view.
     pub fn draw_backdrop_filter(&mut self, canvas: &mut Canvas, path: &mut Path) {
-        let window_width = self.cache.get_width(Entity::root());
-        let window_height = self.cache.get_height(Entity::root());
+        let window_width = self.cache.get_width(self.window);
+        let window_height = self.cache.get_height(self.window);
         let bounds = self.bounds();
 
-        let blur_radius = self.backdrop_filter().map(|filter| match filter {
-            Filter::Blur(r) => r.to_px().unwrap_or_default(),
+        let blur_radius = self.backdrop_filter().and_then(|filter| match filter {
+            Filter::Blur(r) => Some(r.to_px().unwrap_or_default()),
+            Filter::DropShadow { .. } => None,
         });
 
         if let Some(blur_radius) = blur_radius {
@@ -635,7 +836,7 @@ impl<'a> DrawContext<'a> {
                 source
             };
             canvas.restore();
-            canvas.set_render_target(femtovg::RenderTarget::Screen);
+            canvas.set_render_target(self.render_target);
 
             canvas.fill_path(
                 path,
@@ -656,14 +857,18 @@ impl<'a> DrawContext<'a> {
     pub fn draw_text_and_selection(&mut self, canvas: &mut Canvas) {
         if self.text_context.has_buffer(self.current) {
             let mut bounds = self.bounds();
-            let border_width = self.border_width();
 
             // let mut box_x = bounds.x + border_width;
             // let mut box_y = bounds.y + border_width;
             // let mut box_w = bounds.w - border_width * 2.0;
             // let mut box_h = bounds.h - border_width * 2.0;
 
-            bounds = bounds.shrink(border_width);
+            bounds = bounds.shrink_sides(
+                self.border_left_width(),
+                self.border_top_width(),
+                self.border_right_width(),
+                self.border_bottom_width(),
+            );
 
             let child_left = self.child_left();
             let child_right = self.child_right();
@@ -721,22 +926,147 @@ impl<'a> DrawContext<'a> {
             // let origin_x = box_x;
             // let origin_y = box_y;
 
-            self.text_context.sync_styles(self.current, self.style);
+            self.text_context.sync_styles(self.current, self.style, self.scale_factor());
+            self.text_context.set_draw_layout(
+                self.current,
+                canvas.transform(),
+                bounds,
+                (justify_x, justify_y),
+            );
 
             self.draw_text_selection(canvas, bounds, (justify_x, justify_y));
-            self.draw_text_caret(canvas, bounds, (justify_x, justify_y), 1.0);
             self.draw_text(canvas, bounds, (justify_x, justify_y));
+            self.draw_text_caret(canvas, bounds, (justify_x, justify_y), 1.0);
         }
     }
 
     /// Draw the border of the current view.
+    ///
+    /// A border with the same width and color on every side is stroked along the path of the view.
+    /// A border with a single color but different widths is filled between the outer and inner edges
+    /// of the border. Otherwise each side is filled separately, with square corners which are split
+    /// diagonally between adjacent sides.
     pub fn draw_border(&mut self, canvas: &mut Canvas, path: &mut Path) {
-        let border_color = self.border_color();
-        let border_width = self.border_width();
+        let (top, right, bottom, left) = (
+            self.border_top_width(),
+            self.border_right_width(),
+            self.border_bottom_width(),
+            self.border_left_width(),
+        );
+
+        let (top_color, right_color, bottom_color, left_color) = (
+            self.border_top_color(),
+            self.border_right_color(),
+            self.border_bottom_color(),
+            self.border_left_color(),
+        );
 
-        let mut paint = Paint::color(border_color.into());
-        paint.set_line_width(border_width);
-        canvas.stroke_path(path, &paint);
+        let same_color =
+            top_color == right_color && top_color == bottom_color && top_color == left_color;
+
+        if same_color && top == right && top == bottom && top == left {
+            let mut paint = Paint::color(top_color.into());
+            paint.set_line_width(top);
+            canvas.stroke_path(path, &paint);
+            return;
+        }
+
+        let bounds = self.bounds();
+        let inner = bounds.shrink_sides(left, top, right, bottom);
+
+        if same_color {
+            let border_top_left_radius = self.border_top_left_radius();
+            let border_top_right_radius = self.border_top_right_radius();
+            let border_bottom_right_radius = self.border_bottom_right_radius();
+            let border_bottom_left_radius = self.border_bottom_left_radius();
+
+            let mut border_path = Path::new();
+            border_path.rounded_rect_varying(
+                bounds.x,
+                bounds.y,
+                bounds.w,
+                bounds.h,
+                border_top_left_radius,
+                border_top_right_radius,
+                border_bottom_right_radius,
+                border_bottom_left_radius,
+            );
+            border_path.rounded_rect_varying(
+                inner.x,
+                inner.y,
+                inner.w.max(0.0),
+                inner.h.max(0.0),
+                (border_top_left_radius - top.max(left)).max(0.0),
+                (border_top_right_radius - top.max(right)).max(0.0),
+                (border_bottom_right_radius - bottom.max(right)).max(0.0),
+                (border_bottom_left_radius - bottom.max(left)).max(0.0),
+            );
+            border_path.solidity(Solidity::Hole);
+            canvas.fill_path(&border_path, &Paint::color(top_color.into()));
+            return;
+        }
+
+        let (outer_left, outer_top, outer_right, outer_bottom) =
+            (bounds.left(), bounds.top(), bounds.right(), bounds.bottom());
+        let (inner_left, inner_top, inner_right, inner_bottom) =
+            (inner.left(), inner.top(), inner.right(), inner.bottom());
+
+        let sides = [
+            (
+                top,
+                top_color,
+                [
+                    (outer_left, outer_top),
+                    (outer_right, outer_top),
+                    (inner_right, inner_top),
+                    (inner_left, inner_top),
+                ],
+            ),
+            (
+                right,
+                right_color,
+                [
+                    (outer_right, outer_top),
+                    (outer_right, outer_bottom),
+                    (inner_right, inner_bottom),
+                    (inner_right, inner_top),
+                ],
+            ),
+            (
+                bottom,
+                bottom_color,
+                [
+                    (outer_right, outer_bottom),
+                    (outer_left, outer_bottom),
+                    (inner_left, inner_bottom),
+                    (inner_right, inner_bottom),
+                ],
+            ),
+            (
+                left,
+                left_color,
+                [
+                    (outer_left, outer_bottom),
+                    (outer_left, outer_top),
+                    (inner_left, inner_top),
+                    (inner_left, inner_bottom),
+                ],
+            ),
+        ];
+
+        for (width, color, corners) in sides {
+            if width <= 0.0 {
+                continue;
+            }
+
+            let mut side_path = Path::new();
+            side_path.move_to(corners[0].0, corners[0].1);
+            for (x, y) in corners.iter().skip(1) {
+                side_path.line_to(*x, *y);
+            }
+            side_path.close();
+            canvas.fill_path(&side_path, &Paint::color(color.into()));
+        }
     }
 
     /// Draw the outline of the current view.
@@ -898,7 +1228,7 @@ impl<'a> DrawContext<'a> {
                     source
                 };
 
-                canvas.set_render_target(femtovg::RenderTarget::Screen);
+                canvas.set_render_target(self.render_target);
                 canvas.save();
                 let mut shadow_path = Path::new();
                 shadow_path.rect(
@@ -1057,7 +1387,18 @@ impl<'a> DrawContext<'a> {
                     source
                 };
 
-                canvas.set_render_target(femtovg::RenderTarget::Screen);
+                // Cut the border box out of the shadow so that it only shows outside of the view, such
+                // as when the background of the view is translucent.
+                canvas.save();
+                canvas.set_render_target(femtovg::RenderTarget::Image(target_image));
+                canvas.reset_scissor();
+                canvas.reset_transform();
+                canvas.translate(-bounds.x + d / 2.0 - x_offset, -bounds.y + d / 2.0 - y_offset);
+                canvas.global_composite_operation(femtovg::CompositeOperation::DestinationOut);
+                canvas.fill_path(path, &Paint::color(femtovg::Color::black()));
+                canvas.restore();
+
+                canvas.set_render_target(self.render_target);
                 canvas.save();
                 canvas.translate(x_offset, y_offset);
                 let mut shadow_path = Path::new();
@@ -1088,171 +1429,503 @@ impl<'a> DrawContext<'a> {
     }
 
     /// Draw background images (including gradients) for the current view.
-    fn draw_background_images(&self, canvas: &mut Canvas, path: &mut Path) {
+    fn draw_background_images(&mut self, canvas: &mut Canvas, path: &mut Path) {
+        let style = self.style;
+        if let Some(images) = style.background_image.get(self.current) {
+            self.draw_images(
+                canvas,
+                path,
+                ImageLayer::Background,
+                images,
+                self.background_size(),
+                false,
+            );
+        } else {
+            self.remove_gradient_images(canvas, ImageLayer::Background, &[]);
+        }
+    }
+
+    /// Draws the mask images of the current view over the current render target, removing the
+    /// parts of the target which the mask hides. Anything outside the bounds of the view is removed.
+    pub(crate) fn draw_mask(&mut self, canvas: &mut Canvas) {
+        let style = self.style;
+        if let Some(images) = style.mask_image.get(self.current) {
+            let bounds = self.bounds();
+            let luminance = self.style.mask_mode.get(self.current) == Some(&MaskMode::Luminance);
+
+            canvas.save();
+            canvas.reset_scissor();
+            canvas.global_composite_operation(femtovg::CompositeOperation::DestinationIn);
+
+            let mut path = Path::new();
+            path.rect(bounds.x, bounds.y, bounds.w, bounds.h);
+            self.draw_images(canvas, &mut path, ImageLayer::Mask, images, Vec::new(), luminance);
+
+            // Remove anything outside of the bounds of the view.
+            let mut outside = Path::new();
+            outside.rect(-1.0e6, -1.0e6, 2.0e6, 2.0e6);
+            outside.rect(bounds.x, bounds.y, bounds.w, bounds.h);
+            outside.solidity(femtovg::Solidity::Hole);
+            canvas.fill_path(&outside, &Paint::color(femtovg::Color::rgba(0, 0, 0, 0)));
+
+            canvas.restore();
+        } else {
+            self.remove_gradient_images(canvas, ImageLayer::Mask, &[]);
+        }
+    }
+
+    // Removes anything outside of the rounded clip path of the current view from the layer it was
+    // drawn into, as the scissor of the canvas can only clip to a rectangle.
+    pub(crate) fn draw_clip_path(&mut self, canvas: &mut Canvas) {
+        if let Some((shape, radius)) = self.clip_shape() {
+            canvas.save();
+            canvas.reset_scissor();
+            canvas.global_composite_operation(femtovg::CompositeOperation::DestinationIn);
+
+            let mut outside = Path::new();
+            outside.rect(-1.0e6, -1.0e6, 2.0e6, 2.0e6);
+            outside.rounded_rect(shape.x, shape.y, shape.w, shape.h, radius);
+            outside.solidity(femtovg::Solidity::Hole);
+            canvas.fill_path(&outside, &Paint::color(femtovg::Color::rgba(0, 0, 0, 0)));
+
+            canvas.restore();
+        }
+    }
+
+    // Fills the given path with a gradient which can't be drawn with a femtovg paint directly. The
+    // gradient is drawn by the given closure into an image the size of the view, which is cached
+    // for the gradient at the given index in the given list of images of the view, and only drawn
+    // again when the size of the view or the gradient changes.
+    #[allow(clippy::too_many_arguments)]
+    fn fill_with_gradient_image(
+        &mut self,
+        canvas: &mut Canvas,
+        path: &Path,
+        layer: ImageLayer,
+        index: usize,
+        gradient: &Gradient,
+        luminance: bool,
+        draw: impl FnOnce(&mut Canvas, f32, f32),
+    ) {
         let bounds = self.bounds();
+        let width = bounds.w.ceil() as usize;
+        let height = bounds.h.ceil() as usize;
+        if width == 0 || height == 0 {
+            return;
+        }
 
+        let scale_factor = self.scale_factor();
         let parent = self.tree.get_layout_parent(self.current).unwrap_or(Entity::root());
-
         let parent_width = self.cache.get_width(parent);
-        let parent_height = self.cache.get_height(parent);
 
-        if let Some(images) = self.background_images() {
-            let image_sizes = self.background_size();
+        let mut gradient_images =
+            self.cache.gradient_images.remove(self.current).unwrap_or_default();
+        let cached = gradient_images.remove(&(layer, index));
+
+        let image = match cached {
+            Some(cached)
+                if canvas.image_size(cached.image).ok() == Some((width, height))
+                    && cached.gradient == *gradient
+                    && cached.luminance == luminance
+                    && cached.scale_factor == scale_factor
+                    && cached.parent_width == parent_width =>
+            {
+                Some(cached.image)
+            }
+
+            cached => {
+                // The image is reused if it is still the size of the view.
+                let image = match cached {
+                    Some(cached)
+                        if canvas.image_size(cached.image).ok() == Some((width, height)) =>
+                    {
+                        Ok(cached.image)
+                    }
+
+                    cached => {
+                        if let Some(cached) = cached {
+                            canvas.delete_image(cached.image);
+                        }
+
+                        canvas.create_image_empty(
+                            width,
+                            height,
+                            femtovg::PixelFormat::Rgba8,
+                            femtovg::ImageFlags::FLIP_Y | femtovg::ImageFlags::PREMULTIPLIED,
+                        )
+                    }
+                };
 
-            for (index, image) in images.iter().enumerate() {
                 match image {
-                    ImageOrGradient::Gradient(gradient) => match gradient {
-                        Gradient::Linear(linear_gradient) => {
-                            let (start_x, start_y, end_x, end_y, parent_length) =
-                                match linear_gradient.direction {
-                                    LineDirection::Horizontal(horizontal_keyword) => {
-                                        match horizontal_keyword {
-                                            HorizontalPositionKeyword::Left => {
-                                                (bounds.w, 0.0, 0.0, 0.0, parent_width)
-                                            }
+                    Ok(image) => {
+                        canvas.save();
+                        canvas.set_render_target(femtovg::RenderTarget::Image(image));
+                        canvas.reset_scissor();
+                        canvas.reset_transform();
+                        canvas.global_composite_operation(femtovg::CompositeOperation::SourceOver);
+                        canvas.clear_rect(
+                            0,
+                            0,
+                            width as u32,
+                            height as u32,
+                            femtovg::Color::rgba(0, 0, 0, 0),
+                        );
+                        draw(canvas, width as f32, height as f32);
+                        canvas.restore();
+                        canvas.set_render_target(self.render_target);
+
+                        Some(image)
+                    }
 
-                                            HorizontalPositionKeyword::Right => {
-                                                (0.0, 0.0, bounds.w, 0.0, parent_width)
-                                            }
-                                        }
-                                    }
+                    Err(err) => {
+                        log::warn!("Failed to create gradient image: {:?}", err);
+                        None
+                    }
+                }
+            }
+        };
+
+        if let Some(image) = image {
+            gradient_images.insert(
+                (layer, index),
+                GradientImage {
+                    image,
+                    gradient: gradient.clone(),
+                    luminance,
+                    scale_factor,
+                    parent_width,
+                },
+            );
 
-                                    LineDirection::Vertical(vertical_keyword) => {
-                                        match vertical_keyword {
-                                            VerticalPositionKeyword::Top => {
-                                                (0.0, bounds.h, 0.0, 0.0, parent_height)
-                                            }
+            let paint =
+                Paint::image(image, bounds.x, bounds.y, width as f32, height as f32, 0.0, 1.0);
+            canvas.fill_path(path, &paint);
+        }
 
-                                            VerticalPositionKeyword::Bottom => {
-                                                (0.0, 0.0, 0.0, bounds.h, parent_height)
-                                            }
-                                        }
-                                    }
+        self.cache.gradient_images.insert(self.current, gradient_images);
+    }
 
-                                    LineDirection::Corner { horizontal, vertical } => {
-                                        match (horizontal, vertical) {
-                                            (
-                                                HorizontalPositionKeyword::Right,
-                                                VerticalPositionKeyword::Bottom,
-                                            ) => (0.0, 0.0, bounds.w, bounds.h, parent_width),
+    // Deletes the cached gradient images of the given list of images of the current view which are
+    // no longer used, because the list has shrunk or the image at their index is not a gradient.
+    fn remove_gradient_images(
+        &mut self,
+        canvas: &mut Canvas,
+        layer: ImageLayer,
+        images: &[ImageOrGradient],
+    ) {
+        if let Some(gradient_images) = self.cache.gradient_images.get_mut(self.current) {
+            gradient_images.retain(|(image_layer, index), gradient_image| {
+                let used = *image_layer != layer
+                    || matches!(images.get(*index), Some(ImageOrGradient::Gradient(_)));
+                if !used {
+                    canvas.delete_image(gradient_image.image);
+                }
 
-                                            _ => (0.0, 0.0, 0.0, 0.0, 0.0),
-                                        }
+                used
+            });
+        }
+    }
+
+    // Draws a list of images and gradients, such as background images, filling the given path.
+    // With `luminance` set, the colors of gradients are converted to alpha based on their luminance.
+    fn draw_images(
+        &mut self,
+        canvas: &mut Canvas,
+        path: &mut Path,
+        layer: ImageLayer,
+        images: &[ImageOrGradient],
+        image_sizes: Vec<BackgroundSize>,
+        luminance: bool,
+    ) {
+        let bounds = self.bounds();
+
+        let parent = self.tree.get_layout_parent(self.current).unwrap_or(Entity::root());
+
+        let parent_width = self.cache.get_width(parent);
+        let parent_height = self.cache.get_height(parent);
+
+        for (index, image) in images.iter().enumerate() {
+            match image {
+                ImageOrGradient::Gradient(gradient) => match gradient {
+                    Gradient::Linear(linear_gradient) => {
+                        let (start_x, start_y, end_x, end_y, parent_length) = match linear_gradient
+                            .direction
+                        {
+                            LineDirection::Horizontal(horizontal_keyword) => {
+                                match horizontal_keyword {
+                                    HorizontalPositionKeyword::Left => {
+                                        (bounds.w, 0.0, 0.0, 0.0, parent_width)
                                     }
 
-                                    LineDirection::Angle(angle) => {
-                                        let angle_rad = angle.to_radians();
-                                        let start_x =
-                                            ((angle_rad.sin() * bounds.w) - bounds.w) / -2.0;
-                                        let end_x = ((angle_rad.sin() * bounds.w) + bounds.w) / 2.0;
-                                        let start_y =
-                                            ((angle_rad.cos() * bounds.h) + bounds.h) / 2.0;
-                                        let end_y =
-                                            ((angle_rad.cos() * bounds.h) - bounds.h) / -2.0;
-
-                                        // TODO: Figure out what the parent length should be.
-                                        (start_x, start_y, end_x, end_y, parent_width)
+                                    HorizontalPositionKeyword::Right => {
+                                        (0.0, 0.0, bounds.w, 0.0, parent_width)
                                     }
-                                };
+                                }
+                            }
 
-                            let num_stops = linear_gradient.stops.len();
+                            LineDirection::Vertical(vertical_keyword) => match vertical_keyword {
+                                VerticalPositionKeyword::Top => {
+                                    (0.0, bounds.h, 0.0, 0.0, parent_height)
+                                }
 
-                            let mut stops = linear_gradient
-                                .stops
-                                .iter()
-                                .enumerate()
-                                .map(|(index, stop)| {
-                                    let pos = if let Some(pos) = &stop.position {
-                                        pos.to_pixels(parent_length, self.scale_factor())
-                                            / parent_length
-                                    } else {
-                                        index as f32 / (num_stops - 1) as f32
-                                    };
-                                    let col: femtovg::Color = stop.color.into();
-                                    (pos, col)
-                                })
-                                .collect::<Vec<_>>();
-
-                            // Insert a stop at the front if the first stop is not at 0.
-                            if let Some(first) = stops.first() {
-                                if first.0 != 0.0 {
-                                    stops.insert(0, (0.0, first.1));
+                                VerticalPositionKeyword::Bottom => {
+                                    (0.0, 0.0, 0.0, bounds.h, parent_height)
                                 }
-                            }
+                            },
 
-                            // Insert a stop at the end if the last stop is not at 1.0.
-                            if let Some(last) = stops.last() {
-                                if last.0 != 1.0 {
-                                    stops.push((1.0, last.1));
+                            LineDirection::Corner { horizontal, vertical } => {
+                                match (horizontal, vertical) {
+                                    (
+                                        HorizontalPositionKeyword::Right,
+                                        VerticalPositionKeyword::Bottom,
+                                    ) => (0.0, 0.0, bounds.w, bounds.h, parent_width),
+
+                                    _ => (0.0, 0.0, 0.0, 0.0, 0.0),
                                 }
                             }
 
-                            let paint = Paint::linear_gradient_stops(
-                                bounds.x + start_x,
-                                bounds.y + start_y,
-                                bounds.x + end_x,
-                                bounds.y + end_y,
-                                stops.into_iter(),
-                            );
+                            LineDirection::Angle(angle) => {
+                                let angle_rad = angle.to_radians();
+                                let start_x = ((angle_rad.sin() * bounds.w) - bounds.w) / -2.0;
+                                let end_x = ((angle_rad.sin() * bounds.w) + bounds.w) / 2.0;
+                                let start_y = ((angle_rad.cos() * bounds.h) + bounds.h) / 2.0;
+                                let end_y = ((angle_rad.cos() * bounds.h) - bounds.h) / -2.0;
 
-                            canvas.fill_path(path, &paint);
-                        }
+                                // TODO: Figure out what the parent length should be.
+                                (start_x, start_y, end_x, end_y, parent_width)
+                            }
+                        };
 
-                        Gradient::Radial(radial_gradient) => {
-                            let num_stops = radial_gradient.stops.len();
-
-                            let mut stops = radial_gradient
-                                .stops
-                                .iter()
-                                .enumerate()
-                                .map(|(index, stop)| {
-                                    let pos = if let Some(pos) = &stop.position {
-                                        pos.to_pixels(parent_width, self.scale_factor())
-                                            / parent_width
-                                    } else {
-                                        index as f32 / (num_stops - 1) as f32
-                                    };
-                                    let col: femtovg::Color = stop.color.into();
-                                    (pos, col)
-                                })
-                                .collect::<Vec<_>>();
-
-                            // Insert a stop at the front if the first stop is not at 0.
-                            if let Some(first) = stops.first() {
-                                if first.0 != 0.0 {
-                                    stops.insert(0, (0.0, first.1));
-                                }
+                        let num_stops = linear_gradient.stops.len();
+
+                        let stops = gradient_stops(linear_gradient.stops.iter().enumerate().map(
+                            |(index, stop)| {
+                                let pos = if let Some(pos) = &stop.position {
+                                    pos.to_pixels(parent_length, self.scale_factor())
+                                        / parent_length
+                                } else {
+                                    index as f32 / (num_stops - 1) as f32
+                                };
+                                let col: femtovg::Color = stop.color.into();
+                                (pos, if luminance { luminance_to_alpha(col) } else { col })
+                            },
+                        ));
+
+                        let paint = Paint::linear_gradient_stops(
+                            bounds.x + start_x,
+                            bounds.y + start_y,
+                            bounds.x + end_x,
+                            bounds.y + end_y,
+                            stops.into_iter(),
+                        );
+
+                        canvas.fill_path(path, &paint);
+                    }
+
+                    Gradient::Radial(radial_gradient) => {
+                        let num_stops = radial_gradient.stops.len();
+
+                        let stops = gradient_stops(radial_gradient.stops.iter().enumerate().map(
+                            |(index, stop)| {
+                                let pos = if let Some(pos) = &stop.position {
+                                    pos.to_pixels(parent_width, self.scale_factor()) / parent_width
+                                } else {
+                                    index as f32 / (num_stops - 1) as f32
+                                };
+                                let col: femtovg::Color = stop.color.into();
+                                (pos, if luminance { luminance_to_alpha(col) } else { col })
+                            },
+                        ));
+
+                        let center_x = radial_gradient
+                            .position
+                            .x
+                            .to_length_or_percentage()
+                            .to_pixels(bounds.w, self.scale_factor());
+                        let center_y = radial_gradient
+                            .position
+                            .y
+                            .to_length_or_percentage()
+                            .to_pixels(bounds.h, self.scale_factor());
+
+                        // The gradient reaches the farthest corner of the view from its center.
+                        let corner_x = center_x.max(bounds.w - center_x);
+                        let corner_y = center_y.max(bounds.h - center_y);
+
+                        match radial_gradient.shape {
+                            EndingShape::Circle => {
+                                let paint = Paint::radial_gradient_stops(
+                                    bounds.x + center_x,
+                                    bounds.y + center_y,
+                                    0.0,
+                                    corner_x.hypot(corner_y),
+                                    stops.into_iter(),
+                                );
+
+                                canvas.fill_path(path, &paint);
                             }
 
-                            // Insert a stop at the end if the last stop is not at 1.0.
-                            if let Some(last) = stops.last() {
-                                if last.0 != 1.0 {
-                                    stops.push((1.0, last.1));
+                            // An ellipse is drawn as a circle scaled vertically, which can't be
+                            // done without also scaling the path, so it's drawn into an image.
+                            EndingShape::Ellipse => {
+                                let radius_x = corner_x * std::f32::consts::SQRT_2;
+                                let radius_y = corner_y * std::f32::consts::SQRT_2;
+                                if radius_x <= 0.0 || radius_y <= 0.0 {
+                                    continue;
                                 }
+
+                                self.fill_with_gradient_image(
+                                    canvas,
+                                    path,
+                                    layer,
+                                    index,
+                                    gradient,
+                                    luminance,
+                                    |canvas, w, h| {
+                                        let scale_y = radius_y / radius_x;
+                                        canvas.translate(center_x, center_y);
+                                        canvas.scale(1.0, scale_y);
+
+                                        let mut rect = Path::new();
+                                        rect.rect(-center_x, -center_y / scale_y, w, h / scale_y);
+                                        let paint = Paint::radial_gradient_stops(
+                                            0.0,
+                                            0.0,
+                                            0.0,
+                                            radius_x,
+                                            stops.into_iter(),
+                                        );
+                                        canvas.fill_path(&rect, &paint);
+                                    },
+                                );
                             }
-                            let paint = Paint::radial_gradient_stops(
-                                bounds.center().0,
-                                bounds.center().1,
-                                0.0,
-                                bounds.w.max(bounds.h),
-                                stops.into_iter(),
-                            );
-
-                            canvas.fill_path(path, &paint);
                         }
+                    }
+
+                    // Femtovg has no conic gradient paint, so the gradient is drawn into an image as
+                    // a fan of thin wedges, each filled with the color of the gradient at its middle.
+                    Gradient::Conic(conic_gradient) => {
+                        let num_stops = conic_gradient.stops.len();
+
+                        let stops = gradient_stops(conic_gradient.stops.iter().enumerate().map(
+                            |(index, stop)| {
+                                let pos = match &stop.position {
+                                    Some(DimensionPercentage::Dimension(angle)) => {
+                                        angle.to_radians() / std::f32::consts::TAU
+                                    }
+                                    Some(DimensionPercentage::Percentage(percentage)) => {
+                                        percentage.0 / 100.0
+                                    }
+                                    _ => index as f32 / (num_stops - 1).max(1) as f32,
+                                };
+                                let col: femtovg::Color = stop.color.into();
+                                (pos, if luminance { luminance_to_alpha(col) } else { col })
+                            },
+                        ));
+
+                        if stops.is_empty() {
+                            continue;
+                        }
+
+                        let center_x = conic_gradient
+                            .position
+                            .x
+                            .to_length_or_percentage()
+                            .to_pixels(bounds.w, self.scale_factor());
+                        let center_y = conic_gradient
+                            .position
+                            .y
+                            .to_length_or_percentage()
+                            .to_pixels(bounds.h, self.scale_factor());
+                        let start_angle = conic_gradient.angle.to_radians();
+
+                        self.fill_with_gradient_image(
+                            canvas,
+                            path,
+                            layer,
+                            index,
+                            gradient,
+                            luminance,
+                            |canvas, w, h| {
+                                // The wedges reach past the farthest corner of the image.
+                                let radius =
+                                    center_x.max(w - center_x).hypot(center_y.max(h - center_y))
+                                        + 1.0;
+                                // Each wedge is about two pixels wide at its outer edge.
+                                let segments = ((radius * std::f32::consts::TAU / 2.0).ceil()
+                                    as usize)
+                                    .clamp(64, 2048);
+
+                                // Angles are clockwise from the top of the view.
+                                let point = |t: f32| {
+                                    let angle = start_angle + t * std::f32::consts::TAU;
+                                    (
+                                        center_x + radius * angle.sin(),
+                                        center_y - radius * angle.cos(),
+                                    )
+                                };
 
-                        _ => {}
-                    },
-
-                    ImageOrGradient::Image(image_name) => {
-                        if let Some(image) = self.resource_manager.images.get(image_name) {
-                            match image.image {
-                                ImageOrId::Id(id, dim) => {
-                                    let (width, height) =
-                                        if let Some(background_size) = image_sizes.get(index) {
-                                            match background_size {
-                                                BackgroundSize::Explicit { width, height } => {
-                                                    let w = match width {
+                                for segment in 0..segments {
+                                    let t0 = segment as f32 / segments as f32;
+                                    let t1 = (segment + 1) as f32 / segments as f32;
+                                    let (x0, y0) = point(t0);
+                                    let (x1, y1) = point(t1);
+
+                                    let mut wedge = Path::new();
+                                    wedge.move_to(center_x, center_y);
+                                    wedge.line_to(x0, y0);
+                                    wedge.line_to(x1, y1);
+                                    wedge.close();
+
+                                    // Without anti-aliasing the wedges meet without seams.
+                                    let mut paint =
+                                        Paint::color(gradient_color_at(&stops, (t0 + t1) / 2.0));
+                                    paint.set_anti_alias(false);
+                                    canvas.fill_path(&wedge, &paint);
+                                }
+                            },
+                        );
+                    }
+
+                    _ => {}
+                },
+
+                ImageOrGradient::Image(image_name) => {
+                    if let Some(image) = self.resource_manager.images.get(image_name) {
+                        match &image.image {
+                            ImageOrId::Id(id, dim, source, image_flags) => {
+                                // The sampling mode of an image is set when it is loaded into a
+                                // canvas, so the image is copied for views with another mode.
+                                let mut flags = *image_flags;
+                                flags.set(
+                                    femtovg::ImageFlags::NEAREST,
+                                    self.style.image_rendering.get(self.current).copied()
+                                        == Some(ImageRendering::Pixelated),
+                                );
+
+                                // Images are loaded into the canvas of the main window, and into the
+                                // canvases of other windows when first drawn in them.
+                                let id = if self.window == Entity::root() && flags == *image_flags {
+                                    *id
+                                } else {
+                                    match window_image(
+                                        self.cache,
+                                        canvas,
+                                        self.window,
+                                        *id,
+                                        source,
+                                        flags,
+                                    ) {
+                                        Some(id) => id,
+                                        None => continue,
+                                    }
+                                };
+
+                                let (width, height) =
+                                    if let Some(background_size) = image_sizes.get(index) {
+                                        match background_size {
+                                            BackgroundSize::Explicit { width, height } => {
+                                                let w = match width {
                                                     LengthPercentageOrAuto::LengthPercentage(
                                                         length,
                                                     ) => length
@@ -1260,7 +1933,7 @@ impl<'a> DrawContext<'a> {
                                                     LengthPercentageOrAuto::Auto => dim.0 as f32,
                                                 };
 
-                                                    let h = match height {
+                                                let h = match height {
                                                     LengthPercentageOrAuto::LengthPercentage(
                                                         length,
                                                     ) => length
@@ -1268,59 +1941,101 @@ impl<'a> DrawContext<'a> {
                                                     LengthPercentageOrAuto::Auto => dim.1 as f32,
                                                 };
 
-                                                    (w, h)
-                                                }
+                                                (w, h)
+                                            }
 
-                                                BackgroundSize::Contain => {
-                                                    let image_ratio = dim.0 as f32 / dim.1 as f32;
-                                                    let container_ratio = bounds.w / bounds.h;
+                                            BackgroundSize::Contain => {
+                                                let image_ratio = dim.0 as f32 / dim.1 as f32;
+                                                let container_ratio = bounds.w / bounds.h;
 
-                                                    let (w, h) = if image_ratio > container_ratio {
-                                                        (bounds.w, bounds.w / image_ratio)
-                                                    } else {
-                                                        (bounds.h * image_ratio, bounds.h)
-                                                    };
+                                                let (w, h) = if image_ratio > container_ratio {
+                                                    (bounds.w, bounds.w / image_ratio)
+                                                } else {
+                                                    (bounds.h * image_ratio, bounds.h)
+                                                };
 
-                                                    (w, h)
-                                                }
+                                                (w, h)
+                                            }
 
-                                                BackgroundSize::Cover => {
-                                                    let image_ratio = dim.0 as f32 / dim.1 as f32;
-                                                    let container_ratio = bounds.w / bounds.h;
+                                            BackgroundSize::Cover => {
+                                                let image_ratio = dim.0 as f32 / dim.1 as f32;
+                                                let container_ratio = bounds.w / bounds.h;
 
-                                                    let (w, h) = if image_ratio < container_ratio {
-                                                        (bounds.w, bounds.w / image_ratio)
-                                                    } else {
-                                                        (bounds.h * image_ratio, bounds.h)
-                                                    };
+                                                let (w, h) = if image_ratio < container_ratio {
+                                                    (bounds.w, bounds.w / image_ratio)
+                                                } else {
+                                                    (bounds.h * image_ratio, bounds.h)
+                                                };
 
-                                                    (w, h)
-                                                }
+                                                (w, h)
                                             }
-                                        } else {
-                                            (dim.0 as f32, dim.1 as f32)
-                                        };
-
-                                    let paint = Paint::image(
-                                        id, bounds.x, bounds.y, width, height, 0.0, 1.0,
-                                    );
+                                        }
+                                    } else {
+                                        (dim.0 as f32, dim.1 as f32)
+                                    };
 
-                                    canvas.fill_path(path, &paint);
-                                }
+                                let paint = if let Some(tint) =
+                                    self.style.image_tint.get(self.current)
+                                {
+                                    Paint::image_tint(
+                                        id,
+                                        bounds.x,
+                                        bounds.y,
+                                        width,
+                                        height,
+                                        0.0,
+                                        (*tint).into(),
+                                    )
+                                } else {
+                                    Paint::image(id, bounds.x, bounds.y, width, height, 0.0, 1.0)
+                                };
 
-                                _ => {}
+                                canvas.fill_path(path, &paint);
                             }
+
+                            _ => {}
                         }
                     }
                 }
             }
         }
+
+        self.remove_gradient_images(canvas, layer, images);
+    }
+
+    /// Returns the text config used to rasterize the glyphs of the current view, which is the
+    /// application text config overridden by the `font-smoothing` and `font-hinting` of the view.
+    /// Unless subpixel antialiasing is set on the view, text drawn with a rotation or skew is
+    /// antialiased in grayscale, as subpixel antialiasing assumes the glyphs are aligned with the
+    /// color channels of the display.
+    pub fn text_config(&self, canvas: &Canvas) -> TextConfig {
+        let mut config = *self.text_config;
+
+        match self.style.font_smoothing.get(self.current).copied().unwrap_or_default() {
+            FontSmoothing::Auto => {
+                let transform = canvas.transform();
+                if transform[1] != 0.0 || transform[2] != 0.0 {
+                    config.subpixel = false;
+                }
+            }
+            FontSmoothing::Grayscale => config.subpixel = false,
+            FontSmoothing::Subpixel => config.subpixel = true,
+        }
+
+        match self.style.font_hinting.get(self.current).copied().unwrap_or_default() {
+            FontHinting::Auto => {}
+            FontHinting::Full => config.hint = true,
+            FontHinting::None => config.hint = false,
+        }
+
+        config
     }
 
     /// Draw any text for the current view.
     pub fn draw_text(&mut self, canvas: &mut Canvas, bounds: BoundingBox, justify: (f32, f32)) {
+        let config = self.text_config(canvas);
         if let Ok(draw_commands) =
-            self.text_context.fill_to_cmds(canvas, self.current, bounds, justify, *self.text_config)
+            self.text_context.fill_to_cmds(canvas, self.current, bounds, justify, config)
         {
             let opacity = self.opacity();
             for (color, cmds) in draw_commands.into_iter() {
@@ -1359,7 +2074,11 @@ impl<'a> DrawContext<'a> {
         }
     }
 
-    /// Draw text caret for the current view.
+    /// Draw text caret for the current view, with the shape given by the `caret-shape` property.
+    /// The `width` in logical pixels is used if the view has no `caret-width`.
+    ///
+    /// The caret should be drawn after the text, as a block caret draws the character beneath it
+    /// again with the inverse of the caret color.
     pub fn draw_text_caret(
         &mut self,
         canvas: &mut Canvas,
@@ -1367,16 +2086,45 @@ impl<'a> DrawContext<'a> {
         justify: (f32, f32),
         width: f32,
     ) {
+        if self.text_context.is_caret_hidden(self.current) {
+            return;
+        }
+
         let caret_color = self.caret_color();
-        if let Some((x, y, w, h)) = self.text_context.layout_caret(
-            self.current,
-            bounds,
-            justify,
-            self.logical_to_physical(width),
-        ) {
+        let caret_shape = self.style.caret_shape.get(self.current).copied().unwrap_or_default();
+        let width = if let Some(caret_width) = self.style.caret_width.get(self.current) {
+            let bounds = self.bounds();
+            caret_width.to_pixels(bounds.w.min(bounds.h), self.scale_factor())
+        } else {
+            self.logical_to_physical(width)
+        };
+
+        if let Some((x, y, w, h)) =
+            self.text_context.layout_caret(self.current, bounds, justify, caret_shape, width)
+        {
             let mut path = Path::new();
-            path.rect(x, y, w * self.scale_factor(), h);
+            path.rect(x, y, w, h);
             canvas.fill_path(&path, &Paint::color(caret_color.into()));
+
+            if caret_shape == CaretShape::Block {
+                canvas.save();
+                canvas.intersect_scissor(x, y, w, h);
+                let config = self.text_config(canvas);
+                if let Ok(draw_commands) =
+                    self.text_context.fill_to_cmds(canvas, self.current, bounds, justify, config)
+                {
+                    let inverse = femtovg::Color::rgba(
+                        255 - caret_color.r(),
+                        255 - caret_color.g(),
+                        255 - caret_color.b(),
+                        caret_color.a(),
+                    );
+                    for (_, cmds) in draw_commands.into_iter() {
+                        canvas.draw_glyph_commands(cmds, &Paint::color(inverse), 1.0);
+                    }
+                }
+                canvas.restore();
+            }
         }
     }
 }
@@ -1493,3 +2241,181 @@ impl<'a> DataContext for DrawContext<'a> {
 // }
 
 // impl<'a> StyleGet for DrawContext<'a> {}
+
+// Collects the stops of a gradient, as positions between 0 and 1 and colors, into the stops of a
+// femtovg gradient. As in CSS, a stop can't be positioned before the stop preceding it, and the
+// first and last colors extend to the ends of the gradient.
+fn gradient_stops(
+    stops: impl Iterator<Item = (f32, femtovg::Color)>,
+) -> Vec<(f32, femtovg::Color)> {
+    let mut stops = stops
+        .scan(0.0f32, |min, (pos, col)| {
+            *min = pos.clamp(*min, 1.0);
+            Some((*min, col))
+        })
+        .collect::<Vec<_>>();
+
+    // Insert a stop at the front if the first stop is not at 0.
+    if let Some(first) = stops.first() {
+        if first.0 != 0.0 {
+            stops.insert(0, (0.0, first.1));
+        }
+    }
+
+    // Insert a stop at the end if the last stop is not at 1.0.
+    if let Some(last) = stops.last() {
+        if last.0 != 1.0 {
+            stops.push((1.0, last.1));
+        }
+    }
+
+    stops
+}
+
+// Returns the color of a gradient at the given position, interpolated between its stops.
+fn gradient_color_at(stops: &[(f32, femtovg::Color)], pos: f32) -> femtovg::Color {
+    let next = stops.iter().position(|(stop_pos, _)| *stop_pos >= pos).unwrap_or(stops.len() - 1);
+    if next == 0 {
+        return stops[0].1;
+    }
+
+    let (start_pos, start) = stops[next - 1];
+    let (end_pos, end) = stops[next];
+    let t = if end_pos > start_pos { (pos - start_pos) / (end_pos - start_pos) } else { 1.0 };
+
+    femtovg::Color::rgbaf(
+        start.r + (end.r - start.r) * t,
+        start.g + (end.g - start.g) * t,
+        start.b + (end.b - start.b) * t,
+        start.a + (end.a - start.a) * t,
+    )
+}
+
+// Converts a color to black with an alpha equal to the luminance of the color, for luminance masks.
+fn luminance_to_alpha(color: femtovg::Color) -> femtovg::Color {
+    let luminance = 0.2126 * color.r + 0.7152 * color.g + 0.0722 * color.b;
+    femtovg::Color::rgbaf(0.0, 0.0, 0.0, luminance * color.a)
+}
+
+// Returns the copy of an image of the main window with the given flags in the canvas of a window,
+// loading the image into the canvas if it has not been yet.
+fn window_image(
+    cache: &mut CachedData,
+    canvas: &mut Canvas,
+    window: Entity,
+    id: ImageId,
+    image: &image::DynamicImage,
+    flags: femtovg::ImageFlags,
+) -> Option<ImageId> {
+    let images = cache.window_images.entry(window).or_default();
+    if let Some((_, _, copy)) =
+        images.iter().find(|(main_id, copy_flags, _)| *main_id == id && *copy_flags == flags)
+    {
+        return Some(*copy);
+    }
+
+    let copy = canvas.create_image(femtovg::ImageSource::try_from(image).ok()?, flags).ok()?;
+    images.push((id, flags, copy));
+    Some(copy)
+}
+
+// Returns the bounds of a clip path for a view with the given bounds, along with the radius of its
+// corners, or `None` if the clip path doesn't clip the view.
+pub(crate) fn clip_shape(
+    clip_path: &ClipPath,
+    bounds: BoundingBox,
+    scale: f32,
+) -> Option<(BoundingBox, f32)> {
+    let inset = |rect: &Rect<LengthOrPercentage>| {
+        bounds.shrink_sides(
+            rect.3.to_pixels(bounds.w, scale),
+            rect.0.to_pixels(bounds.h, scale),
+            rect.1.to_pixels(bounds.w, scale),
+            rect.2.to_pixels(bounds.h, scale),
+        )
+    };
+
+    match clip_path {
+        ClipPath::Auto => None,
+        ClipPath::Shape(rect) => Some((inset(rect), 0.0)),
+        ClipPath::RoundedShape(rect, radius) => {
+            let shape = inset(rect);
+            Some((shape, radius.to_pixels(shape.w.min(shape.h), scale).max(0.0)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    // Returns a draw context for the given view of the main window.
+    fn draw_context(cx: &mut Context, current: Entity) -> DrawContext<'_> {
+        DrawContext {
+            current,
+            style: &cx.style,
+            cache: &mut cx.cache,
+            tree: &cx.tree,
+            data: &cx.data,
+            views: &mut cx.views,
+            resource_manager: &cx.resource_manager,
+            text_context: &mut cx.text_context,
+            text_config: &cx.text_config,
+            modifiers: &cx.modifiers,
+            mouse: &cx.mouse,
+            opacity: 1.0,
+            render_target: femtovg::RenderTarget::Screen,
+            dirty_rect: None,
+            window: Entity::root(),
+            shader_context: None,
+            drawing_mask: false,
+        }
+    }
+
+    /// Test that the left and right borders and corners of a right-to-left view are swapped.
+    #[test]
+    fn rtl_mirrors_borders() {
+        let mut harness = TestHarness::new(|cx| {
+            for (id, direction) in [("ltr", Direction::Ltr), ("rtl", Direction::Rtl)] {
+                Element::new(cx)
+                    .id(id)
+                    .size(Pixels(100.0))
+                    .direction(direction)
+                    .border_left_width(Pixels(1.0))
+                    .border_right_width(Pixels(4.0))
+                    .border_left_color(Color::red())
+                    .border_right_color(Color::blue())
+                    .border_top_left_radius(Pixels(2.0))
+                    .border_bottom_right_radius(Pixels(8.0))
+                    .border_top_right_shape(BorderCornerShape::Bevel);
+            }
+        });
+
+        let ltr = harness.entity("ltr").unwrap();
+        let cx = draw_context(harness.context(), ltr);
+        assert_eq!(cx.border_left_width(), 1.0);
+        assert_eq!(cx.border_right_width(), 4.0);
+        assert_eq!(cx.border_left_color(), Color::red());
+        assert_eq!(cx.border_right_color(), Color::blue());
+        assert_eq!(cx.border_top_left_radius(), 2.0);
+        assert_eq!(cx.border_top_right_radius(), 0.0);
+        assert_eq!(cx.border_bottom_left_radius(), 0.0);
+        assert_eq!(cx.border_bottom_right_radius(), 8.0);
+        assert_eq!(cx.border_top_left_shape(), BorderCornerShape::Round);
+        assert_eq!(cx.border_top_right_shape(), BorderCornerShape::Bevel);
+
+        let rtl = harness.entity("rtl").unwrap();
+        let cx = draw_context(harness.context(), rtl);
+        assert_eq!(cx.border_left_width(), 4.0);
+        assert_eq!(cx.border_right_width(), 1.0);
+        assert_eq!(cx.border_left_color(), Color::blue());
+        assert_eq!(cx.border_right_color(), Color::red());
+        assert_eq!(cx.border_top_left_radius(), 0.0);
+        assert_eq!(cx.border_top_right_radius(), 2.0);
+        assert_eq!(cx.border_bottom_left_radius(), 8.0);
+        assert_eq!(cx.border_bottom_right_radius(), 0.0);
+        assert_eq!(cx.border_top_left_shape(), BorderCornerShape::Bevel);
+        assert_eq!(cx.border_top_right_shape(), BorderCornerShape::Round);
+    }
+}
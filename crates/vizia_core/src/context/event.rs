@@ -19,14 +19,16 @@ use crate::style::{Abilities, IntoTransform, PseudoClassFlags, Style, SystemFlag
 use crate::window::DropData;
 use vizia_id::GenerationalId;
 use vizia_input::{Modifiers, MouseState};
-use vizia_storage::SparseSet;
+use vizia_storage::{LayoutChildIterator, SparseSet};
 
-use crate::context::EmitContext;
-use crate::text::TextContext;
 #[cfg(feature = "clipboard")]
-use copypasta::ClipboardProvider;
+use super::{ClipboardImage, ClipboardProvider};
+use crate::context::{EmitContext, EventFilters, EventTracer, RendererInfo};
+use crate::systems::Tween;
+use crate::text::TextContext;
+use crate::views::scroll_into_view_delta;
 
-use super::{DARK_THEME, LIGHT_THEME};
+use super::{clip_shape, redraw_queued, InternalEvent, DARK_THEME, LIGHT_THEME};
 
 /// A context used when handling events.
 ///
@@ -73,6 +75,9 @@ pub struct EventContext<'a> {
     pub(crate) views: &'a mut FnvHashMap<Entity, Box<dyn ViewHandler>>,
     pub(crate) listeners:
         &'a mut HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
+    pub(crate) event_filters: &'a mut EventFilters,
+    pub(crate) event_tracer: &'a Option<EventTracer>,
+    pub(crate) tweens: &'a mut Vec<Tween>,
     pub(crate) resource_manager: &'a mut ResourceManager,
     pub(crate) text_context: &'a mut TextContext,
     pub(crate) modifiers: &'a Modifiers,
@@ -84,8 +89,11 @@ pub struct EventContext<'a> {
     #[cfg(feature = "clipboard")]
     clipboard: &'a mut Box<dyn ClipboardProvider>,
     event_proxy: &'a mut Option<Box<dyn crate::context::EventProxy>>,
+    renderer_info: &'a RendererInfo,
     pub(crate) ignore_default_theme: &'a bool,
     pub(crate) drop_data: &'a mut Option<DropData>,
+    quitting: &'a mut bool,
+    announcements: &'a mut Vec<(String, Politeness)>,
 }
 
 impl<'a> EventContext<'a> {
@@ -102,6 +110,9 @@ impl<'a> EventContext<'a> {
             data: &mut cx.data,
             views: &mut cx.views,
             listeners: &mut cx.listeners,
+            event_filters: &mut cx.event_filters,
+            event_tracer: &cx.event_tracer,
+            tweens: &mut cx.tweens,
             resource_manager: &mut cx.resource_manager,
             text_context: &mut cx.text_context,
             modifiers: &cx.modifiers,
@@ -113,8 +124,11 @@ impl<'a> EventContext<'a> {
             #[cfg(feature = "clipboard")]
             clipboard: &mut cx.clipboard,
             event_proxy: &mut cx.event_proxy,
+            renderer_info: &cx.renderer_info,
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
+            quitting: &mut cx.quitting,
+            announcements: &mut cx.announcements,
         }
     }
 
@@ -154,6 +168,24 @@ impl<'a> EventContext<'a> {
         ret
     }
 
+    /// Announces a message to screen readers, e.g. to report that a document was saved.
+    ///
+    /// Messages are added to a live region of the accessibility tree when it is next updated.
+    /// Identical messages announced within the same frame are only announced once.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// cx.announce("Saved", Politeness::Polite);
+    /// ```
+    pub fn announce(&mut self, message: &str, politeness: Politeness) {
+        if !self.announcements.iter().any(|(m, p)| m == message && *p == politeness) {
+            self.announcements.push((message.to_owned(), politeness));
+        }
+    }
+
     // Returns true if in a drop state.
     pub fn has_drop_data(&self) -> bool {
         self.drop_data.is_some()
@@ -168,19 +200,24 @@ impl<'a> EventContext<'a> {
         self.cache.set_bounds(self.current, bounds);
     }
 
-    /// Returns the scale factor.
+    /// Returns the scale factor of the window which contains the current view.
     pub fn scale_factor(&self) -> f32 {
-        self.style.dpi_factor as f32
+        self.style.scale_factor_of(self.tree, self.current)
+    }
+
+    /// Returns information about the graphics driver used to render the application.
+    pub fn renderer_info(&self) -> &RendererInfo {
+        self.renderer_info
     }
 
     /// Converts logical points to physical pixels.
     pub fn logical_to_physical(&self, logical: f32) -> f32 {
-        self.style.logical_to_physical(logical)
+        (logical * self.scale_factor()).round()
     }
 
     /// Convert physical pixels to logical points.
     pub fn physical_to_logical(&self, physical: f32) -> f32 {
-        self.style.physical_to_logical(physical)
+        physical / self.scale_factor()
     }
 
     /// Returns the clip bounds of the current view.
@@ -191,46 +228,45 @@ impl<'a> EventContext<'a> {
 
         // let root_bounds = self.cache.get_bounds(Entity::root());
 
-        let scale = self.scale_factor();
-
-        let clip_bounds = self
-            .style
-            .clip_path
-            .get(self.current)
-            .map(|clip| match clip {
-                ClipPath::Auto => bounds,
-                ClipPath::Shape(rect) => bounds.shrink_sides(
-                    rect.3.to_pixels(bounds.w, scale),
-                    rect.0.to_pixels(bounds.h, scale),
-                    rect.1.to_pixels(bounds.w, scale),
-                    rect.2.to_pixels(bounds.h, scale),
-                ),
-            })
-            .unwrap_or(bounds);
+        let clip_shape = self.clip_shape();
+        let clip_bounds = clip_shape.map_or(bounds, |(shape, _)| shape);
 
         let root_bounds: BoundingBox =
             BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
 
-        match (overflowx, overflowy) {
+        let region = match (overflowx, overflowy) {
             (Overflow::Visible, Overflow::Visible) => root_bounds,
-            (Overflow::Hidden, Overflow::Visible) => {
+            (Overflow::Hidden | Overflow::Scroll, Overflow::Visible) => {
                 let left = clip_bounds.left();
                 let right = clip_bounds.right();
                 let top = root_bounds.top();
                 let bottom = root_bounds.bottom();
                 BoundingBox::from_min_max(left, top, right, bottom)
             }
-            (Overflow::Visible, Overflow::Hidden) => {
+            (Overflow::Visible, Overflow::Hidden | Overflow::Scroll) => {
                 let left = root_bounds.left();
                 let right = root_bounds.right();
                 let top = clip_bounds.top();
                 let bottom = clip_bounds.bottom();
                 BoundingBox::from_min_max(left, top, right, bottom)
             }
-            (Overflow::Hidden, Overflow::Hidden) => clip_bounds,
+            _ => clip_bounds,
+        };
+
+        // A clip path clips the view regardless of its overflow.
+        match clip_shape {
+            Some((shape, _)) => region.intersection(&shape),
+            None => region,
         }
     }
 
+    /// Returns the bounds of the clip path of the current view, along with the radius of its corners,
+    /// or `None` if the view has no clip path.
+    pub(crate) fn clip_shape(&self) -> Option<(BoundingBox, f32)> {
+        let clip_path = self.style.clip_path.get(self.current)?;
+        clip_shape(clip_path, self.bounds(), self.scale_factor())
+    }
+
     /// Returns the transform of the current view.
     pub fn transform(&self) -> Transform2D {
         let mut transform = Transform2D::identity();
@@ -278,7 +314,7 @@ impl<'a> EventContext<'a> {
                     if let Some(end) = animation_state.keyframes.last() {
                         let start_transform = start.value.as_transform(bounds, scale_factor);
                         let end_transform = end.value.as_transform(bounds, scale_factor);
-                        let t = animation_state.t;
+                        let t = start.timing_function.value(animation_state.t);
                         let animated_transform =
                             Transform2D::interpolate(&start_transform, &end_transform, t);
                         transform.premultiply(&animated_transform);
@@ -310,6 +346,114 @@ impl<'a> EventContext<'a> {
         }
     }
 
+    /// Animates a value from `from` to `to` over the given duration, calling `callback` with the
+    /// interpolated value on every frame until the end value is reached.
+    ///
+    /// Unlike the animations of style properties, the value can drive any application state, e.g.
+    /// by emitting an event from the callback. The callback is called with the current view, and
+    /// the animation is stopped if the view is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use instant::Duration;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// pub enum CameraEvent {
+    ///     SetPosition(f32, f32),
+    /// }
+    ///
+    /// cx.animate(
+    ///     (0.0, 0.0),
+    ///     (100.0, 50.0),
+    ///     Duration::from_millis(300),
+    ///     EasingFunction::EaseInOut,
+    ///     |cx, (x, y)| cx.emit(CameraEvent::SetPosition(x, y)),
+    /// );
+    /// ```
+    pub fn animate<T: Interpolator + 'static>(
+        &mut self,
+        from: T,
+        to: T,
+        duration: Duration,
+        easing: EasingFunction,
+        callback: impl FnMut(&mut EventContext, T) + 'static,
+    ) {
+        self.tweens.push(Tween::new(self.current, from, to, duration, easing, callback));
+    }
+
+    /// Plays an animation with the given id on each of the given views in turn, starting each view
+    /// `delay` after the previous one.
+    ///
+    /// Views which have not yet started are held at the first keyframe of the animation. Staggering
+    /// a view again replaces its pending start, and views which are removed before they start are
+    /// skipped, so a stagger can safely be restarted when the list of views changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use instant::Duration;
+    /// # let cx = &mut Context::default();
+    /// # let items = Vec::new();
+    /// # let mut cx = EventContext::new(cx);
+    /// cx.stagger(items, "slide_in", Duration::from_millis(30), Duration::from_millis(200));
+    /// ```
+    pub fn stagger(
+        &mut self,
+        entities: impl IntoIterator<Item = Entity>,
+        anim_id: impl AnimId,
+        delay: Duration,
+        duration: Duration,
+    ) {
+        if let Some(animation) = anim_id.get(self) {
+            for (index, entity) in entities.into_iter().enumerate() {
+                let offset = delay * index as u32;
+                self.style.play_animation_delayed(entity, animation, duration, offset);
+            }
+
+            self.needs_relayout();
+            self.needs_redraw();
+        }
+    }
+
+    /// Pauses an animation with the given id on the current view at the given progress, between 0.0
+    /// and 1.0, playing it first if needed.
+    ///
+    /// This allows an animation to be driven by a value other than time, such as a scroll offset.
+    /// The animation stays paused until it is played again with [`play_animation`](Self::play_animation).
+    pub fn seek_animation(&mut self, anim_id: impl AnimId, progress: f32) {
+        if let Some(animation_id) = anim_id.get(self) {
+            self.style.seek_animation(self.current, animation_id, progress);
+            self.needs_relayout();
+            self.needs_redraw();
+        }
+    }
+
+    /// Fades the opacity of the current view to fully opaque.
+    ///
+    /// The duration is for a fade from fully transparent, so calling this part way through a fade out
+    /// reverses the fade smoothly.
+    pub fn fade_in(&mut self, duration: Duration) {
+        self.style.fade(self.current, 1.0, duration, false);
+    }
+
+    /// Fades the opacity of the current view to fully transparent.
+    ///
+    /// The duration is for a fade from fully opaque, so calling this part way through a fade in
+    /// reverses the fade smoothly.
+    pub fn fade_out(&mut self, duration: Duration) {
+        self.style.fade(self.current, 0.0, duration, false);
+    }
+
+    /// Fades the opacity of the current view to fully transparent and then removes the view.
+    ///
+    /// Calling [`fade_in`](Self::fade_in) before the fade has finished cancels the removal.
+    pub fn fade_out_and_remove(&mut self, duration: Duration) {
+        self.style.fade(self.current, 0.0, duration, true);
+    }
+
     /// Returns true if the current view is currently animating with the given animation id.
     pub fn is_animating(&self, anim_id: impl AnimId) -> bool {
         if let Some(animation_id) = anim_id.get(self) {
@@ -377,6 +521,8 @@ impl<'a> EventContext<'a> {
             self.emit_to(old_focus, WindowEvent::FocusOut);
             self.emit_to(new_focus, WindowEvent::FocusIn);
             *self.focused = self.current();
+            // Updating the accessibility node of the new focus notifies assistive technologies.
+            self.style.needs_access_update(new_focus);
         }
         self.set_focus_pseudo_classes(new_focus, true, focus_visible);
 
@@ -397,11 +543,55 @@ impl<'a> EventContext<'a> {
         self.focus_with_visibility(old_focus_visible)
     }
 
+    /// Sets application focus to the given view using the previous focus visibility.
+    ///
+    /// This can be used to move focus to a view other than the current one, for example to the first
+    /// invalid field of a form when it is submitted.
+    pub fn focus_entity(&mut self, entity: Entity) {
+        let current = self.current;
+        self.current = entity;
+        self.focus();
+        self.current = current;
+    }
+
+    /// Removes focus from the focused view, returning it to the window.
+    pub fn clear_focus(&mut self) {
+        let current = self.current;
+        self.current = Entity::root();
+        self.focus_with_visibility(false);
+        self.current = current;
+    }
+
     /// Returns the currently hovered view.
     pub fn hovered(&self) -> Entity {
         *self.hovered
     }
 
+    /// Returns every view under the given point, ordered from the topmost view to the bottommost.
+    ///
+    /// The point is in physical pixels within the window of the current view, like the cursor
+    /// position in [`mouse()`](Self::mouse). Views are hit-tested in the same way as for hovering, so
+    /// the first view returned is the view which would be hovered by a cursor at the point.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// let (x, y) = (cx.mouse().cursorx, cx.mouse().cursory);
+    /// for entity in cx.views_at(x, y) {
+    ///     println!("{:?}", entity);
+    /// }
+    /// ```
+    pub fn views_at(&mut self, x: f32, y: f32) -> Vec<Entity> {
+        let window = self.tree.window(self.current);
+        crate::systems::hit_test(self, window, x, y)
+            .into_iter()
+            .rev()
+            .filter_map(|(entity, hit)| hit.then_some(entity))
+            .collect()
+    }
+
     /// Returns the currently focused view.
     pub fn focused(&self) -> Entity {
         *self.focused
@@ -511,6 +701,30 @@ impl<'a> EventContext<'a> {
         self.clipboard.set_contents(text)
     }
 
+    /// Get the image stored on the system clipboard.
+    ///
+    /// Returns an error if the clipboard doesn't contain an image, or if the clipboard provider
+    /// doesn't support images.
+    #[cfg(feature = "clipboard")]
+    pub fn get_clipboard_image(
+        &mut self,
+    ) -> Result<ClipboardImage, Box<dyn Error + Send + Sync + 'static>> {
+        self.clipboard.get_image()
+    }
+
+    /// Set the contents of the system clipboard to an image of `width * height` RGBA pixels.
+    ///
+    /// Returns an error if the clipboard provider doesn't support images.
+    #[cfg(feature = "clipboard")]
+    pub fn set_clipboard_image(
+        &mut self,
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.clipboard.set_image(rgba, width, height)
+    }
+
     /// Toggles the addition/removal of a class name for the current view.
     ///
     /// # Example
@@ -558,8 +772,41 @@ impl<'a> EventContext<'a> {
     }
 
     /// Marks the current view as needing to be redrawn.
+    ///
+    /// With [partial redraw](Context::set_partial_redraw) enabled, only the area of the window covered
+    /// by the current view and its descendants is redrawn.
     pub fn needs_redraw(&mut self) {
-        self.style.needs_redraw();
+        self.style.needs_partial_redraw(vec![self.current]);
+    }
+
+    /// Requests that the window be redrawn.
+    ///
+    /// Multiple requests made before the next frame are coalesced into a single redraw. If the event
+    /// loop is idle it will be woken up to process the request. To request a redraw from another
+    /// thread use [`ContextProxy::redraw`].
+    pub fn request_redraw(&mut self) {
+        // The request is queued as an event, which wakes the event loop, unless a redraw is already
+        // pending or queued.
+        if !self.style.system_flags.contains(SystemFlags::REDRAW)
+            && !redraw_queued(self.event_queue)
+        {
+            self.event_queue.push_back(Event::new(InternalEvent::Redraw).target(Entity::root()));
+        }
+    }
+
+    /// Requests that the application quits.
+    ///
+    /// This sends a [`WindowEvent::WindowClose`] event to the root window, which is handled in the
+    /// same way as the user closing the window.
+    pub fn quit(&mut self) {
+        *self.quitting = true;
+        self.emit_to(Entity::root(), WindowEvent::WindowClose);
+    }
+
+    /// Returns true once the application has been asked to quit, either with [`quit`](Self::quit)
+    /// or by the user closing the main window, and is no longer running.
+    pub fn is_quitting(&self) -> bool {
+        *self.quitting
     }
 
     /// Marks the current view as needing a layout computation.
@@ -855,6 +1102,107 @@ impl<'a> EventContext<'a> {
         self.style.overflowy.insert(self.current, overflowy.into());
     }
 
+    /// Returns the scroll offset of the current view in physical pixels, which is the distance its
+    /// children are moved up and to the left by.
+    pub fn scroll_offset(&self) -> (f32, f32) {
+        self.cache.scroll_offset.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Sets the scroll offset of the current view in physical pixels, clamped between zero and the
+    /// distance by which the children of the view overflow it. A view can only be scrolled along an
+    /// axis on which its overflow is [`Overflow::Scroll`].
+    pub fn set_scroll_offset(&mut self, x: f32, y: f32) {
+        let (max_x, max_y) = self.max_scroll_offset();
+        let offset = (x.max(0.0).min(max_x), y.max(0.0).min(max_y));
+        let (previous_x, previous_y) = self.scroll_offset();
+        if offset == (previous_x, previous_y) {
+            return;
+        }
+
+        self.cache.scroll_offset.insert(self.current, offset);
+
+        // Move the descendants right away so that their bounds are up to date before the relayout,
+        // which also updates what is hovered and visible.
+        let (dx, dy) = (offset.0 - previous_x, offset.1 - previous_y);
+        for descendant in self.current.branch_iter(self.tree).skip(1) {
+            if let Some(bounds) = self.cache.bounds.get_mut(descendant) {
+                bounds.x -= dx;
+                bounds.y -= dy;
+            }
+        }
+
+        self.needs_relayout();
+    }
+
+    /// Scrolls each view containing the given view, along each axis on which it can be scrolled, so
+    /// that the given view is shown within it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// # let entity = Entity::root();
+    /// cx.scroll_to(entity);
+    /// ```
+    pub fn scroll_to(&mut self, entity: Entity) {
+        let current = self.current;
+        let mut target = self.cache.get_bounds(entity);
+        let ancestors = entity.parent_iter(self.tree).skip(1).collect::<Vec<_>>();
+        for ancestor in ancestors {
+            self.current = ancestor;
+            if self.max_scroll_offset() == (0.0, 0.0) {
+                continue;
+            }
+
+            let bounds = self.bounds();
+            let (x, y) = self.scroll_offset();
+            let dx = scroll_into_view_delta(bounds.x, bounds.w, target.x, target.w);
+            let dy = scroll_into_view_delta(bounds.y, bounds.h, target.y, target.h);
+            self.set_scroll_offset(x + dx, y + dy);
+
+            // The target moves along with the content of the scrolled view.
+            let (scrolled_x, scrolled_y) = self.scroll_offset();
+            target.x -= scrolled_x - x;
+            target.y -= scrolled_y - y;
+        }
+
+        self.current = current;
+    }
+
+    /// Returns the largest scroll offset of the current view along each axis, at which the far edges
+    /// of its children meet its own far edges. The largest scroll offset is zero along an axis on
+    /// which the overflow of the view is not [`Overflow::Scroll`].
+    pub(crate) fn max_scroll_offset(&self) -> (f32, f32) {
+        let bounds = self.bounds();
+        let (mut width, mut height) = (0.0f32, 0.0f32);
+        for child in LayoutChildIterator::new(self.tree, self.current) {
+            if self.style.display.get(child).copied().unwrap_or_default() == Display::None {
+                continue;
+            }
+
+            if let Some(position) = self.cache.relative_position.get(child) {
+                let child_bounds = self.cache.get_bounds(child);
+                width = width.max(position.x + child_bounds.w);
+                height = height.max(position.y + child_bounds.h);
+            }
+        }
+
+        let scrollable = |overflow: Option<&Overflow>| overflow == Some(&Overflow::Scroll);
+        let max_x = if scrollable(self.style.overflowx.get(self.current)) {
+            (width - bounds.w).max(0.0)
+        } else {
+            0.0
+        };
+        let max_y = if scrollable(self.style.overflowy.get(self.current)) {
+            (height - bounds.h).max(0.0)
+        } else {
+            0.0
+        };
+
+        (max_x, max_y)
+    }
+
     // TRANSFORM
 
     /// Sets the transform of the current view.
@@ -936,6 +1284,31 @@ impl<'a> EventContext<'a> {
         self.needs_relayout();
         self.needs_redraw();
     }
+
+    /// Returns the index of the character nearest to a point, in physical window coordinates, within
+    /// the text of the given view. The point is mapped to the text as it was last drawn, including any
+    /// transform or scrolling applied to the view.
+    ///
+    /// Returns `None` if the view has no text or the text has not been drawn yet.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let label = Label::new(cx, "Hello World").entity();
+    /// # let mut cx = EventContext::new(cx);
+    /// let (x, y) = (cx.mouse().cursorx, cx.mouse().cursory);
+    /// if let Some(index) = cx.text_position_at(label, x, y) {
+    ///     println!("Clicked on character {}", index);
+    /// }
+    /// ```
+    pub fn text_position_at(&mut self, entity: Entity, x: f32, y: f32) -> Option<usize> {
+        if !self.text_context.has_buffer(entity) {
+            return None;
+        }
+
+        self.text_context.hit_position(entity, x, y)
+    }
 }
 
 impl<'a> DataContext for EventContext<'a> {
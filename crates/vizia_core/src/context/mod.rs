@@ -3,22 +3,21 @@
 mod access;
 #[doc(hidden)]
 pub mod backend;
+#[cfg(feature = "clipboard")]
+mod clipboard;
 mod draw;
 mod event;
 mod proxy;
+mod renderer;
 mod resource;
 
 use cosmic_text::Shaping;
-use instant::Instant;
+use instant::{Duration, Instant};
 use std::any::{Any, TypeId};
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Mutex;
 
-#[cfg(all(feature = "clipboard", feature = "x11"))]
-use copypasta::ClipboardContext;
-#[cfg(feature = "clipboard")]
-use copypasta::{nop_clipboard::NopClipboardContext, ClipboardProvider};
 use cosmic_text::{fontdb::Database, Attrs, AttrsList, BufferLine, FamilyOwned};
 use fnv::FnvHashMap;
 use replace_with::replace_with_or_abort;
@@ -26,15 +25,18 @@ use replace_with::replace_with_or_abort;
 use unic_langid::LanguageIdentifier;
 
 pub use access::*;
+#[cfg(feature = "clipboard")]
+pub use clipboard::*;
 pub use draw::*;
 pub use event::*;
 pub use proxy::*;
+pub use renderer::*;
 pub use resource::*;
 
 use crate::binding::BindingHandler;
 use crate::cache::CachedData;
 use crate::environment::{Environment, ThemeMode};
-use crate::events::ViewHandler;
+use crate::events::{visit_entity, CoalescedEvents, ViewHandler};
 #[cfg(feature = "embedded_fonts")]
 use crate::fonts;
 
@@ -42,8 +44,11 @@ use crate::fonts::TABLER_ICONS;
 use crate::model::ModelDataStore;
 use crate::prelude::*;
 use crate::resource::{ImageOrId, ImageRetentionPolicy, ResourceManager, StoredImage};
-use crate::style::{PseudoClassFlags, Style};
-use crate::text::{TextConfig, TextContext};
+use crate::style::{PseudoClassFlags, Style, SystemFlags};
+use crate::systems::{KeyRepeat, Tween};
+use crate::text::{GlyphCache, TextConfig, TextContext};
+use crate::tree::branch_with_owned;
+use crate::view::ShaderContext;
 use vizia_id::{GenerationalId, IdManager};
 use vizia_input::{Modifiers, MouseState};
 use vizia_storage::TreeExt;
@@ -56,6 +61,17 @@ static LIGHT_THEME: &str = include_str!("../../resources/themes/light_theme.css"
 type Views = FnvHashMap<Entity, Box<dyn ViewHandler>>;
 type Models = SparseSet<ModelDataStore>;
 type Bindings = FnvHashMap<Entity, Box<dyn BindingHandler>>;
+pub(crate) type EventFilters =
+    HashMap<Entity, Vec<Box<dyn Fn(&mut EventContext, &mut Event) -> bool>>>;
+pub(crate) type EventTracer = Box<dyn Fn(&Event, EventPhase, Entity)>;
+pub(crate) type DrawOverlay = Box<dyn FnMut(&mut Canvas, (f32, f32))>;
+
+// Tracks the number of entities across frames for the warning set by `Context::warn_on_entity_growth`.
+pub(crate) struct EntityGrowth {
+    frames: usize,
+    last_count: usize,
+    growing: usize,
+}
 
 /// The main storage and control object for a Vizia application.
 pub struct Context {
@@ -68,21 +84,61 @@ pub struct Context {
     pub(crate) bindings: Bindings,
     pub(crate) event_queue: VecDeque<Event>,
     pub(crate) tree_updates: Vec<accesskit::TreeUpdate>,
+    // Messages to announce to screen readers when the accessibility tree is next updated, and the
+    // number of messages which have been announced, used to give each message a new node.
+    pub(crate) announcements: Vec<(String, Politeness)>,
+    pub(crate) announcement_count: usize,
     pub(crate) listeners:
         HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
     pub(crate) global_listeners: Vec<Box<dyn Fn(&mut EventContext, &mut Event)>>,
+    pub(crate) event_filters: EventFilters,
+    pub(crate) event_tracer: Option<EventTracer>,
+    pub(crate) entity_growth: Option<EntityGrowth>,
     pub(crate) style: Style,
     pub(crate) cache: CachedData,
 
     pub(crate) canvases: HashMap<Entity, crate::prelude::Canvas>,
+    pub(crate) shader_contexts: HashMap<Entity, ShaderContext>,
+    // Windows requested with `spawn_window` which are yet to be created by the windowing backend.
+    pub(crate) spawned_windows: Vec<(Entity, WindowDescription)>,
+    // The window the mouse cursor is in, and the glyph caches of the windows other than the main one.
+    pub(crate) cursor_window: Entity,
+    pub(crate) glyph_caches: HashMap<Entity, GlyphCache>,
+    // The views, canvases and shader contexts of removed windows, which the windowing backend drops
+    // once the rendering context of each window has been made current.
+    pub(crate) removed_windows:
+        Vec<(Box<dyn ViewHandler>, crate::prelude::Canvas, Option<ShaderContext>)>,
+    pub(crate) clear_behavior: ClearBehavior,
+    pub(crate) draw_overlay: Option<DrawOverlay>,
     pub(crate) mouse: MouseState<Entity>,
     pub(crate) modifiers: Modifiers,
+    pub(crate) keys_down: HashSet<Code>,
+    pub(crate) key_repeats: Vec<KeyRepeat>,
+    pub(crate) tweens: Vec<Tween>,
+    // The scroll offsets of scroll views in physical pixels, cached by key so that they can be restored
+    // when the views are rebuilt.
+    pub(crate) scroll_offsets: HashMap<String, (f32, f32)>,
 
     pub(crate) captured: Entity,
+    // The view each active touch started on, and the touch which is acting as the mouse, if any.
+    pub(crate) touches: HashMap<u64, Entity>,
+    pub(crate) primary_touch: Option<u64>,
     pub(crate) triggered: Entity,
     pub(crate) hovered: Entity,
+    // The view and URL of the link in text under the cursor, and of the link the cursor was pressed on.
+    pub(crate) hovered_link: Option<(Entity, String)>,
+    pub(crate) pressed_link: Option<(Entity, String)>,
     pub(crate) focused: Entity,
     pub(crate) focus_stack: Vec<Entity>,
+    pub(crate) autofocus: Option<Entity>,
+    pub(crate) keyboard_requested: bool,
+    pub(crate) next_caret_blink: Option<Instant>,
+    pub(crate) coalesced_events: CoalescedEvents,
+    pub(crate) max_events_per_frame: Option<usize>,
+    pub(crate) focus_wrap: bool,
+    pub(crate) partial_redraw: bool,
+    pub(crate) highlight_redraw: bool,
+    pub(crate) deferred_events: usize,
     pub(crate) cursor_icon_locked: bool,
 
     pub(crate) resource_manager: ResourceManager,
@@ -92,6 +148,8 @@ pub struct Context {
 
     pub(crate) event_proxy: Option<Box<dyn EventProxy>>,
 
+    pub(crate) renderer_info: RendererInfo,
+
     /// The window's size in logical pixels, before `user_scale_factor` gets applied to it. If this
     /// value changed during a frame then the window will be resized and a
     /// [`WindowEvent::GeometryChanged`] will be emitted.
@@ -114,6 +172,8 @@ pub struct Context {
     pub window_has_focus: bool,
 
     pub(crate) drop_data: Option<DropData>,
+    /// Whether the application has been asked to quit.
+    pub(crate) quitting: bool,
 }
 
 impl Default for Context {
@@ -153,17 +213,46 @@ impl Context {
             style: Style::default(),
             cache,
             canvases: HashMap::new(),
+            shader_contexts: HashMap::new(),
+            spawned_windows: Vec::new(),
+            cursor_window: Entity::root(),
+            glyph_caches: HashMap::new(),
+            removed_windows: Vec::new(),
+            clear_behavior: ClearBehavior::default(),
+            draw_overlay: None,
             event_queue: VecDeque::new(),
             tree_updates: Vec::new(),
+            announcements: Vec::new(),
+            announcement_count: 0,
             listeners: HashMap::default(),
             global_listeners: vec![],
+            event_filters: HashMap::default(),
+            event_tracer: None,
+            entity_growth: None,
             mouse: MouseState::default(),
             modifiers: Modifiers::empty(),
+            keys_down: HashSet::new(),
+            key_repeats: Vec::new(),
+            tweens: Vec::new(),
+            scroll_offsets: HashMap::new(),
             captured: Entity::null(),
+            touches: HashMap::new(),
+            primary_touch: None,
             triggered: Entity::null(),
             hovered: Entity::root(),
+            hovered_link: None,
+            pressed_link: None,
             focused: Entity::root(),
             focus_stack: Vec::new(),
+            autofocus: None,
+            keyboard_requested: false,
+            next_caret_blink: None,
+            coalesced_events: CoalescedEvents::empty(),
+            max_events_per_frame: None,
+            focus_wrap: true,
+            partial_redraw: false,
+            highlight_redraw: false,
+            deferred_events: 0,
             cursor_icon_locked: false,
             resource_manager: ResourceManager::new(),
             text_context: TextContext::new_from_locale_and_db(
@@ -175,19 +264,21 @@ impl Context {
 
             event_proxy: None,
 
+            renderer_info: RendererInfo::default(),
+
             window_size,
             user_scale_factor,
 
             #[cfg(feature = "clipboard")]
             clipboard: {
-                #[cfg(feature = "x11")]
-                if let Ok(context) = ClipboardContext::new() {
-                    Box::new(context)
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Ok(clipboard) = ArboardClipboard::new() {
+                    Box::new(clipboard)
                 } else {
-                    Box::new(NopClipboardContext::new().unwrap())
+                    Self::fallback_clipboard()
                 }
-                #[cfg(not(feature = "x11"))]
-                Box::new(NopClipboardContext::new().unwrap())
+                #[cfg(target_arch = "wasm32")]
+                Self::fallback_clipboard()
             },
             click_time: Instant::now(),
             clicks: 0,
@@ -197,6 +288,7 @@ impl Context {
             window_has_focus: true,
 
             drop_data: None,
+            quitting: false,
         };
 
         result.style.needs_restyle();
@@ -214,6 +306,12 @@ impl Context {
         result
     }
 
+    /// Returns the clipboard used when the system clipboard is not available.
+    #[cfg(feature = "clipboard")]
+    fn fallback_clipboard() -> Box<dyn ClipboardProvider> {
+        Box::new(LocalClipboard::default())
+    }
+
     /// The "current" entity, generally the entity which is currently being built or the entity
     /// which is currently having an event dispatched to it.
     pub fn current(&self) -> Entity {
@@ -258,9 +356,86 @@ impl Context {
         self.user_scale_factor
     }
 
-    /// Returns the scale factor of the display.
+    /// Returns the scale factor of the display of the window which contains the current view.
     pub fn scale_factor(&self) -> f32 {
-        self.style.dpi_factor as f32
+        self.style.scale_factor_of(&self.tree, self.current)
+    }
+
+    /// Converts logical points to physical pixels using the current scale factor.
+    pub fn logical_to_physical(&self, logical: f32) -> f32 {
+        (logical * self.scale_factor()).round()
+    }
+
+    /// Converts physical pixels to logical points using the current scale factor.
+    pub fn physical_to_logical(&self, physical: f32) -> f32 {
+        physical / self.scale_factor()
+    }
+
+    // Calls the closure with the scale factor of the given window as the scale factor used to lay
+    // out and draw views.
+    pub(crate) fn with_window_scale_factor<T>(
+        &mut self,
+        window: Entity,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let scale_factor = self.style.window_scale_factor(window);
+        let main_scale_factor = std::mem::replace(&mut self.style.dpi_factor, scale_factor);
+        let result = f(self);
+        self.style.dpi_factor = main_scale_factor;
+        result
+    }
+
+    /// Returns information about the graphics driver used to render the application.
+    pub fn renderer_info(&self) -> &RendererInfo {
+        &self.renderer_info
+    }
+
+    /// Opens a new window, described by the given window description, with the views built by the
+    /// `content` closure. Returns the entity of the root view of the window.
+    ///
+    /// The window is created by the windowing backend at the end of the current frame, after which it
+    /// receives its own mouse, keyboard and resize events. The root view of the window matches the
+    /// `:root` selector. Tab navigation stays within the window, and focus moves to the window when it
+    /// is activated. To close the window, emit [`WindowEvent::WindowClose`] to its root view, or remove
+    /// the root view.
+    ///
+    /// Windows share the scale factor of the main window, images are only drawn in the main window,
+    /// and the views in a window are presented to assistive technologies as part of the main window.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let window = cx.spawn_window(WindowDescription::new().with_title("Inspector"), |cx| {
+    ///     Label::new(cx, "Hello from another window");
+    /// });
+    ///
+    /// cx.emit_to(window, WindowEvent::WindowClose);
+    /// ```
+    pub fn spawn_window<F>(&mut self, window_description: WindowDescription, content: F) -> Entity
+    where
+        F: FnOnce(&mut Context),
+    {
+        let width = window_description.inner_size.width as f32;
+        let height = window_description.inner_size.height as f32;
+        let entity = self.with_current(Entity::root(), |cx| {
+            WindowRoot
+                .build(cx, |_| {})
+                .position_type(PositionType::SelfDirected)
+                .left(Pixels(0.0))
+                .top(Pixels(0.0))
+                .width(Pixels(width))
+                .height(Pixels(height))
+                .entity()
+        });
+
+        self.tree.set_window(entity, true);
+        self.tree.set_lock_focus_within(entity, true);
+        self.spawned_windows.push((entity, window_description));
+
+        self.with_current(entity, content);
+
+        entity
     }
 
     /// Mark the application as needing to rerun the draw method
@@ -268,6 +443,21 @@ impl Context {
         self.style.needs_redraw();
     }
 
+    /// Requests that the window be redrawn.
+    ///
+    /// Multiple requests made before the next frame are coalesced into a single redraw. If the event
+    /// loop is idle it will be woken up to process the request. To request a redraw from another
+    /// thread use [`ContextProxy::redraw`].
+    pub fn request_redraw(&mut self) {
+        // The request is queued as an event, which wakes the event loop, unless a redraw is already
+        // pending or queued.
+        if !self.style.system_flags.contains(SystemFlags::REDRAW)
+            && !redraw_queued(&self.event_queue)
+        {
+            self.event_queue.push_back(Event::new(InternalEvent::Redraw).target(Entity::root()));
+        }
+    }
+
     /// Mark the application as needing to recompute view styles
     pub fn needs_restyle(&mut self) {
         self.style.needs_restyle();
@@ -325,6 +515,8 @@ impl Context {
             self.emit_to(old_focus, WindowEvent::FocusOut);
             self.emit_to(new_focus, WindowEvent::FocusIn);
             self.focused = self.current;
+            // Updating the accessibility node of the new focus notifies assistive technologies.
+            self.style.needs_access_update(new_focus);
         }
         self.set_focus_pseudo_classes(new_focus, true, focus_visible);
 
@@ -343,6 +535,28 @@ impl Context {
         self.focus_with_visibility(old_focus_visible)
     }
 
+    /// Sets application focus to the given entity using the previous focus visibility.
+    pub fn focus_entity(&mut self, entity: Entity) {
+        self.with_current(entity, |cx| cx.focus());
+    }
+
+    /// Removes focus from the focused entity, returning it to the window.
+    pub fn clear_focus(&mut self) {
+        self.with_current(Entity::root(), |cx| cx.focus_with_visibility(false));
+    }
+
+    /// Returns the currently focused entity.
+    pub fn focused(&self) -> Entity {
+        self.focused
+    }
+
+    /// Returns every view under the given point, ordered from the topmost view to the bottommost.
+    ///
+    /// See [`EventContext::views_at`] for details.
+    pub fn views_at(&mut self, x: f32, y: f32) -> Vec<Entity> {
+        EventContext::new(self).views_at(x, y)
+    }
+
     /// Removes the children of the provided entity from the application.
     pub(crate) fn remove_children(&mut self, entity: Entity) {
         let child_iter = ChildIterator::new(&self.tree, entity);
@@ -354,7 +568,8 @@ impl Context {
 
     /// Removes the provided entity from the application.
     pub(crate) fn remove(&mut self, entity: Entity) {
-        let delete_list = entity.branch_iter(&self.tree).collect::<Vec<_>>();
+        // The content of a portal is removed along with the view which owns it.
+        let delete_list = branch_with_owned(&self.tree, entity);
 
         if !delete_list.is_empty() {
             self.style.needs_restyle();
@@ -362,6 +577,22 @@ impl Context {
             self.style.needs_redraw();
         }
 
+        // A hovered view which is removed is sent a mouse leave event while it still exists, and the
+        // hover passes to the parent of the removed views until the hovered view is next updated.
+        if delete_list.contains(&self.hovered) {
+            let hovered = self.hovered;
+            let mut event = Event::new(WindowEvent::MouseLeave).direct(hovered);
+            visit_entity(&mut EventContext::new(self), hovered, &mut event);
+
+            let parent = self.tree.get_parent(entity).unwrap_or(Entity::root());
+            if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(parent) {
+                pseudo_classes.set(PseudoClassFlags::HOVER, true);
+            }
+
+            self.hovered = parent;
+            self.event_queue.push_back(Event::new(WindowEvent::MouseEnter).direct(parent));
+        }
+
         for entity in delete_list.iter().rev() {
             if let Some(binding) = self.bindings.remove(entity) {
                 binding.remove(self);
@@ -394,29 +625,68 @@ impl Context {
                 self.captured = Entity::null();
             }
 
-            // Remove any cached filter images associated with the entity.
-            if let Some(canvas) = self.canvases.get_mut(&Entity::root()) {
-                if let Some((s, t)) = self.cache.filter_image.get(*entity).cloned().flatten() {
-                    canvas.delete_image(s);
-                    canvas.delete_image(t);
-                }
+            self.touches.retain(|_, target| target != entity);
+
+            if self.autofocus == Some(*entity) {
+                self.autofocus = None;
             }
 
-            // Remove any cached screenshot images associated with the entity.
-            if let Some(canvas) = self.canvases.get_mut(&Entity::root()) {
-                if let Some(s) = self.cache.screenshot_image.get(*entity).cloned().flatten() {
-                    canvas.delete_image(s);
+            // Remove any cached filter, screenshot, effect layer, shadow, and gradient images
+            // associated with the entity.
+            let mut images = Vec::new();
+            if let Some((s, t)) = self.cache.filter_image.get(*entity).cloned().flatten() {
+                images.extend([s, t]);
+            }
+
+            if let Some(s) = self.cache.screenshot_image.get(*entity).cloned().flatten() {
+                images.push(s);
+            }
+
+            if let Some((input, output)) = self.cache.effect_images.get(*entity).cloned() {
+                images.extend([input, output]);
+            }
+
+            if let Some(shadows) = self.cache.shadow_images.get(*entity).cloned() {
+                for (s, t) in shadows.into_iter().flatten() {
+                    images.extend([s, t]);
                 }
             }
 
-            // Remove any cached shadow images associated with the entity.
-            if let Some(canvas) = self.canvases.get_mut(&Entity::root()) {
-                if let Some(shadows) = self.cache.shadow_images.get(*entity).cloned() {
-                    for (s, t) in shadows.into_iter().flatten() {
-                        canvas.delete_image(s);
-                        canvas.delete_image(t);
+            if let Some(gradient_images) = self.cache.gradient_images.get(*entity) {
+                images.extend(gradient_images.values().map(|gradient_image| gradient_image.image));
+            }
+
+            // Images cached for views in other windows belong to the canvas of their window, and are
+            // deleted when the window is next drawn, unless the window is removed along with them.
+            let window = self.tree.window(*entity);
+            if window == Entity::root() {
+                if let Some(canvas) = self.canvases.get_mut(&Entity::root()) {
+                    for image in images {
+                        canvas.delete_image(image);
                     }
                 }
+            } else if !images.is_empty() && !delete_list.contains(&window) {
+                self.cache.removed_images.entry(window).or_default().extend(images);
+            }
+
+            // The rendering context of a window must be current when its canvas is dropped, so the
+            // windowing backend drops the window once it has been removed.
+            if self.tree.is_window(*entity) && *entity != Entity::root() {
+                if let (Some(view), Some(canvas)) =
+                    (self.views.remove(entity), self.canvases.remove(entity))
+                {
+                    let shader_context = self.shader_contexts.remove(entity);
+                    self.removed_windows.push((view, canvas, shader_context));
+                }
+
+                self.glyph_caches.remove(entity);
+                self.style.window_dpi_factors.remove(entity);
+                self.cache.window_images.remove(entity);
+                self.cache.removed_images.remove(entity);
+                self.spawned_windows.retain(|(window, _)| window != entity);
+                if self.cursor_window == *entity {
+                    self.cursor_window = Entity::root();
+                }
             }
 
             self.tree.remove(*entity).expect("");
@@ -424,6 +694,9 @@ impl Context {
             self.style.remove(*entity);
             self.data.remove(*entity);
             self.views.remove(entity);
+            self.event_filters.remove(entity);
+            self.key_repeats.retain(|key_repeat| key_repeat.entity != *entity);
+            self.tweens.retain(|tween| tween.entity != *entity);
             self.entity_manager.destroy(*entity);
             self.text_context.clear_buffer(*entity);
         }
@@ -461,6 +734,147 @@ impl Context {
         self.global_listeners.push(Box::new(listener));
     }
 
+    /// Adds an event filter to the current entity.
+    ///
+    /// Event filters are called with every event sent to the entity before it reaches the models and
+    /// the view of the entity. A filter can inspect or modify the event, and returning `false` drops
+    /// the event for this entity without consuming it, so it continues along its propagation path.
+    /// Filters are called in the order they were added, and are removed along with the entity.
+    pub fn add_event_filter<F>(&mut self, filter: F)
+    where
+        F: 'static + Fn(&mut EventContext, &mut Event) -> bool,
+    {
+        self.event_filters.entry(self.current).or_default().push(Box::new(filter));
+    }
+
+    /// Sets a callback which is called as every event is dispatched through the event manager, for
+    /// debugging why an event does or does not reach a handler.
+    ///
+    /// The callback receives the event, the [`EventPhase`] of dispatch, and the entity involved,
+    /// e.g. each entity the event visits as it propagates. Only one tracer can be set at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.set_event_tracer(|event, phase, entity| {
+    ///     println!("{} {:?} {}", event.type_name(), phase, entity);
+    /// });
+    /// ```
+    pub fn set_event_tracer<F>(&mut self, tracer: F)
+    where
+        F: 'static + Fn(&Event, EventPhase, Entity),
+    {
+        self.event_tracer = Some(Box::new(tracer));
+    }
+
+    /// Returns the number of entities which are currently alive, including the root window.
+    ///
+    /// A count which keeps rising while the application is not adding content usually means that views
+    /// are being built without the old ones being removed.
+    pub fn entity_count(&self) -> usize {
+        self.entity_manager.count()
+    }
+
+    /// Returns the number of views of each type, ordered from the most to the least common type.
+    ///
+    /// Entities without a view, such as bindings, are not included. This can be used to find which
+    /// views are responsible for a growing [`entity_count`](Context::entity_count).
+    pub fn view_counts(&self) -> Vec<(&'static str, usize)> {
+        let mut counts = HashMap::<&'static str, usize>::new();
+        for view in self.views.values() {
+            *counts.entry(view.type_name()).or_default() += 1;
+        }
+
+        let mut counts = counts.into_iter().collect::<Vec<_>>();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        counts
+    }
+
+    /// Prints a warning, with the most common view types, when the number of entities has grown in
+    /// each of `frames` consecutive frames in which it changed. Passing `0` disables the warning.
+    ///
+    /// This is meant for debugging leaks, where views keep being added to the tree without the old
+    /// ones being removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// #[cfg(debug_assertions)]
+    /// cx.warn_on_entity_growth(60);
+    /// ```
+    pub fn warn_on_entity_growth(&mut self, frames: usize) {
+        self.entity_growth = (frames > 0).then(|| EntityGrowth {
+            frames,
+            last_count: self.entity_manager.count(),
+            growing: 0,
+        });
+    }
+
+    // Called once per frame to update the entity count and print a warning if it has kept growing.
+    pub(crate) fn check_entity_growth(&mut self) {
+        let count = self.entity_manager.count();
+        let warning = if let Some(growth) = &mut self.entity_growth {
+            // Frames in which the count did not change are ignored, so that idle frames do not hide
+            // growth which only happens in response to events.
+            if count > growth.last_count {
+                growth.growing += 1;
+            } else if count < growth.last_count {
+                growth.growing = 0;
+            }
+
+            growth.last_count = count;
+
+            if growth.growing >= growth.frames {
+                growth.growing = 0;
+                Some(growth.frames)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        if let Some(frames) = warning {
+            eprintln!(
+                "Warning: the number of entities has grown for {} frames and is now {}. Most common views:",
+                frames, count
+            );
+            for (name, count) in self.view_counts().into_iter().take(5) {
+                eprintln!("    {}: {}", name, count);
+            }
+        }
+    }
+
+    /// Sets a callback which draws an overlay on top of the whole window after the views have been
+    /// drawn, e.g. for a frame counter or debugging visuals, without adding a view to the tree.
+    ///
+    /// The callback receives the canvas and the size of the window. Drawing is in physical pixels
+    /// from the top-left corner of the window, with no transform or clipping applied. Only one overlay
+    /// can be set at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::vg;
+    /// # let cx = &mut Context::default();
+    /// cx.set_draw_overlay(|canvas, (width, _)| {
+    ///     let mut path = vg::Path::new();
+    ///     path.rect(width - 20.0, 0.0, 20.0, 20.0);
+    ///     canvas.fill_path(&path, &vg::Paint::color(vg::Color::rgb(255, 0, 0)));
+    /// });
+    /// ```
+    pub fn set_draw_overlay<F>(&mut self, overlay: F)
+    where
+        F: 'static + FnMut(&mut Canvas, (f32, f32)),
+    {
+        self.draw_overlay = Some(Box::new(overlay));
+    }
+
     /// Add a font from memory to the application.
     ///
     ///
@@ -536,6 +950,21 @@ impl Context {
         self.style.add_animation(animation)
     }
 
+    /// Animates a value from `from` to `to` over the given duration, calling `callback` with the
+    /// interpolated value on every frame until the end value is reached.
+    ///
+    /// See [`EventContext::animate`] for details.
+    pub fn animate<T: Interpolator + 'static>(
+        &mut self,
+        from: T,
+        to: T,
+        duration: Duration,
+        easing: EasingFunction,
+        callback: impl FnMut(&mut EventContext, T) + 'static,
+    ) {
+        self.tweens.push(Tween::new(self.current, from, to, duration, easing, callback));
+    }
+
     pub fn set_image_loader<F: 'static + Fn(&mut ResourceContext, &str)>(&mut self, loader: F) {
         self.resource_manager.image_loader = Some(Box::new(loader));
     }
@@ -595,6 +1024,97 @@ impl Context {
         }
     }
 
+    /// Sets which high-frequency window events are coalesced before being dispatched.
+    ///
+    /// No events are coalesced by default.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::events::CoalescedEvents;
+    /// # let cx = &mut Context::default();
+    /// cx.set_coalesced_events(CoalescedEvents::MOUSE_MOVE | CoalescedEvents::MOUSE_SCROLL);
+    /// ```
+    pub fn set_coalesced_events(&mut self, coalesced_events: CoalescedEvents) {
+        self.coalesced_events = coalesced_events;
+    }
+
+    /// Sets the maximum number of events which are dispatched in a single frame. Any remaining events,
+    /// including events sent by handlers during the frame, are kept in the queue and dispatched in the
+    /// following frames, so that a flood of events, e.g. from a background thread through a
+    /// [`ContextProxy`], cannot stop the application from drawing.
+    ///
+    /// There is no limit by default. At least one event is dispatched per frame.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.set_max_events_per_frame(Some(1000));
+    /// ```
+    pub fn set_max_events_per_frame(&mut self, max_events: Option<usize>) {
+        self.max_events_per_frame = max_events.map(|max_events| max_events.max(1));
+    }
+
+    /// Returns the total number of events which have been deferred to a later frame because of the
+    /// limit set by [`set_max_events_per_frame`](Context::set_max_events_per_frame). An event which is
+    /// deferred more than once is counted each time.
+    pub fn deferred_event_count(&self) -> usize {
+        self.deferred_events
+    }
+
+    /// Sets whether tab navigation wraps around from the last view to the first, and from the first
+    /// view to the last. When not wrapping, focus stops at the first and last views. This can be
+    /// overridden for a focus scope or locked subtree with [`Handle::focus_wrap`].
+    ///
+    /// Focus wraps around by default.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.set_focus_wrap(false);
+    /// ```
+    pub fn set_focus_wrap(&mut self, wrap: bool) {
+        self.focus_wrap = wrap;
+    }
+
+    /// Sets whether frames which only change views being animated, or views which requested a redraw
+    /// with [`EventContext::needs_redraw`], redraw just the area of the window covered by those views,
+    /// rather than the whole window. This speeds up drawing for an application with a small animating
+    /// view, such as a blinking caret, over a complex static background.
+    ///
+    /// Any other change, such as a restyle, a relayout, or a resize of the window, still redraws the
+    /// whole window. Partial redraw has no effect with [`ClearBehavior::Preserve`], or while a
+    /// backdrop filter is in use.
+    ///
+    /// Partial redraw is disabled by default.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.set_partial_redraw(true);
+    /// ```
+    pub fn set_partial_redraw(&mut self, enabled: bool) {
+        self.partial_redraw = enabled;
+    }
+
+    /// Sets whether the area of the main window redrawn by each frame is tinted, which shows which
+    /// parts of the window are repainted when [partial redraw](Context::set_partial_redraw) is
+    /// enabled. This is intended for debugging.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.set_partial_redraw(true);
+    /// cx.set_highlight_redraw(true);
+    /// ```
+    pub fn set_highlight_redraw(&mut self, enabled: bool) {
+        self.highlight_redraw = enabled;
+    }
+
     /// Finds the entity that identifier identifies
     pub fn resolve_entity_identifier(&self, identity: &str) -> Option<Entity> {
         self.entity_identifiers.get(identity).cloned()
@@ -610,6 +1130,16 @@ pub(crate) enum InternalEvent {
     },
 }
 
+// Returns whether a redraw request is waiting in the given event queue.
+pub(crate) fn redraw_queued(event_queue: &VecDeque<Event>) -> bool {
+    event_queue.iter().any(|event| {
+        matches!(
+            event.message.as_ref().and_then(|message| message.downcast_ref::<InternalEvent>()),
+            Some(InternalEvent::Redraw)
+        )
+    })
+}
+
 /// A trait for any Context-like object that lets you access stored model data.
 ///
 /// This lets e.g Lens::get be generic over any of these types.
@@ -674,3 +1204,9 @@ impl EmitContext for Context {
         self.event_queue.push_back(event);
     }
 }
+
+// The root view of a window opened with `spawn_window`, which is replaced by the window view of the
+// windowing backend once the window has been created.
+struct WindowRoot;
+
+impl View for WindowRoot {}
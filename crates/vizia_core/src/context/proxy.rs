@@ -71,10 +71,18 @@ impl ContextProxy {
         }
     }
 
+    /// Requests that the window be redrawn, waking the event loop if it is idle.
+    ///
+    /// Multiple requests made before the next frame are coalesced into a single redraw.
     pub fn redraw(&mut self) -> Result<(), ProxyEmitError> {
         self.emit(InternalEvent::Redraw)
     }
 
+    /// Requests that the application quits, as if the window had been closed.
+    pub fn quit(&mut self) -> Result<(), ProxyEmitError> {
+        self.emit_to(Entity::root(), WindowEvent::WindowClose)
+    }
+
     pub fn load_image(
         &mut self,
         path: String,
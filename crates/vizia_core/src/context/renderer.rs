@@ -0,0 +1,52 @@
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+
+const GL_VENDOR: u32 = 0x1F00;
+const GL_RENDERER: u32 = 0x1F01;
+const GL_VERSION: u32 = 0x1F02;
+
+/// Information about the graphics driver used to render the application.
+///
+/// This is captured once when the window and its OpenGL context are created, and can be retrieved
+/// with [`Context::renderer_info()`](crate::context::Context::renderer_info). It is useful to include
+/// when reporting rendering bugs, or to warn users about drivers with known issues.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RendererInfo {
+    /// The company responsible for the OpenGL implementation, e.g. "NVIDIA Corporation".
+    pub vendor: String,
+    /// The name of the renderer, typically the name of the GPU.
+    pub renderer: String,
+    /// The OpenGL version string reported by the driver.
+    pub version: String,
+}
+
+impl RendererInfo {
+    /// Queries the renderer information from the OpenGL context using the given function loader.
+    ///
+    /// Returns empty strings for any value which could not be queried.
+    ///
+    /// # Safety
+    ///
+    /// An OpenGL context must be current on the calling thread and the loader must return function
+    /// pointers for that context.
+    pub unsafe fn from_gl_loader(mut load: impl FnMut(&str) -> *const c_void) -> Self {
+        let get_string = load("glGetString");
+        if get_string.is_null() {
+            return Self::default();
+        }
+
+        let get_string: unsafe extern "system" fn(u32) -> *const c_char =
+            std::mem::transmute(get_string);
+
+        let read = |name: u32| {
+            let ptr = get_string(name);
+            if ptr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            }
+        };
+
+        Self { vendor: read(GL_VENDOR), renderer: read(GL_RENDERER), version: read(GL_VERSION) }
+    }
+}
@@ -3,6 +3,7 @@ use std::collections::{hash_map::Entry, HashMap, HashSet};
 use vizia_storage::Tree;
 
 use crate::{
+    cache::CachedData,
     entity::Entity,
     resource::{ImageOrId, ImageRetentionPolicy, ResourceManager, StoredImage},
     style::Style,
@@ -17,6 +18,7 @@ pub struct ResourceContext<'a> {
     pub(crate) resource_manager: &'a mut ResourceManager,
     pub(crate) canvases: &'a mut HashMap<Entity, crate::prelude::Canvas>,
     pub(crate) style: &'a mut Style,
+    pub(crate) cache: &'a CachedData,
     pub(crate) tree: &'a Tree<Entity>,
 }
 
@@ -28,6 +30,7 @@ impl<'a> ResourceContext<'a> {
             resource_manager: &mut cx.resource_manager,
             canvases: &mut cx.canvases,
             style: &mut cx.style,
+            cache: &cx.cache,
             tree: &cx.tree,
         }
     }
@@ -1,6 +1,7 @@
 use crate::entity::Entity;
 use std::{any::Any, fmt::Debug};
 use vizia_id::GenerationalId;
+use vizia_input::Modifiers;
 
 /// Determines how an event propagates through the tree.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -17,12 +18,29 @@ pub enum Propagation {
     Direct,
 }
 
+/// The stage of dispatch an event has reached, reported to an event tracer.
+///
+/// See [`Context::set_event_tracer`](crate::context::Context::set_event_tracer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+    /// The event has been taken from the event queue. The entity is the target of the event.
+    Dispatched,
+    /// The event is about to be sent to the filters, models and view of the entity.
+    Visited,
+    /// The event was dropped for the entity by one of its event filters.
+    Filtered,
+    /// The event was consumed by the entity and will not propagate any further.
+    Consumed,
+}
+
 /// A wrapper around a message, providing metadata on how the event travels through the view tree.
 pub struct Event {
     /// The meta data of the event
     pub(crate) meta: EventMeta,
     /// The message of the event
     pub(crate) message: Option<Box<dyn Any + Send>>,
+    /// The type name of the message, used for debugging
+    pub(crate) type_name: &'static str,
 }
 
 impl Debug for Event {
@@ -37,7 +55,16 @@ impl Event {
     where
         M: Any + Send,
     {
-        Event { meta: Default::default(), message: Some(Box::new(message)) }
+        Event {
+            meta: Default::default(),
+            message: Some(Box::new(message)),
+            type_name: std::any::type_name::<M>(),
+        }
+    }
+
+    /// Returns the type name of the message of the event, e.g. for logging.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
     }
 
     /// Sets the target of the event.
@@ -58,6 +85,12 @@ impl Event {
         self
     }
 
+    /// Sets the keyboard modifiers which were held down when the event was produced.
+    pub fn modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.meta.modifiers = modifiers;
+        self
+    }
+
     /// Sets the propagation to directly target the `entity`.
     pub fn direct(mut self, entity: Entity) -> Self {
         self.meta.propagation = Propagation::Direct;
@@ -165,6 +198,13 @@ pub struct EventMeta {
     pub target: Entity,
     /// How the event propagates through the tree.
     pub propagation: Propagation,
+    /// The keyboard modifiers which were held down when the event was produced.
+    ///
+    /// Events from the windowing backend, such as [`WindowEvent::MouseDown`](crate::prelude::WindowEvent::MouseDown)
+    /// and [`WindowEvent::KeyDown`](crate::prelude::WindowEvent::KeyDown), capture the modifiers when
+    /// they are emitted, so this is reliable even if the modifiers change before the event is handled.
+    /// For other events the modifiers are empty unless set with [`Event::modifiers`].
+    pub modifiers: Modifiers,
     /// Determines whether the event should continue to be propagated.
     pub(crate) consumed: bool,
 }
@@ -182,6 +222,7 @@ impl Default for EventMeta {
             origin: Entity::null(),
             target: Entity::root(),
             propagation: Propagation::Up,
+            modifiers: Modifiers::empty(),
             consumed: false,
         }
     }
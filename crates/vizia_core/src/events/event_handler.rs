@@ -8,6 +8,8 @@ pub(crate) trait ViewHandler: Any {
         None
     }
 
+    fn type_name(&self) -> &'static str;
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event);
 
     fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas);
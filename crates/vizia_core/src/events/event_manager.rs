@@ -1,9 +1,13 @@
-use crate::context::{InternalEvent, ResourceContext};
-use crate::events::EventMeta;
+use crate::context::{EventTracer, InternalEvent, ResourceContext};
+use crate::events::{EventMeta, EventPhase};
 use crate::prelude::*;
 use crate::style::{Abilities, PseudoClassFlags};
-use crate::systems::{compute_matched_rules, hover_system};
-use crate::tree::{focus_backward, focus_forward, is_navigatable};
+use crate::systems::{
+    compute_matched_rules, hit_test, hover_system, start_key_repeats, stop_key_repeats,
+};
+use crate::tree::{branch_with_owned, focus_backward, focus_forward, is_navigatable};
+use crate::views::SCROLL_SENSITIVITY;
+use bitflags::bitflags;
 use instant::{Duration, Instant};
 use std::any::Any;
 use vizia_id::GenerationalId;
@@ -12,6 +16,21 @@ use vizia_storage::TreeIterator;
 
 const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
 
+bitflags! {
+    /// High-frequency window events which are coalesced before being dispatched.
+    ///
+    /// Consecutive events of a coalesced type, which arrive within the same frame, are merged into a
+    /// single event. This reduces the number of times handlers are invoked, e.g. for drag handlers which
+    /// only need the final cursor position for each frame. See [`Context::set_coalesced_events`].
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct CoalescedEvents: u8 {
+        /// Consecutive [`WindowEvent::MouseMove`] events are replaced by the latest one.
+        const MOUSE_MOVE = 1 << 0;
+        /// Consecutive [`WindowEvent::MouseScroll`] events are replaced by a single event with the sum of their deltas.
+        const MOUSE_SCROLL = 1 << 1;
+    }
+}
+
 /// Dispatches events to views and models.
 ///
 /// The [EventManager] is responsible for taking the events in the event queue in context
@@ -28,16 +47,28 @@ impl EventManager {
     }
 
     /// Flush the event queue, dispatching events to their targets.
-    /// Returns whether there are still more events to process, i.e. the event handlers sent events.
-    pub(crate) fn flush_events(&mut self, cx: &mut Context) -> bool {
+    ///
+    /// At most `budget` events are dispatched, if given, and the budget is reduced by the number of
+    /// dispatched events. Returns whether there are still more events to process within the budget,
+    /// i.e. the event handlers sent events.
+    pub(crate) fn flush_events(&mut self, cx: &mut Context, budget: &mut Option<usize>) -> bool {
         // Clear the event queue in the event manager
         self.event_queue.clear();
 
-        // Move events from state to event manager
-        self.event_queue.extend(cx.event_queue.drain(0..));
+        // Move events from state to event manager, leaving any events over the budget in the queue
+        let count = budget.map_or(cx.event_queue.len(), |budget| budget.min(cx.event_queue.len()));
+        if let Some(budget) = budget {
+            *budget -= count;
+        }
+
+        self.event_queue.extend(cx.event_queue.drain(0..count));
+
+        coalesce_events(&mut self.event_queue, cx.coalesced_events);
 
         // Loop over the events in the event queue
         'events: for event in self.event_queue.iter_mut() {
+            trace_event(&cx.event_tracer, event, EventPhase::Dispatched, event.meta.target);
+
             // Handle internal events
             event.map(|internal_event, _| match internal_event {
                 InternalEvent::Redraw => cx.needs_redraw(),
@@ -72,6 +103,7 @@ impl EventManager {
                 }
 
                 if event.meta.consumed {
+                    trace_event(&cx.event_tracer, event, EventPhase::Consumed, entity);
                     continue 'events;
                 }
             }
@@ -85,6 +117,7 @@ impl EventManager {
 
             // Skip to next event if the current event was consumed when handling state updates.
             if event.meta.consumed {
+                trace_event(&cx.event_tracer, event, EventPhase::Consumed, Entity::root());
                 continue 'events;
             }
 
@@ -118,8 +151,9 @@ impl EventManager {
             }
 
             if event.meta.propagation == Propagation::Subtree {
-                // Create a parent iterator and skip the first element which is the target.
-                let iter = target.branch_iter(cx.tree).skip(1);
+                // Collect the subtree, including the content of any portals within it, and skip the
+                // first element which is the target.
+                let iter = branch_with_owned(cx.tree, target).into_iter().skip(1);
                 // Walk down the subtree
                 for entity in iter {
                     // Send event to all entities in the subtree after the target
@@ -131,14 +165,53 @@ impl EventManager {
                     }
                 }
             }
+
+            // Scroll a view with scrolling overflow by a window event which no view consumed.
+            event.map(|window_event, meta| {
+                if meta.origin == Entity::root() {
+                    scroll_default_action(cx, window_event, meta);
+                }
+            });
         }
 
-        // Return true if there are new events in the queue
-        !cx.event_queue.is_empty()
+        // Return true if there are new events in the queue which can be dispatched in this frame
+        !cx.event_queue.is_empty() && *budget != Some(0)
+    }
+}
+
+fn trace_event(tracer: &Option<EventTracer>, event: &Event, phase: EventPhase, entity: Entity) {
+    if let Some(tracer) = tracer {
+        (tracer)(event, phase, entity);
     }
 }
 
-fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
+// Sends an event to the filters, models, and view of an entity.
+pub(crate) fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
+    trace_event(cx.event_tracer, event, EventPhase::Visited, entity);
+
+    // Send event to any filters attached to the entity, which may drop it before it reaches the
+    // models and view of the entity.
+    if let Some(filters) = cx.event_filters.remove(&entity) {
+        cx.current = entity;
+        let pass = filters.iter().all(|filter| (filter)(cx, event));
+
+        // Keep any filters added to the entity by the filters themselves.
+        let added = cx.event_filters.insert(entity, filters);
+        if let Some(added) = added {
+            cx.event_filters.get_mut(&entity).unwrap().extend(added);
+        }
+
+        if event.meta.consumed {
+            trace_event(cx.event_tracer, event, EventPhase::Consumed, entity);
+            return;
+        }
+
+        if !pass {
+            trace_event(cx.event_tracer, event, EventPhase::Filtered, entity);
+            return;
+        }
+    }
+
     // Send event to models attached to the entity
     if let Some(ids) = cx
         .data
@@ -164,6 +237,7 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
 
     // Return early if the event was consumed by a model
     if event.meta.consumed {
+        trace_event(cx.event_tracer, event, EventPhase::Consumed, entity);
         return;
     }
 
@@ -174,15 +248,35 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
 
         cx.views.insert(entity, view);
     }
+
+    if event.meta.consumed {
+        trace_event(cx.event_tracer, event, EventPhase::Consumed, entity);
+    }
 }
 
 /// Update the internal state of the context based on received window event and emit window event to relevant target.
 fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, meta: &mut EventMeta) {
     match window_event {
+        // Closing the main window quits the application.
+        WindowEvent::WindowClose if meta.target == Entity::root() => {
+            context.quitting = true;
+        }
+
         WindowEvent::Drop(drop_data) => {
             context.drop_data = Some(drop_data.clone());
         }
 
+        // Files dragged from outside the application are sent to the view under the cursor, in the
+        // same way as mouse moves.
+        WindowEvent::FileDrop(path) => {
+            context.drop_data = Some(DropData::File(path.clone()));
+            mutate_direct_or_up(meta, context.captured, context.hovered, true);
+        }
+
+        WindowEvent::FileHover(_) | WindowEvent::FileHoverCancel => {
+            mutate_direct_or_up(meta, context.captured, context.hovered, true);
+        }
+
         WindowEvent::MouseMove(x, y) => {
             context.mouse.previous_cursorx = context.mouse.cursorx;
             context.mouse.previous_cursory = context.mouse.cursory;
@@ -202,6 +296,57 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
             //     );
             // }
         }
+        WindowEvent::Touch { id, phase, x, y } => {
+            let target = match context.touches.get(id).copied() {
+                Some(target) if *phase != TouchPhase::Started => target,
+                _ => {
+                    let window = context.cursor_window;
+                    let hit_tested = hit_test(&mut EventContext::new(context), window, *x, *y);
+                    hit_tested.into_iter().rev().find(|(_, hit)| *hit).map_or(window, |(e, _)| e)
+                }
+            };
+
+            match phase {
+                TouchPhase::Started => {
+                    context.touches.insert(*id, target);
+                }
+                TouchPhase::Moved => {}
+                TouchPhase::Ended | TouchPhase::Cancelled => {
+                    context.touches.remove(id);
+                }
+            }
+
+            // The first touch acts as the left mouse button.
+            if *phase == TouchPhase::Started && context.primary_touch.is_none() {
+                context.primary_touch = Some(*id);
+                emit_mouse(context, WindowEvent::MouseMove(*x, *y));
+                emit_mouse(context, WindowEvent::MouseDown(MouseButton::Left));
+            } else if context.primary_touch == Some(*id) {
+                match phase {
+                    TouchPhase::Started | TouchPhase::Moved => {
+                        emit_mouse(context, WindowEvent::MouseMove(*x, *y));
+                    }
+                    TouchPhase::Ended => {
+                        context.primary_touch = None;
+                        emit_mouse(context, WindowEvent::MouseMove(*x, *y));
+                        emit_mouse(context, WindowEvent::MouseUp(MouseButton::Left));
+                    }
+                    // A cancelled touch releases the pressed view without pressing it.
+                    TouchPhase::Cancelled => {
+                        context.primary_touch = None;
+                        if let Some(pseudo_classes) =
+                            context.style.pseudo_classes.get_mut(context.triggered)
+                        {
+                            pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
+                        }
+                        context.triggered = Entity::null();
+                        emit_mouse(context, WindowEvent::MouseUp(MouseButton::Left));
+                    }
+                }
+            }
+
+            mutate_direct_or_up(meta, context.captured, target, true);
+        }
         WindowEvent::MouseDown(button) => {
             // do direct state-updates
             match button {
@@ -211,6 +356,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                     context.mouse.left.pos_down = (context.mouse.cursorx, context.mouse.cursory);
                     context.mouse.left.pressed = context.hovered;
                     context.triggered = context.hovered;
+                    context.pressed_link = context.hovered_link.clone();
 
                     let disabled =
                         context.style.disabled.get(context.hovered).copied().unwrap_or_default();
@@ -315,6 +461,19 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                     );
                 }
 
+                // A link is clicked when the mouse button is pressed and released over the same link.
+                if let Some((entity, url)) = context.pressed_link.take() {
+                    if context.hovered_link == Some((entity, url.clone())) {
+                        emit_direct_or_up(
+                            context,
+                            WindowEvent::LinkClicked(url),
+                            context.captured,
+                            entity,
+                            true,
+                        );
+                    }
+                }
+
                 if let Some(pseudo_classes) =
                     context.style.pseudo_classes.get_mut(context.triggered)
                 {
@@ -333,6 +492,12 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
         WindowEvent::KeyDown(code, _) => {
             meta.target = context.focused;
 
+            // Key repeats are driven by their own timers, so repeated key down events from the OS
+            // are ignored.
+            if context.keys_down.insert(*code) {
+                start_key_repeats(context, *code);
+            }
+
             #[cfg(debug_assertions)]
             if *code == Code::KeyH {
                 for entity in context.tree.into_iter() {
@@ -470,13 +635,32 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 EventContext::new(context).reload_styles().unwrap();
             }
 
+            // Escape returns focus from the views within a focus scope to the scope itself.
+            if *code == Code::Escape {
+                if let Some(scope) = context.tree.focus_scope(context.focused) {
+                    context.with_current(scope, |cx| cx.focus_with_visibility(true));
+                }
+            }
+
             if *code == Code::Tab {
                 let lock_focus_to = context.tree.lock_focus_within(context.focused);
+                // Within a focus scope, tab moves between the views of the scope.
+                let lock_focus_to = context
+                    .tree
+                    .focus_scope(context.focused)
+                    .filter(|scope| scope.is_descendant_of(&context.tree, lock_focus_to))
+                    .unwrap_or(lock_focus_to);
+                // Whether focus wraps around at either end of the scope. Focus always moves onto the
+                // first or last view if nothing is focused.
+                let wrap = context.tree.focus_wrap(lock_focus_to).unwrap_or(context.focus_wrap)
+                    || context.tree.is_window(context.focused);
                 if context.modifiers.contains(Modifiers::SHIFT) {
                     let prev_focused = if let Some(prev_focused) =
                         focus_backward(context, context.focused, lock_focus_to)
                     {
                         prev_focused
+                    } else if !wrap {
+                        context.focused
                     } else {
                         TreeIterator::full(&context.tree)
                             .filter(|node| is_navigatable(context, *node, lock_focus_to))
@@ -485,16 +669,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                     };
 
                     if prev_focused != context.focused {
-                        context.event_queue.push_back(
-                            Event::new(WindowEvent::FocusOut)
-                                .target(context.focused)
-                                .origin(Entity::root()),
-                        );
-                        context.event_queue.push_back(
-                            Event::new(WindowEvent::FocusIn)
-                                .target(prev_focused)
-                                .origin(Entity::root()),
-                        );
+                        context.with_current(prev_focused, |cx| cx.focus_with_visibility(true));
 
                         if let Some(pseudo_classes) =
                             context.style.pseudo_classes.get_mut(context.triggered)
@@ -509,6 +684,8 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                         focus_forward(context, context.focused, lock_focus_to)
                     {
                         next_focused
+                    } else if !wrap {
+                        context.focused
                     } else {
                         TreeIterator::full(&context.tree)
                             .find(|node| is_navigatable(context, *node, lock_focus_to))
@@ -516,16 +693,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                     };
 
                     if next_focused != context.focused {
-                        context.event_queue.push_back(
-                            Event::new(WindowEvent::FocusOut)
-                                .target(context.focused)
-                                .origin(Entity::root()),
-                        );
-                        context.event_queue.push_back(
-                            Event::new(WindowEvent::FocusIn)
-                                .target(next_focused)
-                                .origin(Entity::root()),
-                        );
+                        context.with_current(next_focused, |cx| cx.focus_with_visibility(true));
 
                         if let Some(pseudo_classes) =
                             context.style.pseudo_classes.get_mut(context.triggered)
@@ -550,8 +718,15 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 });
             }
         }
+        WindowEvent::ScancodeDown(_) | WindowEvent::ScancodeUp(_) => {
+            meta.target = context.focused;
+        }
         WindowEvent::KeyUp(code, _) => {
             meta.target = context.focused;
+
+            context.keys_down.remove(code);
+            stop_key_repeats(context, *code);
+
             if matches!(code, Code::Enter | Code::NumpadEnter | Code::Space) {
                 if context.focused == context.triggered {
                     context.with_current(context.triggered, |cx| {
@@ -567,19 +742,48 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 context.triggered = Entity::null();
             }
         }
-        WindowEvent::CharInput(_) => {
+        WindowEvent::CharInput(_) | WindowEvent::ImePreedit(..) | WindowEvent::ImeCommit(_) => {
             meta.target = context.focused;
         }
-        WindowEvent::FocusOut => {
-            context.set_focus_pseudo_classes(context.focused, false, true);
-            context.focused = Entity::null();
+        _ => {}
+    }
+}
+
+// Scrolls the nearest view containing the target of a mouse scroll, or of a Page Up or Page Down key
+// press, which has `Overflow::Scroll` and isn't already scrolled to the end in that direction.
+fn scroll_default_action(cx: &mut EventContext, window_event: &WindowEvent, meta: &EventMeta) {
+    let scale_factor = cx.scale_factor();
+    let (delta, page) = match window_event {
+        WindowEvent::MouseScroll(x, y) => {
+            let (x, y) = if cx.modifiers.contains(Modifiers::SHIFT) { (*y, *x) } else { (*x, *y) };
+            ((-x * SCROLL_SENSITIVITY * scale_factor, -y * SCROLL_SENSITIVITY * scale_factor), 0.0)
+        }
+        WindowEvent::KeyDown(Code::PageUp, _) => ((0.0, 0.0), -1.0),
+        WindowEvent::KeyDown(Code::PageDown, _) => ((0.0, 0.0), 1.0),
+        _ => return,
+    };
+
+    let scrolls = |overflow: Option<&Overflow>| overflow == Some(&Overflow::Scroll);
+    let current = cx.current;
+    for entity in meta.target.parent_iter(cx.tree) {
+        cx.current = entity;
+        let scroll_x = scrolls(cx.style.overflowx.get(entity));
+        let scroll_y = scrolls(cx.style.overflowy.get(entity));
+        if !scroll_x && !scroll_y {
+            continue;
         }
-        WindowEvent::FocusIn => {
-            context.focused = meta.target;
-            context.set_focus_pseudo_classes(context.focused, true, true);
+
+        // A page keeps a little of the previous page in view.
+        let (dx, dy) = (delta.0, delta.1 + page * cx.bounds().h * 0.875);
+        let (x, y) = cx.scroll_offset();
+        cx.set_scroll_offset(if scroll_x { x + dx } else { x }, if scroll_y { y + dy } else { y });
+
+        if cx.scroll_offset() != (x, y) {
+            break;
         }
-        _ => {}
     }
+
+    cx.current = current;
 }
 
 fn mutate_direct_or_up(meta: &mut EventMeta, direct: Entity, up: Entity, root: bool) {
@@ -594,6 +798,17 @@ fn mutate_direct_or_up(meta: &mut EventMeta, direct: Entity, up: Entity, root: b
     }
 }
 
+// Emits a mouse event emulated from a touch, as if it had been sent by the windowing backend.
+fn emit_mouse(context: &mut Context, event: WindowEvent) {
+    context.emit_custom(
+        Event::new(event)
+            .target(Entity::root())
+            .origin(Entity::root())
+            .propagate(Propagation::Up)
+            .modifiers(context.modifiers),
+    );
+}
+
 fn emit_direct_or_up<M: Any + Send>(
     context: &mut Context,
     message: M,
@@ -605,3 +820,276 @@ fn emit_direct_or_up<M: Any + Send>(
     mutate_direct_or_up(&mut event.meta, direct, up, root);
     context.emit_custom(event);
 }
+
+// Merges consecutive window events of the coalesced types.
+fn coalesce_events(events: &mut Vec<Event>, coalesced_events: CoalescedEvents) {
+    if coalesced_events.is_empty() || events.len() < 2 {
+        return;
+    }
+
+    let mut coalesced: Vec<Event> = Vec::with_capacity(events.len());
+    for event in events.drain(0..) {
+        if let Some(previous) = coalesced.last_mut() {
+            if merge_events(previous, &event, coalesced_events) {
+                continue;
+            }
+        }
+
+        coalesced.push(event);
+    }
+
+    *events = coalesced;
+}
+
+// Merges `event` into `previous` if both are window events of the same coalesced type and have the
+// same origin and target. Returns true if the events were merged.
+fn merge_events(previous: &mut Event, event: &Event, coalesced_events: CoalescedEvents) -> bool {
+    if previous.meta.origin != event.meta.origin || previous.meta.target != event.meta.target {
+        return false;
+    }
+
+    let previous_message =
+        previous.message.as_mut().and_then(|message| message.downcast_mut::<WindowEvent>());
+    let message = event.message.as_ref().and_then(|message| message.downcast_ref::<WindowEvent>());
+
+    match (previous_message, message) {
+        (Some(WindowEvent::MouseMove(px, py)), Some(WindowEvent::MouseMove(x, y)))
+            if coalesced_events.contains(CoalescedEvents::MOUSE_MOVE) =>
+        {
+            *px = *x;
+            *py = *y;
+            true
+        }
+
+        (Some(WindowEvent::MouseScroll(px, py)), Some(WindowEvent::MouseScroll(x, y)))
+            if coalesced_events.contains(CoalescedEvents::MOUSE_SCROLL) =>
+        {
+            *px += *x;
+            *py += *y;
+            true
+        }
+
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    // Builds a 100 pixel tall view holding ten 50 pixel tall items, with the given vertical overflow.
+    fn scroll_harness(overflow: Overflow) -> (TestHarness, Entity) {
+        let mut harness = TestHarness::new(|cx| {
+            VStack::new(cx, |cx| {
+                Element::new(cx).height(Pixels(50.0)).id("first");
+                for _ in 0..8 {
+                    Element::new(cx).height(Pixels(50.0));
+                }
+                Element::new(cx).height(Pixels(50.0)).id("last");
+            })
+            .size(Pixels(100.0))
+            .overflowy(overflow)
+            .id("scroll");
+        });
+
+        let scroll = harness.entity("scroll").unwrap();
+        let bounds = harness.bounds(scroll);
+        harness.mouse_move(bounds.center().0, bounds.center().1);
+        (harness, scroll)
+    }
+
+    fn offset(harness: &mut TestHarness, scroll: Entity) -> (f32, f32) {
+        harness.context().cache.scroll_offset.get(scroll).copied().unwrap_or_default()
+    }
+
+    #[test]
+    fn mouse_wheel_scrolls_scrolling_overflow() {
+        let (mut harness, scroll) = scroll_harness(Overflow::Scroll);
+        let first = harness.entity("first").unwrap();
+        let top = harness.bounds(first).y;
+
+        harness.send(WindowEvent::MouseScroll(0.0, -1.0));
+
+        assert_eq!(offset(&mut harness, scroll), (0.0, SCROLL_SENSITIVITY));
+        assert_eq!(harness.bounds(first).y, top - SCROLL_SENSITIVITY);
+    }
+
+    #[test]
+    fn mouse_wheel_does_not_scroll_hidden_overflow() {
+        let (mut harness, scroll) = scroll_harness(Overflow::Hidden);
+
+        harness.send(WindowEvent::MouseScroll(0.0, -1.0));
+
+        assert_eq!(offset(&mut harness, scroll), (0.0, 0.0));
+    }
+
+    #[test]
+    fn page_keys_scroll_the_view_containing_the_focus() {
+        let (mut harness, scroll) = scroll_harness(Overflow::Scroll);
+        harness.context().focused = harness.entity("first").unwrap();
+
+        harness.key_press(Code::PageDown, None);
+        assert_eq!(offset(&mut harness, scroll), (0.0, 87.5));
+
+        harness.key_press(Code::PageUp, None);
+        assert_eq!(offset(&mut harness, scroll), (0.0, 0.0));
+    }
+
+    #[test]
+    fn scrolling_is_clamped_to_the_content() {
+        let (mut harness, scroll) = scroll_harness(Overflow::Scroll);
+        let last = harness.entity("last").unwrap();
+
+        for _ in 0..20 {
+            harness.send(WindowEvent::MouseScroll(0.0, -1.0));
+        }
+
+        assert_eq!(offset(&mut harness, scroll), (0.0, 400.0));
+        assert_eq!(harness.bounds(last).bottom(), harness.bounds(scroll).bottom());
+
+        harness.send(WindowEvent::MouseScroll(0.0, 100.0));
+        assert_eq!(offset(&mut harness, scroll), (0.0, 0.0));
+    }
+
+    #[test]
+    fn scroll_to_shows_the_view() {
+        let (mut harness, scroll) = scroll_harness(Overflow::Scroll);
+        let first = harness.entity("first").unwrap();
+        let last = harness.entity("last").unwrap();
+
+        EventContext::new(harness.context()).scroll_to(last);
+        harness.step();
+        assert_eq!(offset(&mut harness, scroll), (0.0, 400.0));
+        assert_eq!(harness.bounds(last).bottom(), harness.bounds(scroll).bottom());
+
+        EventContext::new(harness.context()).scroll_to(first);
+        harness.step();
+        assert_eq!(offset(&mut harness, scroll), (0.0, 0.0));
+        assert_eq!(harness.bounds(first).top(), harness.bounds(scroll).top());
+    }
+
+    #[test]
+    fn nested_scroll_view_consumes_the_scroll() {
+        let mut harness = TestHarness::new(|cx| {
+            ScrollView::new(cx, 0.0, 0.0, false, true, |cx| {
+                ScrollView::new(cx, 0.0, 0.0, false, true, |cx| {
+                    Element::new(cx).height(Pixels(200.0));
+                })
+                .height(Pixels(50.0))
+                .id("inner");
+                Element::new(cx).height(Pixels(300.0));
+            })
+            .size(Pixels(100.0))
+            .id("outer");
+        });
+
+        let inner = harness.entity("inner").unwrap();
+        let outer = harness.entity("outer").unwrap();
+        let bounds = harness.bounds(inner);
+        harness.mouse_move(bounds.center().0, bounds.center().1);
+
+        harness.send(WindowEvent::MouseScroll(0.0, -1.0));
+
+        let mut scroll_y = |entity: Entity| {
+            let cx = harness.context();
+            cx.current = entity;
+            cx.data::<ScrollData>().unwrap().scroll_y
+        };
+        assert!(scroll_y(inner) > 0.0);
+        assert_eq!(scroll_y(outer), 0.0);
+    }
+
+    #[test]
+    fn closing_the_main_window_quits() {
+        let mut harness = TestHarness::new(|cx| {
+            Element::new(cx);
+        });
+        assert!(!EventContext::new(harness.context()).is_quitting());
+
+        harness.send(WindowEvent::WindowClose);
+
+        assert!(EventContext::new(harness.context()).is_quitting());
+    }
+
+    fn window_events(events: &mut [Event]) -> Vec<WindowEvent> {
+        events.iter_mut().map(|event| event.take::<WindowEvent>().unwrap()).collect()
+    }
+
+    #[test]
+    fn consecutive_mouse_moves_are_replaced_by_the_latest() {
+        let mut events = vec![
+            Event::new(WindowEvent::MouseMove(1.0, 2.0)),
+            Event::new(WindowEvent::MouseMove(3.0, 4.0)),
+            Event::new(WindowEvent::MouseMove(5.0, 6.0)),
+        ];
+
+        coalesce_events(&mut events, CoalescedEvents::MOUSE_MOVE);
+
+        let events = window_events(&mut events);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], WindowEvent::MouseMove(x, y) if x == 5.0 && y == 6.0));
+    }
+
+    #[test]
+    fn consecutive_mouse_scrolls_are_summed() {
+        let mut events = vec![
+            Event::new(WindowEvent::MouseScroll(1.0, -1.0)),
+            Event::new(WindowEvent::MouseScroll(0.5, -2.0)),
+        ];
+
+        coalesce_events(&mut events, CoalescedEvents::MOUSE_SCROLL);
+
+        let events = window_events(&mut events);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], WindowEvent::MouseScroll(x, y) if x == 1.5 && y == -3.0));
+    }
+
+    #[test]
+    fn other_events_are_kept_in_order() {
+        let mut events = vec![
+            Event::new(WindowEvent::MouseMove(1.0, 2.0)),
+            Event::new(WindowEvent::KeyDown(Code::KeyA, None)),
+            Event::new(WindowEvent::KeyDown(Code::KeyA, None)),
+            Event::new(WindowEvent::MouseMove(3.0, 4.0)),
+            Event::new(WindowEvent::MouseMove(5.0, 6.0)),
+        ];
+
+        coalesce_events(&mut events, CoalescedEvents::all());
+
+        let events = window_events(&mut events);
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[0], WindowEvent::MouseMove(x, y) if x == 1.0 && y == 2.0));
+        assert!(matches!(events[1], WindowEvent::KeyDown(Code::KeyA, None)));
+        assert!(matches!(events[2], WindowEvent::KeyDown(Code::KeyA, None)));
+        assert!(matches!(events[3], WindowEvent::MouseMove(x, y) if x == 5.0 && y == 6.0));
+    }
+
+    #[test]
+    fn events_are_only_coalesced_when_enabled() {
+        let mut events = vec![
+            Event::new(WindowEvent::MouseMove(1.0, 2.0)),
+            Event::new(WindowEvent::MouseMove(3.0, 4.0)),
+            Event::new(WindowEvent::MouseScroll(0.0, -1.0)),
+            Event::new(WindowEvent::MouseScroll(0.0, -1.0)),
+        ];
+
+        coalesce_events(&mut events, CoalescedEvents::MOUSE_SCROLL);
+        assert_eq!(events.len(), 3);
+
+        coalesce_events(&mut events, CoalescedEvents::empty());
+        assert_eq!(events.len(), 3);
+    }
+
+    #[test]
+    fn events_with_different_targets_are_not_merged() {
+        let mut events = vec![
+            Event::new(WindowEvent::MouseMove(1.0, 2.0)).target(Entity::root()),
+            Event::new(WindowEvent::MouseMove(3.0, 4.0)).target(Entity::new(1, 0)),
+        ];
+
+        coalesce_events(&mut events, CoalescedEvents::MOUSE_MOVE);
+
+        assert_eq!(events.len(), 2);
+    }
+}
@@ -87,10 +87,11 @@
 //! ```
 
 mod event_manager;
-pub(crate) use event_manager::EventManager;
+pub use event_manager::CoalescedEvents;
+pub(crate) use event_manager::{visit_entity, EventManager};
 
 mod event;
-pub use event::{Event, EventMeta, Propagation};
+pub use event::{Event, EventMeta, EventPhase, Propagation};
 
 mod event_handler;
 pub(crate) use event_handler::ViewHandler;
@@ -0,0 +1,55 @@
+//! A model for the interaction state of a view, which can be bound to by the descendants of the view.
+use crate::{model::Model, prelude::Wrapper};
+use vizia_derive::Lens;
+
+use crate::binding::Lens;
+use crate::style::PseudoClassFlags;
+
+/// A model which tracks the interaction state of the view it is built into, so that the content of the
+/// view can be bound to whether the view is hovered, active, or focused.
+///
+/// The state is the same as the `:hover`, `:over`, `:active`, and `:focus` pseudo-classes of the
+/// view, and is updated before bindings are updated each frame. Event handlers can instead use
+/// methods such as [`EventContext::is_hovered`](crate::context::EventContext::is_hovered).
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Button::new(
+///     cx,
+///     |_| {},
+///     |cx| {
+///         Interaction::new().build(cx);
+///         Label::new(cx, Interaction::hovered.map(|hovered| if *hovered { "Hovered" } else { "Press" }))
+///     },
+/// );
+/// ```
+#[derive(Lens, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Interaction {
+    /// Whether the view is the hovered view.
+    pub hovered: bool,
+    /// Whether the mouse cursor is over the view or any of its descendants.
+    pub over: bool,
+    /// Whether the view is being pressed.
+    pub active: bool,
+    /// Whether the view has keyboard focus.
+    pub focused: bool,
+}
+
+impl Interaction {
+    /// Creates a new interaction state, which is updated once built into a view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn update(&mut self, pseudo_classes: PseudoClassFlags) {
+        self.hovered = pseudo_classes.contains(PseudoClassFlags::HOVER);
+        self.over = pseudo_classes.contains(PseudoClassFlags::OVER);
+        self.active = pseudo_classes.contains(PseudoClassFlags::ACTIVE);
+        self.focused = pseudo_classes.contains(PseudoClassFlags::FOCUS);
+    }
+}
+
+impl Model for Interaction {}
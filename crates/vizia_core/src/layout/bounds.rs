@@ -208,6 +208,15 @@ impl BoundingBox {
         BoundingBox::from_min_max(left, top, right, bottom)
     }
 
+    /// Returns the smallest bounding box which contains both this bounding box and the other.
+    pub fn union(&self, other: &Self) -> Self {
+        let left = self.left().min(other.left());
+        let right = self.right().max(other.right());
+        let top = self.top().min(other.top());
+        let bottom = self.bottom().max(other.bottom());
+        BoundingBox::from_min_max(left, top, right, bottom)
+    }
+
     pub fn intersects(&self, other: &Self) -> bool {
         let x_hit = (self.x >= other.x && self.x < other.x + other.w)
             || (other.x >= self.x && other.x < self.x + self.w);
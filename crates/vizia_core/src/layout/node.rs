@@ -1,4 +1,4 @@
-use morphorm::{Node, Units};
+use morphorm::{LayoutType, Node, Units};
 use vizia_storage::MorphormChildIter;
 
 use crate::prelude::*;
@@ -6,6 +6,48 @@ use crate::resource::{ImageOrId, ResourceManager};
 use crate::style::{ImageOrGradient, Style};
 use crate::text::TextContext;
 
+// Returns the horizontal and vertical space taken by the child space and border of a view with
+// content-box sizing, which is added to its pixel sizes, or zero with border-box sizing.
+//
+// Only child space in pixels is included, as stretch and percentage child space depend on the size.
+fn content_box_extra(entity: Entity, store: &Style) -> (f32, f32) {
+    if store.box_sizing.get(entity).copied().unwrap_or_default() != BoxSizing::ContentBox {
+        return (0.0, 0.0);
+    }
+
+    let pixels = |units: Option<&Units>| match units {
+        Some(Units::Pixels(val)) => store.logical_to_physical(*val),
+        _ => 0.0,
+    };
+
+    // Matches the border widths given to the layout system below.
+    let border = |border_width: Option<&LengthOrPercentage>| match border_width {
+        Some(LengthOrPercentage::Length(val)) => val.to_px().unwrap_or_default(),
+        _ => 0.0,
+    };
+
+    (
+        pixels(store.child_left.get(entity))
+            + pixels(store.child_right.get(entity))
+            + border(store.border_left_width.get(entity))
+            + border(store.border_right_width.get(entity)),
+        pixels(store.child_top.get(entity))
+            + pixels(store.child_bottom.get(entity))
+            + border(store.border_top_width.get(entity))
+            + border(store.border_bottom_width.get(entity)),
+    )
+}
+
+// Returns the size needed to fit the wrapped lines of a view with an auto cross axis size, computed by
+// the wrap system after a previous layout, if the view has the given layout type.
+fn wrap_size(entity: Entity, store: &Style, layout_type: LayoutType) -> Option<Units> {
+    if store.layout_type.get(entity).cloned().unwrap_or_default() != layout_type {
+        return None;
+    }
+
+    store.wrap_size.get(entity).copied().map(Units::Pixels)
+}
+
 pub struct SubLayout<'a> {
     pub text_context: &'a mut TextContext,
     pub resource_manager: &'a ResourceManager,
@@ -39,7 +81,7 @@ impl Node for Entity {
     }
 
     fn left(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.left.get(*self).cloned().map(|l| match l {
+        store.horizontal_space(*self).0.cloned().map(|l| match l {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
             t => t,
         })
@@ -60,7 +102,7 @@ impl Node for Entity {
     }
 
     fn right(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.right.get(*self).cloned().map(|r| match r {
+        store.horizontal_space(*self).1.cloned().map(|r| match r {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
             t => t,
         })
@@ -123,22 +165,26 @@ impl Node for Entity {
     }
 
     fn width(&self, store: &Self::Store) -> Option<morphorm::Units> {
+        let extra = content_box_extra(*self, store).0;
         store.width.get(*self).cloned().map(|w| match w {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
+            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val) + extra),
+            Units::Auto => wrap_size(*self, store, LayoutType::Column).unwrap_or(Units::Auto),
             t => t,
         })
     }
 
     fn min_width(&self, store: &Self::Store) -> Option<morphorm::Units> {
+        let extra = content_box_extra(*self, store).0;
         store.min_width.get(*self).cloned().map(|w| match w {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
+            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val) + extra),
             t => t,
         })
     }
 
     fn max_width(&self, store: &Self::Store) -> Option<morphorm::Units> {
+        let extra = content_box_extra(*self, store).0;
         store.max_width.get(*self).cloned().map(|w| match w {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
+            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val) + extra),
             t => t,
         })
     }
@@ -151,7 +197,8 @@ impl Node for Entity {
         height: Option<f32>,
     ) -> Option<(f32, f32)> {
         if sublayout.text_context.has_buffer(*self) {
-            // If the width is known use that, else use 0 for wrapping text or 999999 for non-wrapping text.
+            // If the width is known use that, else use 0 for wrapping text or min-content sizing, or
+            // 999999 for non-wrapping text or max-content sizing.
             let max_width = if let Some(width) = width {
                 let child_left =
                     store.child_left.get(*self).cloned().unwrap_or_default().to_px(width, 0.0)
@@ -160,10 +207,13 @@ impl Node for Entity {
                     store.child_right.get(*self).cloned().unwrap_or_default().to_px(width, 0.0)
                         * store.scale_factor();
                 (width.ceil() - child_left - child_right) as i32
-            } else if store.text_wrap.get(*self).copied().unwrap_or(true) {
-                0
             } else {
-                999999
+                match store.intrinsic_size.get(*self) {
+                    Some(IntrinsicSize::MinContent) => 0,
+                    Some(IntrinsicSize::MaxContent) => 999999,
+                    None if store.text_wrap.get(*self).copied().unwrap_or(true) => 0,
+                    None => 999999,
+                }
             };
 
             let child_left = store.child_left.get(*self).cloned().unwrap_or_default();
@@ -192,7 +242,7 @@ impl Node for Entity {
                 child_space_y += val;
             }
 
-            sublayout.text_context.sync_styles(*self, store);
+            sublayout.text_context.sync_styles(*self, store, store.scale_factor());
             let (text_width, mut text_height) =
                 sublayout.text_context.with_buffer(*self, |fs, buffer| {
                     buffer.set_size(fs, max_width as f32, f32::MAX);
@@ -231,7 +281,7 @@ impl Node for Entity {
             for image in images.iter() {
                 match image {
                     ImageOrGradient::Image(image_name) => {
-                        if let Some(ImageOrId::Id(_, dim)) = sublayout
+                        if let Some(ImageOrId::Id(_, dim, ..)) = sublayout
                             .resource_manager
                             .images
                             .get(image_name)
@@ -255,22 +305,26 @@ impl Node for Entity {
     }
 
     fn height(&self, store: &Self::Store) -> Option<morphorm::Units> {
+        let extra = content_box_extra(*self, store).1;
         store.height.get(*self).cloned().map(|h| match h {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
+            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val) + extra),
+            Units::Auto => wrap_size(*self, store, LayoutType::Row).unwrap_or(Units::Auto),
             t => t,
         })
     }
 
     fn min_height(&self, store: &Self::Store) -> Option<morphorm::Units> {
+        let extra = content_box_extra(*self, store).1;
         store.min_height.get(*self).cloned().map(|h| match h {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
+            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val) + extra),
             t => t,
         })
     }
 
     fn max_height(&self, store: &Self::Store) -> Option<morphorm::Units> {
+        let extra = content_box_extra(*self, store).1;
         store.max_height.get(*self).cloned().map(|h| match h {
-            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
+            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val) + extra),
             t => t,
         })
     }
@@ -318,28 +372,28 @@ impl Node for Entity {
     }
 
     fn border_left(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_left_width.get(*self).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => Units::Pixels(val.to_px().unwrap_or_default()),
             LengthOrPercentage::Percentage(val) => Units::Percentage(*val),
         })
     }
 
     fn border_right(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_right_width.get(*self).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => Units::Pixels(val.to_px().unwrap_or_default()),
             LengthOrPercentage::Percentage(val) => Units::Percentage(*val),
         })
     }
 
     fn border_top(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_top_width.get(*self).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => Units::Pixels(val.to_px().unwrap_or_default()),
             LengthOrPercentage::Percentage(val) => Units::Percentage(*val),
         })
     }
 
     fn border_bottom(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_bottom_width.get(*self).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => Units::Pixels(val.to_px().unwrap_or_default()),
             LengthOrPercentage::Percentage(val) => Units::Percentage(*val),
         })
@@ -20,6 +20,7 @@ pub mod environment;
 pub mod events;
 mod fonts;
 pub mod input;
+pub mod interaction;
 pub mod layout;
 pub mod localization;
 pub mod model;
@@ -27,6 +28,7 @@ pub mod modifiers;
 pub mod resource;
 pub mod style;
 mod systems;
+pub mod testing;
 pub(crate) mod text;
 #[doc(hidden)]
 pub mod tree;
@@ -52,12 +54,18 @@ pub mod icons {
     pub use super::fonts::icons::*;
 }
 
+/// Types used to configure the key bindings of text inputs.
+pub mod editing {
+    pub use super::text::{Direction, EditCommand, Movement, TextKeyBindings, COMMAND_MODIFIER};
+}
+
 #[doc(hidden)]
 pub mod backend {
     #[cfg(not(target_arch = "wasm32"))]
     pub use super::accessibility::IntoNode;
     pub use super::context::backend::BackendContext;
     pub use super::text::cosmic::TextConfig;
+    pub use super::view::ShaderContext;
     pub use vizia_window::WindowDescription;
 }
 
@@ -70,35 +78,44 @@ pub mod prelude {
 
     pub use crate::model::Model;
 
-    pub use super::animation::{Animation, AnimationBuilder, KeyframeBuilder};
+    pub use super::animation::{
+        easing, Animation, AnimationBuilder, Interpolator, KeyframeBuilder,
+    };
     pub use super::context::{
         AccessContext, AccessNode, Context, ContextProxy, DataContext, DrawContext, EmitContext,
-        EventContext, ProxyEmitError,
+        EventContext, Politeness, ProxyEmitError, RendererInfo,
     };
     pub use super::entity::Entity;
     pub use super::environment::{Environment, EnvironmentEvent, ThemeMode};
-    pub use super::events::{Event, Propagation};
+    pub use super::events::{Event, EventPhase, Propagation};
     pub use super::include_style;
     pub use super::input::{Keymap, KeymapEntry, KeymapEvent};
+    pub use super::interaction::Interaction;
     pub use super::layout::{BoundingBox, GeoChanged};
     pub use super::localization::Localized;
     pub use super::modifiers::{
-        AbilityModifiers, AccessibilityModifiers, ActionModifiers, BoxShadowBuilder,
-        LayoutModifiers, LinearGradientBuilder, StyleModifiers, TextModifiers,
+        AbilityModifiers, AccessibilityModifiers, ActionModifiers, Background, BoxShadowBuilder,
+        BoxShadows, ConicGradientBuilder, LayoutModifiers, LinearGradientBuilder,
+        RadialGradientBuilder, StyleModifiers, TextModifiers,
     };
     pub use super::resource::ImageRetentionPolicy;
     pub use super::util::{IntoCssStr, CSS};
-    pub use super::view::{Canvas, Handle, View};
+    pub use super::view::{Canvas, FragmentShader, Handle, ShaderEffect, ShaderUniform, View};
     pub use super::views::*;
-    pub use super::window::{DropData, WindowEvent, WindowModifiers};
+    pub use super::window::{
+        Cursor, CursorImage, DropData, TouchPhase, WindowEvent, WindowModifiers,
+    };
     pub use accesskit::{Action, DefaultActionVerb, Live, Role};
     pub use vizia_derive::{Data, Lens, Model, Setter};
     pub use vizia_id::GenerationalId;
     pub use vizia_input::{Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState};
     pub use vizia_storage::{Tree, TreeExt};
-    pub use vizia_window::WindowSize;
+    pub use vizia_window::{
+        ClearBehavior, Fullscreen, VideoMode, WindowDescription, WindowLevel, WindowSize,
+    };
 
     pub use super::style::*;
+    pub use super::text::{EditCommand, TextKeyBindings};
 
     pub use cosmic_text::FamilyOwned;
     pub use morphorm::Units::*;
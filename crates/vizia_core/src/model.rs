@@ -114,12 +114,18 @@ pub(crate) trait ModelData: Any {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
 
     fn as_any_ref(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
 impl dyn ModelData {
     pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
         self.as_any_ref().downcast_ref()
     }
+
+    pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
 }
 
 impl<T: Model> ModelData for T {
@@ -130,6 +136,10 @@ impl<T: Model> ModelData for T {
     fn as_any_ref(&self) -> &dyn Any {
         self
     }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
 }
 
 #[derive(Default)]
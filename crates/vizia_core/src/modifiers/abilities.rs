@@ -103,6 +103,26 @@ pub trait AbilityModifiers: internal::Modifiable {
 
         self
     }
+
+    /// Sets whether the view should receive keyboard focus when it is first built.
+    ///
+    /// If more than one view is marked to be autofocused, the first one to be built receives focus.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Button::new(cx, |_| {}, |cx| Label::new(cx, "Press Me"))
+    ///     .autofocus(true);
+    /// ```
+    fn autofocus(mut self, flag: bool) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        if flag && cx.autofocus.is_none() {
+            cx.autofocus = Some(entity);
+        }
+
+        self
+    }
 }
 
 impl<'a, V> AbilityModifiers for Handle<'a, V> {}
@@ -2,6 +2,26 @@ use super::internal;
 use crate::prelude::*;
 use crate::style::SystemFlags;
 
+// Generates a modifier for a logical layout property which sets the style property it is stored in.
+macro_rules! logical_modifier {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $property:ident
+    ) => {
+        $(#[$meta])*
+        fn $name<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
+            let entity = self.entity();
+            value.set_or_bind(self.context(), entity, |cx, entity, v| {
+                cx.style.$property.insert(entity, v.into());
+
+                cx.style.needs_relayout();
+            });
+
+            self
+        }
+    };
+}
+
 /// Modifiers for changing the layout properties of a view.
 pub trait LayoutModifiers: internal::Modifiable {
     modifier!(
@@ -57,6 +77,51 @@ pub trait LayoutModifiers: internal::Modifiable {
         SystemFlags::RELAYOUT
     );
 
+    modifier!(
+        /// Sets the layout direction of the view, which is inherited by its descendants.
+        ///
+        /// With `Direction::Rtl` the horizontal layout of the children of the view is mirrored, so rows are
+        /// stacked from right to left and the horizontal space and child space properties act as start and
+        /// end values, e.g. `left` and `child_left` apply to the right-hand side. Setting the direction
+        /// on the root view mirrors the whole UI for right-to-left locales.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     Label::new(cx, "First");
+        ///     Label::new(cx, "Second");
+        /// })
+        /// .direction(Direction::Rtl);
+        /// ```
+        direction,
+        Direction,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets whether the pixel width and height of the view include its child space and border.
+        ///
+        /// With the default `BoxSizing::BorderBox` the child space and border are inside the width and
+        /// height. With `BoxSizing::ContentBox` the width and height are the size of the content, and
+        /// any child space and border in pixels are added around it. Stretch, percentage, and auto sizes
+        /// are not affected.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx)
+        ///     .width(Pixels(100.0))
+        ///     .child_space(Pixels(10.0))
+        ///     .box_sizing(BoxSizing::ContentBox); // Laid out 120px wide.
+        /// ```
+        box_sizing,
+        BoxSizing,
+        SystemFlags::RELAYOUT
+    );
+
     modifier!(
         /// Sets the space on the left side of the view.
         ///
@@ -157,13 +222,76 @@ pub trait LayoutModifiers: internal::Modifiable {
         self
     }
 
-    modifier!(
-        /// Sets the width of the view.
-        width,
-        Units,
-        SystemFlags::RELAYOUT
+    logical_modifier!(
+        /// Sets the space on the inline start side of the view, which is the left side for a view with a
+        /// left-to-right direction and the right side for a view with a right-to-left direction.
+        ///
+        /// The side is resolved against the direction of the view itself, which may differ from the
+        /// direction of its parent, and overrides the physical space on that side. See
+        /// [`direction`](LayoutModifiers::direction) for how the layout direction is determined.
+        space_inline_start,
+        space_inline_start
+    );
+
+    logical_modifier!(
+        /// Sets the space on the inline end side of the view, which is the right side for a view with a
+        /// left-to-right direction and the left side for a view with a right-to-left direction.
+        ///
+        /// Like [`space_inline_start`](LayoutModifiers::space_inline_start), the side is resolved
+        /// against the direction of the view itself.
+        space_inline_end,
+        space_inline_end
     );
 
+    logical_modifier!(
+        /// Sets the space on the block start side of the view, which is always the top side.
+        space_block_start,
+        top
+    );
+
+    logical_modifier!(
+        /// Sets the space on the block end side of the view, which is always the bottom side.
+        space_block_end,
+        bottom
+    );
+
+    /// Sets the width of the view.
+    ///
+    /// A width of `SizeUnits::MinContent` or `SizeUnits::MaxContent` is an auto width which sets how
+    /// the content is measured, and is inherited by the descendants of the view. With `MinContent`
+    /// text is measured wrapped at every opportunity, so the view is as narrow as its longest word.
+    /// With `MaxContent` text is measured without wrapping, so the view is as wide as its longest
+    /// line. Otherwise, text is measured according to its
+    /// [`text_wrap`](crate::prelude::TextModifiers::text_wrap) setting.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// HStack::new(cx, |cx| {
+    ///     Label::new(cx, "Bold");
+    ///     Label::new(cx, "Italic");
+    /// })
+    /// .width(SizeUnits::MaxContent);
+    /// ```
+    fn width<U: Into<SizeUnits>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, entity, v| {
+            let width: SizeUnits = v.into();
+            cx.style.width.insert(entity, width.units());
+            match width.intrinsic_size() {
+                Some(intrinsic_size) => cx.style.intrinsic_size.insert(entity, intrinsic_size),
+                None => {
+                    cx.style.intrinsic_size.remove(entity);
+                }
+            }
+
+            cx.style.system_flags |= SystemFlags::RELAYOUT;
+        });
+
+        self
+    }
+
     modifier!(
         /// Sets the height of the view.
         height,
@@ -221,6 +349,36 @@ pub trait LayoutModifiers: internal::Modifiable {
         SystemFlags::RELAYOUT
     );
 
+    logical_modifier!(
+        /// Sets the space between the inline start side of the view and the inline start side of its
+        /// children, which is the left side for left-to-right layout and the right side for right-to-left layout.
+        ///
+        /// Applies only to child views which have a `space_inline_start` property set to `Auto`.
+        child_space_inline_start,
+        child_left
+    );
+
+    logical_modifier!(
+        /// Sets the space between the inline end side of the view and the inline end side of its
+        /// children, which is the right side for left-to-right layout and the left side for right-to-left layout.
+        ///
+        /// Applies only to child views which have a `space_inline_end` property set to `Auto`.
+        child_space_inline_end,
+        child_right
+    );
+
+    logical_modifier!(
+        /// Sets the space between the top side of the view and the top side of its children.
+        child_space_block_start,
+        child_top
+    );
+
+    logical_modifier!(
+        /// Sets the space between the bottom side of the view and the bottom side of its children.
+        child_space_block_end,
+        child_bottom
+    );
+
     /// Sets the space between the vew and its children.
     ///
     /// The child_space works by overriding the `Auto` space properties of its children.
@@ -253,6 +411,154 @@ pub trait LayoutModifiers: internal::Modifiable {
         SystemFlags::RELAYOUT
     );
 
+    /// Sets the space between the children of the view in both horizontal and vertical stacks,
+    /// equivalent to setting both [`row_between`](Self::row_between) and [`col_between`](Self::col_between).
+    ///
+    /// The gap is only added between children, not before the first or after the last child, and is
+    /// overridden by any space set on the children themselves. In CSS the gap can also be set with
+    /// the `gap`, `row-gap` and `column-gap` properties.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// HStack::new(cx, |cx| {
+    ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Ok"));
+    ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Cancel"));
+    /// })
+    /// .gap(Pixels(8.0));
+    /// ```
+    fn gap<U: Into<Units>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, entity, v| {
+            let value = v.into();
+            cx.style.row_between.insert(entity, value);
+            cx.style.col_between.insert(entity, value);
+
+            cx.style.needs_relayout();
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets whether the children of a row or column view flow onto multiple lines when they don't fit.
+        ///
+        /// With `FlexWrap::Wrap` children which would overflow the main axis of the view are moved onto a
+        /// new line. The [`col_between`](Self::col_between) and [`row_between`](Self::row_between) space,
+        /// or [`gap`](Self::gap), is added between the children of a line and between the lines. A view
+        /// with an auto cross axis size, e.g. the height of a row, grows to fit all of its lines.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     for tag in ["rust", "gui", "reactive", "css", "layout"] {
+        ///         Label::new(cx, tag).width(Pixels(80.0));
+        ///     }
+        /// })
+        /// .width(Pixels(200.0))
+        /// .height(Auto)
+        /// .gap(Pixels(4.0))
+        /// .flex_wrap(FlexWrap::Wrap);
+        /// ```
+        flex_wrap,
+        FlexWrap,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets how the wrapped lines of the view are aligned along its cross axis when the view is
+        /// larger than its lines. Only applies to views with [`flex_wrap`](Self::flex_wrap) set to `FlexWrap::Wrap`.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Ok"));
+        ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Cancel"));
+        /// })
+        /// .flex_wrap(FlexWrap::Wrap)
+        /// .align_content(AlignContent::Center);
+        /// ```
+        align_content,
+        AlignContent,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets how the children of a row or column view are distributed along its main axis, e.g.
+        /// horizontally in a row.
+        ///
+        /// The children are first packed against the start of the view, keeping any space between them
+        /// such as the [`gap`](Self::gap) or their own pixel space, and the remaining free space is then
+        /// distributed. Children which take up the free space themselves, e.g. with a stretch width,
+        /// leave no space to distribute. Wrapped lines are each distributed separately.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Back"));
+        ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Next"));
+        /// })
+        /// .justify_content(JustifyContent::SpaceBetween);
+        /// ```
+        justify_content,
+        JustifyContent,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets how the children of a row or column view are aligned along its cross axis, e.g.
+        /// vertically in a row.
+        ///
+        /// Only children without their own space along the cross axis are aligned, so a child can still
+        /// be positioned individually, e.g. with [`top`](Self::top) in a row. Children of a wrapping view
+        /// are aligned within their line.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// VStack::new(cx, |cx| {
+        ///     Label::new(cx, "Centered");
+        /// })
+        /// .align_items(AlignItems::Center)
+        /// .justify_content(JustifyContent::Center);
+        /// ```
+        align_items,
+        AlignItems,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the visual order of the view among the children of its row or column parent.
+        ///
+        /// Children are laid out, drawn, and hit-tested in order of increasing `order`, and children with
+        /// the same order keep their tree order. Defaults to 0. The order is only visual, so keyboard
+        /// focus navigation and accessibility still follow the tree order.
+        ///
+        /// Children of a reordered view are packed along the main axis with their pixel space, or the
+        /// [`gap`](Self::gap) of the parent, between them.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     Label::new(cx, "Content");
+        ///     Label::new(cx, "Sidebar").order(-1); // Laid out before the content.
+        /// });
+        /// ```
+        order,
+        i32,
+        SystemFlags::RELAYOUT
+    );
+
     modifier!(
         /// Sets the minimum width of the view.
         min_width,
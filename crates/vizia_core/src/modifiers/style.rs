@@ -1,8 +1,14 @@
-use vizia_style::{BorderRadius, BoxShadow, ColorStop, Gradient, Position, Rect, Scale, Translate};
+use vizia_style::{
+    BorderRadius, BoxShadow, ColorStop, DimensionPercentage, Gradient, Position, Rect, Scale,
+    Translate,
+};
 
 use super::internal;
+use crate::animation::AnimId;
 use crate::prelude::*;
 use crate::style::{Abilities, ImageOrGradient, PseudoClassFlags, SystemFlags};
+use instant::Duration;
+use std::rc::Rc;
 
 /// Modifiers for changing the style properties of a view.
 pub trait StyleModifiers: internal::Modifiable {
@@ -170,6 +176,82 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    modifier!(
+        /// Hints that a property of the view is about to change, e.g. before starting a transition.
+        ///
+        /// The hint is advisory and does not change how the view is drawn. With `WillChange::MaskImage`
+        /// the offscreen layer used to draw a masked view is allocated in advance, so that it is not
+        /// created in the first frame of the change.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// Element::new(cx).will_change(WillChange::MaskImage);
+        /// ```
+        will_change,
+        WillChange,
+        SystemFlags::REDRAW
+    );
+
+    /// Fades the view in from transparent to opaque over the given duration when it is built.
+    ///
+    /// Use [`EventContext::fade_in`] and [`EventContext::fade_out`] to fade the view at a later time.
+    fn fade_in(mut self, duration: Duration) -> Self {
+        let entity = self.entity();
+        let style = &mut self.context().style;
+        style.opacity.insert(entity, Opacity(0.0));
+        style.fade(entity, 1.0, duration, false);
+
+        self
+    }
+
+    /// Fades the view out from opaque to transparent over the given duration when it is built.
+    ///
+    /// The view is not removed once it is transparent. Use [`EventContext::fade_in`] to fade it back
+    /// in, or [`EventContext::fade_out_and_remove`] to remove a view after fading it out.
+    fn fade_out(mut self, duration: Duration) -> Self {
+        let entity = self.entity();
+        let style = &mut self.context().style;
+        style.opacity.insert(entity, Opacity(1.0));
+        style.fade(entity, 0.0, duration, false);
+
+        self
+    }
+
+    /// Drives the progress of an animation on the view with a value between 0.0 and 1.0 instead of time.
+    ///
+    /// Binding the progress to the scroll offset of a [`ScrollView`] creates scroll-driven effects,
+    /// such as parallax backgrounds or views which fade in as they are scrolled into view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let parallax = cx.add_animation(
+    ///     AnimationBuilder::new()
+    ///         .keyframe(0.0, |key| key.translate((Pixels(0.0), Pixels(0.0))))
+    ///         .keyframe(1.0, |key| key.translate((Pixels(0.0), Pixels(200.0)))),
+    /// );
+    ///
+    /// ScrollView::new(cx, 0.0, 0.0, false, true, move |cx| {
+    ///     Element::new(cx).animation_progress(parallax, ScrollData::scroll_y);
+    /// });
+    /// ```
+    fn animation_progress(
+        mut self,
+        animation: impl AnimId + Copy + 'static,
+        progress: impl Res<f32>,
+    ) -> Self {
+        let entity = self.entity();
+        progress.set_or_bind(self.context(), entity, move |cx, entity, progress| {
+            cx.with_current(entity, |cx| EventContext::new(cx).seek_animation(animation, progress));
+        });
+
+        self
+    }
+
     /// Sets the z-index of the view.
     ///
     /// Views with a higher z-index will be rendered on top of those with a lower z-order.
@@ -185,7 +267,54 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
-    /// Sets the clip path for the the view.
+    /// Sets the paint order of the view among its siblings.
+    ///
+    /// Siblings are drawn and hit-tested in tree order, except that views with a higher paint order
+    /// are drawn after, and so on top of, siblings with a lower paint order. Unlike the z-index, the
+    /// paint order does not move the view out of the layer of its parent, so it is only a tiebreaker
+    /// between siblings with the same z-index. Defaults to 0.
+    fn paint_order<U: Into<i32>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, entity, v| {
+            cx.style.paint_order.insert(entity, v.into());
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
+    /// Sets whether the view escapes the clipping of its ancestors.
+    ///
+    /// A view which escapes clipping is drawn and hit-tested in the root stacking context of its
+    /// window, as if rendered in a portal, so it isn't clipped by an ancestor with `overflow: hidden`
+    /// or a clip path. This is useful for a tooltip or dropdown within a scroll view. The view is drawn
+    /// on top of the other views with the same z-index, and its own clipping still applies to its
+    /// descendants.
+    fn escape_clip(mut self, flag: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        flag.set_or_bind(self.context(), entity, |cx, entity, flag| {
+            cx.tree.set_escape_clip(entity, flag);
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
+    /// Sets the clip path of the view, which clips the view and its descendants to an inset of its
+    /// bounds, optionally with rounded corners. Views are also not hovered outside of the clip path.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).size(Pixels(100.0)).clip_path(ClipPath::rounded_inset(
+    ///     Pixels(10.0),
+    ///     Pixels(10.0),
+    ///     Pixels(10.0),
+    ///     Pixels(10.0),
+    ///     Pixels(20.0),
+    /// ));
+    /// ```
     fn clip_path<U: Into<ClipPath>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         value.set_or_bind(self.context(), entity, |cx, entity, v| {
@@ -242,16 +371,28 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
-    /// Add a box-shadow to the view.
-    fn box_shadow<U: Into<BoxShadow>>(mut self, value: impl Res<U>) -> Self {
+    /// Sets the filter applied to the drawn content of the view and its descendants.
+    ///
+    /// A [`Filter::Blur`] blurs the content by the given radius. A [`Filter::DropShadow`] casts a
+    /// shadow in the shape of the content, such as the glyphs of a label or the opaque parts of an
+    /// image, rather than of the bounds of the view.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Label::new(cx, "Hello").filter(Filter::DropShadow {
+    ///     x_offset: Length::px(2.0),
+    ///     y_offset: Length::px(2.0),
+    ///     blur_radius: Some(Length::px(4.0)),
+    ///     color: Some(Color::rgba(0, 0, 0, 128)),
+    /// });
+    /// ```
+    fn filter<U: Into<Filter>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         value.set_or_bind(self.context(), entity, |cx, entity, v| {
             let value = v.into();
-            if let Some(box_shadows) = cx.style.box_shadow.get_inline_mut(entity) {
-                box_shadows.push(value);
-            } else {
-                cx.style.box_shadow.insert(entity, vec![value]);
-            }
+            cx.style.filter.insert(entity, value);
 
             cx.needs_redraw();
         });
@@ -259,6 +400,126 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets the images or gradients used to mask the view and its descendants.
+    ///
+    /// The view is drawn into an offscreen layer and multiplied by the mask, which is sized to the
+    /// bounds of the view. Content outside of the bounds of the view is hidden. A gradient which fades
+    /// to transparent can be used to fade out the edges of a view.
+    fn mask_image<'i, U: Into<Vec<BackgroundImage<'i>>>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, entity, val| {
+            let images = val.into();
+            let images = images
+                .into_iter()
+                .filter_map(|img| match img {
+                    BackgroundImage::Gradient(gradient) => {
+                        Some(ImageOrGradient::Gradient(*gradient))
+                    }
+                    BackgroundImage::Url(url) => Some(ImageOrGradient::Image(url.url.to_string())),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            cx.style.mask_image.insert(entity, images);
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
+    /// Masks the view and its descendants with the alpha of another view and its descendants, such as
+    /// a sibling with a gradient background or an image.
+    ///
+    /// The mask view is drawn where it is laid out, but only into the mask, so it is not visible on its
+    /// own. It is applied after any mask images, and always uses its alpha regardless of the mask mode.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let mask = Element::new(cx)
+    ///     .position_type(PositionType::SelfDirected)
+    ///     .background_gradient(
+    ///         LinearGradientBuilder::with_direction("to bottom")
+    ///             .add_stop(Color::black())
+    ///             .add_stop(Color::transparent()),
+    ///     )
+    ///     .entity();
+    ///
+    /// Label::new(cx, "Fading text").mask_view(mask);
+    /// ```
+    fn mask_view(mut self, view: Entity) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        cx.style.mask_view.insert(entity, view);
+        cx.style.mask_sources.insert(view, true);
+        cx.needs_redraw();
+
+        self
+    }
+
+    modifier!(
+        /// Sets whether the alpha or the luminance of the mask images of the view is used to mask it.
+        ///
+        /// Luminance is only supported for gradients. Images always use their alpha.
+        mask_mode,
+        MaskMode,
+        SystemFlags::REDRAW
+    );
+
+    /// Sets a custom effect which post-processes the rendered output of the view and its descendants.
+    ///
+    /// See [`ShaderEffect`] for details.
+    fn shader_effect(mut self, effect: impl ShaderEffect + 'static) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        cx.style.shader_effect.insert(entity, Rc::new(effect));
+        cx.needs_redraw();
+
+        self
+    }
+
+    /// Sets the box shadows of the view.
+    ///
+    /// Accepts a single [`BoxShadow`] or [`BoxShadowBuilder`], or a `Vec` or tuple of them to stack
+    /// several shadows. As in CSS, the first shadow in the list is drawn on top of the others. Outset
+    /// shadows are only drawn outside of the border box of the view, and inset shadows only inside of
+    /// it, both following its border radius and corner shape.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).size(Pixels(100.0)).box_shadow((
+    ///     BoxShadowBuilder::new().y_offset(4.0).blur_radius(8.0).color(Color::black()),
+    ///     BoxShadowBuilder::new().spread_radius(2.0).color(Color::white()).inset(),
+    /// ));
+    /// ```
+    fn box_shadow<U: Into<BoxShadows>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, entity, v| {
+            let value: BoxShadows = v.into();
+            cx.style.box_shadow.insert(entity, value.0);
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
+    /// Adds a linear, radial, or conic gradient to the background images of the view.
+    ///
+    /// The gradient fills the background of the view, following its border radius and corner shape.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).size(Pixels(100.0)).background_gradient(
+    ///     ConicGradientBuilder::new()
+    ///         .add_stop((0.0, Color::red()))
+    ///         .add_stop((0.5, Color::blue()))
+    ///         .add_stop((1.0, Color::red())),
+    /// );
+    /// ```
     fn background_gradient<U: Into<Gradient>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         value.set_or_bind(self.context(), entity, |cx, entity, v| {
@@ -275,6 +536,42 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets the background of the view to a solid color or a gradient, replacing any background
+    /// images of the view.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).size(Pixels(100.0)).background(
+    ///     LinearGradientBuilder::with_direction("to bottom")
+    ///         .add_stop((0.0, Color::white()))
+    ///         .add_stop((1.0, Color::gray())),
+    /// );
+    /// ```
+    fn background<U: Into<Background>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, entity, v| {
+            match v.into() {
+                Background::Color(color) => {
+                    cx.style.background_color.insert(entity, color);
+                    cx.style.background_image.insert(entity, Vec::new());
+                }
+
+                Background::Gradient(gradient) => {
+                    cx.style.background_color.insert(entity, Color::transparent());
+                    cx.style
+                        .background_image
+                        .insert(entity, vec![ImageOrGradient::Gradient(gradient)]);
+                }
+            }
+
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
     // Background Properties
     modifier!(
         /// Sets the background color of the view.
@@ -307,17 +604,109 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    modifier!(
+        /// Sets how the images of the view are sampled when scaled, e.g. to keep pixel art crisp.
+        image_rendering,
+        ImageRendering,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets a color which is multiplied with the images of the view.
+        ///
+        /// This allows a single white icon to be recolored, for example when the view is hovered.
+        /// The alpha of the image is preserved when the tint is opaque.
+        image_tint,
+        Color,
+        SystemFlags::REDRAW
+    );
+
     // Border Properties
+    /// Sets the border width for all four sides of the view.
+    fn border_width<U: Into<LengthOrPercentage>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, entity, v| {
+            let value = v.into();
+            cx.style.border_top_width.insert(entity, value.clone());
+            cx.style.border_right_width.insert(entity, value.clone());
+            cx.style.border_bottom_width.insert(entity, value.clone());
+            cx.style.border_left_width.insert(entity, value);
+
+            cx.needs_relayout();
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the border width for the top side of the view.
+        border_top_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the border width for the right side of the view.
+        border_right_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the border width for the bottom side of the view.
+        border_bottom_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
     modifier!(
-        /// Sets the border width of the view.
-        border_width,
+        /// Sets the border width for the left side of the view.
+        border_left_width,
         LengthOrPercentage,
         SystemFlags::RELAYOUT | SystemFlags::REDRAW
     );
 
+    /// Sets the border color for all four sides of the view.
+    fn border_color<U: Into<Color>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, entity, v| {
+            let value = v.into();
+            cx.style.border_top_color.insert(entity, value);
+            cx.style.border_right_color.insert(entity, value);
+            cx.style.border_bottom_color.insert(entity, value);
+            cx.style.border_left_color.insert(entity, value);
+
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
     modifier!(
-        /// Sets the border color of the view.
-        border_color,
+        /// Sets the border color for the top side of the view.
+        border_top_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the border color for the right side of the view.
+        border_right_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the border color for the bottom side of the view.
+        border_bottom_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the border color for the left side of the view.
+        border_left_color,
         Color,
         SystemFlags::REDRAW
     );
@@ -440,9 +829,29 @@ pub trait StyleModifiers: internal::Modifiable {
 
     // Cursor Icon
     modifier!(
-        /// Sets the mouse cursor used when the view is hovered.
+        /// Sets the mouse cursor used when the view is hovered, either a [`CursorIcon`] or a
+        /// [`CursorImage`].
         cursor,
-        CursorIcon,
+        Cursor,
+        SystemFlags::empty()
+    );
+
+    // Hit Testing
+    modifier!(
+        /// Sets the inset of the area used for hit-testing the view, relative to its bounds.
+        ///
+        /// A negative inset expands the clickable area beyond the visual bounds of the view, which is
+        /// useful for meeting minimum touch target sizes on small views such as icons.
+        /// The hit area inset only affects hit-testing and does not affect layout or rendering.
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// // A 24px icon with a 44px clickable area.
+        /// Element::new(cx).size(Pixels(24.0)).hit_area_inset(Pixels(-10.0));
+        /// ```
+        hit_area_inset,
+        LengthOrPercentage,
         SystemFlags::empty()
     );
 
@@ -458,7 +867,8 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
-    /// Sets the transform origin of the the view.
+    /// Sets the origin of the translation, rotation, scale, and transform of the view, relative to
+    /// its top-left corner. Defaults to the center of the view.
     fn transform_origin<U: Into<Position>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         value.set_or_bind(self.context(), entity, |cx, entity, v| {
@@ -543,6 +953,153 @@ impl From<LinearGradientBuilder> for Gradient {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct RadialGradientBuilder {
+    shape: EndingShape,
+    position: Position,
+    stops: Vec<ColorStop<LengthOrPercentage>>,
+}
+
+impl Default for RadialGradientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RadialGradientBuilder {
+    pub fn new() -> Self {
+        RadialGradientBuilder {
+            shape: EndingShape::default(),
+            position: Position::center(),
+            stops: Vec::new(),
+        }
+    }
+
+    pub fn with_shape(shape: EndingShape) -> Self {
+        RadialGradientBuilder { shape, position: Position::center(), stops: Vec::new() }
+    }
+
+    fn build(self) -> Gradient {
+        Gradient::Radial(RadialGradient {
+            shape: self.shape,
+            position: self.position,
+            stops: self.stops,
+        })
+    }
+
+    /// Sets the center of the gradient, relative to the top-left corner of the view.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = position.into();
+
+        self
+    }
+
+    pub fn add_stop(mut self, stop: impl Into<ColorStop<LengthOrPercentage>>) -> Self {
+        self.stops.push(stop.into());
+
+        self
+    }
+}
+
+impl From<RadialGradientBuilder> for Gradient {
+    fn from(value: RadialGradientBuilder) -> Self {
+        value.build()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConicGradientBuilder {
+    angle: Angle,
+    position: Position,
+    stops: Vec<ColorStop<DimensionPercentage<Angle>>>,
+}
+
+impl Default for ConicGradientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConicGradientBuilder {
+    pub fn new() -> Self {
+        ConicGradientBuilder {
+            angle: Angle::default(),
+            position: Position::center(),
+            stops: Vec::new(),
+        }
+    }
+
+    /// Creates a conic gradient which starts at the given angle, clockwise from the top of the view.
+    pub fn with_angle(angle: Angle) -> Self {
+        ConicGradientBuilder { angle, position: Position::center(), stops: Vec::new() }
+    }
+
+    fn build(self) -> Gradient {
+        Gradient::Conic(ConicGradient {
+            angle: self.angle,
+            position: self.position,
+            stops: self.stops,
+        })
+    }
+
+    /// Sets the center of the gradient, relative to the top-left corner of the view.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = position.into();
+
+        self
+    }
+
+    pub fn add_stop(mut self, stop: impl Into<ColorStop<DimensionPercentage<Angle>>>) -> Self {
+        self.stops.push(stop.into());
+
+        self
+    }
+}
+
+impl From<ConicGradientBuilder> for Gradient {
+    fn from(value: ConicGradientBuilder) -> Self {
+        value.build()
+    }
+}
+
+/// The background of a view, which is either a solid color or a gradient. Used by the
+/// [`background`](StyleModifiers::background) modifier.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Background {
+    Color(Color),
+    Gradient(Gradient),
+}
+
+impl From<Color> for Background {
+    fn from(value: Color) -> Self {
+        Background::Color(value)
+    }
+}
+
+impl From<Gradient> for Background {
+    fn from(value: Gradient) -> Self {
+        Background::Gradient(value)
+    }
+}
+
+impl From<LinearGradientBuilder> for Background {
+    fn from(value: LinearGradientBuilder) -> Self {
+        Background::Gradient(value.build())
+    }
+}
+
+impl From<RadialGradientBuilder> for Background {
+    fn from(value: RadialGradientBuilder) -> Self {
+        Background::Gradient(value.build())
+    }
+}
+
+impl From<ConicGradientBuilder> for Background {
+    fn from(value: ConicGradientBuilder) -> Self {
+        Background::Gradient(value.build())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BoxShadowBuilder {
     box_shadow: BoxShadow,
@@ -563,41 +1120,66 @@ impl BoxShadowBuilder {
         self.box_shadow
     }
 
+    /// Sets the horizontal offset of the shadow.
     pub fn x_offset(mut self, offset: impl Into<Length>) -> Self {
         self.box_shadow.x_offset = offset.into();
 
         self
     }
 
+    /// Sets the vertical offset of the shadow.
     pub fn y_offset(mut self, offset: impl Into<Length>) -> Self {
         self.box_shadow.y_offset = offset.into();
 
         self
     }
 
-    pub fn blur(mut self, radius: Length) -> Self {
-        self.box_shadow.blur_radius = Some(radius);
+    /// Sets the blur radius of the shadow, which softens its edges.
+    pub fn blur_radius(mut self, radius: impl Into<Length>) -> Self {
+        self.box_shadow.blur_radius = Some(radius.into());
 
         self
     }
 
-    pub fn spread(mut self, radius: Length) -> Self {
-        self.box_shadow.spread_radius = Some(radius);
+    /// Sets the spread radius of the shadow, which grows the shadow, or shrinks it if negative.
+    pub fn spread_radius(mut self, radius: impl Into<Length>) -> Self {
+        self.box_shadow.spread_radius = Some(radius.into());
 
         self
     }
 
+    /// Sets the blur radius of the shadow.
+    #[deprecated(note = "Use `blur_radius` instead")]
+    pub fn blur(self, radius: Length) -> Self {
+        self.blur_radius(radius)
+    }
+
+    /// Sets the spread radius of the shadow.
+    #[deprecated(note = "Use `spread_radius` instead")]
+    pub fn spread(self, radius: Length) -> Self {
+        self.spread_radius(radius)
+    }
+
+    /// Sets the color of the shadow.
     pub fn color(mut self, color: Color) -> Self {
         self.box_shadow.color = Some(color);
 
         self
     }
 
+    /// Draws the shadow inside of the border box of the view rather than outside of it.
     pub fn inset(mut self) -> Self {
         self.box_shadow.inset = true;
 
         self
     }
+
+    /// Sets whether the shadow is drawn inside of the border box of the view rather than outside of it.
+    pub fn set_inset(mut self, inset: bool) -> Self {
+        self.box_shadow.inset = inset;
+
+        self
+    }
 }
 
 impl From<BoxShadowBuilder> for BoxShadow {
@@ -605,3 +1187,52 @@ impl From<BoxShadowBuilder> for BoxShadow {
         value.build()
     }
 }
+
+/// A list of box shadows, which can be created from a single [`BoxShadow`] or [`BoxShadowBuilder`],
+/// or from a `Vec` or tuple of them. Used by the [`box_shadow`](StyleModifiers::box_shadow) modifier.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoxShadows(pub Vec<BoxShadow>);
+
+impl From<BoxShadow> for BoxShadows {
+    fn from(value: BoxShadow) -> Self {
+        BoxShadows(vec![value])
+    }
+}
+
+impl From<BoxShadowBuilder> for BoxShadows {
+    fn from(value: BoxShadowBuilder) -> Self {
+        BoxShadows(vec![value.build()])
+    }
+}
+
+impl From<Vec<BoxShadow>> for BoxShadows {
+    fn from(value: Vec<BoxShadow>) -> Self {
+        BoxShadows(value)
+    }
+}
+
+impl From<Vec<BoxShadowBuilder>> for BoxShadows {
+    fn from(value: Vec<BoxShadowBuilder>) -> Self {
+        BoxShadows(value.into_iter().map(BoxShadowBuilder::build).collect())
+    }
+}
+
+impl<A: Into<BoxShadow>, B: Into<BoxShadow>> From<(A, B)> for BoxShadows {
+    fn from(value: (A, B)) -> Self {
+        BoxShadows(vec![value.0.into(), value.1.into()])
+    }
+}
+
+impl<A: Into<BoxShadow>, B: Into<BoxShadow>, C: Into<BoxShadow>> From<(A, B, C)> for BoxShadows {
+    fn from(value: (A, B, C)) -> Self {
+        BoxShadows(vec![value.0.into(), value.1.into(), value.2.into()])
+    }
+}
+
+impl<A: Into<BoxShadow>, B: Into<BoxShadow>, C: Into<BoxShadow>, D: Into<BoxShadow>>
+    From<(A, B, C, D)> for BoxShadows
+{
+    fn from(value: (A, B, C, D)) -> Self {
+        BoxShadows(vec![value.0.into(), value.1.into(), value.2.into(), value.3.into()])
+    }
+}
@@ -1,7 +1,8 @@
 use super::internal;
 use crate::{prelude::*, style::SystemFlags};
 use cosmic_text::FamilyOwned;
-use vizia_style::{FontSize, FontStretch, FontStyle, FontWeight};
+use instant::Duration;
+use vizia_style::{FontHinting, FontSize, FontSmoothing, FontStretch, FontStyle, FontWeight};
 
 /// Modifiers for changing the text properties of a view.
 pub trait TextModifiers: internal::Modifiable {
@@ -50,6 +51,23 @@ pub trait TextModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    modifier!(
+        /// Sets how the glyphs of the text of the view are antialiased, overriding the antialiasing of
+        /// the application text config. Rotated or skewed text is antialiased in grayscale unless
+        /// `FontSmoothing::Subpixel` is set.
+        font_smoothing,
+        FontSmoothing,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets whether the glyphs of the text of the view are hinted, overriding the hinting of the
+        /// application text config.
+        font_hinting,
+        FontHinting,
+        SystemFlags::REDRAW
+    );
+
     /// Sets the text color of the view.
     fn color<U: Into<Color>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -71,12 +89,63 @@ pub trait TextModifiers: internal::Modifiable {
     }
 
     modifier!(
-        /// Sets the ext caret color of the view.
+        /// Sets the text caret color of the view.
         caret_color,
         Color,
         SystemFlags::REDRAW
     );
 
+    modifier!(
+        /// Sets how long the text caret of the view is shown, and then hidden, for while blinking.
+        /// A zero duration disables blinking.
+        caret_blink_rate,
+        Duration,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the shape of the text caret of the view. A block caret covers the character at the
+        /// caret and draws it inverted.
+        caret_shape,
+        CaretShape,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the width of the text caret of the view, which is the thickness of a bar or underline
+        /// caret. Defaults to one logical pixel.
+        caret_width,
+        LengthOrPercentage,
+        SystemFlags::REDRAW
+    );
+
+    /// Sets whether URLs in the text of the view are detected and shown as links. Clicking on a link
+    /// emits a [`WindowEvent::LinkClicked`] with the URL, and the cursor changes to a hand while over
+    /// a link.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Label::new(cx, "See https://github.com/vizia/vizia for more.").detect_links(true);
+    /// ```
+    fn detect_links(mut self, flag: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        flag.set_or_bind(self.context(), entity, |cx, entity, flag| {
+            cx.text_context.set_link_detection(entity, flag);
+            cx.style.needs_text_layout.insert(entity, true);
+            cx.needs_redraw();
+        });
+        self
+    }
+
+    modifier!(
+        /// Sets the color of links in the text of the view. Defaults to blue.
+        link_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
     modifier!(
         /// Sets the color used to highlight selected text within the view.
         selection_color,
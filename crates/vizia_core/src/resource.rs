@@ -20,7 +20,9 @@ pub(crate) struct StoredImage {
 
 pub(crate) enum ImageOrId {
     Image(image::DynamicImage, femtovg::ImageFlags),
-    Id(femtovg::ImageId, (u32, u32)),
+    // An image loaded into the canvas of the main window. The decoded image is kept so that it can be
+    // loaded into the canvases of other windows.
+    Id(femtovg::ImageId, (u32, u32), image::DynamicImage, femtovg::ImageFlags),
 }
 
 impl ImageOrId {
@@ -31,10 +33,12 @@ impl ImageOrId {
                 let res = canvas
                     .create_image(femtovg::ImageSource::try_from(image_ref).unwrap(), *flags)
                     .unwrap();
-                *self = ImageOrId::Id(res, image.dimensions());
+                let dimensions = image.dimensions();
+                let image = std::mem::replace(image, image::DynamicImage::new_rgba8(0, 0));
+                *self = ImageOrId::Id(res, dimensions, image, *flags);
                 res
             }
-            ImageOrId::Id(i, _) => *i,
+            ImageOrId::Id(i, ..) => *i,
         }
     }
 }
@@ -158,6 +158,14 @@ where
     pub fn remove(&mut self, entity: Entity) -> Option<T> {
         let entity_index = entity.index();
 
+        // Animation states held paused for the entity, e.g. while waiting for a staggered start, are
+        // persistent and so would otherwise never be removed.
+        while let Some(anim_index) =
+            self.active_animations.iter().position(|state| state.entities.contains(&entity))
+        {
+            self.remove_active_animation(entity, anim_index);
+        }
+
         if entity_index < self.inline_data.sparse.len() {
             let data_index = self.inline_data.sparse[entity_index].data_index;
             if data_index.is_inline() && !data_index.is_inherited() {
@@ -268,6 +276,10 @@ where
         self.animations.insert(animation, animation_description);
     }
 
+    pub(crate) fn remove_animation(&mut self, animation: Animation) {
+        self.animations.remove(animation);
+    }
+
     pub(crate) fn insert_rule(&mut self, rule: Rule, value: T) {
         self.shared_data.insert(rule, value);
     }
@@ -306,38 +318,27 @@ where
         }
 
         if entity_index < self.inline_data.sparse.len() {
+            // A replayed animation replaces the previous state of the animation for the entity, which
+            // may have been paused by a seek, so that it restarts from the first keyframe.
+            if let Some(anim_index) = self.find_active_animation(entity, animation) {
+                self.remove_active_animation(entity, anim_index);
+            }
+
             let active_anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
             if active_anim_index < self.active_animations.len() {
                 let anim_state = &mut self.active_animations[active_anim_index];
-                if anim_state.id == animation {
-                    anim_state.active = true;
-                    anim_state.t = 0.0;
-                    anim_state.start_time = instant::Instant::now();
-                    anim_state.output = Some(
-                        self.animations
-                            .get(animation)
-                            .cloned()
-                            .unwrap()
-                            .keyframes
-                            .first()
-                            .unwrap()
-                            .value
-                            .clone(),
-                    );
-                } else {
-                    anim_state.output = Some(
-                        self.animations
-                            .get(animation)
-                            .cloned()
-                            .unwrap()
-                            .keyframes
-                            .first()
-                            .unwrap()
-                            .value
-                            .clone(),
-                    );
-                    anim_state.entities.remove(&entity);
-                }
+                anim_state.output = Some(
+                    self.animations
+                        .get(animation)
+                        .cloned()
+                        .unwrap()
+                        .keyframes
+                        .first()
+                        .unwrap()
+                        .value
+                        .clone(),
+                );
+                anim_state.entities.remove(&entity);
             }
 
             // Safe to unwrap because already checked that the animation exists
@@ -360,11 +361,81 @@ where
         }
     }
 
+    /// Pauses an animation for an entity at the given progress, between 0.0 and 1.0, playing the
+    /// animation first if it is not already active for the entity.
+    ///
+    /// The animation state is looked up by both the entity and the animation, so seeking the
+    /// animation for one entity does not move it for other entities playing the same animation.
+    pub(crate) fn seek_animation(&mut self, entity: Entity, animation: Animation, t: f32) {
+        let anim_index = match self.find_active_animation(entity, animation) {
+            Some(anim_index) => anim_index,
+            None => {
+                self.play_animation(entity, animation, Duration::from_secs(1));
+
+                // The animation does not animate this property.
+                match self.find_active_animation(entity, animation) {
+                    Some(anim_index) => anim_index,
+                    None => return,
+                }
+            }
+        };
+
+        // A state shared with other entities is split off so that only this entity is moved.
+        let anim_index = if self.active_animations[anim_index].entities.len() > 1 {
+            let shared_state = &mut self.active_animations[anim_index];
+            shared_state.entities.remove(&entity);
+            let mut state = shared_state.clone();
+            state.entities.clear();
+            state.entities.insert(entity);
+            self.active_animations.push(state);
+            self.active_animations.len() - 1
+        } else {
+            anim_index
+        };
+
+        self.inline_data.sparse[entity.index()].anim_index = anim_index as u32;
+
+        let state = &mut self.active_animations[anim_index];
+        state.paused = true;
+        state.persistent = true;
+        state.seek(t);
+    }
+
+    // Returns the index of the most recently played active state of the animation for the entity.
+    fn find_active_animation(&self, entity: Entity, animation: Animation) -> Option<usize> {
+        self.active_animations
+            .iter()
+            .rposition(|state| state.id == animation && state.entities.contains(&entity))
+    }
+
+    // Removes the entity from an active animation state, removing the state if no other entities are
+    // playing it.
+    fn remove_active_animation(&mut self, entity: Entity, anim_index: usize) {
+        let state = &mut self.active_animations[anim_index];
+        state.entities.remove(&entity);
+        let unused = state.entities.is_empty();
+
+        let entity_anim_index = &mut self.inline_data.sparse[entity.index()].anim_index;
+        if *entity_anim_index == anim_index as u32 {
+            *entity_anim_index = u32::MAX;
+        }
+
+        if unused {
+            self.active_animations.remove(anim_index);
+
+            for (index, state) in self.active_animations.iter().enumerate().skip(anim_index) {
+                for entity in state.entities.iter() {
+                    self.inline_data.sparse[entity.index()].anim_index = index as u32;
+                }
+            }
+        }
+    }
+
     pub fn tick(&mut self, time: instant::Instant) -> bool {
         if self.has_animations() {
             for state in self.active_animations.iter_mut() {
-                // If the animation is already finished then skip
-                if state.t == 1.0 {
+                // If the animation is already finished, or is paused, then skip
+                if state.t == 1.0 || state.paused {
                     continue;
                 }
 
@@ -374,26 +445,12 @@ where
                 }
 
                 let elapsed_time = time.duration_since(state.start_time);
-                let mut normalised_time =
-                    (elapsed_time.as_secs_f32() / state.duration.as_secs_f32()) - state.delay;
+                let duration = state.play_duration().as_secs_f32();
+                let mut normalised_time = (elapsed_time.as_secs_f32() / duration) - state.delay;
 
                 normalised_time = normalised_time.clamp(0.0, 1.0);
 
-                let mut i = 0;
-                while i < state.keyframes.len() - 1 && state.keyframes[i + 1].time < normalised_time
-                {
-                    i += 1;
-                }
-                let start = &state.keyframes[i];
-                let end = &state.keyframes[i + 1];
-
-                let normalised_elapsed_time =
-                    (normalised_time - start.time) / (end.time - start.time);
-
-                state.t = normalised_time;
-
-                let timing_t = start.timing_function.value(normalised_elapsed_time);
-                state.output = Some(T::interpolate(&start.value, &end.value, timing_t));
+                state.seek(normalised_time);
             }
 
             self.remove_innactive_animations();
@@ -404,6 +461,22 @@ where
         false
     }
 
+    /// Ticks the active animations like [`tick`](Self::tick), adding the entities whose value changes
+    /// in this tick to `changed`.
+    pub(crate) fn tick_entities(
+        &mut self,
+        time: instant::Instant,
+        changed: &mut Vec<Entity>,
+    ) -> bool {
+        for state in self.active_animations.iter() {
+            if state.t < 1.0 && !state.paused {
+                changed.extend(state.entities.iter().copied());
+            }
+        }
+
+        self.tick(time)
+    }
+
     /// Returns true if the given entity is linked to an active animation
     // pub fn is_animating(&self, entity: Entity) -> bool {
     //     let entity_index = entity.index();
@@ -445,7 +518,7 @@ where
 
     pub fn has_animations(&self) -> bool {
         for state in self.active_animations.iter() {
-            if state.t < 1.0 {
+            if state.t < 1.0 && !state.paused {
                 return true;
             }
         }
@@ -691,6 +764,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::animation::{Keyframe, TimingFunction};
 
     // DataIndex tests
 
@@ -759,4 +833,84 @@ mod tests {
         animatable_storage.insert(Entity::root(), 5.0);
         //assert_eq!(animatable_storage.entity_indices.first().unwrap().data_index, DataIndex::inline(0));
     }
+
+    /// Test that seeking an animation for one entity does not move it for other entities.
+    #[test]
+    fn seek_animation_per_entity() {
+        let mut animatable_storage = AnimatableSet::<f32>::default();
+        let animation = Animation::new(0, 0);
+        let keyframe =
+            |time, value| Keyframe { time, value, timing_function: TimingFunction::linear() };
+        animatable_storage.insert_animation(
+            animation,
+            AnimationState::new(animation)
+                .with_keyframe(keyframe(0.0, 0.0))
+                .with_keyframe(keyframe(1.0, 100.0)),
+        );
+
+        let first = Entity::new(1, 0);
+        let second = Entity::new(2, 0);
+        animatable_storage.seek_animation(first, animation, 0.25);
+        animatable_storage.seek_animation(second, animation, 0.75);
+        assert_eq!(animatable_storage.get(first), Some(&25.0));
+        assert_eq!(animatable_storage.get(second), Some(&75.0));
+
+        animatable_storage.seek_animation(first, animation, 0.5);
+        assert_eq!(animatable_storage.get(first), Some(&50.0));
+        assert_eq!(animatable_storage.get(second), Some(&75.0));
+    }
+
+    /// Test that replaying a paused animation restarts it from the first keyframe.
+    #[test]
+    fn replay_paused_animation() {
+        let mut animatable_storage = AnimatableSet::<f32>::default();
+        let animation = Animation::new(0, 0);
+        let keyframe =
+            |time, value| Keyframe { time, value, timing_function: TimingFunction::linear() };
+        animatable_storage.insert_animation(
+            animation,
+            AnimationState::new(animation)
+                .with_keyframe(keyframe(0.0, 0.0))
+                .with_keyframe(keyframe(1.0, 100.0)),
+        );
+
+        let entity = Entity::new(1, 0);
+        animatable_storage.seek_animation(entity, animation, 0.5);
+        assert_eq!(animatable_storage.get(entity), Some(&50.0));
+
+        animatable_storage.play_animation(entity, animation, Duration::from_secs(1));
+        assert_eq!(animatable_storage.get(entity), Some(&0.0));
+        assert_eq!(animatable_storage.active_animations.len(), 1);
+        assert!(!animatable_storage.active_animations[0].paused);
+        assert!(!animatable_storage.active_animations[0].persistent);
+
+        animatable_storage.tick(instant::Instant::now() + Duration::from_secs(2));
+        assert!(animatable_storage.active_animations.is_empty());
+    }
+
+    /// Test that removing an entity removes an animation state held paused for it.
+    #[test]
+    fn remove_paused_animation() {
+        let mut animatable_storage = AnimatableSet::<f32>::default();
+        let animation = Animation::new(0, 0);
+        let keyframe =
+            |time, value| Keyframe { time, value, timing_function: TimingFunction::linear() };
+        animatable_storage.insert_animation(
+            animation,
+            AnimationState::new(animation)
+                .with_keyframe(keyframe(0.0, 0.0))
+                .with_keyframe(keyframe(1.0, 100.0)),
+        );
+
+        let first = Entity::new(1, 0);
+        let second = Entity::new(2, 0);
+        animatable_storage.seek_animation(first, animation, 0.0);
+        animatable_storage.play_animation(second, animation, Duration::from_secs(1));
+        assert_eq!(animatable_storage.active_animations.len(), 2);
+
+        animatable_storage.remove(first);
+        assert_eq!(animatable_storage.active_animations.len(), 1);
+        assert_eq!(animatable_storage.get(first), None);
+        assert_eq!(animatable_storage.get(second), Some(&0.0));
+    }
 }
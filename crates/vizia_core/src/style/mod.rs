@@ -61,26 +61,28 @@
 //! ```
 
 use fnv::FnvHashMap;
-use instant::Duration;
+use instant::{Duration, Instant};
 use morphorm::{LayoutType, PositionType, Units};
 use std::collections::HashSet;
 use std::fmt::Debug;
+use std::rc::Rc;
 use vizia_id::GenerationalId;
 
 use crate::prelude::*;
 
 pub use vizia_style::{
-    Angle, BackgroundImage, BackgroundSize, BorderCornerShape, BoxShadow, ClipPath, Color, CssRule,
-    CursorIcon, Display, Filter, FontFamily, FontSize, FontStretch, FontStyle, FontWeight,
-    FontWeightKeyword, GenericFontFamily, Gradient, HorizontalPosition, HorizontalPositionKeyword,
-    Length, LengthOrPercentage, LengthValue, LineDirection, LinearGradient, Matrix, Opacity,
-    Overflow, Position, Scale, TextAlign, Transform, Transition, Translate, VerticalPosition,
-    VerticalPositionKeyword, Visibility, RGBA,
+    AlignContent, AlignItems, Angle, BackgroundImage, BackgroundSize, BorderCornerShape, BoxShadow,
+    BoxSizing, CaretShape, ClipPath, Color, ConicGradient, CssRule, CursorIcon, Direction, Display,
+    EasingFunction, EndingShape, Filter, FlexWrap, FontFamily, FontHinting, FontSize,
+    FontSmoothing, FontStretch, FontStyle, FontWeight, FontWeightKeyword, GenericFontFamily,
+    Gradient, HorizontalPosition, HorizontalPositionKeyword, ImageRendering, IntrinsicSize,
+    JustifyContent, Length, LengthOrPercentage, LengthValue, LineDirection, LinearGradient,
+    MaskMode, Matrix, Opacity, Overflow, Position, RadialGradient, Scale, SizeUnits, StepPosition,
+    TextAlign, Transform, Transition, Translate, VerticalPosition, VerticalPositionKeyword,
+    Visibility, WillChange, RGBA,
 };
 
-use vizia_style::{
-    EasingFunction, KeyframeSelector, ParserOptions, Property, SelectorList, Selectors, StyleSheet,
-};
+use vizia_style::{KeyframeSelector, ParserOptions, Property, SelectorList, Selectors, StyleSheet};
 
 mod rule;
 pub(crate) use rule::Rule;
@@ -130,6 +132,8 @@ bitflags! {
         const RELAYOUT = 1 << 1;
         /// Draw system flag.
         const REDRAW = 1 << 2;
+        /// Draw system flag for redrawing only the views in `redraw_entities`.
+        const REDRAW_PARTIAL = 1 << 3;
         /// Text constraints system flag.
         const REFLOW = 1 << 5;
     }
@@ -147,6 +151,20 @@ pub enum ImageOrGradient {
     Gradient(Gradient),
 }
 
+/// An opacity fade playing on a view.
+pub(crate) struct Fade {
+    animation: Animation,
+    // Whether the view should be removed when the fade finishes
+    remove: bool,
+}
+
+/// An animation which is waiting to be played on a view, see `Style::play_animation_delayed()`.
+pub(crate) struct DelayedAnimation {
+    animation: Animation,
+    duration: Duration,
+    start: Instant,
+}
+
 /// Stores the style properties of all entities in the application.
 #[derive(Default)]
 pub struct Style {
@@ -155,6 +173,10 @@ pub struct Style {
     // Creates and destroys animation ids
     pub(crate) animation_manager: IdManager<Animation>,
     pub(crate) animations: FnvHashMap<String, Animation>,
+    // Opacity fades which are currently playing, see `Style::fade()`
+    pub(crate) fades: FnvHashMap<Entity, Fade>,
+    // Animations which are waiting to be played, see `Style::play_animation_delayed()`
+    pub(crate) delayed_animations: FnvHashMap<Entity, DelayedAnimation>,
 
     // List of rules
     pub(crate) rules: Vec<(Rule, SelectorList<Selectors>)>,
@@ -189,8 +211,12 @@ pub struct Style {
     // Opacity
     pub(crate) opacity: AnimatableSet<Opacity>,
 
+    // Will Change
+    pub(crate) will_change: StyleSet<WillChange>,
+
     // Z Order
     pub(crate) z_index: StyleSet<i32>,
+    pub(crate) paint_order: SparseSet<i32>,
 
     // Clipping
     pub(crate) clip_path: AnimatableSet<ClipPath>,
@@ -201,6 +227,8 @@ pub struct Style {
 
     // Filters
     pub(crate) backdrop_filter: AnimatableSet<Filter>,
+    pub(crate) filter: AnimatableSet<Filter>,
+    pub(crate) shader_effect: SparseSet<Rc<dyn ShaderEffect>>,
 
     // Transform
     pub(crate) transform: AnimatableSet<Vec<Transform>>,
@@ -209,9 +237,17 @@ pub struct Style {
     pub(crate) rotate: AnimatableSet<Angle>,
     pub(crate) scale: AnimatableSet<Scale>,
 
-    // Border
-    pub(crate) border_width: AnimatableSet<LengthOrPercentage>,
-    pub(crate) border_color: AnimatableSet<Color>,
+    // Border Width
+    pub(crate) border_top_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_right_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_bottom_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_left_width: AnimatableSet<LengthOrPercentage>,
+
+    // Border Color
+    pub(crate) border_top_color: AnimatableSet<Color>,
+    pub(crate) border_right_color: AnimatableSet<Color>,
+    pub(crate) border_bottom_color: AnimatableSet<Color>,
+    pub(crate) border_left_color: AnimatableSet<Color>,
 
     // Border Shape
     pub(crate) border_top_left_shape: StyleSet<BorderCornerShape>,
@@ -234,6 +270,15 @@ pub struct Style {
     pub(crate) background_color: AnimatableSet<Color>,
     pub(crate) background_image: AnimatableSet<Vec<ImageOrGradient>>,
     pub(crate) background_size: AnimatableSet<Vec<BackgroundSize>>,
+    pub(crate) image_rendering: StyleSet<ImageRendering>,
+    pub(crate) image_tint: AnimatableSet<Color>,
+
+    // Mask
+    pub(crate) mask_image: StyleSet<Vec<ImageOrGradient>>,
+    pub(crate) mask_mode: StyleSet<MaskMode>,
+    // The view whose alpha masks a view, and the views which are only drawn as the mask of another.
+    pub(crate) mask_view: SparseSet<Entity>,
+    pub(crate) mask_sources: SparseSet<bool>,
 
     // Box Shadow
     pub(crate) box_shadow: AnimatableSet<Vec<BoxShadow>>,
@@ -247,11 +292,20 @@ pub struct Style {
     pub(crate) font_weight: StyleSet<FontWeight>,
     pub(crate) font_style: StyleSet<FontStyle>,
     pub(crate) font_stretch: StyleSet<FontStretch>,
+    pub(crate) font_smoothing: StyleSet<FontSmoothing>,
+    pub(crate) font_hinting: StyleSet<FontHinting>,
     pub(crate) caret_color: AnimatableSet<Color>,
+    pub(crate) caret_blink_rate: StyleSet<Duration>,
+    pub(crate) caret_shape: StyleSet<CaretShape>,
+    pub(crate) caret_width: StyleSet<LengthOrPercentage>,
+    pub(crate) link_color: StyleSet<Color>,
     pub(crate) selection_color: AnimatableSet<Color>,
 
     // cursor Icon
-    pub(crate) cursor: StyleSet<CursorIcon>,
+    pub(crate) cursor: StyleSet<Cursor>,
+
+    // Hit Testing
+    pub(crate) hit_area_inset: StyleSet<LengthOrPercentage>,
 
     // LAYOUT
 
@@ -261,12 +315,25 @@ pub struct Style {
     // Position Type
     pub(crate) position_type: StyleSet<PositionType>,
 
+    // Direction
+    pub(crate) direction: StyleSet<Direction>,
+    // The entities whose direction differs from the direction of their layout parent.
+    pub(crate) reversed_inline: HashSet<Entity>,
+
+    // Box Sizing
+    pub(crate) box_sizing: StyleSet<BoxSizing>,
+    pub(crate) intrinsic_size: StyleSet<IntrinsicSize>,
+
     // Spacing
     pub(crate) left: AnimatableSet<Units>,
     pub(crate) right: AnimatableSet<Units>,
     pub(crate) top: AnimatableSet<Units>,
     pub(crate) bottom: AnimatableSet<Units>,
 
+    // Logical Spacing
+    pub(crate) space_inline_start: StyleSet<Units>,
+    pub(crate) space_inline_end: StyleSet<Units>,
+
     // Child Spacing
     pub(crate) child_left: AnimatableSet<Units>,
     pub(crate) child_right: AnimatableSet<Units>,
@@ -275,6 +342,20 @@ pub struct Style {
     pub(crate) row_between: AnimatableSet<Units>,
     pub(crate) col_between: AnimatableSet<Units>,
 
+    // Wrapping
+    pub(crate) flex_wrap: StyleSet<FlexWrap>,
+    pub(crate) align_content: StyleSet<AlignContent>,
+
+    // Alignment
+    pub(crate) justify_content: StyleSet<JustifyContent>,
+    pub(crate) align_items: StyleSet<AlignItems>,
+
+    // Order
+    pub(crate) order: StyleSet<i32>,
+    // The cross axis size of the wrapped lines of views with an auto cross axis size, computed by
+    // the layout system.
+    pub(crate) wrap_size: SparseSet<f32>,
+
     // Size
     pub(crate) width: AnimatableSet<Units>,
     pub(crate) height: AnimatableSet<Units>,
@@ -297,6 +378,11 @@ pub struct Style {
 
     pub(crate) system_flags: SystemFlags,
 
+    // The views to redraw when only part of the window is redrawn, and whether the whole window must
+    // be redrawn anyway because something else changed since the last draw.
+    pub(crate) redraw_entities: Vec<Entity>,
+    pub(crate) full_redraw: bool,
+
     // TODO: When we can do incremental updates on a per entity basis, change this to a bitflag
     // for layout, text layout, rendering, etc. to replace the above `needs_` members.
     pub needs_text_layout: SparseSet<bool>,
@@ -305,6 +391,9 @@ pub struct Style {
 
     /// This includes both the system's HiDPI scaling factor as well as `cx.user_scale_factor`.
     pub(crate) dpi_factor: f64,
+    // The scale factors of the windows other than the main window, by the root view of each window.
+    // While a window is laid out or drawn, `dpi_factor` is temporarily set to its scale factor.
+    pub(crate) window_dpi_factors: FnvHashMap<Entity, f64>,
 }
 
 impl Style {
@@ -312,6 +401,20 @@ impl Style {
         self.dpi_factor as f32
     }
 
+    /// Returns the scale factor of the window with the given root view.
+    pub(crate) fn window_scale_factor(&self, window: Entity) -> f64 {
+        self.window_dpi_factors.get(&window).copied().unwrap_or(self.dpi_factor)
+    }
+
+    /// Returns the scale factor of the window which contains the entity.
+    pub(crate) fn scale_factor_of(&self, tree: &Tree<Entity>, entity: Entity) -> f32 {
+        if self.window_dpi_factors.is_empty() {
+            return self.dpi_factor as f32;
+        }
+
+        self.window_scale_factor(tree.window(entity)) as f32
+    }
+
     /// Function to convert logical points to physical pixels.
     pub fn logical_to_physical(&self, logical: f32) -> f32 {
         (logical * self.dpi_factor as f32).round()
@@ -322,6 +425,22 @@ impl Style {
         physical / self.dpi_factor as f32
     }
 
+    /// Returns the left and right space of an entity in the layout of its parent, which is mirrored
+    /// when the parent has a right-to-left direction.
+    ///
+    /// The logical inline space of the entity overrides the physical space. It is resolved against
+    /// the direction of the entity itself, so its inline start is the end side of a parent with the
+    /// opposite direction.
+    pub(crate) fn horizontal_space(&self, entity: Entity) -> (Option<&Units>, Option<&Units>) {
+        let (start, end) = if self.reversed_inline.contains(&entity) {
+            (self.space_inline_end.get(entity), self.space_inline_start.get(entity))
+        } else {
+            (self.space_inline_start.get(entity), self.space_inline_end.get(entity))
+        };
+
+        (start.or_else(|| self.left.get(entity)), end.or_else(|| self.right.get(entity)))
+    }
+
     pub(crate) fn remove_rules(&mut self) {
         self.rule_manager.reset();
         self.rules.clear();
@@ -335,15 +454,17 @@ impl Style {
         &mut self,
         animation_id: Animation,
         time: f32,
+        timing_function: TimingFunction,
         properties: &[Property],
     ) {
         fn insert_keyframe<T: 'static + Interpolator + Debug + Clone + PartialEq + Default>(
             storage: &mut AnimatableSet<T>,
             animation_id: Animation,
             time: f32,
+            timing_function: TimingFunction,
             value: T,
         ) {
-            let keyframe = Keyframe { time, value, timing_function: TimingFunction::linear() };
+            let keyframe = Keyframe { time, value, timing_function };
 
             if let Some(anim_state) = storage.get_animation_mut(animation_id) {
                 anim_state.keyframes.push(keyframe)
@@ -357,53 +478,206 @@ impl Style {
             match property {
                 // DISPLAY
                 Property::Display(value) => {
-                    insert_keyframe(&mut self.display, animation_id, time, *value);
+                    insert_keyframe(&mut self.display, animation_id, time, timing_function, *value);
                 }
 
                 Property::Opacity(value) => {
-                    insert_keyframe(&mut self.opacity, animation_id, time, *value);
+                    insert_keyframe(&mut self.opacity, animation_id, time, timing_function, *value);
                 }
 
                 Property::ClipPath(value) => {
-                    insert_keyframe(&mut self.clip_path, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.clip_path,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 // TRANSFORM
                 Property::Transform(value) => {
-                    insert_keyframe(&mut self.transform, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.transform,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 Property::TransformOrigin(transform_origin) => {
                     let x = transform_origin.x.to_length_or_percentage();
                     let y = transform_origin.y.to_length_or_percentage();
                     let value = Translate { x, y };
-                    insert_keyframe(&mut self.transform_origin, animation_id, time, value);
+                    insert_keyframe(
+                        &mut self.transform_origin,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value,
+                    );
                 }
 
                 Property::Translate(value) => {
-                    insert_keyframe(&mut self.translate, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.translate,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 Property::Rotate(value) => {
-                    insert_keyframe(&mut self.rotate, animation_id, time, *value);
+                    insert_keyframe(&mut self.rotate, animation_id, time, timing_function, *value);
                 }
 
                 Property::Scale(value) => {
-                    insert_keyframe(&mut self.scale, animation_id, time, *value);
+                    insert_keyframe(&mut self.scale, animation_id, time, timing_function, *value);
                 }
 
                 // BORDER
                 Property::BorderWidth(value) => {
                     insert_keyframe(
-                        &mut self.border_width,
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.top.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.right.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
                         animation_id,
                         time,
+                        timing_function,
+                        value.bottom.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_left_width,
+                        animation_id,
+                        time,
+                        timing_function,
                         value.left.0.clone(),
                     );
                 }
 
+                Property::BorderTopWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderRightWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderBottomWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderLeftWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.0.clone(),
+                    );
+                }
+
                 Property::BorderColor(value) => {
-                    insert_keyframe(&mut self.border_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.border_top_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.top,
+                    );
+                    insert_keyframe(
+                        &mut self.border_right_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.right,
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.bottom,
+                    );
+                    insert_keyframe(
+                        &mut self.border_left_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.left,
+                    );
+                }
+
+                Property::BorderTopColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
+                }
+
+                Property::BorderRightColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
+                }
+
+                Property::BorderBottomColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
+                }
+
+                Property::BorderLeftColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::BorderTopLeftRadius(value) => {
@@ -411,6 +685,7 @@ impl Style {
                         &mut self.border_top_left_radius,
                         animation_id,
                         time,
+                        timing_function,
                         value.clone(),
                     );
                 }
@@ -420,6 +695,7 @@ impl Style {
                         &mut self.border_top_right_radius,
                         animation_id,
                         time,
+                        timing_function,
                         value.clone(),
                     );
                 }
@@ -429,6 +705,7 @@ impl Style {
                         &mut self.border_bottom_left_radius,
                         animation_id,
                         time,
+                        timing_function,
                         value.clone(),
                     );
                 }
@@ -438,6 +715,7 @@ impl Style {
                         &mut self.border_bottom_right_radius,
                         animation_id,
                         time,
+                        timing_function,
                         value.clone(),
                     );
                 }
@@ -448,21 +726,50 @@ impl Style {
                         &mut self.outline_width,
                         animation_id,
                         time,
+                        timing_function,
                         value.left.0.clone(),
                     );
                 }
 
                 Property::OutlineColor(value) => {
-                    insert_keyframe(&mut self.outline_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.outline_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::OutlineOffset(value) => {
-                    insert_keyframe(&mut self.outline_offset, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.outline_offset,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 // BACKGROUND
                 Property::BackgroundColor(value) => {
-                    insert_keyframe(&mut self.background_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.background_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
+                }
+
+                Property::ImageTint(value) => {
+                    insert_keyframe(
+                        &mut self.image_tint,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::BackgroundImage(images) => {
@@ -478,134 +785,273 @@ impl Style {
                             }
                         })
                         .collect::<Vec<_>>();
-                    insert_keyframe(&mut self.background_image, animation_id, time, images);
+                    insert_keyframe(
+                        &mut self.background_image,
+                        animation_id,
+                        time,
+                        timing_function,
+                        images,
+                    );
                 }
 
                 Property::BackgroundSize(value) => {
-                    insert_keyframe(&mut self.background_size, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.background_size,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 // BOX SHADOW
                 Property::BoxShadow(value) => {
-                    insert_keyframe(&mut self.box_shadow, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.box_shadow,
+                        animation_id,
+                        time,
+                        timing_function,
+                        value.clone(),
+                    );
                 }
 
                 // TEXT
                 Property::FontColor(value) => {
-                    insert_keyframe(&mut self.font_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.font_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::FontSize(value) => {
-                    insert_keyframe(&mut self.font_size, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.font_size,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::CaretColor(value) => {
-                    insert_keyframe(&mut self.caret_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.caret_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::SelectionColor(value) => {
-                    insert_keyframe(&mut self.selection_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.selection_color,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 // SPACE
                 Property::Left(value) => {
-                    insert_keyframe(&mut self.left, animation_id, time, *value);
+                    insert_keyframe(&mut self.left, animation_id, time, timing_function, *value);
                 }
 
                 Property::Right(value) => {
-                    insert_keyframe(&mut self.right, animation_id, time, *value);
+                    insert_keyframe(&mut self.right, animation_id, time, timing_function, *value);
                 }
 
                 Property::Top(value) => {
-                    insert_keyframe(&mut self.top, animation_id, time, *value);
+                    insert_keyframe(&mut self.top, animation_id, time, timing_function, *value);
                 }
 
                 Property::Bottom(value) => {
-                    insert_keyframe(&mut self.bottom, animation_id, time, *value);
+                    insert_keyframe(&mut self.bottom, animation_id, time, timing_function, *value);
                 }
 
                 // CHILD SPACE
                 Property::ChildLeft(value) => {
-                    insert_keyframe(&mut self.child_left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.child_left,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::ChildRight(value) => {
-                    insert_keyframe(&mut self.child_right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.child_right,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::ChildTop(value) => {
-                    insert_keyframe(&mut self.child_top, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.child_top,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::ChildBottom(value) => {
-                    insert_keyframe(&mut self.child_bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.child_bottom,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::ColBetween(value) => {
-                    insert_keyframe(&mut self.col_between, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.col_between,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::RowBetween(value) => {
-                    insert_keyframe(&mut self.row_between, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.row_between,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 // SIZE
-                Property::Width(value) => {
-                    insert_keyframe(&mut self.width, animation_id, time, *value);
+                // A width measured from the content can't be animated.
+                Property::Width(SizeUnits::Units(value)) => {
+                    insert_keyframe(&mut self.width, animation_id, time, timing_function, *value);
                 }
 
                 Property::Height(value) => {
-                    insert_keyframe(&mut self.height, animation_id, time, *value);
+                    insert_keyframe(&mut self.height, animation_id, time, timing_function, *value);
                 }
 
                 // SIZE CONSTRAINTS
                 Property::MinWidth(value) => {
-                    insert_keyframe(&mut self.min_width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxWidth(value) => {
-                    insert_keyframe(&mut self.max_width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_width,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MinHeight(value) => {
-                    insert_keyframe(&mut self.min_height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_height,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxHeight(value) => {
-                    insert_keyframe(&mut self.max_height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_height,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 // SPACE CONSTRAINTS
                 Property::MinLeft(value) => {
-                    insert_keyframe(&mut self.min_left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_left,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxLeft(value) => {
-                    insert_keyframe(&mut self.max_left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_left,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MinRight(value) => {
-                    insert_keyframe(&mut self.min_right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_right,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxRight(value) => {
-                    insert_keyframe(&mut self.max_right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_right,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MinTop(value) => {
-                    insert_keyframe(&mut self.min_top, animation_id, time, *value);
+                    insert_keyframe(&mut self.min_top, animation_id, time, timing_function, *value);
                 }
 
                 Property::MaxTop(value) => {
-                    insert_keyframe(&mut self.max_top, animation_id, time, *value);
+                    insert_keyframe(&mut self.max_top, animation_id, time, timing_function, *value);
                 }
 
                 Property::MinBottom(value) => {
-                    insert_keyframe(&mut self.min_bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_bottom,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 Property::MaxBottom(value) => {
-                    insert_keyframe(&mut self.max_bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_bottom,
+                        animation_id,
+                        time,
+                        timing_function,
+                        *value,
+                    );
                 }
 
                 _ => {}
@@ -615,8 +1061,9 @@ impl Style {
 
     pub(crate) fn add_animation(&mut self, animation: AnimationBuilder) -> Animation {
         let animation_id = self.animation_manager.create();
+        let timing_function = TimingFunction::from(animation.easing);
         for keyframe in animation.keyframes.iter() {
-            self.add_keyframe(animation_id, keyframe.time, &keyframe.properties);
+            self.add_keyframe(animation_id, keyframe.time, timing_function, &keyframe.properties);
         }
 
         animation_id
@@ -638,8 +1085,14 @@ impl Style {
         self.rotate.play_animation(entity, animation, duration);
         self.scale.play_animation(entity, animation, duration);
 
-        self.border_width.play_animation(entity, animation, duration);
-        self.border_color.play_animation(entity, animation, duration);
+        self.border_top_width.play_animation(entity, animation, duration);
+        self.border_right_width.play_animation(entity, animation, duration);
+        self.border_bottom_width.play_animation(entity, animation, duration);
+        self.border_left_width.play_animation(entity, animation, duration);
+        self.border_top_color.play_animation(entity, animation, duration);
+        self.border_right_color.play_animation(entity, animation, duration);
+        self.border_bottom_color.play_animation(entity, animation, duration);
+        self.border_left_color.play_animation(entity, animation, duration);
 
         self.border_top_left_radius.play_animation(entity, animation, duration);
         self.border_top_right_radius.play_animation(entity, animation, duration);
@@ -651,6 +1104,7 @@ impl Style {
         self.outline_offset.play_animation(entity, animation, duration);
 
         self.background_color.play_animation(entity, animation, duration);
+        self.image_tint.play_animation(entity, animation, duration);
         self.background_image.play_animation(entity, animation, duration);
         self.background_size.play_animation(entity, animation, duration);
 
@@ -691,6 +1145,181 @@ impl Style {
         self.max_bottom.play_animation(entity, animation, duration);
     }
 
+    /// Pauses an animation for an entity at the given progress, between 0.0 and 1.0.
+    ///
+    /// The animation is played first if it is not already active for the entity.
+    pub(crate) fn seek_animation(&mut self, entity: Entity, animation: Animation, t: f32) {
+        self.display.seek_animation(entity, animation, t);
+        self.opacity.seek_animation(entity, animation, t);
+        self.clip_path.seek_animation(entity, animation, t);
+
+        self.transform.seek_animation(entity, animation, t);
+        self.transform_origin.seek_animation(entity, animation, t);
+        self.translate.seek_animation(entity, animation, t);
+        self.rotate.seek_animation(entity, animation, t);
+        self.scale.seek_animation(entity, animation, t);
+
+        self.border_top_width.seek_animation(entity, animation, t);
+        self.border_right_width.seek_animation(entity, animation, t);
+        self.border_bottom_width.seek_animation(entity, animation, t);
+        self.border_left_width.seek_animation(entity, animation, t);
+        self.border_top_color.seek_animation(entity, animation, t);
+        self.border_right_color.seek_animation(entity, animation, t);
+        self.border_bottom_color.seek_animation(entity, animation, t);
+        self.border_left_color.seek_animation(entity, animation, t);
+
+        self.border_top_left_radius.seek_animation(entity, animation, t);
+        self.border_top_right_radius.seek_animation(entity, animation, t);
+        self.border_bottom_left_radius.seek_animation(entity, animation, t);
+        self.border_bottom_right_radius.seek_animation(entity, animation, t);
+
+        self.outline_width.seek_animation(entity, animation, t);
+        self.outline_color.seek_animation(entity, animation, t);
+        self.outline_offset.seek_animation(entity, animation, t);
+
+        self.background_color.seek_animation(entity, animation, t);
+        self.image_tint.seek_animation(entity, animation, t);
+        self.background_image.seek_animation(entity, animation, t);
+        self.background_size.seek_animation(entity, animation, t);
+
+        self.box_shadow.seek_animation(entity, animation, t);
+
+        self.font_color.seek_animation(entity, animation, t);
+        self.font_size.seek_animation(entity, animation, t);
+        self.caret_color.seek_animation(entity, animation, t);
+        self.selection_color.seek_animation(entity, animation, t);
+
+        self.left.seek_animation(entity, animation, t);
+        self.right.seek_animation(entity, animation, t);
+        self.top.seek_animation(entity, animation, t);
+        self.bottom.seek_animation(entity, animation, t);
+
+        self.child_left.seek_animation(entity, animation, t);
+        self.child_right.seek_animation(entity, animation, t);
+        self.child_top.seek_animation(entity, animation, t);
+        self.child_bottom.seek_animation(entity, animation, t);
+        self.col_between.seek_animation(entity, animation, t);
+        self.row_between.seek_animation(entity, animation, t);
+
+        self.width.seek_animation(entity, animation, t);
+        self.height.seek_animation(entity, animation, t);
+
+        self.min_width.seek_animation(entity, animation, t);
+        self.max_width.seek_animation(entity, animation, t);
+        self.min_height.seek_animation(entity, animation, t);
+        self.max_height.seek_animation(entity, animation, t);
+
+        self.min_left.seek_animation(entity, animation, t);
+        self.max_left.seek_animation(entity, animation, t);
+        self.min_right.seek_animation(entity, animation, t);
+        self.max_right.seek_animation(entity, animation, t);
+        self.min_top.seek_animation(entity, animation, t);
+        self.max_top.seek_animation(entity, animation, t);
+        self.min_bottom.seek_animation(entity, animation, t);
+        self.max_bottom.seek_animation(entity, animation, t);
+    }
+
+    /// Plays an animation on an entity after a delay, holding the entity at the first keyframe of
+    /// the animation until then.
+    ///
+    /// Each entity has at most one delayed animation, so this replaces any which has not yet started.
+    pub(crate) fn play_animation_delayed(
+        &mut self,
+        entity: Entity,
+        animation: Animation,
+        duration: Duration,
+        delay: Duration,
+    ) {
+        if delay.is_zero() {
+            self.delayed_animations.remove(&entity);
+            self.play_animation(entity, animation, duration);
+        } else {
+            self.seek_animation(entity, animation, 0.0);
+            self.delayed_animations.insert(
+                entity,
+                DelayedAnimation { animation, duration, start: Instant::now() + delay },
+            );
+        }
+    }
+
+    /// Plays the delayed animations which are due, returning whether any are still waiting.
+    pub(crate) fn play_delayed_animations(&mut self, time: Instant) -> bool {
+        let due = self
+            .delayed_animations
+            .iter()
+            .filter(|(_, delayed)| delayed.start <= time)
+            .map(|(entity, _)| *entity)
+            .collect::<Vec<_>>();
+
+        for entity in due {
+            if let Some(delayed) = self.delayed_animations.remove(&entity) {
+                self.play_animation(entity, delayed.animation, delayed.duration);
+            }
+        }
+
+        !self.delayed_animations.is_empty()
+    }
+
+    /// Animates the opacity of an entity from its current value to `target`.
+    ///
+    /// The duration is for a fade across the full opacity range and is scaled by the distance to
+    /// the target, so retriggering a fade part way through reverses it smoothly. If `remove` is
+    /// true then the entity is returned by [`Style::finish_fades`] once the fade has finished.
+    pub(crate) fn fade(&mut self, entity: Entity, target: f32, duration: Duration, remove: bool) {
+        let start = self.opacity.get(entity).map(|opacity| opacity.0).unwrap_or(1.0);
+
+        if let Some(fade) = self.fades.remove(&entity) {
+            self.remove_fade_animation(fade.animation);
+        }
+
+        self.opacity.insert(entity, Opacity(target));
+
+        let distance = (target - start).abs();
+        let animation = if distance > 0.0 {
+            let animation = self.animation_manager.create();
+            let linear = TimingFunction::linear();
+            self.add_keyframe(animation, 0.0, linear, &[Property::Opacity(Opacity(start))]);
+            self.add_keyframe(animation, 1.0, linear, &[Property::Opacity(Opacity(target))]);
+            self.opacity.play_animation(entity, animation, duration.mul_f32(distance));
+            animation
+        } else {
+            Animation::null()
+        };
+
+        self.fades.insert(entity, Fade { animation, remove });
+        self.needs_redraw();
+    }
+
+    /// Removes any fades which have finished, returning the entities which should be removed.
+    pub(crate) fn finish_fades(&mut self) -> Vec<Entity> {
+        let finished = self
+            .fades
+            .iter()
+            .filter(|(entity, fade)| !self.opacity.has_active_animation(**entity, fade.animation))
+            .map(|(entity, _)| *entity)
+            .collect::<Vec<_>>();
+
+        let mut removed = Vec::new();
+        for entity in finished {
+            if let Some(fade) = self.fades.remove(&entity) {
+                self.remove_fade_animation(fade.animation);
+
+                if fade.remove {
+                    removed.push(entity);
+                }
+            }
+        }
+
+        removed
+    }
+
+    fn remove_fade_animation(&mut self, animation: Animation) {
+        if animation != Animation::null() {
+            self.opacity.remove_animation(animation);
+            self.animation_manager.destroy(animation);
+        }
+    }
+
     pub(crate) fn is_animating(&self, entity: Entity, animation: Animation) -> bool {
         self.display.has_active_animation(entity, animation)
             | self.opacity.has_active_animation(entity, animation)
@@ -700,8 +1329,14 @@ impl Style {
             | self.translate.has_active_animation(entity, animation)
             | self.rotate.has_active_animation(entity, animation)
             | self.scale.has_active_animation(entity, animation)
-            | self.border_width.has_active_animation(entity, animation)
-            | self.border_color.has_active_animation(entity, animation)
+            | self.border_top_width.has_active_animation(entity, animation)
+            | self.border_right_width.has_active_animation(entity, animation)
+            | self.border_bottom_width.has_active_animation(entity, animation)
+            | self.border_left_width.has_active_animation(entity, animation)
+            | self.border_top_color.has_active_animation(entity, animation)
+            | self.border_right_color.has_active_animation(entity, animation)
+            | self.border_bottom_color.has_active_animation(entity, animation)
+            | self.border_left_color.has_active_animation(entity, animation)
             | self.border_top_left_radius.has_active_animation(entity, animation)
             | self.border_top_right_radius.has_active_animation(entity, animation)
             | self.border_bottom_left_radius.has_active_animation(entity, animation)
@@ -710,6 +1345,7 @@ impl Style {
             | self.outline_color.has_active_animation(entity, animation)
             | self.outline_offset.has_active_animation(entity, animation)
             | self.background_color.has_active_animation(entity, animation)
+            | self.image_tint.has_active_animation(entity, animation)
             | self.background_image.has_active_animation(entity, animation)
             | self.background_size.has_active_animation(entity, animation)
             | self.box_shadow.has_active_animation(entity, animation)
@@ -790,6 +1426,7 @@ impl Style {
                                 self.add_keyframe(
                                     animation_id,
                                     time,
+                                    TimingFunction::linear(),
                                     &keyframes.declarations.declarations,
                                 );
                             }
@@ -848,20 +1485,96 @@ impl Style {
             }
 
             "border" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
             }
 
             "border-width" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
             }
 
             "border-color" => {
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
+            }
+
+            "border-top-width" => {
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+            }
+
+            "border-right-width" => {
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom-width" => {
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+            }
+
+            "border-left-width" => {
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+            }
+
+            "border-top-color" => {
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+            }
+
+            "border-right-color" => {
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom-color" => {
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+            }
+
+            "border-left-color" => {
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
             }
 
             "border-radius" => {
@@ -930,6 +1643,11 @@ impl Style {
                 self.background_color.insert_transition(rule_id, animation);
             }
 
+            "image-tint" => {
+                self.image_tint.insert_animation(animation, self.add_transition(transition));
+                self.image_tint.insert_transition(rule_id, animation);
+            }
+
             "background-image" => {
                 self.background_image.insert_animation(animation, self.add_transition(transition));
                 self.background_image.insert_transition(rule_id, animation);
@@ -1106,6 +1824,11 @@ impl Style {
                 self.opacity.insert_rule(rule_id, opacity);
             }
 
+            // Will Change
+            Property::WillChange(will_change) => {
+                self.will_change.insert_rule(rule_id, will_change);
+            }
+
             // Clipping
             Property::ClipPath(clip) => {
                 self.clip_path.insert_rule(rule_id, clip);
@@ -1116,6 +1839,10 @@ impl Style {
                 self.backdrop_filter.insert_rule(rule_id, filter);
             }
 
+            Property::Filter(filter) => {
+                self.filter.insert_rule(rule_id, filter);
+            }
+
             // Layout Type
             Property::LayoutType(layout_type) => {
                 self.layout_type.insert_rule(rule_id, layout_type);
@@ -1126,6 +1853,16 @@ impl Style {
                 self.position_type.insert_rule(rule_id, position_type);
             }
 
+            // Direction
+            Property::Direction(direction) => {
+                self.direction.insert_rule(rule_id, direction);
+            }
+
+            // Box Sizing
+            Property::BoxSizing(box_sizing) => {
+                self.box_sizing.insert_rule(rule_id, box_sizing);
+            }
+
             // Space
             Property::Space(space) => {
                 self.left.insert_rule(rule_id, space);
@@ -1150,6 +1887,24 @@ impl Style {
                 self.bottom.insert_rule(rule_id, bottom);
             }
 
+            // Logical Space. The inline space is resolved against the direction of the entity during
+            // layout, while the block space is always vertical.
+            Property::SpaceInlineStart(space) => {
+                self.space_inline_start.insert_rule(rule_id, space);
+            }
+
+            Property::SpaceInlineEnd(space) => {
+                self.space_inline_end.insert_rule(rule_id, space);
+            }
+
+            Property::SpaceBlockStart(space) => {
+                self.top.insert_rule(rule_id, space);
+            }
+
+            Property::SpaceBlockEnd(space) => {
+                self.bottom.insert_rule(rule_id, space);
+            }
+
             // Size
             Property::Size(size) => {
                 self.width.insert_rule(rule_id, size);
@@ -1157,7 +1912,10 @@ impl Style {
             }
 
             Property::Width(width) => {
-                self.width.insert_rule(rule_id, width);
+                self.width.insert_rule(rule_id, width.units());
+                if let Some(intrinsic_size) = width.intrinsic_size() {
+                    self.intrinsic_size.insert_rule(rule_id, intrinsic_size);
+                }
             }
 
             Property::Height(height) => {
@@ -1188,6 +1946,24 @@ impl Style {
                 self.child_bottom.insert_rule(rule_id, child_bottom);
             }
 
+            // Logical Child Space. The children of an entity are mirrored by the direction of the entity
+            // itself, so the inline start side of its child space is always the child-left side.
+            Property::ChildSpaceInlineStart(child_space) => {
+                self.child_left.insert_rule(rule_id, child_space);
+            }
+
+            Property::ChildSpaceInlineEnd(child_space) => {
+                self.child_right.insert_rule(rule_id, child_space);
+            }
+
+            Property::ChildSpaceBlockStart(child_space) => {
+                self.child_top.insert_rule(rule_id, child_space);
+            }
+
+            Property::ChildSpaceBlockEnd(child_space) => {
+                self.child_bottom.insert_rule(rule_id, child_space);
+            }
+
             Property::RowBetween(row_between) => {
                 self.row_between.insert_rule(rule_id, row_between);
             }
@@ -1196,6 +1972,43 @@ impl Style {
                 self.col_between.insert_rule(rule_id, col_between);
             }
 
+            // Gap
+            Property::Gap(gap) => {
+                self.row_between.insert_rule(rule_id, gap);
+                self.col_between.insert_rule(rule_id, gap);
+            }
+
+            Property::RowGap(row_gap) => {
+                self.row_between.insert_rule(rule_id, row_gap);
+            }
+
+            Property::ColumnGap(column_gap) => {
+                self.col_between.insert_rule(rule_id, column_gap);
+            }
+
+            // Wrapping
+            Property::FlexWrap(flex_wrap) => {
+                self.flex_wrap.insert_rule(rule_id, flex_wrap);
+            }
+
+            Property::AlignContent(align_content) => {
+                self.align_content.insert_rule(rule_id, align_content);
+            }
+
+            // Alignment
+            Property::JustifyContent(justify_content) => {
+                self.justify_content.insert_rule(rule_id, justify_content);
+            }
+
+            Property::AlignItems(align_items) => {
+                self.align_items.insert_rule(rule_id, align_items);
+            }
+
+            // Order
+            Property::Order(order) => {
+                self.order.insert_rule(rule_id, order);
+            }
+
             // Space Constraints
             Property::MinSpace(min_space) => {
                 self.min_left.insert_rule(rule_id, min_space);
@@ -1278,20 +2091,59 @@ impl Style {
             // Border
             Property::Border(border) => {
                 if let Some(border_color) = border.color {
-                    self.border_color.insert_rule(rule_id, border_color);
+                    self.border_top_color.insert_rule(rule_id, border_color);
+                    self.border_right_color.insert_rule(rule_id, border_color);
+                    self.border_bottom_color.insert_rule(rule_id, border_color);
+                    self.border_left_color.insert_rule(rule_id, border_color);
                 }
 
                 if let Some(border_width) = border.width {
-                    self.border_width.insert_rule(rule_id, border_width.into());
+                    let border_width: LengthOrPercentage = border_width.into();
+                    self.border_top_width.insert_rule(rule_id, border_width.clone());
+                    self.border_right_width.insert_rule(rule_id, border_width.clone());
+                    self.border_bottom_width.insert_rule(rule_id, border_width.clone());
+                    self.border_left_width.insert_rule(rule_id, border_width);
                 }
             }
 
-            // Border
+            // Border Width
             Property::BorderWidth(border_width) => {
-                self.border_width.insert_rule(rule_id, border_width.top.0);
+                self.border_top_width.insert_rule(rule_id, border_width.top.0);
+                self.border_right_width.insert_rule(rule_id, border_width.right.0);
+                self.border_bottom_width.insert_rule(rule_id, border_width.bottom.0);
+                self.border_left_width.insert_rule(rule_id, border_width.left.0);
             }
-            Property::BorderColor(color) => {
-                self.border_color.insert_rule(rule_id, color);
+            Property::BorderTopWidth(border_width) => {
+                self.border_top_width.insert_rule(rule_id, border_width.0);
+            }
+            Property::BorderRightWidth(border_width) => {
+                self.border_right_width.insert_rule(rule_id, border_width.0);
+            }
+            Property::BorderBottomWidth(border_width) => {
+                self.border_bottom_width.insert_rule(rule_id, border_width.0);
+            }
+            Property::BorderLeftWidth(border_width) => {
+                self.border_left_width.insert_rule(rule_id, border_width.0);
+            }
+
+            // Border Color
+            Property::BorderColor(border_color) => {
+                self.border_top_color.insert_rule(rule_id, border_color.top);
+                self.border_right_color.insert_rule(rule_id, border_color.right);
+                self.border_bottom_color.insert_rule(rule_id, border_color.bottom);
+                self.border_left_color.insert_rule(rule_id, border_color.left);
+            }
+            Property::BorderTopColor(color) => {
+                self.border_top_color.insert_rule(rule_id, color);
+            }
+            Property::BorderRightColor(color) => {
+                self.border_right_color.insert_rule(rule_id, color);
+            }
+            Property::BorderBottomColor(color) => {
+                self.border_bottom_color.insert_rule(rule_id, color);
+            }
+            Property::BorderLeftColor(color) => {
+                self.border_left_color.insert_rule(rule_id, color);
             }
 
             // Border Radius
@@ -1387,11 +2239,38 @@ impl Style {
                 self.font_stretch.insert_rule(rule_id, font_stretch);
             }
 
+            // Font Smoothing
+            Property::FontSmoothing(font_smoothing) => {
+                self.font_smoothing.insert_rule(rule_id, font_smoothing);
+            }
+
+            // Font Hinting
+            Property::FontHinting(font_hinting) => {
+                self.font_hinting.insert_rule(rule_id, font_hinting);
+            }
+
             // Caret Color
             Property::CaretColor(caret_color) => {
                 self.caret_color.insert_rule(rule_id, caret_color);
             }
 
+            Property::CaretBlinkRate(caret_blink_rate) => {
+                self.caret_blink_rate.insert_rule(rule_id, caret_blink_rate);
+            }
+
+            Property::CaretShape(caret_shape) => {
+                self.caret_shape.insert_rule(rule_id, caret_shape);
+            }
+
+            Property::CaretWidth(caret_width) => {
+                self.caret_width.insert_rule(rule_id, caret_width);
+            }
+
+            // Link Color
+            Property::LinkColor(link_color) => {
+                self.link_color.insert_rule(rule_id, link_color);
+            }
+
             // Selection Color
             Property::SelectionColor(selection_color) => {
                 self.selection_color.insert_rule(rule_id, selection_color);
@@ -1483,6 +2362,39 @@ impl Style {
                 self.background_size.insert_rule(rule_id, sizes);
             }
 
+            // Image Rendering
+            Property::ImageRendering(image_rendering) => {
+                self.image_rendering.insert_rule(rule_id, image_rendering);
+            }
+
+            // Image Tint
+            Property::ImageTint(color) => {
+                self.image_tint.insert_rule(rule_id, color);
+            }
+
+            // Mask Image
+            Property::MaskImage(images) => {
+                let images = images
+                    .into_iter()
+                    .filter_map(|img| match img {
+                        BackgroundImage::None => None,
+                        BackgroundImage::Gradient(gradient) => {
+                            Some(ImageOrGradient::Gradient(*gradient))
+                        }
+                        BackgroundImage::Url(url) => {
+                            Some(ImageOrGradient::Image(url.url.to_string()))
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                self.mask_image.insert_rule(rule_id, images);
+            }
+
+            // Mask Mode
+            Property::MaskMode(mask_mode) => {
+                self.mask_mode.insert_rule(rule_id, mask_mode);
+            }
+
             // Text Wrapping
             Property::TextWrap(text_wrap) => {
                 self.text_wrap.insert_rule(rule_id, text_wrap);
@@ -1500,7 +2412,7 @@ impl Style {
 
             // Cursor Icon
             Property::Cursor(cursor) => {
-                self.cursor.insert_rule(rule_id, cursor);
+                self.cursor.insert_rule(rule_id, cursor.into());
             }
 
             // Unparsed. TODO: Log the error.
@@ -1522,17 +2434,8 @@ impl Style {
         &self,
         transition: &Transition,
     ) -> AnimationState<T> {
-        let timing_function = transition
-            .timing_function
-            .map(|easing| match easing {
-                EasingFunction::Linear => TimingFunction::linear(),
-                EasingFunction::Ease => TimingFunction::ease(),
-                EasingFunction::EaseIn => TimingFunction::ease_in(),
-                EasingFunction::EaseOut => TimingFunction::ease_out(),
-                EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
-                EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
-            })
-            .unwrap_or_default();
+        let timing_function =
+            transition.timing_function.map(TimingFunction::from).unwrap_or_default();
 
         AnimationState::new(Animation::null())
             .with_duration(transition.duration)
@@ -1551,6 +2454,12 @@ impl Style {
 
     // Remove style data for the given entity.
     pub fn remove(&mut self, entity: Entity) {
+        if let Some(fade) = self.fades.remove(&entity) {
+            self.remove_fade_animation(fade.animation);
+        }
+
+        self.delayed_animations.remove(&entity);
+
         self.ids.remove(entity);
         self.classes.remove(entity);
         self.pseudo_classes.remove(entity);
@@ -1571,13 +2480,18 @@ impl Style {
         self.visibility.remove(entity);
         // Opacity
         self.opacity.remove(entity);
+        // Will Change
+        self.will_change.remove(entity);
         // Z Order
         self.z_index.remove(entity);
+        self.paint_order.remove(entity);
         // Clipping
         self.clip_path.remove(entity);
 
         // Backdrop Filter
         self.backdrop_filter.remove(entity);
+        self.filter.remove(entity);
+        self.shader_effect.remove(entity);
 
         // Transform
         self.transform.remove(entity);
@@ -1590,8 +2504,14 @@ impl Style {
         self.overflowy.remove(entity);
 
         // Border
-        self.border_width.remove(entity);
-        self.border_color.remove(entity);
+        self.border_top_width.remove(entity);
+        self.border_right_width.remove(entity);
+        self.border_bottom_width.remove(entity);
+        self.border_left_width.remove(entity);
+        self.border_top_color.remove(entity);
+        self.border_right_color.remove(entity);
+        self.border_bottom_color.remove(entity);
+        self.border_left_color.remove(entity);
 
         // Border Shape
         self.border_bottom_left_shape.remove(entity);
@@ -1614,6 +2534,12 @@ impl Style {
         self.background_color.remove(entity);
         self.background_image.remove(entity);
         self.background_size.remove(entity);
+        self.image_rendering.remove(entity);
+        self.image_tint.remove(entity);
+        self.mask_image.remove(entity);
+        self.mask_mode.remove(entity);
+        self.mask_view.remove(entity);
+        self.mask_sources.remove(entity);
 
         // Box Shadow
         self.box_shadow.remove(entity);
@@ -1624,12 +2550,24 @@ impl Style {
         // Position Type
         self.position_type.remove(entity);
 
+        // Direction
+        self.direction.remove(entity);
+        self.reversed_inline.remove(&entity);
+
+        // Box Sizing
+        self.box_sizing.remove(entity);
+        self.intrinsic_size.remove(entity);
+
         // Space
         self.left.remove(entity);
         self.right.remove(entity);
         self.top.remove(entity);
         self.bottom.remove(entity);
 
+        // Logical Space
+        self.space_inline_start.remove(entity);
+        self.space_inline_end.remove(entity);
+
         // Size
         self.width.remove(entity);
         self.height.remove(entity);
@@ -1658,6 +2596,18 @@ impl Style {
         self.col_between.remove(entity);
         self.row_between.remove(entity);
 
+        // Wrapping
+        self.flex_wrap.remove(entity);
+        self.align_content.remove(entity);
+        self.wrap_size.remove(entity);
+
+        // Alignment
+        self.justify_content.remove(entity);
+        self.align_items.remove(entity);
+
+        // Order
+        self.order.remove(entity);
+
         // Text and Font
         self.text_wrap.remove(entity);
         self.text_align.remove(entity);
@@ -1666,12 +2616,21 @@ impl Style {
         self.font_style.remove(entity);
         self.font_color.remove(entity);
         self.font_size.remove(entity);
+        self.font_smoothing.remove(entity);
+        self.font_hinting.remove(entity);
         self.selection_color.remove(entity);
         self.caret_color.remove(entity);
+        self.caret_blink_rate.remove(entity);
+        self.caret_shape.remove(entity);
+        self.caret_width.remove(entity);
+        self.link_color.remove(entity);
 
         // Cursor
         self.cursor.remove(entity);
 
+        // Hit Testing
+        self.hit_area_inset.remove(entity);
+
         self.name.remove(entity);
 
         self.needs_text_layout.remove(entity);
@@ -1690,14 +2649,29 @@ impl Style {
         self.system_flags.set(SystemFlags::REDRAW, true);
     }
 
+    /// Marks the given views as needing to be redrawn. With partial redraw enabled only the area of
+    /// the window they cover is redrawn, otherwise the whole window is.
+    pub(crate) fn needs_partial_redraw(&mut self, entities: Vec<Entity>) {
+        for entity in entities {
+            if !self.redraw_entities.contains(&entity) {
+                self.redraw_entities.push(entity);
+            }
+        }
+        self.system_flags.set(SystemFlags::REDRAW_PARTIAL, true);
+    }
+
     pub fn needs_access_update(&mut self, entity: Entity) {
         self.needs_access_update.insert(entity, true);
     }
 
     pub fn should_redraw<F: FnOnce()>(&mut self, f: F) {
-        if self.system_flags.contains(SystemFlags::REDRAW) {
+        if self.system_flags.intersects(SystemFlags::REDRAW | SystemFlags::REDRAW_PARTIAL) {
+            if self.system_flags.contains(SystemFlags::REDRAW) {
+                self.full_redraw = true;
+            }
+
             f();
-            self.system_flags.set(SystemFlags::REDRAW, false);
+            self.system_flags.set(SystemFlags::REDRAW | SystemFlags::REDRAW_PARTIAL, false);
         }
     }
 
@@ -1710,6 +2684,8 @@ impl Style {
         self.visibility.clear_rules();
         // Opacity
         self.opacity.clear_rules();
+        // Will Change
+        self.will_change.clear_rules();
         // Z Order
         self.z_index.clear_rules();
 
@@ -1718,6 +2694,7 @@ impl Style {
 
         // Backdrop Filer
         self.backdrop_filter.clear_rules();
+        self.filter.clear_rules();
 
         // Transform
         self.transform.clear_rules();
@@ -1730,8 +2707,14 @@ impl Style {
         self.overflowy.clear_rules();
 
         // Border
-        self.border_width.clear_rules();
-        self.border_color.clear_rules();
+        self.border_top_width.clear_rules();
+        self.border_right_width.clear_rules();
+        self.border_bottom_width.clear_rules();
+        self.border_left_width.clear_rules();
+        self.border_top_color.clear_rules();
+        self.border_right_color.clear_rules();
+        self.border_bottom_color.clear_rules();
+        self.border_left_color.clear_rules();
 
         // Border Shape
         self.border_bottom_left_shape.clear_rules();
@@ -1754,11 +2737,18 @@ impl Style {
         self.background_color.clear_rules();
         self.background_image.clear_rules();
         self.background_size.clear_rules();
+        self.image_rendering.clear_rules();
+        self.image_tint.clear_rules();
+        self.mask_image.clear_rules();
+        self.mask_mode.clear_rules();
 
         self.box_shadow.clear_rules();
 
         self.layout_type.clear_rules();
         self.position_type.clear_rules();
+        self.direction.clear_rules();
+        self.box_sizing.clear_rules();
+        self.intrinsic_size.clear_rules();
 
         // Space
         self.left.clear_rules();
@@ -1766,6 +2756,10 @@ impl Style {
         self.top.clear_rules();
         self.bottom.clear_rules();
 
+        // Logical Space
+        self.space_inline_start.clear_rules();
+        self.space_inline_end.clear_rules();
+
         // Size
         self.width.clear_rules();
         self.height.clear_rules();
@@ -1794,6 +2788,17 @@ impl Style {
         self.col_between.clear_rules();
         self.row_between.clear_rules();
 
+        // Wrapping
+        self.flex_wrap.clear_rules();
+        self.align_content.clear_rules();
+
+        // Alignment
+        self.justify_content.clear_rules();
+        self.align_items.clear_rules();
+
+        // Order
+        self.order.clear_rules();
+
         // Text and Font
         self.text_wrap.clear_rules();
         self.text_align.clear_rules();
@@ -1802,8 +2807,14 @@ impl Style {
         self.font_style.clear_rules();
         self.font_color.clear_rules();
         self.font_size.clear_rules();
+        self.font_smoothing.clear_rules();
+        self.font_hinting.clear_rules();
         self.selection_color.clear_rules();
         self.caret_color.clear_rules();
+        self.caret_blink_rate.clear_rules();
+        self.caret_shape.clear_rules();
+        self.caret_width.clear_rules();
+        self.link_color.clear_rules();
 
         self.cursor.clear_rules();
 
@@ -5,7 +5,7 @@ use crate::{
     prelude::*,
     style::{Abilities, PseudoClassFlags},
 };
-use accesskit::{CheckedState, NodeBuilder, Rect, TreeUpdate};
+use accesskit::{CheckedState, Node, NodeBuilder, NodeId, Rect, TreeUpdate};
 use fnv::FnvHashMap;
 use vizia_storage::LayoutTreeIterator;
 
@@ -72,6 +72,103 @@ pub(crate) fn accessibility_system(cx: &mut Context) {
     }
 }
 
+// The index of the first of the live region nodes, which are generated children of the root node.
+// Announced messages are given the indices below it in turn.
+const LIVE_REGION_INDEX: usize = u32::MAX as usize - 1;
+
+// The politeness of each live region, in the order the regions are added to the root node.
+const LIVE_REGIONS: [Politeness; 2] = [Politeness::Polite, Politeness::Assertive];
+
+fn live_region_id(index: usize) -> NodeId {
+    AccessNode::new_from_parent(Entity::root().accesskit_id(), LIVE_REGION_INDEX + index).node_id()
+}
+
+fn live_region_node(cx: &mut Context, politeness: Politeness, messages: Vec<NodeId>) -> Node {
+    let mut node_builder = NodeBuilder::new(Role::GenericContainer);
+    node_builder.set_live(politeness.into());
+    node_builder.set_children(messages);
+    node_builder.build(&mut cx.style.accesskit_node_classes)
+}
+
+/// Returns the accesskit ids of the children of a view. The children of the root view also include
+/// the live regions which announced messages are added to.
+pub(crate) fn access_children(tree: &Tree<Entity>, entity: Entity) -> Vec<NodeId> {
+    let mut children =
+        entity.child_iter(tree).map(|entity| entity.accesskit_id()).collect::<Vec<_>>();
+    if entity == Entity::root() {
+        children.extend((0..LIVE_REGIONS.len()).map(live_region_id));
+    }
+    children
+}
+
+/// Returns the nodes of the initial accessibility tree of the main window, which are the root node
+/// and the empty live regions. The live regions must already be in the tree when the first message
+/// is announced, as screen readers only announce nodes which are added to an existing live region.
+pub(crate) fn initial_access_nodes(cx: &mut Context) -> Vec<(NodeId, Node)> {
+    let mut nodes = Vec::new();
+
+    let mut access_context = AccessContext {
+        current: Entity::root(),
+        tree: &cx.tree,
+        cache: &cx.cache,
+        style: &cx.style,
+        text_context: &mut cx.text_context,
+    };
+
+    if let Some(mut node) = get_access_node(&mut access_context, &mut cx.views, Entity::root()) {
+        // The descendants of the root view are added by their own updates.
+        node.node_builder
+            .set_children((0..LIVE_REGIONS.len()).map(live_region_id).collect::<Vec<_>>());
+        nodes.push((node.node_id(), node.node_builder.build(&mut cx.style.accesskit_node_classes)));
+    }
+
+    for (index, politeness) in LIVE_REGIONS.into_iter().enumerate() {
+        nodes.push((live_region_id(index), live_region_node(cx, politeness, Vec::new())));
+    }
+
+    nodes
+}
+
+/// Adds the messages announced since the last update to the live regions of the accessibility tree,
+/// so that they are read out by screen readers.
+pub(crate) fn announcement_system(cx: &mut Context) {
+    if cx.announcements.is_empty() {
+        return;
+    }
+
+    let root_id = Entity::root().accesskit_id();
+    let mut nodes = Vec::new();
+
+    for (index, politeness) in LIVE_REGIONS.into_iter().enumerate() {
+        // Each message is given a new node, as screen readers announce nodes added to a live region.
+        let mut message_ids = Vec::new();
+        for (message, _) in cx.announcements.iter().filter(|(_, p)| *p == politeness) {
+            let message_id =
+                AccessNode::new_from_parent(root_id, cx.announcement_count % LIVE_REGION_INDEX)
+                    .node_id();
+            cx.announcement_count = cx.announcement_count.wrapping_add(1);
+
+            let mut node_builder = NodeBuilder::new(Role::StaticText);
+            node_builder.set_name(message.clone().into_boxed_str());
+            nodes.push((message_id, node_builder.build(&mut cx.style.accesskit_node_classes)));
+            message_ids.push(message_id);
+        }
+
+        // The previous messages of the region are replaced, which removes them from the tree.
+        if !message_ids.is_empty() {
+            nodes.push((live_region_id(index), live_region_node(cx, politeness, message_ids)));
+        }
+    }
+
+    cx.tree_updates.push(TreeUpdate {
+        nodes,
+        tree: None,
+        focus: cx.window_has_focus.then_some(cx.focused.accesskit_id()),
+    });
+
+    cx.announcements.clear();
+}
+
 pub(crate) fn get_access_node(
     cx: &mut AccessContext,
     views: &mut FnvHashMap<Entity, Box<dyn ViewHandler>>,
@@ -167,8 +264,7 @@ pub(crate) fn get_access_node(
         }
     }
 
-    let children =
-        entity.child_iter(cx.tree).map(|entity| entity.accesskit_id()).collect::<Vec<_>>();
+    let children = access_children(cx.tree, entity);
     if !children.is_empty() {
         node_builder.set_children(children);
     }
@@ -184,3 +280,29 @@ pub(crate) fn get_access_node(
 
     Some(node)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[test]
+    fn announcements_only_update_live_regions() {
+        let mut harness = TestHarness::new(|cx| {
+            Label::new(cx, "Hello");
+        });
+
+        let cx = harness.context();
+        assert!(access_children(&cx.tree, Entity::root()).contains(&live_region_id(0)));
+
+        cx.announcements.push(("Saved".to_owned(), Politeness::Polite));
+        announcement_system(cx);
+
+        let update = cx.tree_updates.pop().unwrap();
+        let ids = update.nodes.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        assert!(!ids.contains(&Entity::root().accesskit_id()));
+        assert!(ids.contains(&live_region_id(0)));
+        assert!(!ids.contains(&live_region_id(1)));
+        assert!(cx.announcements.is_empty());
+    }
+}
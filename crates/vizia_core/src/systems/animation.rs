@@ -1,44 +1,56 @@
 use crate::{prelude::*, style::SystemFlags};
+use instant::Duration;
 
 pub(crate) fn animation_system(cx: &mut Context) -> bool {
     let time = instant::Instant::now();
 
-    // Properties which affect rendering
+    // Start any delayed animations which are due before ticking.
+    let delayed = cx.style.play_delayed_animations(time);
+
+    // Properties which affect rendering, along with the views they change
+    let mut changed = Vec::new();
     let needs_redraw =
         // Opacity
-        cx.style.opacity.tick(time)
+        cx.style.opacity.tick_entities(time, &mut changed)
         // Border Colour
-        | cx.style.border_color.tick(time)
+        | cx.style.border_top_color.tick_entities(time, &mut changed)
+        | cx.style.border_right_color.tick_entities(time, &mut changed)
+        | cx.style.border_bottom_color.tick_entities(time, &mut changed)
+        | cx.style.border_left_color.tick_entities(time, &mut changed)
         // Border Radius
-        | cx.style.border_top_left_radius.tick(time)
-        | cx.style.border_top_right_radius.tick(time)
-        | cx.style.border_bottom_left_radius.tick(time)
-        | cx.style.border_bottom_right_radius.tick(time)
+        | cx.style.border_top_left_radius.tick_entities(time, &mut changed)
+        | cx.style.border_top_right_radius.tick_entities(time, &mut changed)
+        | cx.style.border_bottom_left_radius.tick_entities(time, &mut changed)
+        | cx.style.border_bottom_right_radius.tick_entities(time, &mut changed)
         // Background
-        | cx.style.background_color.tick(time)
-        | cx.style.background_image.tick(time)
-        | cx.style.background_size.tick(time)
+        | cx.style.background_color.tick_entities(time, &mut changed)
+        | cx.style.background_image.tick_entities(time, &mut changed)
+        | cx.style.background_size.tick_entities(time, &mut changed)
+        | cx.style.image_tint.tick_entities(time, &mut changed)
         // Box Shadow
-        | cx.style.box_shadow.tick(time)
+        | cx.style.box_shadow.tick_entities(time, &mut changed)
         // Font Color
-        | cx.style.font_color.tick(time)
+        | cx.style.font_color.tick_entities(time, &mut changed)
         // Transform
-        | cx.style.transform.tick(time)
-        | cx.style.transform_origin.tick(time)
-        | cx.style.translate.tick(time)
-        | cx.style.rotate.tick(time)
-        | cx.style.scale.tick(time)
+        | cx.style.transform.tick_entities(time, &mut changed)
+        | cx.style.transform_origin.tick_entities(time, &mut changed)
+        | cx.style.translate.tick_entities(time, &mut changed)
+        | cx.style.rotate.tick_entities(time, &mut changed)
+        | cx.style.scale.tick_entities(time, &mut changed)
         // Outline
-        | cx.style.outline_color.tick(time)
-        | cx.style.outline_offset.tick(time)
-        | cx.style.outline_width.tick(time)
+        | cx.style.outline_color.tick_entities(time, &mut changed)
+        | cx.style.outline_offset.tick_entities(time, &mut changed)
+        | cx.style.outline_width.tick_entities(time, &mut changed)
         // Clip Path
-        | cx.style.clip_path.tick(time);
+        | cx.style.clip_path.tick_entities(time, &mut changed);
 
     // Properties which affect layout
     let needs_relayout =
         // Border Width
-        cx.style.border_width.tick(time)
+        cx.style.border_top_width.tick(time)
+        | cx.style.border_right_width.tick(time)
+        | cx.style.border_bottom_width.tick(time)
+        | cx.style.border_left_width.tick(time)
         // Font Size
         | cx.style.font_size.tick(time)
         // Space
@@ -72,13 +84,29 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
         | cx.style.child_top.tick(time)
         | cx.style.child_bottom.tick(time);
 
+    // Blink the caret of the focused text input.
+    let caret_blink_rate = if cx.keyboard_requested {
+        cx.style.caret_blink_rate.get(cx.focused).copied().unwrap_or_default()
+    } else {
+        Duration::ZERO
+    };
+    let caret_changed = cx.text_context.update_caret_blink(cx.focused, caret_blink_rate, time);
+    if caret_changed {
+        changed.push(cx.focused);
+    }
+    let needs_redraw = needs_redraw | caret_changed;
+
+    // The caret only changes when it toggles, so backends can wait until then instead of polling.
+    cx.next_caret_blink = cx.text_context.next_caret_blink(caret_blink_rate, time);
+
     if needs_relayout {
         cx.style.system_flags.set(SystemFlags::RELAYOUT, true);
     }
 
+    // With partial redraw enabled, only the views changed by the animations are redrawn.
     if needs_redraw {
-        cx.style.system_flags.set(SystemFlags::REDRAW, true);
+        cx.style.needs_partial_redraw(changed);
     }
 
-    needs_redraw | needs_relayout
+    needs_redraw | needs_relayout | delayed
 }
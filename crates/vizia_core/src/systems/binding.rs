@@ -1,4 +1,5 @@
 use crate::{model::ModelOrView, prelude::*};
+use std::any::TypeId;
 use std::collections::HashSet;
 
 pub(crate) fn binding_system(cx: &mut Context) {
@@ -6,6 +7,20 @@ pub(crate) fn binding_system(cx: &mut Context) {
 
     // Loop through all model data and check for changes.
     for entry in cx.data.dense.iter_mut() {
+        // Update the interaction state of views which track it, before it is checked for changes.
+        if let Some(interaction) = entry
+            .value
+            .models
+            .get_mut(&TypeId::of::<Interaction>())
+            .and_then(|model| model.downcast_mut::<Interaction>())
+        {
+            if let Some(pseudo_classes) =
+                cx.style.pseudo_classes.get(Entity::new(entry.key as u32, 0))
+            {
+                interaction.update(*pseudo_classes);
+            }
+        }
+
         // Determine observers of model data.
         for (_, model) in entry.value.models.iter() {
             let model = ModelOrView::Model(model.as_ref());
@@ -1,75 +1,306 @@
+use super::{paint_ordered_children, ZQueue};
+use crate::cache::CachedData;
 use crate::prelude::*;
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use crate::resource::ImageOrId;
+use femtovg::{CompositeOperation, ImageFlags, ImageId, Paint, Path, PixelFormat, RenderTarget};
 use vizia_id::GenerationalId;
-use vizia_storage::LayoutChildIterator;
-
-pub(crate) fn draw_system(cx: &mut Context) {
-    let canvas = cx.canvases.get_mut(&Entity::root()).unwrap();
-    cx.resource_manager.mark_images_unused();
-    let window_width = cx.cache.get_width(Entity::root());
-    let window_height = cx.cache.get_height(Entity::root());
-    let clear_color =
-        cx.style.background_color.get(Entity::root()).cloned().unwrap_or(RGBA::TRANSPARENT.into());
+
+pub(crate) fn draw_system(cx: &mut Context, window: Entity) {
+    let main_window = window == Entity::root();
+    let canvas = match cx.canvases.get_mut(&window) {
+        Some(canvas) => canvas,
+        None => return,
+    };
+    let window_width = cx.cache.get_width(window);
+    let window_height = cx.cache.get_height(window);
+
+    // Nothing is drawn while the window has no area, such as while it is minimized.
+    if window_width < 1.0 || window_height < 1.0 {
+        return;
+    }
+
+    if main_window {
+        cx.resource_manager.mark_images_unused();
+    } else if let Some(images) = cx.cache.removed_images.remove(&window) {
+        for image in images {
+            canvas.delete_image(image);
+        }
+    }
+    let clear_color = match cx.clear_behavior {
+        // A window with a background of its own is cleared to transparent and its background is drawn
+        // by the window view, so that its alpha is only applied once and its corner radius is kept.
+        // A window without a background of its own is cleared with the background of the main window.
+        ClearBehavior::Background => Some(if cx.style.background_color.get(window).is_some() {
+            RGBA::TRANSPARENT.into()
+        } else {
+            cx.style
+                .background_color
+                .get(Entity::root())
+                .cloned()
+                .unwrap_or(RGBA::TRANSPARENT.into())
+        }),
+        ClearBehavior::Color(color) => Some(color),
+        ClearBehavior::Preserve => None,
+    };
     canvas.set_size(window_width as u32, window_height as u32, 1.0);
-    canvas.clear_rect(0, 0, window_width as u32, window_height as u32, clear_color.into());
 
-    let mut queue = BinaryHeap::new();
-    queue.push(ZEntity { index: 0, entity: Entity::root(), opacity: 1.0, visible: true });
-    while !queue.is_empty() {
-        let zentity = queue.pop().unwrap();
+    // With partial redraw, the window is drawn into a frame image which keeps the content of the
+    // previous frame, and only the area covered by the views in `redraw_entities` is redrawn. Without
+    // it, those views cause the whole window to be redrawn. A backdrop filter reads the window itself,
+    // so it needs the whole window redrawn. Other windows are always redrawn in full.
+    let has_backdrop_filter = !cx.style.backdrop_filter.inline_data.is_empty()
+        || !cx.style.backdrop_filter.shared_data.is_empty();
+    let frame = match clear_color {
+        _ if !main_window => None,
+
+        Some(_) if cx.partial_redraw && !has_backdrop_filter => {
+            frame_image(&mut cx.cache, canvas, window_width, window_height)
+        }
+
+        _ => {
+            if let Some(frame_image) = cx.cache.frame_image.take() {
+                canvas.delete_image(frame_image);
+            }
+
+            None
+        }
+    };
+
+    let (full_redraw, redraw_entities) = if main_window {
+        (std::mem::take(&mut cx.style.full_redraw), std::mem::take(&mut cx.style.redraw_entities))
+    } else {
+        (true, Vec::new())
+    };
+
+    let mut draw_cx = DrawContext {
+        current: window,
+        style: &cx.style,
+        cache: &mut cx.cache,
+        tree: &cx.tree,
+        data: &cx.data,
+        views: &mut cx.views,
+        resource_manager: &cx.resource_manager,
+        text_context: &mut cx.text_context,
+        text_config: &cx.text_config,
+        modifiers: &cx.modifiers,
+        mouse: &cx.mouse,
+        opacity: 1.0,
+        render_target: RenderTarget::Screen,
+        dirty_rect: None,
+        window,
+        shader_context: cx.shader_contexts.get_mut(&window),
+        drawing_mask: false,
+    };
+
+    if let Some((frame_image, created)) = frame {
+        draw_cx.render_target = RenderTarget::Image(frame_image);
+        canvas.set_render_target(draw_cx.render_target);
+
+        // Only the dirty area is redrawn, unless the whole window changed or the frame image has no
+        // previous content, such as after a resize.
+        if !full_redraw && !created {
+            draw_cx.dirty_rect = Some(redraw_region(&mut draw_cx, &redraw_entities));
+        }
+    }
+
+    if let Some(clear_color) = clear_color {
+        if let Some(dirty_rect) = draw_cx.dirty_rect {
+            // Only the dirty area of the frame image is cleared, keeping the rest of the last frame.
+            let mut path = Path::new();
+            path.rect(dirty_rect.x, dirty_rect.y, dirty_rect.w, dirty_rect.h);
+            canvas.global_composite_operation(CompositeOperation::Copy);
+            canvas.fill_path(&path, &Paint::color(clear_color.into()));
+            canvas.global_composite_operation(CompositeOperation::SourceOver);
+        } else {
+            // Views are drawn with premultiplied alpha, which is how a transparent window is composited
+            // with the desktop, but the color given to a clear is written as is.
+            let clear_color: femtovg::Color = clear_color.into();
+            canvas.clear_rect(
+                0,
+                0,
+                window_width as u32,
+                window_height as u32,
+                clear_color.premultiplied(),
+            );
+        }
+    }
+
+    let mut queue = ZQueue::new();
+    queue.push(0, ZEntity { entity: window, opacity: 1.0, visible: true });
+    while let Some((z_index, zentity)) = queue.pop() {
         canvas.save();
-        draw_entity(
-            &mut DrawContext {
-                current: zentity.entity,
-                style: &cx.style,
-                cache: &mut cx.cache,
-                tree: &cx.tree,
-                data: &cx.data,
-                views: &mut cx.views,
-                resource_manager: &cx.resource_manager,
-                text_context: &mut cx.text_context,
-                text_config: &cx.text_config,
-                modifiers: &cx.modifiers,
-                mouse: &cx.mouse,
-                opacity: zentity.opacity,
-            },
-            canvas,
-            zentity.index,
-            &mut queue,
-            zentity.visible,
+        if let Some(dirty_rect) = draw_cx.dirty_rect {
+            canvas.scissor(dirty_rect.x, dirty_rect.y, dirty_rect.w, dirty_rect.h);
+        }
+        draw_cx.current = zentity.entity;
+        draw_cx.opacity = zentity.opacity;
+        draw_entity(&mut draw_cx, canvas, z_index, &mut queue, zentity.visible);
+        canvas.restore();
+    }
+
+    let dirty_rect = draw_cx.dirty_rect;
+
+    // Copy the frame image to the window.
+    if let Some((frame_image, _)) = frame {
+        canvas.set_render_target(RenderTarget::Screen);
+        canvas.clear_rect(
+            0,
+            0,
+            window_width as u32,
+            window_height as u32,
+            femtovg::Color::rgba(0, 0, 0, 0),
         );
+
+        let mut path = Path::new();
+        path.rect(0.0, 0.0, window_width, window_height);
+        canvas.fill_path(
+            &path,
+            &Paint::image(frame_image, 0.0, 0.0, window_width, window_height, 0.0, 1.0),
+        );
+    }
+
+    // Tint the redrawn area of the window for debugging.
+    if cx.highlight_redraw && main_window {
+        let tint = dirty_rect
+            .unwrap_or_else(|| BoundingBox::from_min_max(0.0, 0.0, window_width, window_height));
+        let mut path = Path::new();
+        path.rect(tint.x, tint.y, tint.w, tint.h);
+        canvas.save();
+        canvas.reset_transform();
+        canvas.reset_scissor();
+        canvas.fill_path(&path, &Paint::color(femtovg::Color::rgba(255, 0, 255, 64)));
+        canvas.restore();
+    }
+
+    if let Some(overlay) = cx.draw_overlay.as_mut().filter(|_| main_window) {
+        canvas.save();
+        canvas.reset_transform();
+        canvas.reset_scissor();
+        (overlay)(canvas, (window_width, window_height));
         canvas.restore();
     }
 
     canvas.flush();
+
+    // Delete the copies of images which have been unloaded from the main window.
+    if let Some(images) = cx.cache.window_images.get_mut(&window) {
+        let resource_manager = &cx.resource_manager;
+        images.retain(|(id, _, copy)| {
+            let loaded = resource_manager
+                .images
+                .values()
+                .any(|image| matches!(image.image, ImageOrId::Id(main_id, ..) if main_id == *id));
+            if !loaded {
+                canvas.delete_image(*copy);
+            }
+
+            loaded
+        });
+    }
+}
+
+// Returns the frame image the window is drawn into with partial redraw, and whether it was created
+// for this frame, in which case it has no previous content. Returns `None` if the image could not be
+// created, in which case the whole window is drawn directly.
+fn frame_image(
+    cache: &mut CachedData,
+    canvas: &mut Canvas,
+    width: f32,
+    height: f32,
+) -> Option<(ImageId, bool)> {
+    let (width, height) = (width as usize, height as usize);
+
+    match cache.frame_image {
+        Some(image) if canvas.image_size(image).ok() == Some((width, height)) => {
+            Some((image, false))
+        }
+
+        image => {
+            if let Some(image) = image {
+                canvas.delete_image(image);
+            }
+
+            cache.frame_image = match canvas.create_image_empty(
+                width,
+                height,
+                PixelFormat::Rgba8,
+                ImageFlags::FLIP_Y | ImageFlags::PREMULTIPLIED,
+            ) {
+                Ok(image) => Some(image),
+                Err(err) => {
+                    eprintln!("Failed to create the frame image for partial redraw: {:?}", err);
+                    None
+                }
+            };
+
+            cache.frame_image.map(|image| (image, true))
+        }
+    }
+}
+
+// Returns the area of the window to redraw, which covers each of the given views and their
+// descendants both where they are now and where they were last drawn.
+fn redraw_region(cx: &mut DrawContext, entities: &[Entity]) -> BoundingBox {
+    let mut dirty_rect: Option<BoundingBox> = None;
+    for entity in entities.iter().copied() {
+        if cx.cache.bounds.get(entity).is_none() {
+            continue;
+        }
+
+        for descendant in entity.branch_iter(cx.tree) {
+            cx.current = descendant;
+            let mut region = cx.draw_region();
+            // A view which hasn't been drawn yet has nothing on the window to cover.
+            if let Some(previous) = cx.cache.redraw_regions.get(descendant) {
+                region = region.union(previous);
+            }
+
+            dirty_rect = Some(dirty_rect.map_or(region, |dirty_rect| dirty_rect.union(&region)));
+        }
+    }
+
+    cx.current = cx.window;
+
+    // Round out to whole pixels so that the edges of the redrawn area are not blended with the last
+    // frame. Nothing is redrawn if no view changed.
+    let window = cx.cache.get_bounds(cx.window);
+    dirty_rect.map_or(BoundingBox::from_min_max(0.0, 0.0, 0.0, 0.0), |dirty_rect| {
+        let left = dirty_rect.left().floor().max(window.left());
+        let top = dirty_rect.top().floor().max(window.top());
+        let right = dirty_rect.right().ceil().min(window.right()).max(left);
+        let bottom = dirty_rect.bottom().ceil().min(window.bottom()).max(top);
+        BoundingBox::from_min_max(left, top, right, bottom)
+    })
 }
 
 fn draw_entity(
     cx: &mut DrawContext,
     canvas: &mut Canvas,
     current_z: i32,
-    queue: &mut BinaryHeap<ZEntity>,
+    queue: &mut ZQueue<ZEntity>,
     visible: bool,
 ) {
     let current = cx.current;
 
-    // Skip views with display: none.
-    if cx.display() == Display::None {
+    // Skip views with display: none, and views which are only drawn as the mask of another view.
+    if cx.display() == Display::None
+        || (!cx.drawing_mask && cx.style.mask_sources.get(current).is_some())
+    {
         return;
     }
 
-    // TODO: Looks like I'll need to keep track of the current transform manually instead of within femtovg
-    // because elements with a higher z-index aren't getting the transform of their parent.
     let z_index = cx.tree.z_index(current);
     if z_index > current_z {
-        queue.push(ZEntity { index: z_index, entity: current, opacity: cx.opacity, visible });
+        queue.push(z_index, ZEntity { entity: current, opacity: cx.opacity, visible });
         return;
     }
 
     canvas.save();
 
-    canvas.set_transform(&cx.transform());
+    // The cached transform includes the transforms of the ancestors of the view, which are not on the
+    // canvas for views drawn from the queue.
+    canvas.reset_transform();
+    canvas.set_transform(&cx.cache.get_transform(current));
 
     let clip_region = cx.clip_region();
 
@@ -81,51 +312,374 @@ fn draw_entity(
         (_, Some(Visibility::Visible)) => true,
     };
 
-    // Draw the view
-    if is_visible {
+    // A view with a shader effect, a mask, a rounded clip path, or a filter is drawn, along with its
+    // children, into an offscreen layer.
+    let target = cx.render_target;
+    let effect = cx.style.shader_effect.get(current).cloned();
+    let has_mask_image = cx.style.mask_image.get(current).is_some();
+    let mask_view = cx.style.mask_view.get(current).copied();
+    let has_mask = has_mask_image || mask_view.is_some();
+    let has_rounded_clip = matches!(cx.clip_shape(), Some((_, radius)) if radius > 0.0);
+    let drop_shadow = match cx.style.filter.get(current) {
+        Some(filter @ Filter::DropShadow { .. }) => Some(filter.clone()),
+        _ => None,
+    };
+    let blur_radius = match cx.style.filter.get(current) {
+        Some(Filter::Blur(radius)) => {
+            Some(cx.logical_to_physical(radius.to_px().unwrap_or_default()))
+                .filter(|radius| *radius > 0.0)
+        }
+        _ => None,
+    };
+    let layer = if is_visible
+        && (effect.is_some()
+            || has_mask
+            || has_rounded_clip
+            || drop_shadow.is_some()
+            || blur_radius.is_some())
+    {
+        begin_layer(cx, canvas)
+    } else {
+        // Allocate the layer of a view which is about to be masked ahead of time.
+        if is_visible && cx.style.will_change.get(current) == Some(&WillChange::MaskImage) {
+            layer_images(cx, canvas);
+        }
+
+        None
+    };
+
+    // Draw the view, unless only part of the window is redrawn and the view is outside of it. The area
+    // it is drawn in is recorded, as it needs to be covered when the view is next partially redrawn.
+    let draw_region = cx.draw_region();
+    let dirty = cx.dirty_rect.map_or(true, |dirty_rect| draw_region.intersects(&dirty_rect));
+    if is_visible && dirty {
         if let Some(view) = cx.views.remove(&current) {
             view.draw(cx, canvas);
             cx.views.insert(current, view);
         }
+
+        cx.cache.redraw_regions.insert(current, draw_region);
     }
 
-    let child_iter = LayoutChildIterator::new(cx.tree, cx.current);
+    let children = paint_ordered_children(cx.tree, cx.style, cx.current);
 
     let parent_opacity = cx.opacity();
     // Draw its children
-    for child in child_iter {
+    for child in children {
         cx.current = child;
         let opactiy = cx.style.opacity.get(child).copied().unwrap_or(Opacity(1.0)).0;
         cx.opacity = parent_opacity * opactiy;
+        // A view which escapes the clipping of its ancestors is deferred to the queue, from which it is
+        // drawn without their scissor, on top of the other views with the same z-index.
+        if cx.tree.escape_clip(child) {
+            let z_index = cx.tree.z_index(child).max(current_z);
+            queue
+                .push(z_index, ZEntity { entity: child, opacity: cx.opacity, visible: is_visible });
+            continue;
+        }
         // TODO: Skip views with zero-sized bounding boxes here? Or let user decide if they want to skip?
         draw_entity(cx, canvas, current_z, queue, is_visible);
     }
 
-    canvas.restore();
     cx.current = current;
-}
+    cx.render_target = target;
 
-struct ZEntity {
-    pub index: i32,
-    pub entity: Entity,
-    pub opacity: f32,
-    pub visible: bool,
+    if let Some((input, output)) = layer {
+        // The mask is applied to the layer before any effect, with the transform of the view.
+        if has_mask_image {
+            cx.draw_mask(canvas);
+        }
+
+        if let Some(mask_view) = mask_view {
+            draw_mask_view(cx, canvas, mask_view, input, output);
+        }
+
+        if has_rounded_clip {
+            cx.draw_clip_path(canvas);
+        }
+
+        let window_width = cx.cache.get_width(cx.window);
+        let window_height = cx.cache.get_height(cx.window);
+
+        canvas.save();
+        canvas.reset_transform();
+        canvas.reset_scissor();
+
+        let result = if let Some(effect) = effect {
+            canvas.set_render_target(RenderTarget::Image(output));
+            canvas.clear_rect(
+                0,
+                0,
+                window_width as u32,
+                window_height as u32,
+                femtovg::Color::rgba(0, 0, 0, 0),
+            );
+            // A fragment shader is run directly on the layer textures when the window supports it.
+            let bounds = cx.bounds();
+            let shaded = match (effect.fragment_shader(), cx.shader_context.as_deref_mut()) {
+                (Some(shader), Some(shader_context)) => {
+                    shader_context.run(canvas, shader, input, output, bounds)
+                }
+                _ => false,
+            };
+
+            if !shaded {
+                effect.apply(canvas, input, output, bounds);
+            }
+
+            output
+        } else {
+            input
+        };
+
+        // The blurred layer is drawn into whichever layer image does not hold the result.
+        let result = if let Some(blur_radius) = blur_radius {
+            let blurred = if result == input { output } else { input };
+            canvas.set_render_target(RenderTarget::Image(blurred));
+            canvas.clear_rect(
+                0,
+                0,
+                window_width as u32,
+                window_height as u32,
+                femtovg::Color::rgba(0, 0, 0, 0),
+            );
+            canvas.filter_image(
+                blurred,
+                femtovg::ImageFilter::GaussianBlur { sigma: blur_radius / 2.0 },
+                result,
+            );
+
+            blurred
+        } else {
+            result
+        };
+
+        // The shadow is drawn into whichever layer image does not hold the result.
+        let shadow = drop_shadow.map(|drop_shadow| {
+            let shadow_image = if result == input { output } else { input };
+            draw_drop_shadow(cx, canvas, &drop_shadow, result, shadow_image)
+        });
+
+        // Draw the processed layer in place of the view.
+        canvas.set_render_target(target);
+        if let Some(dirty_rect) = cx.dirty_rect {
+            canvas.scissor(dirty_rect.x, dirty_rect.y, dirty_rect.w, dirty_rect.h);
+        }
+
+        if let Some((shadow_image, x, y)) = shadow {
+            let mut path = Path::new();
+            path.rect(x, y, window_width, window_height);
+            canvas.fill_path(
+                &path,
+                &Paint::image(shadow_image, x, y, window_width, window_height, 0.0, 1.0),
+            );
+        }
+
+        let mut path = Path::new();
+        path.rect(0.0, 0.0, window_width, window_height);
+        canvas.fill_path(
+            &path,
+            &Paint::image(result, 0.0, 0.0, window_width, window_height, 0.0, 1.0),
+        );
+
+        canvas.restore();
+    }
+
+    canvas.restore();
 }
 
-impl Ord for ZEntity {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.index.cmp(&self.index)
+// Draws the view which masks the current view, along with its descendants, into the mask image, and
+// multiplies the layer of the current view by its alpha. The mask image is free to be used, as a
+// shader effect only writes to it after the mask is applied.
+fn draw_mask_view(
+    cx: &mut DrawContext,
+    canvas: &mut Canvas,
+    mask_view: Entity,
+    layer: ImageId,
+    mask_image: ImageId,
+) {
+    if cx.cache.bounds.get(mask_view).is_none() {
+        return;
     }
+
+    let window_width = cx.cache.get_width(cx.window);
+    let window_height = cx.cache.get_height(cx.window);
+
+    let current = cx.current;
+    let opacity = cx.opacity;
+    let target = cx.render_target;
+    let dirty_rect = cx.dirty_rect.take();
+
+    canvas.save();
+    canvas.reset_transform();
+    canvas.reset_scissor();
+
+    cx.render_target = RenderTarget::Image(mask_image);
+    canvas.set_render_target(cx.render_target);
+    canvas.clear_rect(
+        0,
+        0,
+        window_width as u32,
+        window_height as u32,
+        femtovg::Color::rgba(0, 0, 0, 0),
+    );
+
+    // The mask view is drawn on its own, including any of its descendants with a higher z-index.
+    cx.drawing_mask = true;
+    let mut queue = ZQueue::new();
+    let mask_opacity = cx.style.opacity.get(mask_view).copied().unwrap_or(Opacity(1.0)).0;
+    queue.push(
+        cx.tree.z_index(mask_view),
+        ZEntity { entity: mask_view, opacity: mask_opacity, visible: true },
+    );
+    while let Some((z_index, zentity)) = queue.pop() {
+        cx.current = zentity.entity;
+        cx.opacity = zentity.opacity;
+        draw_entity(cx, canvas, z_index, &mut queue, zentity.visible);
+    }
+    cx.drawing_mask = false;
+
+    cx.current = current;
+    cx.opacity = opacity;
+    cx.render_target = target;
+    cx.dirty_rect = dirty_rect;
+
+    // Keep the layer only where the mask view was drawn.
+    canvas.set_render_target(RenderTarget::Image(layer));
+    canvas.global_composite_operation(CompositeOperation::DestinationIn);
+    let mut path = Path::new();
+    path.rect(0.0, 0.0, window_width, window_height);
+    canvas.fill_path(
+        &path,
+        &Paint::image(mask_image, 0.0, 0.0, window_width, window_height, 0.0, 1.0),
+    );
+
+    canvas.restore();
 }
-impl PartialOrd for ZEntity {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+
+// Draws the shadow cast by the alpha channel of the source layer into the shadow image, returning the
+// shadow image and the offset to draw it at.
+fn draw_drop_shadow(
+    cx: &mut DrawContext,
+    canvas: &mut Canvas,
+    drop_shadow: &Filter,
+    source: ImageId,
+    shadow_image: ImageId,
+) -> (ImageId, f32, f32) {
+    let (x_offset, y_offset, blur_radius, color) = match drop_shadow {
+        Filter::DropShadow { x_offset, y_offset, blur_radius, color } => {
+            (x_offset, y_offset, blur_radius, color)
+        }
+        _ => unreachable!(),
+    };
+
+    let window_width = cx.cache.get_width(cx.window);
+    let window_height = cx.cache.get_height(cx.window);
+
+    let x = cx.logical_to_physical(x_offset.to_px().unwrap_or_default());
+    let y = cx.logical_to_physical(y_offset.to_px().unwrap_or_default());
+    let blur_radius = cx.logical_to_physical(
+        blur_radius.as_ref().and_then(|radius| radius.to_px()).unwrap_or_default(),
+    );
+    let color = color.unwrap_or(Color::rgb(0, 0, 0));
+    let color =
+        Color::rgba(color.r(), color.g(), color.b(), (color.a() as f32 * cx.opacity()) as u8);
+
+    canvas.set_render_target(RenderTarget::Image(shadow_image));
+    canvas.clear_rect(
+        0,
+        0,
+        window_width as u32,
+        window_height as u32,
+        femtovg::Color::rgba(0, 0, 0, 0),
+    );
+
+    let mut path = Path::new();
+    path.rect(0.0, 0.0, window_width, window_height);
+
+    if blur_radius > 0.0 {
+        canvas.filter_image(
+            shadow_image,
+            femtovg::ImageFilter::GaussianBlur { sigma: blur_radius / 2.0 },
+            source,
+        );
+    } else {
+        canvas.fill_path(
+            &path,
+            &Paint::image(source, 0.0, 0.0, window_width, window_height, 0.0, 1.0),
+        );
     }
+
+    // Replace the color of the content with the shadow color, keeping its alpha.
+    canvas.global_composite_operation(CompositeOperation::SourceIn);
+    canvas.fill_path(&path, &Paint::color(color.into()));
+    canvas.global_composite_operation(CompositeOperation::SourceOver);
+
+    (shadow_image, x, y)
 }
-impl PartialEq for ZEntity {
-    fn eq(&self, other: &Self) -> bool {
-        self.index == other.index
+
+// Sets the render target of the canvas to a cleared layer image the size of the window, returning
+// the layer image and the image which a shader effect processes it into, or `None` if the images
+// could not be created.
+fn begin_layer(cx: &mut DrawContext, canvas: &mut Canvas) -> Option<(ImageId, ImageId)> {
+    let (input, output) = layer_images(cx, canvas)?;
+
+    cx.render_target = RenderTarget::Image(input);
+    canvas.set_render_target(cx.render_target);
+
+    let width = cx.cache.get_width(cx.window) as u32;
+    let height = cx.cache.get_height(cx.window) as u32;
+    canvas.clear_rect(0, 0, width, height, femtovg::Color::rgba(0, 0, 0, 0));
+
+    Some((input, output))
+}
+
+// Returns the cached layer images of the current view, creating them if they don't exist or are not
+// the size of the window. Returns `None` if the window has no area or the images could not be
+// created, in which case the view is drawn without a layer.
+fn layer_images(cx: &mut DrawContext, canvas: &mut Canvas) -> Option<(ImageId, ImageId)> {
+    let width = cx.cache.get_width(cx.window) as usize;
+    let height = cx.cache.get_height(cx.window) as usize;
+
+    if let Some((input, output)) = cx.cache.effect_images.get(cx.current).cloned() {
+        if canvas.image_size(input).ok() == Some((width, height)) {
+            return Some((input, output));
+        }
+
+        canvas.delete_image(input);
+        canvas.delete_image(output);
+        cx.cache.effect_images.remove(cx.current);
+    }
+
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let flags = ImageFlags::FLIP_Y | ImageFlags::PREMULTIPLIED;
+    let input = canvas.create_image_empty(width, height, PixelFormat::Rgba8, flags);
+    let output = canvas.create_image_empty(width, height, PixelFormat::Rgba8, flags);
+    match (input, output) {
+        (Ok(input), Ok(output)) => {
+            cx.cache.effect_images.insert(cx.current, (input, output));
+            Some((input, output))
+        }
+
+        (input, output) => {
+            for image in [&input, &output].into_iter().flatten() {
+                canvas.delete_image(*image);
+            }
+
+            if let Some(err) = input.err().or_else(|| output.err()) {
+                log::warn!("Failed to create layer images for {:?}: {:?}", cx.current, err);
+            }
+
+            None
+        }
     }
 }
 
-impl Eq for ZEntity {}
+struct ZEntity {
+    pub entity: Entity,
+    pub opacity: f32,
+    pub visible: bool,
+}
@@ -0,0 +1,329 @@
+use morphorm::{LayoutType, Units};
+use vizia_storage::LayoutChildIterator;
+
+use crate::cache::{CachedData, Pos};
+use crate::prelude::*;
+use crate::style::Style;
+
+// A child of a row or column view, with its size and the space around it along the main and cross
+// axes.
+struct FlexItem {
+    entity: Entity,
+    main_before: Option<f32>,
+    main_size: f32,
+    main_after: f32,
+    cross_before: f32,
+    cross_size: f32,
+    // Whether the child has no space of its own along the cross axis, and so can be aligned by its
+    // parent.
+    cross_auto: bool,
+}
+
+// A line of children, with the main and cross axis position of each child, and the cross axis
+// position and size of the line.
+#[derive(Default)]
+struct FlexLine {
+    items: Vec<(usize, f32, f32)>,
+    cross_start: f32,
+    cross_size: f32,
+}
+
+// Moves the children of views with `FlexWrap::Wrap` onto multiple lines, reorders children with an
+// `order`, and aligns the children of views with a `JustifyContent` or `AlignItems` after layout.
+// The cross axis size of the lines is recorded for wrapping views with an auto cross axis size.
+// Returns whether any recorded size changed, in which case layout must be performed again so that
+// the views take up their new size.
+pub(crate) fn flex_system(
+    cache: &mut CachedData,
+    tree: &Tree<Entity>,
+    style: &mut Style,
+    window: Entity,
+) -> bool {
+    let mut changed = false;
+
+    // Only the views of the given window are laid out with its scale factor.
+    for entity in tree.into_iter().filter(|entity| tree.window(*entity) == window) {
+        let wrap = style.flex_wrap.get(entity).copied().unwrap_or_default() == FlexWrap::Wrap;
+        let aligned =
+            style.justify_content.get(entity).is_some() || style.align_items.get(entity).is_some();
+        let reordered =
+            LayoutChildIterator::new(tree, entity).any(|child| style.order.get(child).is_some());
+
+        let wrap_size = if wrap || aligned || reordered {
+            layout_lines(cache, tree, style, entity, wrap)
+        } else {
+            None
+        };
+
+        if style.wrap_size.get(entity).copied() != wrap_size {
+            if let Some(size) = wrap_size {
+                style.wrap_size.insert(entity, size);
+            } else {
+                style.wrap_size.remove(entity);
+            }
+
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+// Positions the children of a row or column view on lines and returns the cross axis size of the
+// view needed to fit the lines, if the view has an auto cross axis size and the children are
+// wrapped onto more than one line.
+fn layout_lines(
+    cache: &mut CachedData,
+    tree: &Tree<Entity>,
+    style: &Style,
+    entity: Entity,
+    wrap: bool,
+) -> Option<f32> {
+    let layout_type = style.layout_type.get(entity).cloned().unwrap_or_default();
+    if layout_type != LayoutType::Row && layout_type != LayoutType::Column {
+        return None;
+    }
+
+    let row = layout_type == LayoutType::Row;
+
+    let pixels = |units: Option<&Units>| match units {
+        Some(Units::Pixels(val)) => Some(style.logical_to_physical(*val)),
+        _ => None,
+    };
+
+    // Matches the border widths given to the layout system.
+    let border = |border_width: Option<&LengthOrPercentage>| match border_width {
+        Some(LengthOrPercentage::Length(val)) => val.to_px().unwrap_or_default(),
+        _ => 0.0,
+    };
+
+    let bounds = cache.get_bounds(entity);
+    let (child_left, child_right, child_top, child_bottom) = (
+        pixels(style.child_left.get(entity)).unwrap_or_default()
+            + border(style.border_left_width.get(entity)),
+        pixels(style.child_right.get(entity)).unwrap_or_default()
+            + border(style.border_right_width.get(entity)),
+        pixels(style.child_top.get(entity)).unwrap_or_default()
+            + border(style.border_top_width.get(entity)),
+        pixels(style.child_bottom.get(entity)).unwrap_or_default()
+            + border(style.border_bottom_width.get(entity)),
+    );
+
+    let col_between = pixels(style.col_between.get(entity)).unwrap_or_default();
+    let row_between = pixels(style.row_between.get(entity)).unwrap_or_default();
+
+    let (main_start, main_end, cross_start, cross_end, main_gap, cross_gap) = if row {
+        (child_left, bounds.w - child_right, child_top, child_bottom, col_between, row_between)
+    } else {
+        (child_top, bounds.h - child_bottom, child_left, child_right, row_between, col_between)
+    };
+
+    let mut items = LayoutChildIterator::new(tree, entity)
+        .filter(|child| style.display.get(*child).copied().unwrap_or_default() != Display::None)
+        .filter(|child| {
+            style.position_type.get(*child).cloned().unwrap_or_default()
+                != PositionType::SelfDirected
+        })
+        .map(|child| {
+            let child_bounds = cache.get_bounds(child);
+            let (left, right) = style.horizontal_space(child);
+            let (top, bottom) = (style.top.get(child), style.bottom.get(child));
+
+            let (main_before, main_after, cross_before, cross_after, main_size, cross_size) = if row
+            {
+                (left, right, top, bottom, child_bounds.w, child_bounds.h)
+            } else {
+                (top, bottom, left, right, child_bounds.h, child_bounds.w)
+            };
+
+            let auto = |units: Option<&Units>| matches!(units, None | Some(Units::Auto));
+
+            FlexItem {
+                entity: child,
+                main_before: pixels(main_before),
+                main_size,
+                main_after: pixels(main_after).unwrap_or_default(),
+                cross_before: pixels(cross_before).unwrap_or_default(),
+                cross_size: pixels(cross_before).unwrap_or_default()
+                    + cross_size
+                    + pixels(cross_after).unwrap_or_default(),
+                cross_auto: auto(cross_before) && auto(cross_after),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        return None;
+    }
+
+    // The sort is stable, so children with the same order stay in tree order.
+    let reordered = items.iter().any(|item| style.order.get(item.entity).is_some());
+    if reordered {
+        items.sort_by_key(|item| style.order.get(item.entity).copied().unwrap_or_default());
+    }
+
+    // Break the children into lines. A child which is not given its own space before it is
+    // separated from the previous child on its line by the gap.
+    let mut lines = vec![FlexLine::default()];
+    if wrap {
+        let mut cursor = main_start;
+        for (index, item) in items.iter().enumerate() {
+            let first = lines.last().unwrap().items.is_empty();
+            let mut before = item.main_before.unwrap_or(if first { 0.0 } else { main_gap });
+            if !first && cursor + before + item.main_size + item.main_after > main_end {
+                lines.push(FlexLine::default());
+                cursor = main_start;
+                before = item.main_before.unwrap_or_default();
+            }
+
+            let line = lines.last_mut().unwrap();
+            line.items.push((index, cursor + before, item.cross_before));
+            line.cross_size = line.cross_size.max(item.cross_size);
+            cursor += before + item.main_size + item.main_after;
+        }
+    }
+
+    let cross_total = if row { bounds.h } else { bounds.w };
+    let mut wrap_size = None;
+
+    if lines.len() < 2 {
+        // Children on one line keep the position given to them by the layout system, and the line
+        // takes up the whole cross axis of the view.
+        let line = &mut lines[0];
+        line.items = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let position =
+                    cache.relative_position.get(item.entity).copied().unwrap_or_default();
+                if row {
+                    (index, position.x, position.y)
+                } else {
+                    (index, position.y, position.x)
+                }
+            })
+            .collect();
+        line.cross_start = cross_start;
+        line.cross_size = (cross_total - cross_start - cross_end).max(0.0);
+
+        // Reordered children are packed in their new order from the start of the line.
+        if reordered {
+            let mut cursor = line
+                .items
+                .iter()
+                .map(|(index, main_position, _)| {
+                    main_position - items[*index].main_before.unwrap_or_default()
+                })
+                .fold(f32::MAX, f32::min);
+
+            for (position, (index, main_position, _)) in line.items.iter_mut().enumerate() {
+                let item = &items[*index];
+                let before = item.main_before.unwrap_or(if position == 0 { 0.0 } else { main_gap });
+                *main_position = cursor + before;
+                cursor += before + item.main_size + item.main_after;
+            }
+        }
+    } else {
+        let content_size = lines.iter().map(|line| line.cross_size).sum::<f32>()
+            + cross_gap * (lines.len() - 1) as f32;
+
+        let cross_units = if row { style.height.get(entity) } else { style.width.get(entity) };
+        if cross_units == Some(&Units::Auto) {
+            wrap_size = Some(cross_start + content_size + cross_end);
+        }
+
+        // Align the lines within the cross axis of the view.
+        let free_space =
+            (wrap_size.unwrap_or(cross_total) - cross_start - cross_end - content_size).max(0.0);
+        let count = lines.len() as f32;
+        let (offset, spacing) = match style.align_content.get(entity).copied().unwrap_or_default() {
+            AlignContent::Start => (0.0, 0.0),
+            AlignContent::Center => (free_space / 2.0, 0.0),
+            AlignContent::End => (free_space, 0.0),
+            AlignContent::SpaceBetween => (0.0, free_space / (count - 1.0)),
+            AlignContent::SpaceAround => (free_space / count / 2.0, free_space / count),
+        };
+
+        let mut line_start = cross_start + offset;
+        for line in lines.iter_mut() {
+            line.cross_start = line_start;
+            for (_, _, cross_position) in line.items.iter_mut() {
+                *cross_position += line_start;
+            }
+
+            line_start += line.cross_size + cross_gap + spacing;
+        }
+    }
+
+    let justify_content = style.justify_content.get(entity).copied();
+    let align_items = style.align_items.get(entity).copied();
+
+    for line in lines.iter_mut() {
+        if let Some(justify_content) = justify_content {
+            justify_line(line, &items, justify_content, main_start, main_end);
+        }
+
+        if let Some(align_items) = align_items {
+            for (index, _, cross_position) in line.items.iter_mut() {
+                let item = &items[*index];
+                if item.cross_auto {
+                    let free_space = line.cross_size - item.cross_size;
+                    *cross_position = line.cross_start
+                        + match align_items {
+                            AlignItems::Start => 0.0,
+                            AlignItems::Center => free_space / 2.0,
+                            AlignItems::End => free_space,
+                        };
+                }
+            }
+        }
+
+        for (index, main_position, cross_position) in line.items.iter().copied() {
+            if let Some(relative_position) = cache.relative_position.get_mut(items[index].entity) {
+                *relative_position = if row {
+                    Pos { x: main_position, y: cross_position }
+                } else {
+                    Pos { x: cross_position, y: main_position }
+                };
+            }
+        }
+    }
+
+    wrap_size
+}
+
+// Distributes the free space along the main axis of a line. The line is first moved to the start of
+// the main axis, so any space between the children, such as the gap, is kept and added to.
+fn justify_line(
+    line: &mut FlexLine,
+    items: &[FlexItem],
+    justify_content: JustifyContent,
+    main_start: f32,
+    main_end: f32,
+) {
+    let (first, first_position, _) = line.items[0];
+    let shift = main_start - (first_position - items[first].main_before.unwrap_or_default());
+
+    let line_end = line
+        .items
+        .iter()
+        .map(|(index, main_position, _)| {
+            main_position + items[*index].main_size + items[*index].main_after
+        })
+        .fold(f32::MIN, f32::max);
+
+    let free_space = (main_end - line_end - shift).max(0.0);
+    let count = line.items.len() as f32;
+    let (offset, spacing) = match justify_content {
+        JustifyContent::Start => (0.0, 0.0),
+        JustifyContent::Center => (free_space / 2.0, 0.0),
+        JustifyContent::End => (free_space, 0.0),
+        JustifyContent::SpaceBetween if count > 1.0 => (0.0, free_space / (count - 1.0)),
+        JustifyContent::SpaceBetween => (0.0, 0.0),
+        JustifyContent::SpaceAround => (free_space / count / 2.0, free_space / count),
+    };
+
+    for (position, (_, main_position, _)) in line.items.iter_mut().enumerate() {
+        *main_position += shift + offset + spacing * position as f32;
+    }
+}
@@ -1,34 +1,26 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
-
+use super::{paint_ordered_children, ZQueue};
 use crate::{
     prelude::*,
     style::{Abilities, PseudoClassFlags},
 };
-use femtovg::Transform2D;
-use vizia_id::GenerationalId;
-use vizia_storage::LayoutChildIterator;
 
 // Determines the hovered entity based on the mouse cursor position.
 pub(crate) fn hover_system(cx: &mut Context) {
-    let mut queue = BinaryHeap::new();
-    queue.push(ZEntity { index: 0, entity: Entity::root() });
-    let mut hovered = Entity::root();
-    let transform = Transform2D::identity();
-    // let clip_bounds = cx.cache.get_bounds(Entity::root());
-    let clip_bounds: BoundingBox =
-        BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
-    while !queue.is_empty() {
-        let zentity = queue.pop().unwrap();
-        cx.with_current(zentity.entity, |cx| {
-            hover_entity(
-                &mut EventContext::new(cx),
-                zentity.index,
-                &mut queue,
-                &mut hovered,
-                transform,
-                &clip_bounds,
-            );
-        });
+    // Only the views in the window under the cursor are hit-tested.
+    let window = cx.cursor_window;
+    let (cursorx, cursory) = (cx.mouse.cursorx, cx.mouse.cursory);
+    let hit_tested = hit_test(&mut EventContext::new(cx), window, cursorx, cursory);
+
+    // The last view to pass the hit-test is the topmost one on screen.
+    let mut hovered = window;
+    for (entity, hit) in hit_tested {
+        if hit {
+            hovered = entity;
+        }
+
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(entity) {
+            pseudo_classes.set(PseudoClassFlags::OVER, hit);
+        }
     }
 
     if hovered != cx.hovered {
@@ -61,27 +53,90 @@ pub(crate) fn hover_system(cx: &mut Context) {
             pseudo_classes.set(PseudoClassFlags::HOVER, false);
         }
 
-        // Send mouse enter/leave events directly to entity.
-        cx.event_queue.push_back(Event::new(WindowEvent::MouseEnter).direct(hovered));
+        // Send mouse leave/enter events directly to entity. The previously hovered view is always
+        // left before the newly hovered view is entered.
         cx.event_queue.push_back(Event::new(WindowEvent::MouseLeave).direct(cx.hovered));
+        cx.event_queue.push_back(Event::new(WindowEvent::MouseEnter).direct(hovered));
 
-        // Send mouse over/out events to entity and ancestors.
-        cx.event_queue.push_back(Event::new(WindowEvent::MouseOver).target(hovered));
+        // Send mouse out/over events to entity and ancestors.
         cx.event_queue.push_back(Event::new(WindowEvent::MouseOut).target(cx.hovered));
+        cx.event_queue.push_back(Event::new(WindowEvent::MouseOver).target(hovered));
 
         cx.hovered = hovered;
 
         cx.style.needs_restyle();
     }
+
+    link_hover_system(cx);
+}
+
+// Determines the link in the text of the hovered view which is under the mouse cursor, and shows a
+// hand cursor while over a link.
+fn link_hover_system(cx: &mut Context) {
+    let hovered = cx.hovered;
+    let link = if cx.text_context.has_links(hovered) {
+        cx.text_context
+            .link_at(hovered, cx.mouse.cursorx, cx.mouse.cursory)
+            .map(|url| (hovered, url))
+    } else {
+        None
+    };
+
+    let link_entity = link.as_ref().map(|(entity, _)| *entity);
+    let previous_entity = cx.hovered_link.as_ref().map(|(entity, _)| *entity);
+    if link_entity != previous_entity && !cx.cursor_icon_locked {
+        let cursor = if link.is_some() {
+            CursorIcon::Hand.into()
+        } else {
+            cx.style.cursor.get(hovered).cloned().unwrap_or_default()
+        };
+        cx.emit(WindowEvent::SetCursor(cursor));
+    }
+
+    cx.hovered_link = link;
+}
+
+// Hit-tests the views of a window against a point, returning each of the views visited along with
+// whether the point is within it. Views are visited in the same order as they are drawn, so the
+// topmost view under the point is the last to pass the hit-test.
+pub(crate) fn hit_test(
+    cx: &mut EventContext,
+    window: Entity,
+    x: f32,
+    y: f32,
+) -> Vec<(Entity, bool)> {
+    let current = cx.current;
+    let mut queue = ZQueue::new();
+    queue.push(0, window);
+    let mut hit_tested = Vec::new();
+    let clip_bounds: BoundingBox =
+        BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
+    while let Some((z_index, entity)) = queue.pop() {
+        cx.current = entity;
+        hit_test_entity(
+            cx,
+            (x, y),
+            z_index,
+            &mut queue,
+            &mut hit_tested,
+            &clip_bounds,
+            &mut Vec::new(),
+        );
+    }
+
+    cx.current = current;
+
+    hit_tested
 }
 
-fn hover_entity(
+fn hit_test_entity(
     cx: &mut EventContext,
+    point: (f32, f32),
     current_z: i32,
-    queue: &mut BinaryHeap<ZEntity>,
-    hovered: &mut Entity,
-    parent_transform: Transform2D,
+    queue: &mut ZQueue<Entity>,
+    hit_tested: &mut Vec<(Entity, bool)>,
     clip_bounds: &BoundingBox,
+    rounded_clips: &mut Vec<(BoundingBox, f32)>,
 ) {
     // Skip if non-hoverable (will skip any descendants)
     let hoverable = cx
@@ -104,81 +159,71 @@ fn hover_entity(
     // Push to queue if the z-index is higher than the current z-index.
     let z_index = cx.tree.z_index(cx.current);
     if z_index > current_z {
-        queue.push(ZEntity { index: z_index, entity: cx.current });
+        queue.push(z_index, cx.current);
         return;
     }
 
     let bounds = cx.bounds();
 
-    let cursorx = cx.mouse.cursorx;
-    let cursory = cx.mouse.cursory;
-
-    let mut transform = parent_transform;
-
-    transform.premultiply(&cx.transform());
-
-    let mut t = transform;
+    // The point is hit-tested in the untransformed space of the view.
+    let mut t = cx.cache.get_transform(cx.current);
     t.inverse();
-    let (tx, ty) = t.transform_point(cursorx, cursory);
+    let (tx, ty) = t.transform_point(point.0, point.1);
 
     let clipping = clip_bounds.intersection(&cx.clip_region());
 
-    let b = bounds.intersection(&clipping);
-
-    if tx >= b.left() && tx < b.right() && ty >= b.top() && ty < b.bottom() {
-        *hovered = cx.current;
-
-        if !cx
-            .style
-            .pseudo_classes
-            .get(cx.current)
-            .copied()
-            .unwrap_or_default()
-            .contains(PseudoClassFlags::OVER)
-        {
-            if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
-                pseudo_class.set(PseudoClassFlags::OVER, true);
-            }
-        }
-    } else if cx
-        .style
-        .pseudo_classes
-        .get(cx.current)
-        .copied()
-        .unwrap_or_default()
-        .contains(PseudoClassFlags::OVER)
-    {
-        if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
-            pseudo_class.set(PseudoClassFlags::OVER, false);
-        }
+    // An inset hit area is only clipped by the ancestors of the view so that a negative inset can
+    // extend the hit area beyond the bounds of the view itself.
+    let b = if let Some(inset) = cx.style.hit_area_inset.get(cx.current) {
+        let inset = inset.to_pixels(bounds.w.min(bounds.h), cx.scale_factor());
+        bounds.shrink(inset).intersection(clip_bounds)
+    } else {
+        bounds.intersection(&clipping)
+    };
+
+    // The corners of rounded clip paths are cut out of the rectangular clip bounds. Like the clip
+    // bounds, the rounded clip path of the view itself doesn't clip an inset hit area.
+    let rounded_clip = cx.clip_shape().filter(|(_, radius)| *radius > 0.0);
+    let in_own_clip = cx.style.hit_area_inset.get(cx.current).is_some()
+        || rounded_clip.map_or(true, |(shape, radius)| in_rounded_rect(&shape, radius, tx, ty));
+
+    let hit = tx >= b.left()
+        && tx < b.right()
+        && ty >= b.top()
+        && ty < b.bottom()
+        && in_own_clip
+        && rounded_clips.iter().all(|(shape, radius)| in_rounded_rect(shape, *radius, tx, ty));
+    hit_tested.push((cx.current, hit));
+
+    if let Some(rounded_clip) = rounded_clip {
+        rounded_clips.push(rounded_clip);
     }
 
-    let child_iter = LayoutChildIterator::new(cx.tree, cx.current);
-    for child in child_iter {
+    let children = paint_ordered_children(cx.tree, cx.style, cx.current);
+    for child in children {
+        // A view which escapes the clipping of its ancestors is hit-tested from the queue, in the same
+        // order as it is drawn.
+        if cx.tree.escape_clip(child) {
+            queue.push(cx.tree.z_index(child).max(current_z), child);
+            continue;
+        }
         cx.current = child;
-        hover_entity(cx, current_z, queue, hovered, transform, &clipping);
+        hit_test_entity(cx, point, current_z, queue, hit_tested, &clipping, rounded_clips);
     }
-}
-
-struct ZEntity {
-    pub index: i32,
-    pub entity: Entity,
-}
 
-impl Ord for ZEntity {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.index.cmp(&self.index)
+    if rounded_clip.is_some() {
+        rounded_clips.pop();
     }
 }
-impl PartialOrd for ZEntity {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl PartialEq for ZEntity {
-    fn eq(&self, other: &Self) -> bool {
-        self.index == other.index
+
+// Returns whether a point is within a rectangle with corners rounded by the given radius.
+fn in_rounded_rect(rect: &BoundingBox, radius: f32, x: f32, y: f32) -> bool {
+    if x < rect.left() || x >= rect.right() || y < rect.top() || y >= rect.bottom() {
+        return false;
     }
-}
 
-impl Eq for ZEntity {}
+    let radius = radius.min(rect.w / 2.0).min(rect.h / 2.0);
+    let corner_x = x.max(rect.left() + radius).min(rect.right() - radius);
+    let corner_y = y.max(rect.top() + radius).min(rect.bottom() - radius);
+    (x - corner_x).powi(2) + (y - corner_y).powi(2) <= radius * radius
+}
@@ -13,8 +13,37 @@ pub(crate) fn image_system(cx: &mut Context) {
 
     // Iterate the tree and load any defined images that aren't already loaded
     for entity in cx.tree.into_iter() {
+        // Load any mask images of the entity
+        if let Some(mask_images) = cx.style.mask_image.get(entity).cloned() {
+            for image in mask_images.iter() {
+                if let ImageOrGradient::Image(name) = image {
+                    load_image(cx, entity, name);
+                }
+            }
+        }
+
         // Load a background-image if the entity has one
         if let Some(background_images) = cx.style.background_image.get(entity).cloned() {
+            // Lazy images are only loaded when the entity is near the viewport.
+            if let Some(unload) = cx.cache.lazy_images.get(entity).copied() {
+                let near = cx.cache.viewport_observers.get(entity).map_or(true, |o| o.visible);
+                if !near {
+                    for image in background_images.iter() {
+                        if let ImageOrGradient::Image(name) = image {
+                            if unload {
+                                unload_image(cx, entity, name);
+                            } else if let Some(image_store) =
+                                cx.resource_manager.images.get_mut(name.as_str())
+                            {
+                                image_store.used = true;
+                            }
+                        }
+                    }
+
+                    continue;
+                }
+            }
+
             for image in background_images.iter() {
                 match image {
                     ImageOrGradient::Image(name) => {
@@ -43,12 +72,38 @@ fn load_image(cx: &mut ResourceContext, entity: Entity, image_name: &str) {
     }
 }
 
+// Removes the entity as an observer of an image and unloads the image from the canvas if it has no
+// other observers. The decoded image is kept, as there may be no image loader to load it again, and
+// is then dropped according to its retention policy.
+fn unload_image(cx: &mut ResourceContext, entity: Entity, image_name: &str) {
+    if let Some(image_store) = cx.resource_manager.images.get_mut(image_name) {
+        image_store.observers.remove(&entity);
+        if !image_store.observers.is_empty() {
+            image_store.used = true;
+            return;
+        }
+
+        let (image, flags) = match &mut image_store.image {
+            ImageOrId::Id(id, _, image, flags) => {
+                if let Some(canvas) = cx.canvases.get_mut(&Entity::root()) {
+                    canvas.delete_image(*id);
+                }
+
+                (std::mem::replace(image, image::DynamicImage::new_rgba8(0, 0)), *flags)
+            }
+            ImageOrId::Image(..) => return,
+        };
+
+        image_store.image = ImageOrId::Image(image, flags);
+    }
+}
+
 fn try_load_image(cx: &mut ResourceContext, entity: Entity, image_name: &str) -> bool {
     // Check if the image is already loaded
     if let Some(image_store) = cx.resource_manager.images.get_mut(image_name) {
         match &image_store.image {
             // Image exists and is already loaded so just add this entity as an observer and mark image as used
-            ImageOrId::Id(_, _) => {
+            ImageOrId::Id(..) => {
                 // TODO: check if the image is actually the same?
                 image_store.observers.insert(entity);
                 image_store.used = true;
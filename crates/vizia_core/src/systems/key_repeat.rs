@@ -0,0 +1,130 @@
+use crate::prelude::*;
+use instant::{Duration, Instant};
+use vizia_storage::TreeExt;
+
+/// An action which is repeated at a fixed rate while a key is held down.
+pub(crate) struct KeyRepeat {
+    pub entity: Entity,
+    pub code: Code,
+    pub delay: Duration,
+    pub interval: Duration,
+    pub action: Box<dyn Fn(&mut EventContext)>,
+    // The time the action is next due, or `None` if the key is not being repeated.
+    pub next: Option<Instant>,
+    // Whether the action has not yet run since the key was pressed.
+    pub first: bool,
+}
+
+// Whether the view of the key repeat, or one of its descendants, has keyboard focus.
+fn has_focus_within(cx: &Context, entity: Entity) -> bool {
+    cx.focused == entity || cx.focused.is_descendant_of(&cx.tree, entity)
+}
+
+// Starts repeating the actions bound to a key when it is first pressed.
+pub(crate) fn start_key_repeats(cx: &mut Context, code: Code) {
+    let now = Instant::now();
+    for index in 0..cx.key_repeats.len() {
+        let key_repeat = &cx.key_repeats[index];
+        if key_repeat.code == code && has_focus_within(cx, key_repeat.entity) {
+            cx.key_repeats[index].next = Some(now);
+            cx.key_repeats[index].first = true;
+        }
+    }
+}
+
+// Stops repeating the actions bound to a key when it is released.
+pub(crate) fn stop_key_repeats(cx: &mut Context, code: Code) {
+    for key_repeat in cx.key_repeats.iter_mut() {
+        if key_repeat.code == code {
+            key_repeat.next = None;
+        }
+    }
+}
+
+// Stops repeating the actions of every key, e.g. when the window loses focus.
+pub(crate) fn stop_all_key_repeats(cx: &mut Context) {
+    for key_repeat in cx.key_repeats.iter_mut() {
+        key_repeat.next = None;
+    }
+}
+
+// Runs the actions of held keys which are due and returns whether any keys are being repeated.
+pub(crate) fn key_repeat_system(cx: &mut Context) -> bool {
+    if cx.key_repeats.is_empty() {
+        return false;
+    }
+
+    let now = Instant::now();
+    let mut key_repeats = std::mem::take(&mut cx.key_repeats);
+    let mut repeating = false;
+
+    for key_repeat in key_repeats.iter_mut() {
+        if let Some(next) = key_repeat.next {
+            // Stop repeating if focus has moved away from the view.
+            if !has_focus_within(cx, key_repeat.entity) {
+                key_repeat.next = None;
+                continue;
+            }
+
+            repeating = true;
+
+            if now >= next {
+                cx.with_current(key_repeat.entity, |cx| {
+                    (key_repeat.action)(&mut EventContext::new(cx))
+                });
+
+                // The action runs once when the key is pressed, then again after the initial delay,
+                // and then once per interval.
+                let wait = if key_repeat.first { key_repeat.delay } else { key_repeat.interval };
+                key_repeat.first = false;
+                key_repeat.next = Some(now + wait);
+            }
+        }
+    }
+
+    // Actions may have added new key repeats, which are kept after the existing ones, or removed
+    // views, whose key repeats are dropped.
+    key_repeats.append(&mut cx.key_repeats);
+    key_repeats.retain(|key_repeat| cx.entity_manager.is_alive(key_repeat.entity));
+    cx.key_repeats = key_repeats;
+
+    repeating
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::BackendContext;
+    use crate::testing::TestHarness;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn losing_window_focus_releases_held_keys() {
+        let count = Rc::new(Cell::new(0));
+        let counter = count.clone();
+        let mut harness = TestHarness::new(move |cx| {
+            Element::new(cx).id("repeat").focusable(true).repeat_key(
+                Code::ArrowUp,
+                Duration::from_secs(60),
+                Duration::from_secs(60),
+                move |_| counter.set(counter.get() + 1),
+            );
+        });
+
+        let entity = harness.entity("repeat").unwrap();
+        harness.context().with_current(entity, |cx| cx.focus_with_visibility(false));
+
+        harness.key_down(Code::ArrowUp, None);
+        assert_eq!(count.get(), 1);
+
+        // The key is released while the window is unfocused, so no key up event is received.
+        BackendContext::new(harness.context()).set_window_focus(Entity::root(), false);
+        assert!(harness.context().keys_down.is_empty());
+        assert!(harness.context().key_repeats.iter().all(|key_repeat| key_repeat.next.is_none()));
+
+        BackendContext::new(harness.context()).set_window_focus(Entity::root(), true);
+        harness.key_down(Code::ArrowUp, None);
+        assert_eq!(count.get(), 2);
+    }
+}
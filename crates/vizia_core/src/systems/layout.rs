@@ -5,6 +5,9 @@ use crate::layout::node::SubLayout;
 use crate::prelude::*;
 use crate::style::SystemFlags;
 
+// The maximum number of times layout is performed when the size of wrapping views changes.
+const MAX_WRAP_PASSES: usize = 4;
+
 /// Determines the size and position of views.
 /// TODO: Currently relayout is done on an entire tree rather than incrementally.
 /// Incremental relayout can be done by keeping a list of nodes that need relayout,
@@ -12,16 +15,43 @@ use crate::style::SystemFlags;
 /// then continue relayout on the remaining nodes in the list.
 pub(crate) fn layout_system(cx: &mut Context) {
     if cx.style.system_flags.contains(SystemFlags::RELAYOUT) {
-        // Perform layout on the whole tree.
-        Entity::root().layout(
-            &mut cx.cache,
-            &cx.tree,
-            &cx.style,
-            &mut SubLayout {
-                text_context: &mut cx.text_context,
-                resource_manager: &cx.resource_manager,
-            },
-        );
+        // The logical inline space of a view is resolved against its own direction, which can differ
+        // from the direction its parent lays out its children in.
+        cx.style.reversed_inline.clear();
+        for entity in cx.tree.into_iter() {
+            if let Some(parent) = cx.tree.get_layout_parent(entity) {
+                let rtl = |entity| cx.style.direction.get(entity).copied() == Some(Direction::Rtl);
+                if rtl(entity) != rtl(parent) {
+                    cx.style.reversed_inline.insert(entity);
+                }
+            }
+        }
+
+        // Perform layout on the whole tree, then wrap and align the children of flex views. Wrapping
+        // views which change size are laid out again, a limited number of times as nested wrapping
+        // views can each change the size of their parent. Each window is laid out separately, with
+        // the scale factor of the display it is on.
+        let windows = cx.tree.into_iter().filter(|entity| cx.tree.is_window(*entity));
+        let windows = std::iter::once(Entity::root()).chain(windows).collect::<Vec<_>>();
+        for window in windows {
+            cx.with_window_scale_factor(window, |cx| {
+                for _ in 0..MAX_WRAP_PASSES {
+                    window.layout(
+                        &mut cx.cache,
+                        &cx.tree,
+                        &cx.style,
+                        &mut SubLayout {
+                            text_context: &mut cx.text_context,
+                            resource_manager: &cx.resource_manager,
+                        },
+                    );
+
+                    if !flex_system(&mut cx.cache, &cx.tree, &mut cx.style, window) {
+                        break;
+                    }
+                }
+            });
+        }
 
         // If layout has changed then redraw
         cx.style.system_flags.set(SystemFlags::REDRAW, true);
@@ -52,15 +82,19 @@ pub(crate) fn layout_system(cx: &mut Context) {
                         .unwrap_or_default()
                         .to_px(width, 0.0)
                         * cx.scale_factor();
-                    let border_width = cx
-                        .style
-                        .border_width
-                        .get(entity)
-                        .cloned()
-                        .unwrap_or_default()
-                        .to_pixels(width, cx.scale_factor());
-                    let width = width.ceil() - child_left - child_right - 2.0 * border_width;
-                    cx.text_context.sync_styles(entity, cx.style);
+                    let border_width = |border_width: Option<&LengthOrPercentage>| {
+                        border_width
+                            .cloned()
+                            .unwrap_or_default()
+                            .to_pixels(width, cx.scale_factor())
+                    };
+                    let width = width.ceil()
+                        - child_left
+                        - child_right
+                        - border_width(cx.style.border_left_width.get(entity))
+                        - border_width(cx.style.border_right_width.get(entity));
+                    let scale_factor = cx.scale_factor();
+                    cx.text_context.sync_styles(entity, cx.style, scale_factor);
                     let (text_width, text_height) =
                         cx.text_context.with_buffer(entity, |fs, buf| {
                             buf.set_size(fs, width, f32::MAX);
@@ -94,14 +128,17 @@ pub(crate) fn layout_system(cx: &mut Context) {
                         .unwrap_or_default()
                         .to_px(width, 0.0)
                         * cx.scale_factor();
-                    let border_width = cx
-                        .style
-                        .border_width
-                        .get(entity)
-                        .cloned()
-                        .unwrap_or_default()
-                        .to_pixels(width, cx.scale_factor());
-                    let width = width.ceil() - child_left - child_right - 2.0 * border_width;
+                    let border_width = |border_width: Option<&LengthOrPercentage>| {
+                        border_width
+                            .cloned()
+                            .unwrap_or_default()
+                            .to_pixels(width, cx.scale_factor())
+                    };
+                    let width = width.ceil()
+                        - child_left
+                        - child_right
+                        - border_width(cx.style.border_left_width.get(entity))
+                        - border_width(cx.style.border_right_width.get(entity));
 
                     cx.text_context.with_buffer(entity, |fs, buffer| {
                         buffer.set_size(fs, width, f32::MAX);
@@ -109,13 +146,29 @@ pub(crate) fn layout_system(cx: &mut Context) {
                 }
             }
 
-            // Morphorm produces relative positions so convert to absolute.
+            // Keep the scroll offset within the content of a scrolled view, which may have shrunk.
+            if let Some((x, y)) = cx.cache.scroll_offset.get(entity).copied() {
+                let (max_x, max_y) = cx.max_scroll_offset();
+                cx.cache.scroll_offset.insert(entity, (x.min(max_x), y.min(max_y)));
+            }
+
+            // Morphorm produces relative positions so convert to absolute, moving the children of a
+            // scrolled view by its scroll offset.
             if let Some(parent) = cx.tree.get_layout_parent(entity) {
                 let parent_bounds = cx.cache.get_bounds(parent);
+                let (scroll_x, scroll_y) =
+                    cx.cache.scroll_offset.get(parent).copied().unwrap_or_default();
+                let rtl = cx.style.direction.get(parent).copied() == Some(Direction::Rtl);
                 if let Some(bounds) = cx.cache.bounds.get_mut(entity) {
                     if let Some(relative_position) = cx.cache.relative_position.get(entity) {
-                        bounds.x = relative_position.x + parent_bounds.x;
-                        bounds.y = relative_position.y + parent_bounds.y;
+                        // Mirror the horizontal position within the parent for right-to-left layout.
+                        let x = if rtl {
+                            parent_bounds.x + parent_bounds.w - relative_position.x - bounds.w
+                        } else {
+                            relative_position.x + parent_bounds.x
+                        };
+                        bounds.x = x - scroll_x;
+                        bounds.y = relative_position.y + parent_bounds.y - scroll_y;
                     }
                 }
             }
@@ -135,6 +188,27 @@ pub(crate) fn layout_system(cx: &mut Context) {
             if let Some(geo) = cx.cache.geo_changed.get_mut(entity) {
                 *geo = GeoChanged::empty();
             }
+
+            if let Some(observer) = cx.cache.viewport_observers.get(entity).copied() {
+                let proportion = visible_proportion(cx, entity, observer.margin);
+                let visible = proportion > 0.0 && proportion >= observer.threshold;
+                if visible != observer.visible {
+                    if let Some(observer) = cx.cache.viewport_observers.get_mut(entity) {
+                        observer.visible = visible;
+                    }
+
+                    let message = if visible {
+                        WindowEvent::EnteredViewport
+                    } else {
+                        WindowEvent::LeftViewport
+                    };
+                    let mut event = Event::new(message)
+                        .target(entity)
+                        .origin(entity)
+                        .propagate(Propagation::Direct);
+                    visit_entity(cx, entity, &mut event);
+                }
+            }
         }
 
         // A relayout, retransform, or reclip, can cause the element under the cursor to change. So we push a mouse move event here to force
@@ -153,6 +227,30 @@ pub(crate) fn layout_system(cx: &mut Context) {
     }
 }
 
+// Returns the proportion of the area of an entity which is within the window and the clip regions
+// of its ancestors, such as the bounds of a scroll view, each expanded by the given margin. Ancestors
+// above a view which escapes their clipping are not taken into account.
+fn visible_proportion(cx: &mut EventContext, entity: Entity, margin: f32) -> f32 {
+    let bounds = cx.cache.get_bounds(entity);
+    let area = bounds.w * bounds.h;
+    if area <= 0.0 {
+        return 0.0;
+    }
+
+    // The ancestors which clip the entity are those it is drawn within, so the parent of an owned
+    // entity is used rather than its owner.
+    let mut visible = bounds.intersection(&cx.cache.get_bounds(Entity::root()).expand(margin));
+    let mut view = entity;
+    while let Some(parent) = cx.tree.get_parent(view).filter(|_| !cx.tree.escape_clip(view)) {
+        cx.current = parent;
+        visible = visible.intersection(&cx.clip_region().expand(margin));
+        view = parent;
+    }
+    cx.current = entity;
+
+    visible.w.max(0.0) * visible.h.max(0.0) / area
+}
+
 fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
     // Send event to models attached to the entity
     if let Some(ids) = cx
@@ -190,3 +288,43 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
         cx.views.insert(entity, view);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::testing::TestHarness;
+
+    /// Test that the logical inline space of a view is resolved against the direction of the view.
+    #[test]
+    fn logical_space_follows_view_direction() {
+        use Direction::{Ltr, Rtl};
+
+        let mut harness = TestHarness::new(|cx| {
+            for (parent, child) in [(Ltr, Ltr), (Ltr, Rtl), (Rtl, Rtl), (Rtl, Ltr)] {
+                let id = format!("{:?}-{:?}", parent, child);
+                VStack::new(cx, |cx| {
+                    Element::new(cx)
+                        .id(id.clone())
+                        .size(Pixels(50.0))
+                        .direction(child)
+                        .space_inline_start(Pixels(10.0))
+                        .space_inline_end(Stretch(1.0));
+                })
+                .id(format!("{}-parent", id))
+                .size(Pixels(200.0))
+                .direction(parent);
+            }
+        });
+
+        // Returns the horizontal position of a view within its parent.
+        let x = |harness: &TestHarness, id: &str| {
+            let parent = harness.entity(&format!("{}-parent", id)).unwrap();
+            harness.bounds(harness.entity(id).unwrap()).x - harness.bounds(parent).x
+        };
+
+        assert_eq!(x(&harness, "Ltr-Ltr"), 10.0);
+        assert_eq!(x(&harness, "Ltr-Rtl"), 140.0);
+        assert_eq!(x(&harness, "Rtl-Rtl"), 140.0);
+        assert_eq!(x(&harness, "Rtl-Ltr"), 10.0);
+    }
+}
@@ -2,15 +2,25 @@ pub(crate) mod accessibility;
 pub(crate) mod animation;
 pub(crate) mod binding;
 pub(crate) mod draw;
+pub(crate) mod flex;
 pub(crate) mod hover;
 pub(crate) mod image;
+pub(crate) mod key_repeat;
 pub(crate) mod layout;
 pub(crate) mod style;
+pub(crate) mod transform;
+pub(crate) mod tween;
+pub(crate) mod z_order;
 pub(crate) use self::image::*;
 pub(crate) use accessibility::*;
 pub(crate) use animation::*;
 pub(crate) use binding::*;
 pub(crate) use draw::*;
+pub(crate) use flex::*;
 pub(crate) use hover::*;
+pub(crate) use key_repeat::*;
 pub(crate) use layout::*;
 pub(crate) use style::*;
+pub(crate) use transform::*;
+pub(crate) use tween::*;
+pub(crate) use z_order::*;
@@ -82,8 +82,9 @@ impl<'s, 't, 'v> Element for Node<'s, 't, 'v> {
         !self.tree.has_children(self.entity)
     }
 
+    // The root view of every window matches the `:root` selector.
     fn is_root(&self) -> bool {
-        self.entity == Entity::root()
+        self.tree.is_window(self.entity)
     }
 
     fn is_html_element_in_html_document(&self) -> bool {
@@ -242,14 +243,19 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context) {
     for entity in cx.tree.into_iter() {
         if let Some(parent) = cx.tree.get_layout_parent(entity) {
             cx.style.disabled.inherit_inline(entity, parent);
+            cx.style.direction.inherit_inline(entity, parent);
+            cx.style.intrinsic_size.inherit_inline(entity, parent);
 
             cx.style.font_color.inherit_inline(entity, parent);
             cx.style.font_size.inherit_inline(entity, parent);
             cx.style.font_family.inherit_inline(entity, parent);
             cx.style.font_weight.inherit_inline(entity, parent);
             cx.style.font_style.inherit_inline(entity, parent);
+            cx.style.font_smoothing.inherit_inline(entity, parent);
+            cx.style.font_hinting.inherit_inline(entity, parent);
             cx.style.caret_color.inherit_inline(entity, parent);
             cx.style.selection_color.inherit_inline(entity, parent);
+            cx.style.image_rendering.inherit_inline(entity, parent);
         }
     }
 }
@@ -258,13 +264,18 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context) {
 pub(crate) fn shared_inheritance_system(cx: &mut Context) {
     for entity in cx.tree.into_iter() {
         if let Some(parent) = cx.tree.get_layout_parent(entity) {
+            cx.style.direction.inherit_shared(entity, parent);
+            cx.style.intrinsic_size.inherit_shared(entity, parent);
             cx.style.font_color.inherit_shared(entity, parent);
             cx.style.font_size.inherit_shared(entity, parent);
             cx.style.font_family.inherit_shared(entity, parent);
             cx.style.font_weight.inherit_shared(entity, parent);
             cx.style.font_style.inherit_shared(entity, parent);
+            cx.style.font_smoothing.inherit_shared(entity, parent);
+            cx.style.font_hinting.inherit_shared(entity, parent);
             cx.style.caret_color.inherit_shared(entity, parent);
             cx.style.selection_color.inherit_shared(entity, parent);
+            cx.style.image_rendering.inherit_shared(entity, parent);
         }
     }
 }
@@ -304,11 +315,19 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_redraw = true;
     }
 
+    if style.filter.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     // Opacity
     if style.opacity.link(entity, matched_rules) {
         should_redraw = true;
     }
 
+    if style.will_change.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.left.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
@@ -329,6 +348,16 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_redraw = true;
     }
 
+    if style.space_inline_start.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.space_inline_end.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     if style.min_left.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
@@ -402,12 +431,39 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
     }
 
     // Border
-    if style.border_width.link(entity, matched_rules) {
+    if style.border_top_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_right_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_bottom_width.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
     }
 
-    if style.border_color.link(entity, matched_rules) {
+    if style.border_left_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_top_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_right_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_bottom_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_left_color.link(entity, matched_rules) {
         should_redraw = true;
     }
 
@@ -465,6 +521,46 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_redraw = true;
     }
 
+    if style.direction.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.box_sizing.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.intrinsic_size.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.flex_wrap.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.align_content.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.justify_content.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.align_items.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.order.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
     // Background
     if style.background_color.link(entity, matched_rules) {
         should_redraw = true;
@@ -478,6 +574,22 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_redraw = true;
     }
 
+    if style.image_rendering.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.image_tint.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.mask_image.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.mask_mode.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     // Font
     if style.font_color.link(entity, matched_rules) {
         should_redraw = true;
@@ -508,6 +620,14 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_relayout = true;
     }
 
+    if style.font_smoothing.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.font_hinting.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.text_wrap.link(entity, matched_rules) {
         should_redraw = true;
         should_relayout = true;
@@ -521,6 +641,22 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_redraw = true;
     }
 
+    if style.caret_blink_rate.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.caret_shape.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.caret_width.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.link_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     // Outer Shadow
     if style.box_shadow.link(entity, matched_rules) {
         should_redraw = true;
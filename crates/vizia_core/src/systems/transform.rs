@@ -0,0 +1,33 @@
+use femtovg::Transform2D;
+
+use crate::prelude::*;
+use crate::style::SystemFlags;
+
+/// Composes the translation, rotation, scale, and transform functions of each view, about its
+/// transform origin, with the transform of its parent. The result is cached so that views are drawn
+/// and hit-tested with the same transform, including views drawn out of tree order by z-index.
+pub(crate) fn transform_system(cx: &mut Context) {
+    if !cx.style.system_flags.intersects(SystemFlags::REDRAW | SystemFlags::REDRAW_PARTIAL) {
+        return;
+    }
+
+    let cx = &mut EventContext::new(cx);
+
+    for entity in cx.tree.into_iter() {
+        cx.current = entity;
+
+        // Each window is drawn to its own canvas, so its transform does not include its ancestors.
+        let mut transform = if cx.tree.is_window(entity) {
+            Transform2D::identity()
+        } else {
+            cx.tree
+                .get_parent(entity)
+                .map(|parent| cx.cache.get_transform(parent))
+                .unwrap_or_else(Transform2D::identity)
+        };
+
+        transform.premultiply(&cx.transform());
+
+        cx.cache.transform.insert(entity, transform);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::animation::{Interpolator, TimingFunction};
+use crate::prelude::*;
+use instant::{Duration, Instant};
+
+/// A value which is animated over time by calling a callback with the interpolated value each frame.
+pub(crate) struct Tween {
+    pub entity: Entity,
+    pub start: Instant,
+    pub duration: Duration,
+    pub timing_function: TimingFunction,
+    // Called with the eased progress of the tween, between 0.0 and 1.0.
+    pub callback: Box<dyn FnMut(&mut EventContext, f32)>,
+}
+
+impl Tween {
+    pub fn new<T: Interpolator + 'static>(
+        entity: Entity,
+        from: T,
+        to: T,
+        duration: Duration,
+        easing: EasingFunction,
+        mut callback: impl FnMut(&mut EventContext, T) + 'static,
+    ) -> Self {
+        // A tween eased with a spring runs until the spring comes to rest.
+        let timing_function = TimingFunction::from(easing);
+        Self {
+            entity,
+            start: Instant::now(),
+            duration: timing_function.settle_duration().unwrap_or(duration),
+            timing_function,
+            callback: Box::new(move |cx, t| (callback)(cx, T::interpolate(&from, &to, t))),
+        }
+    }
+}
+
+// Calls the callbacks of running tweens and returns whether any tweens are still running.
+pub(crate) fn tween_system(cx: &mut Context) -> bool {
+    if cx.tweens.is_empty() {
+        return false;
+    }
+
+    let now = Instant::now();
+    let tweens = std::mem::take(&mut cx.tweens);
+    let mut running = Vec::with_capacity(tweens.len());
+
+    for mut tween in tweens {
+        // Drop tweens of views which have been removed by the callback of an earlier tween.
+        if !cx.entity_manager.is_alive(tween.entity) {
+            continue;
+        }
+
+        let progress = if tween.duration.is_zero() {
+            1.0
+        } else {
+            (now.duration_since(tween.start).as_secs_f32() / tween.duration.as_secs_f32()).min(1.0)
+        };
+
+        let t = tween.timing_function.value(progress);
+        cx.with_current(tween.entity, |cx| (tween.callback)(&mut EventContext::new(cx), t));
+
+        // The callback is always called with the final value before the tween is dropped.
+        if progress < 1.0 {
+            running.push(tween);
+        }
+    }
+
+    // Callbacks may have started new tweens, which are kept after the existing ones.
+    running.append(&mut cx.tweens);
+    running.retain(|tween| cx.entity_manager.is_alive(tween.entity));
+    cx.tweens = running;
+
+    !cx.tweens.is_empty()
+}
@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::entity::Entity;
+use crate::style::Style;
+use vizia_storage::{LayoutChildIterator, Tree};
+
+/// A queue of deferred subtrees, ordered by z-index and then by the order in which they were pushed.
+///
+/// Systems which need to visit views in the order they are drawn (e.g. drawing and hit-testing) push
+/// any view with a higher z-index than the current layer into the queue, and then pop them off once
+/// the current layer has been processed. Views with an equal z-index are popped in the order they were
+/// pushed, which matches the tree order in which they are encountered.
+pub(crate) struct ZQueue<T> {
+    heap: BinaryHeap<ZEntry<T>>,
+    sequence: usize,
+}
+
+impl<T> ZQueue<T> {
+    pub fn new() -> Self {
+        Self { heap: BinaryHeap::new(), sequence: 0 }
+    }
+
+    /// Pushes an item with the given z-index onto the queue.
+    pub fn push(&mut self, z_index: i32, item: T) {
+        self.heap.push(ZEntry { z_index, sequence: self.sequence, item });
+        self.sequence += 1;
+    }
+
+    /// Pops the next item to be processed, returning its z-index along with the item.
+    pub fn pop(&mut self) -> Option<(i32, T)> {
+        self.heap.pop().map(|entry| (entry.z_index, entry.item))
+    }
+}
+
+struct ZEntry<T> {
+    z_index: i32,
+    sequence: usize,
+    item: T,
+}
+
+impl<T> Ord for ZEntry<T> {
+    // The heap is a max-heap so the comparison is reversed such that the lowest z-index, and then the
+    // earliest pushed entry, is popped first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.z_index.cmp(&self.z_index).then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<T> PartialOrd for ZEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for ZEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.z_index == other.z_index && self.sequence == other.sequence
+    }
+}
+
+impl<T> Eq for ZEntry<T> {}
+
+/// Returns the layout children of a view in the order they are drawn and hit-tested.
+///
+/// Children are in tree order, except that children with a higher paint order are moved after their
+/// siblings with a lower paint order, and children with the same paint order are in their visual order.
+/// Children which are the roots of other windows are left out, as they are drawn into their own window.
+pub(crate) fn paint_ordered_children<'a>(
+    tree: &'a Tree<Entity>,
+    style: &Style,
+    entity: Entity,
+) -> PaintOrderedChildren<'a> {
+    // Most views don't reorder their children, in which case they are visited straight from the tree.
+    if !LayoutChildIterator::new(tree, entity)
+        .any(|child| style.paint_order.get(child).is_some() || style.order.get(child).is_some())
+    {
+        return PaintOrderedChildren::Tree(tree, LayoutChildIterator::new(tree, entity));
+    }
+
+    let mut children: Vec<Entity> =
+        LayoutChildIterator::new(tree, entity).filter(|child| !tree.is_window(*child)).collect();
+
+    // The sort is stable, so children with the same paint order and order stay in tree order.
+    children.sort_by_key(|child| {
+        (
+            style.paint_order.get(*child).copied().unwrap_or_default(),
+            style.order.get(*child).copied().unwrap_or_default(),
+        )
+    });
+
+    PaintOrderedChildren::Sorted(children.into_iter())
+}
+
+/// An iterator over the layout children of a view in paint order, see [`paint_ordered_children`].
+pub(crate) enum PaintOrderedChildren<'a> {
+    Tree(&'a Tree<Entity>, LayoutChildIterator<'a, Entity>),
+    Sorted(std::vec::IntoIter<Entity>),
+}
+
+impl<'a> Iterator for PaintOrderedChildren<'a> {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            PaintOrderedChildren::Tree(tree, children) => {
+                children.find(|child| !tree.is_window(*child))
+            }
+            PaintOrderedChildren::Sorted(children) => children.next(),
+        }
+    }
+}
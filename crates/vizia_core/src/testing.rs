@@ -0,0 +1,514 @@
+//! Utilities for testing views without a window.
+//!
+//! A [`TestHarness`] owns a [`Context`] and drives it the same way a windowing backend does. Tests can
+//! push synthetic window events, such as mouse and keyboard input, and step the event, data binding,
+//! style, animation, and layout systems before asserting on the resulting state.
+//!
+//! Given a canvas, e.g. one created from a headless OpenGL context, the harness can also render the
+//! application so that the output can be compared against a stored snapshot with [`assert_snapshot!`].
+
+use std::path::Path;
+
+use crate::backend::BackendContext;
+use crate::prelude::*;
+use image::{Rgba, RgbaImage};
+
+/// The default maximum difference allowed between the color channels of a rendered pixel and the
+/// pixel stored in a snapshot.
+pub const DEFAULT_SNAPSHOT_TOLERANCE: u8 = 2;
+
+/// The environment variable which, when set, causes snapshots to be overwritten with the rendered output.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "VIZIA_UPDATE_SNAPSHOTS";
+
+/// A headless application used to test interactions with views.
+///
+/// Each method which sends an event also steps the systems of the application once, so the effects
+/// of the event, including any changes to bound views, styles and layout, can be checked straight away.
+/// Positions are in physical pixels relative to the top-left corner of the window.
+///
+/// Drawing requires a rendering context and is not performed by the harness.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::testing::TestHarness;
+/// #
+/// #[derive(Lens)]
+/// pub struct AppData {
+///     count: i32,
+/// }
+///
+/// pub enum AppEvent {
+///     Increment,
+/// }
+///
+/// impl Model for AppData {
+///     fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+///         event.map(|app_event, _| match app_event {
+///             AppEvent::Increment => self.count += 1,
+///         });
+///     }
+/// }
+///
+/// let mut harness = TestHarness::new(|cx| {
+///     AppData { count: 0 }.build(cx);
+///
+///     Button::new(cx, |cx| cx.emit(AppEvent::Increment), |cx| Label::new(cx, "Increment"))
+///         .id("increment");
+/// });
+///
+/// let button = harness.entity("increment").unwrap();
+/// harness.simulate_click(button);
+///
+/// assert_eq!(harness.context().data::<AppData>().unwrap().count, 1);
+/// ```
+pub struct TestHarness {
+    cx: Context,
+}
+
+impl TestHarness {
+    /// Creates a new harness with an 800 by 600 window and builds its content with the given closure.
+    pub fn new(content: impl FnOnce(&mut Context)) -> Self {
+        Self::with_size(WindowSize::new(800, 600), content)
+    }
+
+    /// Creates a new harness with a window of the given size and builds its content with the given closure.
+    pub fn with_size(window_size: WindowSize, content: impl FnOnce(&mut Context)) -> Self {
+        let mut cx = Context::new(window_size, 1.0);
+
+        let mut bcx = BackendContext::new(&mut cx);
+        bcx.set_window_size(window_size.width as f32, window_size.height as f32);
+        bcx.style().disabled.insert(Entity::root(), false);
+
+        cx.remove_user_themes();
+        (content)(&mut cx);
+
+        let mut harness = Self { cx };
+        harness.step();
+        harness
+    }
+
+    /// Returns a mutable reference to the context of the harness, e.g. to access model data.
+    pub fn context(&mut self) -> &mut Context {
+        &mut self.cx
+    }
+
+    /// Runs the event, data binding, style, animation, and layout systems once, in the same order as
+    /// a windowing backend does for each frame.
+    pub fn step(&mut self) {
+        let mut cx = BackendContext::new_with_event_manager(&mut self.cx);
+
+        cx.process_events();
+        cx.process_data_updates();
+        cx.process_style_updates();
+        cx.process_animations();
+        cx.process_visual_updates();
+
+        // Accessibility updates are not consumed by the harness but must still be cleared.
+        cx.process_tree_updates(|_| {});
+    }
+
+    /// Sends a window event to the application, as if it came from the windowing backend, and steps
+    /// the systems of the application.
+    pub fn send(&mut self, event: WindowEvent) {
+        BackendContext::new(&mut self.cx).emit_origin(event);
+        self.step();
+    }
+
+    /// Sends a custom event with its own target, origin, and propagation, and steps the systems of
+    /// the application.
+    pub fn send_event(&mut self, event: Event) {
+        BackendContext::new(&mut self.cx).send_event(event);
+        self.step();
+    }
+
+    /// Sets the keyboard modifiers which are held down.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        *BackendContext::new(&mut self.cx).modifiers() = modifiers;
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn mouse_move(&mut self, x: f32, y: f32) {
+        self.send(WindowEvent::MouseMove(x, y));
+    }
+
+    /// Presses the given mouse button at the current cursor position.
+    pub fn mouse_down(&mut self, button: MouseButton) {
+        self.send(WindowEvent::MouseDown(button));
+    }
+
+    /// Releases the given mouse button at the current cursor position.
+    pub fn mouse_up(&mut self, button: MouseButton) {
+        self.send(WindowEvent::MouseUp(button));
+    }
+
+    /// Moves the mouse cursor to the given position and clicks the left mouse button.
+    pub fn click_at(&mut self, x: f32, y: f32) {
+        self.mouse_move(x, y);
+        self.mouse_down(MouseButton::Left);
+        self.mouse_up(MouseButton::Left);
+    }
+
+    /// Clicks the left mouse button at the center of the given view.
+    pub fn simulate_click(&mut self, entity: Entity) {
+        let bounds = self.bounds(entity);
+        self.click_at(bounds.center().0, bounds.center().1);
+    }
+
+    /// Presses the key with the given code.
+    pub fn key_down(&mut self, code: Code, key: Option<Key>) {
+        self.send(WindowEvent::KeyDown(code, key));
+    }
+
+    /// Releases the key with the given code.
+    pub fn key_up(&mut self, code: Code, key: Option<Key>) {
+        self.send(WindowEvent::KeyUp(code, key));
+    }
+
+    /// Presses and releases the key with the given code.
+    pub fn key_press(&mut self, code: Code, key: Option<Key>) {
+        self.key_down(code, key.clone());
+        self.key_up(code, key);
+    }
+
+    /// Sends a character input event for each character of the given text, as if it were typed.
+    pub fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            self.send(WindowEvent::CharInput(character));
+        }
+    }
+
+    /// Sets the canvas used to render the application.
+    ///
+    /// A canvas is required to [`capture`](TestHarness::capture) the rendered output of the application.
+    pub fn set_canvas(&mut self, canvas: Canvas) {
+        self.cx.canvases.insert(Entity::root(), canvas);
+        self.cx.style.needs_redraw();
+    }
+
+    /// Draws the application and returns the rendered image, or `None` if no canvas has been set.
+    pub fn capture(&mut self) -> Option<RgbaImage> {
+        if !self.cx.canvases.contains_key(&Entity::root()) {
+            return None;
+        }
+
+        BackendContext::new(&mut self.cx).draw();
+
+        let screenshot = self.cx.canvases.get_mut(&Entity::root())?.screenshot().ok()?;
+        let pixels = screenshot.as_ref().pixels().flat_map(|p| [p.r, p.g, p.b, p.a]).collect();
+
+        RgbaImage::from_raw(screenshot.width() as u32, screenshot.height() as u32, pixels)
+    }
+
+    /// Returns the entity of the view with the given id, if any.
+    pub fn entity(&self, id: &str) -> Option<Entity> {
+        self.cx.resolve_entity_identifier(id)
+    }
+
+    /// Returns the bounding box of the given view, in physical pixels.
+    pub fn bounds(&self, entity: Entity) -> BoundingBox {
+        self.cx.cache.get_bounds(entity)
+    }
+
+    /// Returns the entity of the view under the mouse cursor.
+    pub fn hovered(&self) -> Entity {
+        self.cx.hovered
+    }
+
+    /// Returns the entity of the view with keyboard focus.
+    pub fn focused(&self) -> Entity {
+        self.cx.focused
+    }
+}
+
+/// Compares two images and returns an image highlighting the differences, or `None` if the images
+/// match.
+///
+/// Images match if they are the same size and no color channel of any pixel differs by more than
+/// `tolerance`. Differing pixels are drawn in red over a faded copy of the expected image.
+pub fn compare_images(
+    expected: &RgbaImage,
+    actual: &RgbaImage,
+    tolerance: u8,
+) -> Option<RgbaImage> {
+    if expected.dimensions() != actual.dimensions() {
+        return Some(actual.clone());
+    }
+
+    let mut matches = true;
+    let diff = RgbaImage::from_fn(expected.width(), expected.height(), |x, y| {
+        let e = expected.get_pixel(x, y);
+        let a = actual.get_pixel(x, y);
+        if e.0.iter().zip(a.0.iter()).any(|(e, a)| e.abs_diff(*a) > tolerance) {
+            matches = false;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let luma = ((e[0] as u32 + e[1] as u32 + e[2] as u32) / 3) as u8;
+            Rgba([luma, luma, luma, 64])
+        }
+    });
+
+    if matches {
+        None
+    } else {
+        Some(diff)
+    }
+}
+
+/// Renders the application and compares the output against the snapshot image stored at `path`.
+///
+/// If the snapshot does not exist, or the [`UPDATE_SNAPSHOTS_VAR`] environment variable is set, the
+/// rendered output is saved as the new snapshot. Otherwise, on a mismatch the rendered output and an
+/// image highlighting the differences are saved next to the snapshot, with `.new.png` and `.diff.png`
+/// extensions, and the function panics.
+///
+/// This is usually called through the [`assert_snapshot!`](crate::assert_snapshot) macro.
+///
+/// # Panics
+///
+/// Panics if no canvas has been set on the harness, if the snapshot cannot be read or written, or if
+/// the rendered output does not match the snapshot.
+pub fn assert_snapshot(harness: &mut TestHarness, path: impl AsRef<Path>, tolerance: u8) {
+    let actual = harness.capture().expect("A canvas must be set on the harness to take a snapshot");
+    assert_image_snapshot(&actual, path.as_ref(), tolerance);
+}
+
+// Compares a rendered image against the snapshot stored at `path`, as described for
+// `assert_snapshot`.
+fn assert_image_snapshot(actual: &RgbaImage, path: &Path, tolerance: u8) {
+    if std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() || !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("Failed to create snapshot directory");
+        }
+        actual.save(path).expect("Failed to save snapshot");
+        return;
+    }
+
+    let expected = image::open(path).expect("Failed to load snapshot").to_rgba8();
+
+    if let Some(diff) = compare_images(&expected, actual, tolerance) {
+        let new_path = path.with_extension("new.png");
+        let diff_path = path.with_extension("diff.png");
+        actual.save(&new_path).expect("Failed to save rendered output");
+        diff.save(&diff_path).expect("Failed to save snapshot diff");
+
+        panic!(
+            "Rendered output does not match snapshot {}. See {} and {}, or set {} to update the snapshot.",
+            path.display(),
+            new_path.display(),
+            diff_path.display(),
+            UPDATE_SNAPSHOTS_VAR,
+        );
+    }
+}
+
+/// Renders a [`TestHarness`] and compares the output against a snapshot image.
+///
+/// Snapshots are stored as `tests/snapshots/<name>.png` within the package of the calling crate. An
+/// optional third argument sets the per-channel tolerance, which defaults to
+/// [`DEFAULT_SNAPSHOT_TOLERANCE`](crate::testing::DEFAULT_SNAPSHOT_TOLERANCE). See
+/// [`assert_snapshot`](crate::testing::assert_snapshot) for details.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::testing::TestHarness;
+/// # use vizia_core::assert_snapshot;
+/// # fn headless_canvas() -> Canvas { unimplemented!() }
+/// #
+/// let mut harness = TestHarness::new(|cx| {
+///     Label::new(cx, "Hello");
+/// });
+///
+/// harness.set_canvas(headless_canvas());
+///
+/// assert_snapshot!(harness, "hello_label");
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($harness:expr, $name:expr) => {
+        $crate::assert_snapshot!($harness, $name, $crate::testing::DEFAULT_SNAPSHOT_TOLERANCE)
+    };
+
+    ($harness:expr, $name:expr, $tolerance:expr) => {
+        $crate::testing::assert_snapshot(
+            &mut $harness,
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/snapshots")
+                .join(format!("{}.png", $name)),
+            $tolerance,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Lens)]
+    struct Counter {
+        count: i32,
+    }
+
+    enum CounterEvent {
+        Increment,
+    }
+
+    impl Model for Counter {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|counter_event, _| match counter_event {
+                CounterEvent::Increment => self.count += 1,
+            });
+        }
+    }
+
+    fn counter_harness() -> TestHarness {
+        TestHarness::new(|cx| {
+            Counter { count: 0 }.build(cx);
+
+            VStack::new(cx, |cx| {
+                Button::new(
+                    cx,
+                    |cx| cx.emit(CounterEvent::Increment),
+                    |cx| Label::new(cx, "Increment"),
+                )
+                .id("increment");
+                Button::new(cx, |_| {}, |cx| Label::new(cx, "Other")).id("other");
+            });
+        })
+    }
+
+    fn count(harness: &mut TestHarness) -> i32 {
+        harness.context().data::<Counter>().unwrap().count
+    }
+
+    #[test]
+    fn clicking_a_button_updates_the_model() {
+        let mut harness = counter_harness();
+        let increment = harness.entity("increment").unwrap();
+
+        harness.simulate_click(increment);
+        assert_eq!(harness.hovered(), increment);
+        assert_eq!(count(&mut harness), 1);
+
+        harness.simulate_click(increment);
+        assert_eq!(count(&mut harness), 2);
+    }
+
+    #[test]
+    fn clicking_outside_of_a_button_does_not_press_it() {
+        let mut harness = counter_harness();
+        let increment = harness.entity("increment").unwrap();
+        let bounds = harness.bounds(increment);
+
+        harness.click_at(bounds.right() + 10.0, bounds.center().1);
+
+        assert_ne!(harness.hovered(), increment);
+        assert_eq!(count(&mut harness), 0);
+    }
+
+    #[test]
+    fn keyboard_navigation_presses_the_focused_button() {
+        let mut harness = counter_harness();
+        let increment = harness.entity("increment").unwrap();
+        let other = harness.entity("other").unwrap();
+
+        harness.key_press(Code::Tab, None);
+        assert_eq!(harness.focused(), increment);
+
+        harness.key_press(Code::Space, None);
+        assert_eq!(count(&mut harness), 1);
+
+        harness.key_press(Code::Tab, None);
+        assert_eq!(harness.focused(), other);
+
+        harness.key_press(Code::Enter, None);
+        assert_eq!(count(&mut harness), 1);
+    }
+
+    fn solid_image(width: u32, height: u32, color: [u8; 4]) -> RgbaImage {
+        RgbaImage::from_pixel(width, height, Rgba(color))
+    }
+
+    // Returns a path for a snapshot in a directory unique to the test.
+    fn snapshot_path(test: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("vizia_snapshots_{}_{}", test, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("snapshot.png")
+    }
+
+    #[test]
+    fn matching_images_have_no_diff() {
+        let image = solid_image(4, 4, [10, 20, 30, 255]);
+        assert_eq!(compare_images(&image, &image.clone(), 0), None);
+    }
+
+    #[test]
+    fn mismatched_pixels_are_highlighted() {
+        let expected = solid_image(4, 4, [10, 20, 30, 255]);
+        let mut actual = expected.clone();
+        actual.put_pixel(1, 2, Rgba([200, 20, 30, 255]));
+
+        let diff = compare_images(&expected, &actual, 0).unwrap();
+        assert_eq!(diff.dimensions(), (4, 4));
+        assert_eq!(diff.get_pixel(1, 2), &Rgba([255, 0, 0, 255]));
+        assert_eq!(diff.get_pixel(0, 0), &Rgba([20, 20, 20, 64]));
+    }
+
+    #[test]
+    fn images_of_different_sizes_do_not_match() {
+        let expected = solid_image(4, 4, [10, 20, 30, 255]);
+        let actual = solid_image(4, 5, [10, 20, 30, 255]);
+
+        assert_eq!(compare_images(&expected, &actual, 255), Some(actual));
+    }
+
+    #[test]
+    fn differences_within_the_tolerance_match() {
+        let expected = solid_image(4, 4, [10, 20, 30, 255]);
+        let actual = solid_image(4, 4, [12, 18, 30, 253]);
+
+        assert_eq!(compare_images(&expected, &actual, 2), None);
+        assert!(compare_images(&expected, &actual, 1).is_some());
+    }
+
+    #[test]
+    fn missing_snapshots_are_created() {
+        let path = snapshot_path("missing");
+        let image = solid_image(4, 4, [10, 20, 30, 255]);
+
+        assert_image_snapshot(&image, &path, 0);
+
+        assert_eq!(image::open(&path).unwrap().to_rgba8(), image);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn matching_snapshots_pass() {
+        let path = snapshot_path("matching");
+        let image = solid_image(4, 4, [10, 20, 30, 255]);
+        assert_image_snapshot(&image, &path, 0);
+
+        assert_image_snapshot(&solid_image(4, 4, [11, 20, 30, 255]), &path, 1);
+
+        assert!(!path.with_extension("diff.png").exists());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn mismatched_snapshots_panic_and_save_the_output_and_diff() {
+        let path = snapshot_path("mismatched");
+        assert_image_snapshot(&solid_image(4, 4, [10, 20, 30, 255]), &path, 0);
+
+        let actual = solid_image(4, 4, [200, 20, 30, 255]);
+        let result = std::panic::catch_unwind(|| assert_image_snapshot(&actual, &path, 0));
+
+        assert!(result.is_err());
+        assert_eq!(image::open(path.with_extension("new.png")).unwrap().to_rgba8(), actual);
+        assert!(path.with_extension("diff.png").exists());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use vizia_input::{Code, KeyChord, Modifiers};
+
+use super::{Direction, Movement};
+
+/// The modifier used for editing shortcuts such as copy and paste.
+///
+/// This is `LOGO` (command) on macOS and `CTRL` on other platforms.
+#[cfg(target_os = "macos")]
+pub const COMMAND_MODIFIER: Modifiers = Modifiers::LOGO;
+/// The modifier used for editing shortcuts such as copy and paste.
+///
+/// This is `LOGO` (command) on macOS and `CTRL` on other platforms.
+#[cfg(not(target_os = "macos"))]
+pub const COMMAND_MODIFIER: Modifiers = Modifiers::CTRL;
+
+/// An editing command which can be bound to a key chord within a [`TextKeyBindings`] set.
+#[derive(Debug, Clone, Copy)]
+pub enum EditCommand {
+    /// Move the caret, extending the selection if shift is held.
+    Move(Movement),
+    /// Move the caret while extending the selection.
+    Select(Movement),
+    /// Delete the selection, or the text between the caret and the movement target if there is no selection.
+    Delete(Movement),
+    /// Select all of the text.
+    SelectAll,
+    /// Copy the selected text to the clipboard.
+    Copy,
+    /// Paste text from the clipboard.
+    Paste,
+    /// Cut the selected text to the clipboard.
+    Cut,
+    /// Undo the last edit.
+    Undo,
+    /// Redo the last undone edit.
+    Redo,
+}
+
+/// A set of key chords used by text inputs to trigger editing commands.
+///
+/// Bindings in the set take priority over the built-in key handling of a textbox, which allows
+/// applications to choose their own editing conventions. For example, the [`emacs`](TextKeyBindings::emacs)
+/// set binds `CTRL+A` to moving the caret to the start of the line instead of selecting all of the text.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::editing::{Direction, Movement};
+/// #
+/// let bindings = TextKeyBindings::emacs()
+///     .insert(KeyChord::new(Modifiers::CTRL, Code::KeyU), EditCommand::Delete(Movement::LineStart))
+///     .insert(KeyChord::new(Modifiers::ALT, Code::Backspace), EditCommand::Delete(Movement::Word(Direction::Upstream)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TextKeyBindings {
+    entries: HashMap<KeyChord, EditCommand>,
+}
+
+impl TextKeyBindings {
+    /// Creates an empty set of bindings, leaving all key handling to the textbox.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a set of Emacs/readline-style bindings.
+    ///
+    /// | Key chord          | Command                       |
+    /// |--------------------|-------------------------------|
+    /// | `CTRL+A`           | Move to line start            |
+    /// | `CTRL+E`           | Move to line end              |
+    /// | `CTRL+B`           | Move back one character       |
+    /// | `CTRL+F`           | Move forward one character    |
+    /// | `CTRL+P`           | Move up one line              |
+    /// | `CTRL+N`           | Move down one line            |
+    /// | `CTRL+H`           | Delete the previous character |
+    /// | `CTRL+D`           | Delete the next character     |
+    /// | `CTRL+K`           | Delete to line end            |
+    /// | `ALT+Left`         | Move back one word            |
+    /// | `ALT+Right`        | Move forward one word         |
+    /// | `ALT+Backspace`    | Delete the previous word      |
+    /// | `ALT+Delete`       | Delete the next word          |
+    pub fn emacs() -> Self {
+        let ctrl = |code| KeyChord::new(Modifiers::CTRL, code);
+        let alt = |code| KeyChord::new(Modifiers::ALT, code);
+
+        Self::new()
+            .insert(ctrl(Code::KeyA), EditCommand::Move(Movement::LineStart))
+            .insert(ctrl(Code::KeyE), EditCommand::Move(Movement::LineEnd))
+            .insert(ctrl(Code::KeyB), EditCommand::Move(Movement::Grapheme(Direction::Left)))
+            .insert(ctrl(Code::KeyF), EditCommand::Move(Movement::Grapheme(Direction::Right)))
+            .insert(ctrl(Code::KeyP), EditCommand::Move(Movement::Line(Direction::Upstream)))
+            .insert(ctrl(Code::KeyN), EditCommand::Move(Movement::Line(Direction::Downstream)))
+            .insert(ctrl(Code::KeyH), EditCommand::Delete(Movement::Grapheme(Direction::Upstream)))
+            .insert(
+                ctrl(Code::KeyD),
+                EditCommand::Delete(Movement::Grapheme(Direction::Downstream)),
+            )
+            .insert(ctrl(Code::KeyK), EditCommand::Delete(Movement::LineEnd))
+            .insert(alt(Code::ArrowLeft), EditCommand::Move(Movement::Word(Direction::Left)))
+            .insert(alt(Code::ArrowRight), EditCommand::Move(Movement::Word(Direction::Right)))
+            .insert(alt(Code::Backspace), EditCommand::Delete(Movement::Word(Direction::Upstream)))
+            .insert(alt(Code::Delete), EditCommand::Delete(Movement::Word(Direction::Downstream)))
+    }
+
+    /// Creates the default bindings for the current platform.
+    ///
+    /// On macOS this is the [`emacs`](TextKeyBindings::emacs) set, which matches the system text fields.
+    /// On other platforms the set is empty.
+    pub fn platform_default() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::emacs()
+        } else {
+            Self::new()
+        }
+    }
+
+    /// Binds a key chord to an editing command, replacing any existing binding for the chord.
+    pub fn insert(mut self, chord: KeyChord, command: EditCommand) -> Self {
+        self.entries.insert(chord, command);
+        self
+    }
+
+    /// Removes the binding for a key chord.
+    pub fn remove(mut self, chord: KeyChord) -> Self {
+        self.entries.remove(&chord);
+        self
+    }
+
+    /// Returns the editing command bound to the given modifiers and key code.
+    ///
+    /// If there is no binding for the exact chord but shift is held, the chord without shift is
+    /// looked up and any [`EditCommand::Move`] is turned into an [`EditCommand::Select`].
+    pub fn get(&self, modifiers: Modifiers, code: Code) -> Option<EditCommand> {
+        if let Some(command) = self.entries.get(&KeyChord::new(modifiers, code)) {
+            return Some(*command);
+        }
+
+        if modifiers.contains(Modifiers::SHIFT) {
+            let unshifted = KeyChord::new(modifiers - Modifiers::SHIFT, code);
+            if let Some(EditCommand::Move(movement)) = self.entries.get(&unshifted) {
+                return Some(EditCommand::Select(*movement));
+            }
+        }
+
+        None
+    }
+}
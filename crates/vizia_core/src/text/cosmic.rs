@@ -12,26 +12,34 @@ use femtovg::imgref::{Img, ImgRef};
 use femtovg::rgb::RGBA8;
 use femtovg::{
     Atlas, Canvas, DrawCommand, ErrorKind, GlyphDrawCommands, ImageFlags, ImageId, ImageSource,
-    Quad, Renderer,
+    Quad, Renderer, Transform2D,
 };
 use fnv::FnvHashMap;
+use instant::{Duration, Instant};
 use morphorm::Units;
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::ops::Range;
 use swash::scale::image::Content;
 use swash::scale::{Render, ScaleContext, Source, StrikeWith};
 use swash::zeno::{Format, Vector};
 use unicode_segmentation::UnicodeSegmentation;
 use vizia_storage::SparseSet;
-use vizia_style::{FontStretch, FontStyle, TextAlign};
+use vizia_style::{CaretShape, FontStretch, FontStyle, TextAlign};
 
 const GLYPH_PADDING: u32 = 1;
 const GLYPH_MARGIN: u32 = 1;
 const TEXTURE_SIZE: usize = 512;
+// The color of links in text when no `link-color` is set.
+const DEFAULT_LINK_COLOR: Color = Color::rgb(0, 102, 204);
 
-#[derive(Debug, Clone, Copy)]
+/// The rasterization settings for the glyphs of text, which can be overridden for a view with the
+/// `font-hinting` and `font-smoothing` style properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TextConfig {
+    /// Whether glyph outlines are hinted to align with the pixel grid.
     pub hint: bool,
+    /// Whether glyphs are antialiased per color channel rather than in grayscale.
     pub subpixel: bool,
 }
 
@@ -44,10 +52,18 @@ impl Default for TextConfig {
 pub struct TextContext {
     font_system: FontSystem,
     scale_context: ScaleContext,
-    rendered_glyphs: FnvHashMap<CacheKey, Option<RenderedGlyph>>,
+    // Glyphs are cached per config, as the same glyph may be drawn with different rasterization settings.
+    rendered_glyphs: FnvHashMap<(CacheKey, TextConfig), Option<RenderedGlyph>>,
     glyph_textures: Vec<FontTexture>,
     buffers: HashMap<Entity, Editor>,
     bounds: SparseSet<BoundingBox>,
+    // The canvas transform, bounds, and justification the text of each entity was last drawn with.
+    draw_layouts: SparseSet<(Transform2D, BoundingBox, (f32, f32))>,
+    // The links detected in the text of entities with link detection enabled.
+    links: SparseSet<Vec<TextLink>>,
+    // The start of the current caret blink cycle and the entity whose caret is currently hidden by it.
+    caret_blink_start: Instant,
+    hidden_caret: Option<Entity>,
 }
 
 impl TextContext {
@@ -62,6 +78,21 @@ impl TextContext {
 
     pub(crate) fn clear_buffer(&mut self, entity: Entity) {
         self.buffers.remove(&entity);
+        self.draw_layouts.remove(entity);
+        self.links.remove(entity);
+    }
+
+    /// Removes all rendered glyphs and their textures, causing them to be rasterized again when next drawn.
+    pub(crate) fn clear_glyph_cache(&mut self) {
+        self.rendered_glyphs.clear();
+        self.glyph_textures.clear();
+    }
+
+    /// Swaps the rendered glyphs and their textures with those in the given cache. The glyph textures
+    /// belong to the canvas they were created on, so each window keeps its own glyph cache.
+    pub(crate) fn swap_glyph_cache(&mut self, glyph_cache: &mut GlyphCache) {
+        std::mem::swap(&mut self.rendered_glyphs, &mut glyph_cache.rendered_glyphs);
+        std::mem::swap(&mut self.glyph_textures, &mut glyph_cache.glyph_textures);
     }
 
     pub(crate) fn has_buffer(&self, entity: Entity) -> bool {
@@ -102,8 +133,115 @@ impl TextContext {
         self.bounds.get(entity).copied()
     }
 
-    /// Sync the style data from vizia with the style attribites stored in cosmic-text buffers.
-    pub(crate) fn sync_styles(&mut self, entity: Entity, style: &Style) {
+    pub(crate) fn set_draw_layout(
+        &mut self,
+        entity: Entity,
+        transform: Transform2D,
+        bounds: BoundingBox,
+        justify: (f32, f32),
+    ) {
+        self.draw_layouts.insert(entity, (transform, bounds, justify));
+    }
+
+    // Returns the cursor nearest to a point in physical window coordinates, using the position the
+    // text was last drawn at.
+    fn hit_cursor(&mut self, entity: Entity, x: f32, y: f32) -> Option<Cursor> {
+        let (mut transform, bounds, justify) = self.draw_layouts.get(entity).copied()?;
+        transform.inverse();
+        let (x, y) = transform.transform_point(x, y);
+
+        self.with_buffer(entity, |_, buffer| {
+            let total_height = buffer.layout_runs().len() as f32 * buffer.metrics().line_height;
+            let x = x - bounds.x;
+            let y = y - (bounds.y + bounds.h * justify.1 - total_height * justify.1);
+
+            buffer.hit(x as i32, y as i32)
+        })
+    }
+
+    // Returns the index of the character nearest to a point in physical window coordinates.
+    pub(crate) fn hit_position(&mut self, entity: Entity, x: f32, y: f32) -> Option<usize> {
+        let cursor = self.hit_cursor(entity, x, y)?;
+
+        self.with_buffer(entity, |_, buffer| {
+            // Count the characters of the preceding lines, and the line breaks between them.
+            let index = buffer.lines[..cursor.line]
+                .iter()
+                .map(|line| line.text().chars().count() + 1)
+                .sum::<usize>()
+                + buffer.lines[cursor.line].text()[..cursor.index].chars().count();
+
+            Some(index)
+        })
+    }
+
+    pub(crate) fn set_link_detection(&mut self, entity: Entity, enabled: bool) {
+        if enabled {
+            if self.links.get(entity).is_none() {
+                self.links.insert(entity, Vec::new());
+            }
+        } else {
+            self.links.remove(entity);
+        }
+    }
+
+    pub(crate) fn has_links(&self, entity: Entity) -> bool {
+        self.links.get(entity).map_or(false, |links| !links.is_empty())
+    }
+
+    // Returns the URL of the link at a point in physical window coordinates.
+    pub(crate) fn link_at(&mut self, entity: Entity, x: f32, y: f32) -> Option<String> {
+        let cursor = self.hit_cursor(entity, x, y)?;
+
+        self.links.get(entity)?.iter().find_map(|link| {
+            (link.line == cursor.line && link.range.contains(&cursor.index))
+                .then(|| link.url.clone())
+        })
+    }
+
+    /// Restarts the caret blink cycle so that the caret is shown, e.g. after the caret has moved.
+    pub(crate) fn reset_caret_blink(&mut self) {
+        self.caret_blink_start = Instant::now();
+        self.hidden_caret = None;
+    }
+
+    /// Updates whether the caret of the given entity is in the hidden half of its blink cycle, where
+    /// `rate` is how long the caret is shown or hidden for. A zero rate disables blinking.
+    ///
+    /// Returns true if the visibility of the caret changed.
+    pub(crate) fn update_caret_blink(
+        &mut self,
+        entity: Entity,
+        rate: Duration,
+        time: Instant,
+    ) -> bool {
+        let hidden = !rate.is_zero()
+            && (time.duration_since(self.caret_blink_start).as_nanos() / rate.as_nanos()) % 2 == 1;
+        let hidden_caret = if hidden { Some(entity) } else { None };
+        let changed = hidden_caret != self.hidden_caret;
+        self.hidden_caret = hidden_caret;
+        changed
+    }
+
+    /// Returns the time at which the caret next toggles between shown and hidden, where `rate` is
+    /// how long the caret is shown or hidden for, or `None` if the caret does not blink.
+    pub(crate) fn next_caret_blink(&self, rate: Duration, time: Instant) -> Option<Instant> {
+        if rate.is_zero() {
+            return None;
+        }
+
+        let elapsed = time.duration_since(self.caret_blink_start).as_nanos();
+        let remaining = rate.as_nanos() - elapsed % rate.as_nanos();
+        Some(time + Duration::from_nanos(remaining as u64))
+    }
+
+    pub(crate) fn is_caret_hidden(&self, entity: Entity) -> bool {
+        self.hidden_caret == Some(entity)
+    }
+
+    /// Sync the style data from vizia with the style attribites stored in cosmic-text buffers, with the
+    /// scale factor of the window which contains the entity.
+    pub(crate) fn sync_styles(&mut self, entity: Entity, style: &Style, scale_factor: f32) {
         let (families, font_weight, font_style) = {
             let families = style
                 .font_family
@@ -192,10 +330,19 @@ impl TextContext {
             alignment = None;
         }
 
-        self.with_buffer(entity, |fs, buf| {
+        let detect_links = self.links.get(entity).is_some();
+        let link_color = style.link_color.get(entity).copied().unwrap_or(DEFAULT_LINK_COLOR);
+
+        let links = self.with_buffer(entity, |fs, buf| {
             let attrs = Attrs::new().family(family).weight(font_weight).style(font_style).color(
                 FontColor::rgba(font_color.r(), font_color.g(), font_color.b(), font_color.a()),
             );
+            let link_attrs = attrs.color(FontColor::rgba(
+                link_color.r(),
+                link_color.g(),
+                link_color.b(),
+                link_color.a(),
+            ));
 
             let wrap = if style.text_wrap.get(entity).copied().unwrap_or(true) {
                 Wrap::Word
@@ -203,19 +350,33 @@ impl TextContext {
                 Wrap::None
             };
             buf.set_wrap(fs, wrap);
-            for line in buf.lines.iter_mut() {
+            let mut links = Vec::new();
+            for (line_index, line) in buf.lines.iter_mut().enumerate() {
                 // TODO spans
-                line.set_attrs_list(AttrsList::new(attrs));
+                let mut attrs_list = AttrsList::new(attrs);
+                if detect_links {
+                    for (range, url) in detect_urls(line.text()) {
+                        attrs_list.add_span(range.clone(), link_attrs);
+                        links.push(TextLink { line: line_index, range, url });
+                    }
+                }
+                line.set_attrs_list(attrs_list);
                 line.set_align(alignment);
             }
-            let font_size = style.font_size.get(entity).copied().map(|f| f.0).unwrap_or(16.0)
-                * style.dpi_factor as f32;
+            let font_size =
+                style.font_size.get(entity).copied().map(|f| f.0).unwrap_or(16.0) * scale_factor;
             // TODO configurable line spacing
             buf.set_metrics(fs, Metrics::new(font_size, font_size * 1.25));
             // buf.set_size(fs, 200.0, 200.0);
             // buf.shape_until_scroll(fs);
             buf.shape_until(fs, i32::MAX);
+
+            links
         });
+
+        if detect_links {
+            self.links.insert(entity, links);
+        }
     }
 
     /// Generate a series of canvas path operations to render the text of a particular entity.
@@ -249,8 +410,9 @@ impl TextContext {
                 let (position_y, subpixel_y) = SubpixelBin::new(position_y);
                 cache_key.x_bin = subpixel_x;
                 cache_key.y_bin = subpixel_y;
+                let key = (cache_key, config);
                 // perform cache lookup for rendered glyph
-                let Some(rendered) = self.rendered_glyphs.entry(cache_key).or_insert_with(|| {
+                let Some(rendered) = self.rendered_glyphs.entry(key).or_insert_with(|| {
                         // ...or insert it
 
                         // do the actual rasterization
@@ -418,11 +580,14 @@ impl TextContext {
         })
     }
 
+    // Returns the bounds of the caret with the given shape. The width is the thickness of a bar or
+    // underline caret, while a block or underline caret covers the character at the caret.
     pub(crate) fn layout_caret(
         &mut self,
         entity: Entity,
         bounds: BoundingBox,
         justify: (f32, f32),
+        shape: CaretShape,
         width: f32,
     ) -> Option<(f32, f32, f32, f32)> {
         self.with_editor(entity, |_, buf| {
@@ -477,6 +642,15 @@ impl TextContext {
                 };
 
                 if let Some((cursor_glyph, cursor_glyph_offset)) = cursor_glyph_opt(&buf.cursor()) {
+                    // The width of the character at the caret, which is negative for right-to-left
+                    // text so that it extends to the left of the caret. Half of the font size is
+                    // used past the end of a line.
+                    let glyph_width = match run.glyphs.get(cursor_glyph) {
+                        Some(glyph) if glyph.level.is_rtl() => cursor_glyph_offset - glyph.w,
+                        Some(glyph) => glyph.w - cursor_glyph_offset,
+                        None => font_size / 2.0,
+                    };
+
                     let x = match run.glyphs.get(cursor_glyph) {
                         Some(glyph) => {
                             // Start of detected glyph
@@ -502,12 +676,21 @@ impl TextContext {
                         },
                     };
 
-                    return Some((
-                        x as f32 + position_x,
-                        (line_y - font_size) + position_y,
-                        width,
-                        line_height,
-                    ));
+                    let x = x as f32 + position_x;
+                    let y = (line_y - font_size) + position_y;
+
+                    return Some(match shape {
+                        CaretShape::Bar => (x, y, width, line_height),
+                        CaretShape::Block => {
+                            (x.min(x + glyph_width), y, glyph_width.abs(), line_height)
+                        }
+                        CaretShape::Underline => (
+                            x.min(x + glyph_width),
+                            y + line_height - width,
+                            glyph_width.abs(),
+                            width,
+                        ),
+                    });
                 }
             }
             None
@@ -534,10 +717,60 @@ impl TextContext {
             glyph_textures: vec![],
             buffers: HashMap::new(),
             bounds: SparseSet::new(),
+            draw_layouts: SparseSet::new(),
+            links: SparseSet::new(),
+            caret_blink_start: Instant::now(),
+            hidden_caret: None,
         }
     }
 }
 
+// A link detected in the text of an entity, with the byte range of the link within its line.
+struct TextLink {
+    line: usize,
+    range: Range<usize>,
+    url: String,
+}
+
+// Finds the URLs within a line of text, returning the byte range and text of each one. Surrounding
+// brackets, quotes, and trailing punctuation are not included in a URL.
+fn detect_urls(text: &str) -> Vec<(Range<usize>, String)> {
+    let mut urls = Vec::new();
+    let mut word_start = None;
+
+    for (index, c) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        if !c.is_whitespace() {
+            word_start.get_or_insert(index);
+            continue;
+        }
+
+        if let Some(start) = word_start.take() {
+            let word = &text[start..index];
+            let trimmed = word.trim_start_matches(&['(', '[', '<', '"', '\''][..]);
+            let start = start + word.len() - trimmed.len();
+            let url = trimmed
+                .trim_end_matches(&['.', ',', ';', ':', '!', '?', ')', ']', '>', '"', '\''][..]);
+
+            let is_url = ["https://", "http://", "www."]
+                .iter()
+                .any(|prefix| url.len() > prefix.len() && url.starts_with(prefix));
+
+            if is_url {
+                urls.push((start..start + url.len(), url.to_owned()));
+            }
+        }
+    }
+
+    urls
+}
+
+/// The rendered glyphs and glyph textures of a window other than the main window.
+#[derive(Default)]
+pub(crate) struct GlyphCache {
+    rendered_glyphs: FnvHashMap<(CacheKey, TextConfig), Option<RenderedGlyph>>,
+    glyph_textures: Vec<FontTexture>,
+}
+
 pub(crate) struct FontTexture {
     atlas: Atlas,
     image_id: ImageId,
@@ -554,3 +787,47 @@ pub(crate) struct RenderedGlyph {
     atlas_y: u32,
     color_glyph: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_urls_boundaries() {
+        let text = "see https://vizia.dev and www.example.com/docs";
+        assert_eq!(
+            detect_urls(text),
+            vec![
+                (4..21, "https://vizia.dev".to_owned()),
+                (26..46, "www.example.com/docs".to_owned()),
+            ]
+        );
+
+        // A URL at the very start or end of the line, and a prefix on its own, which is not a URL.
+        assert_eq!(detect_urls("http://a"), vec![(0..8, "http://a".to_owned())]);
+        assert_eq!(detect_urls("https:// www."), vec![]);
+        assert_eq!(detect_urls("no links here"), vec![]);
+
+        // Ranges are in bytes rather than characters.
+        assert_eq!(detect_urls("→ https://a.b"), vec![(4..15, "https://a.b".to_owned())]);
+    }
+
+    #[test]
+    fn detect_urls_punctuation() {
+        let text = "(https://vizia.dev), \"www.example.com\". <http://a.b/c?d=e>!";
+        assert_eq!(
+            detect_urls(text),
+            vec![
+                (1..18, "https://vizia.dev".to_owned()),
+                (22..37, "www.example.com".to_owned()),
+                (41..57, "http://a.b/c?d=e".to_owned()),
+            ]
+        );
+
+        // Punctuation within a URL is kept.
+        assert_eq!(
+            detect_urls("https://vizia.dev/a.b,c"),
+            vec![(0..23, "https://vizia.dev/a.b,c".to_owned())]
+        );
+    }
+}
@@ -1,6 +1,9 @@
 mod movement;
 pub use movement::*;
 
+mod bindings;
+pub use bindings::*;
+
 pub(crate) mod scrolling;
 pub(crate) use scrolling::*;
 
@@ -1,3 +1,5 @@
+use unicode_segmentation::UnicodeSegmentation;
+
 #[derive(Debug, Clone, Copy)]
 pub enum Direction {
     Left,
@@ -27,3 +29,61 @@ pub enum VerticalMovement {
     DocumentStart,
     DocumentEnd,
 }
+
+/// Returns the byte index of the start of the word before `index` in `text`.
+///
+/// Words are found using Unicode word segmentation (UAX #29), so punctuation and scripts which
+/// don't separate words with spaces are handled. Whitespace between words is skipped.
+pub fn prev_word_boundary(text: &str, index: usize) -> usize {
+    text.split_word_bound_indices()
+        .filter(|(start, word)| *start < index && !word.chars().all(char::is_whitespace))
+        .last()
+        .map(|(start, _)| start)
+        .unwrap_or(0)
+}
+
+/// Returns the byte index of the end of the word after `index` in `text`.
+///
+/// Words are found using Unicode word segmentation (UAX #29), so punctuation and scripts which
+/// don't separate words with spaces are handled. Whitespace between words is skipped.
+pub fn next_word_boundary(text: &str, index: usize) -> usize {
+    text.split_word_bound_indices()
+        .map(|(start, word)| (start + word.len(), word))
+        .find(|(end, word)| *end > index && !word.chars().all(char::is_whitespace))
+        .map(|(end, _)| end)
+        .unwrap_or(text.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_boundaries_punctuation() {
+        let text = "foo.bar baz";
+        assert_eq!(next_word_boundary(text, 0), 7);
+        assert_eq!(next_word_boundary(text, 7), 11);
+        assert_eq!(next_word_boundary(text, 11), 11);
+        assert_eq!(prev_word_boundary(text, 11), 8);
+        assert_eq!(prev_word_boundary(text, 8), 0);
+        assert_eq!(prev_word_boundary(text, 0), 0);
+
+        let text = "foo, bar";
+        assert_eq!(next_word_boundary(text, 0), 3);
+        assert_eq!(next_word_boundary(text, 3), 4);
+        assert_eq!(next_word_boundary(text, 4), 8);
+        assert_eq!(prev_word_boundary(text, 8), 5);
+        assert_eq!(prev_word_boundary(text, 5), 3);
+    }
+
+    #[test]
+    fn word_boundaries_cjk() {
+        // Each ideograph is its own word while katakana runs are grouped.
+        let text = "日本語 テキスト";
+        assert_eq!(next_word_boundary(text, 0), 3);
+        assert_eq!(next_word_boundary(text, 6), 9);
+        assert_eq!(next_word_boundary(text, 9), 22);
+        assert_eq!(prev_word_boundary(text, 22), 10);
+        assert_eq!(prev_word_boundary(text, 10), 6);
+    }
+}
@@ -26,11 +26,21 @@ pub(crate) fn is_navigatable(cx: &Context, node: Entity, lock_focus_to: Entity)
         return false;
     }
 
+    // Skip nodes in other windows
+    if cx.tree.window(node) != cx.tree.window(lock_focus_to) {
+        return false;
+    }
+
     // Skip ignored widgets
     if cx.tree.is_ignored(node) {
         return false;
     }
 
+    // Skip focus scopes, which are navigated to through the views within them
+    if cx.tree.is_focus_scope(node) {
+        return false;
+    }
+
     cx.style
         .abilities
         .get(node)
@@ -39,10 +49,14 @@ pub(crate) fn is_navigatable(cx: &Context, node: Entity, lock_focus_to: Entity)
 }
 
 /// Get the next entity to be focused during forward keyboard navigation.
+///
+/// Navigating forward from a focus scope skips the views within it.
 pub(crate) fn focus_forward(cx: &Context, node: Entity, lock_focus_to: Entity) -> Option<Entity> {
+    let skip_scope = cx.tree.is_focus_scope(node);
     TreeIterator::new(&cx.tree, DoubleEndedTreeTour::new(Some(node), Some(Entity::root())))
         .skip(1)
-        .find(|node| is_navigatable(cx, *node, lock_focus_to))
+        .filter(|next| !skip_scope || !next.is_descendant_of(&cx.tree, node))
+        .find(|next| is_navigatable(cx, *next, lock_focus_to))
 }
 
 /// Get the next entity to be focused during backward keybaord navigation.
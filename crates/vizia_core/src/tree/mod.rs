@@ -3,3 +3,31 @@ pub(crate) use focus_iter::*;
 
 // Re-export tree
 pub use vizia_storage::{ChildIterator, ParentIterator, Tree, TreeExt};
+
+use crate::entity::Entity;
+use std::collections::HashSet;
+
+/// Returns an entity and its descendants in tree order, followed by the entities it or any of its
+/// descendants [own](Tree::owner), such as the content of a portal, along with their descendants.
+pub(crate) fn branch_with_owned(tree: &Tree<Entity>, entity: Entity) -> Vec<Entity> {
+    let mut branch = entity.branch_iter(tree).collect::<Vec<_>>();
+    let mut visited = branch.iter().copied().collect::<HashSet<_>>();
+
+    let mut index = 0;
+    while index < branch.len() {
+        let owned = tree.owned_by(branch[index]).collect::<Vec<_>>();
+        for owned in owned {
+            if !visited.contains(&owned) {
+                for descendant in owned.branch_iter(tree) {
+                    if visited.insert(descendant) {
+                        branch.push(descendant);
+                    }
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    branch
+}
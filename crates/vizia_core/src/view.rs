@@ -15,11 +15,15 @@
 use crate::context::AccessNode;
 use crate::model::ModelDataStore;
 use crate::prelude::*;
-use crate::systems::get_access_node;
+use crate::systems::{access_children, get_access_node};
 use crate::{accessibility::IntoNode, context::AccessContext};
 use std::any::Any;
+mod effect;
 mod handle;
+mod shader;
+pub use effect::ShaderEffect;
 pub use handle::Handle;
+pub use shader::{FragmentShader, ShaderContext, ShaderUniform};
 
 use crate::events::ViewHandler;
 use accesskit::{NodeBuilder, TreeUpdate};
@@ -128,8 +132,7 @@ pub trait View: 'static + Sized {
         let parent_id = cx.tree.get_layout_parent(id).unwrap();
         let parent_node_id = parent_id.accesskit_id();
         let node_id = id.accesskit_id();
-        let children =
-            parent_id.child_iter(&cx.tree).map(|entity| entity.accesskit_id()).collect::<Vec<_>>();
+        let children = access_children(&cx.tree, parent_id);
 
         let mut access_context = AccessContext {
             current: id,
@@ -290,6 +293,10 @@ where
         <T as View>::element(self)
     }
 
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         <T as View>::event(self, cx, event);
     }
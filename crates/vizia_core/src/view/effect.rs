@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use femtovg::ImageId;
+
+/// A custom effect which post-processes the rendered output of a view and its descendants.
+///
+/// A view with an effect is drawn, along with its children, into an offscreen layer image the size
+/// of the window. The effect then processes the layer into a second image, which is drawn to the
+/// window in place of the view. Both images are cached and reused between frames.
+///
+/// A GLSL fragment shader can be used as an effect with [`FragmentShader`]. Otherwise, effects are
+/// written with canvas operations on the layer image, such as
+/// [`filter_image`](femtovg::Canvas::filter_image), image paints and composite operations. The
+/// parameters of an effect, the equivalent of shader uniforms, are the fields of the type
+/// implementing the trait, and can be changed by setting a new effect with
+/// [`StyleModifiers::shader_effect`].
+///
+/// Children with a higher z-index than the view are drawn after the layer and are not affected by
+/// the effect.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::vg;
+/// # let cx = &mut Context::default();
+/// pub struct Glow {
+///     sigma: f32,
+/// }
+///
+/// impl ShaderEffect for Glow {
+///     fn apply(&self, canvas: &mut Canvas, input: vg::ImageId, output: vg::ImageId, _: BoundingBox) {
+///         canvas.filter_image(output, vg::ImageFilter::GaussianBlur { sigma: self.sigma }, input);
+///
+///         // Draw the original content over the blurred copy.
+///         let (width, height) = canvas.image_size(input).unwrap();
+///         let (width, height) = (width as f32, height as f32);
+///         let mut path = vg::Path::new();
+///         path.rect(0.0, 0.0, width, height);
+///         canvas.fill_path(&path, &vg::Paint::image(input, 0.0, 0.0, width, height, 0.0, 1.0));
+///     }
+/// }
+///
+/// Label::new(cx, "Glowing").shader_effect(Glow { sigma: 4.0 });
+/// ```
+pub trait ShaderEffect {
+    /// Processes the `input` layer image, which contains the view and its descendants, into the
+    /// `output` layer image.
+    ///
+    /// When called, the render target of the canvas is the `output` image, which has been cleared,
+    /// and the transform and scissor of the canvas have been reset. Both images are the size of the
+    /// window and `bounds` is the bounding box of the view within them, in physical pixels.
+    fn apply(&self, canvas: &mut Canvas, input: ImageId, output: ImageId, bounds: BoundingBox);
+
+    /// Returns the fragment shader to run in place of [`apply`](ShaderEffect::apply), if any.
+    ///
+    /// [`apply`](ShaderEffect::apply) is still called if the shader can't be run by the window.
+    fn fragment_shader(&self) -> Option<&FragmentShader> {
+        None
+    }
+}
@@ -1,4 +1,7 @@
+use crate::cache::ViewportObserver;
 use crate::prelude::*;
+use crate::systems::KeyRepeat;
+use instant::Duration;
 use std::{
     any::{Any, TypeId},
     marker::PhantomData,
@@ -67,6 +70,52 @@ impl<'a, V> Handle<'a, V> {
         self
     }
 
+    /// Marks the view as a focus scope, which is useful for composite views like a date picker.
+    ///
+    /// Once focus is within the scope, tab moves between the views within it, wrapping around at
+    /// either end unless disabled with [`focus_wrap`](Self::focus_wrap). Pressing escape returns
+    /// focus to the scope itself, from which tab moves on to the views after the scope. Unlike
+    /// [`lock_focus_to_within`](Self::lock_focus_to_within), focus can still be moved out of the
+    /// scope with the mouse or programmatically.
+    pub fn focus_scope(self, flag: bool) -> Self {
+        self.cx.tree.set_focus_scope(self.entity, flag);
+        self
+    }
+
+    /// Sets whether tab navigation wraps around from the last view to the first, and from the first
+    /// view to the last, within the [focus scope](Self::focus_scope) or
+    /// [locked subtree](Self::lock_focus_to_within) of the view. When not wrapping, focus stops at
+    /// the first and last views.
+    ///
+    /// Overrides the application setting from [`Context::set_focus_wrap`] for this view.
+    pub fn focus_wrap(self, flag: bool) -> Self {
+        self.cx.tree.set_focus_wrap(self.entity, Some(flag));
+        self
+    }
+
+    /// Sends [`WindowEvent::EnteredViewport`] and [`WindowEvent::LeftViewport`] events to the view when
+    /// it enters or leaves the visible area of the window, e.g. when scrolled within a [`ScrollView`].
+    ///
+    /// The threshold is the proportion of the view, between 0.0 and 1.0, which must be visible for it
+    /// to be in the viewport. A threshold of 0.0 means any part of the view is visible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).observe_viewport(0.5);
+    /// ```
+    pub fn observe_viewport(self, threshold: f32) -> Self {
+        self.cx.cache.viewport_observers.insert(
+            self.entity,
+            ViewportObserver { threshold: threshold.clamp(0.0, 1.0), margin: 0.0, visible: false },
+        );
+        self.cx.needs_relayout();
+
+        self
+    }
+
     /// Mody the internal data of the view.
     pub fn modify<F>(self, f: F) -> Self
     where
@@ -85,6 +134,77 @@ impl<'a, V> Handle<'a, V> {
         self
     }
 
+    /// Adds a filter which is called with every event sent to the view before it reaches the models
+    /// and the view itself. Returning `false` from the filter drops the event for this view.
+    ///
+    /// See [`Context::add_event_filter`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// // Prevent the button from being pressed while still allowing it to be hovered.
+    /// Button::new(cx, |_| {}, |cx| Label::new(cx, "Press me")).event_filter(|_, event| {
+    ///     let mut pass = true;
+    ///     event.map(|window_event, _| match window_event {
+    ///         WindowEvent::Press { .. } | WindowEvent::PressDown { .. } => pass = false,
+    ///         _ => {}
+    ///     });
+    ///     pass
+    /// });
+    /// ```
+    pub fn event_filter<F>(self, filter: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &mut Event) -> bool,
+    {
+        self.cx.with_current(self.entity, |cx| cx.add_event_filter(filter));
+
+        self
+    }
+
+    /// Runs an action while a key is held down and the view, or one of its descendants, has
+    /// keyboard focus.
+    ///
+    /// The action runs once when the key is pressed, again after `delay`, and then once every
+    /// `interval` until the key is released. The timing does not depend on the key repeat settings
+    /// of the operating system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use instant::Duration;
+    /// # let cx = &mut Context::default();
+    /// # #[derive(Lens)]
+    /// # struct AppData { value: i32 }
+    /// # impl Model for AppData {}
+    /// # AppData { value: 0 }.build(cx);
+    /// # enum AppEvent { Increment }
+    /// Label::new(cx, AppData::value).focusable(true).repeat_key(
+    ///     Code::ArrowUp,
+    ///     Duration::from_millis(400),
+    ///     Duration::from_millis(50),
+    ///     |cx| cx.emit(AppEvent::Increment),
+    /// );
+    /// ```
+    pub fn repeat_key<F>(self, code: Code, delay: Duration, interval: Duration, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext),
+    {
+        self.cx.key_repeats.push(KeyRepeat {
+            entity: self.entity,
+            code,
+            delay,
+            interval,
+            action: Box::new(action),
+            next: None,
+            first: false,
+        });
+
+        self
+    }
+
     /// Callback which is run when the view is built/rebuilt.
     pub fn on_build<F>(self, callback: F) -> Self
     where
@@ -137,4 +257,14 @@ impl<'a, V> Handle<'a, V> {
     pub fn scale_factor(&self) -> f32 {
         self.cx.scale_factor()
     }
+
+    /// Converts logical points to physical pixels using the current scale factor.
+    pub fn logical_to_physical(&self, logical: f32) -> f32 {
+        self.cx.logical_to_physical(logical)
+    }
+
+    /// Converts physical pixels to logical points using the current scale factor.
+    pub fn physical_to_logical(&self, physical: f32) -> f32 {
+        self.cx.physical_to_logical(physical)
+    }
 }
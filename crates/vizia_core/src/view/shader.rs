@@ -0,0 +1,440 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+use femtovg::ImageId;
+
+/// A [`ShaderEffect`] which runs a GLSL fragment shader over the layer of a view.
+///
+/// The source of the shader is compiled for the OpenGL version of the window, with a version
+/// directive and the following declarations prepended:
+///
+/// ```glsl
+/// // The layer containing the view and its descendants, with premultiplied alpha.
+/// uniform sampler2D u_input;
+/// // The size of the layer, which is the size of the window, in physical pixels.
+/// uniform vec2 u_resolution;
+/// // The bounding box of the view within the layer, as (x, y, width, height) in physical pixels.
+/// uniform vec4 u_bounds;
+/// // The coordinates of the fragment within `u_input`.
+/// in vec2 v_uv;
+/// // The position of the fragment in physical pixels from the top-left corner of the window.
+/// in vec2 v_position;
+/// // The color of the fragment, with premultiplied alpha.
+/// out vec4 f_color;
+/// ```
+///
+/// Any other uniforms are set with [`uniform`](FragmentShader::uniform), and can be changed by
+/// setting a new effect with [`StyleModifiers::shader_effect`].
+///
+/// The shader is compiled the first time it is drawn. If it fails to compile the error is printed,
+/// and the view is drawn without the effect, as it is if the windowing backend does not support
+/// custom shaders.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// let tint = FragmentShader::new(
+///     "void main() {
+///         vec4 color = texture(u_input, v_uv);
+///         f_color = vec4(color.rgb * u_tint, color.a);
+///     }",
+/// )
+/// .uniform("u_tint", [1.0, 0.5, 0.5]);
+///
+/// Label::new(cx, "Tinted").shader_effect(tint);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FragmentShader {
+    source: String,
+    uniforms: Vec<(String, ShaderUniform)>,
+}
+
+impl FragmentShader {
+    /// Creates a fragment shader from its GLSL source.
+    pub fn new(source: impl Into<String>) -> Self {
+        Self { source: source.into(), uniforms: Vec::new() }
+    }
+
+    /// Sets the value of a uniform declared by the shader.
+    pub fn uniform(mut self, name: impl Into<String>, value: impl Into<ShaderUniform>) -> Self {
+        let name = name.into();
+        let value = value.into();
+        if let Some(uniform) = self.uniforms.iter_mut().find(|(n, _)| *n == name) {
+            uniform.1 = value;
+        } else {
+            self.uniforms.push((name, value));
+        }
+
+        self
+    }
+}
+
+impl ShaderEffect for FragmentShader {
+    // Draws the layer unchanged, for when the shader can't be run.
+    fn apply(&self, canvas: &mut Canvas, input: ImageId, _: ImageId, _: BoundingBox) {
+        if let Ok((width, height)) = canvas.image_size(input) {
+            let (width, height) = (width as f32, height as f32);
+            let mut path = femtovg::Path::new();
+            path.rect(0.0, 0.0, width, height);
+            canvas
+                .fill_path(&path, &femtovg::Paint::image(input, 0.0, 0.0, width, height, 0.0, 1.0));
+        }
+    }
+
+    fn fragment_shader(&self) -> Option<&FragmentShader> {
+        Some(self)
+    }
+}
+
+/// The value of a uniform of a [`FragmentShader`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShaderUniform {
+    Float(f32),
+    Vec2([f32; 2]),
+    Vec3([f32; 3]),
+    Vec4([f32; 4]),
+}
+
+impl From<f32> for ShaderUniform {
+    fn from(value: f32) -> Self {
+        ShaderUniform::Float(value)
+    }
+}
+
+impl From<[f32; 2]> for ShaderUniform {
+    fn from(value: [f32; 2]) -> Self {
+        ShaderUniform::Vec2(value)
+    }
+}
+
+impl From<[f32; 3]> for ShaderUniform {
+    fn from(value: [f32; 3]) -> Self {
+        ShaderUniform::Vec3(value)
+    }
+}
+
+impl From<[f32; 4]> for ShaderUniform {
+    fn from(value: [f32; 4]) -> Self {
+        ShaderUniform::Vec4(value)
+    }
+}
+
+// Draws a triangle which covers the whole target, with texture coordinates from 0 to 1 across it.
+const VERTEX_SHADER: &str = "
+out vec2 v_uv;
+out vec2 v_position;
+uniform vec2 u_resolution;
+
+void main() {
+    v_uv = vec2(float((gl_VertexID << 1) & 2), float(gl_VertexID & 2));
+    // Layers are drawn upside down, with the top of the window at the top of the texture.
+    v_position = vec2(v_uv.x, 1.0 - v_uv.y) * u_resolution;
+    gl_Position = vec4(v_uv * 2.0 - 1.0, 0.0, 1.0);
+}
+";
+
+const FRAGMENT_DECLARATIONS: &str = "
+uniform sampler2D u_input;
+uniform vec2 u_resolution;
+uniform vec4 u_bounds;
+in vec2 v_uv;
+in vec2 v_position;
+out vec4 f_color;
+";
+
+/// The OpenGL context of a window, used to run the [`FragmentShader`] effects of its views.
+///
+/// This is created by windowing backends for each window which supports custom shaders, and is
+/// given to the context with
+/// [`set_shader_context`](crate::backend::BackendContext::set_shader_context).
+pub struct ShaderContext {
+    gl: glow::Context,
+    // The program compiled from each shader source, or `None` if it failed to compile.
+    programs: HashMap<String, Option<glow::Program>>,
+}
+
+impl ShaderContext {
+    /// Loads the OpenGL functions used to run shaders using the given function loader.
+    ///
+    /// # Safety
+    ///
+    /// The loader must return function pointers for the OpenGL context of the window, which must be
+    /// current whenever the window is drawn.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub unsafe fn from_gl_loader(mut load: impl FnMut(&str) -> *const std::ffi::c_void) -> Self {
+        Self { gl: glow::Context::from_loader_function(|s| load(s)), programs: HashMap::new() }
+    }
+
+    /// Runs the shader over the `input` layer image, writing the result to the `output` layer image,
+    /// and returns whether the shader was run.
+    pub(crate) fn run(
+        &mut self,
+        canvas: &mut Canvas,
+        shader: &FragmentShader,
+        input: ImageId,
+        output: ImageId,
+        bounds: BoundingBox,
+    ) -> bool {
+        let program = match self.program(&shader.source) {
+            Some(program) => program,
+            None => return false,
+        };
+
+        let (input_texture, output_texture, (width, height)) = match (
+            canvas.get_native_texture(input),
+            canvas.get_native_texture(output),
+            canvas.image_size(output),
+        ) {
+            (Ok(input), Ok(output), Ok(size)) => (input, output, size),
+            _ => return false,
+        };
+
+        // The layer must be drawn before it is read by the shader.
+        canvas.flush();
+
+        unsafe { self.draw(program, shader, input_texture, output_texture, width, height, bounds) }
+    }
+
+    // Drops the context without deleting its programs, which belong to a lost OpenGL context.
+    pub(crate) fn discard(mut self) {
+        self.programs.clear();
+    }
+
+    // Returns the program compiled from the given source, compiling it if it has not been yet.
+    fn program(&mut self, source: &str) -> Option<glow::Program> {
+        if let Some(program) = self.programs.get(source) {
+            return *program;
+        }
+
+        let program = match unsafe { compile_program(&self.gl, source) } {
+            Ok(program) => Some(program),
+            Err(err) => {
+                log::warn!("Failed to compile fragment shader: {}", err);
+                None
+            }
+        };
+
+        self.programs.insert(source.to_owned(), program);
+        program
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn draw(
+        &self,
+        program: glow::Program,
+        shader: &FragmentShader,
+        input: glow::Texture,
+        output: glow::Texture,
+        width: usize,
+        height: usize,
+        bounds: BoundingBox,
+    ) -> bool {
+        use glow::HasContext;
+        use std::num::NonZeroU32;
+
+        let gl = &self.gl;
+
+        let (framebuffer, vertex_array) = match (gl.create_framebuffer(), gl.create_vertex_array())
+        {
+            (Ok(framebuffer), Ok(vertex_array)) => (framebuffer, vertex_array),
+            (framebuffer, vertex_array) => {
+                if let Ok(framebuffer) = framebuffer {
+                    gl.delete_framebuffer(framebuffer);
+                }
+                if let Ok(vertex_array) = vertex_array {
+                    gl.delete_vertex_array(vertex_array);
+                }
+                return false;
+            }
+        };
+
+        // Save the state which the renderer expects to be unchanged.
+        let previous_framebuffer = gl.get_parameter_i32(glow::FRAMEBUFFER_BINDING);
+        let previous_program = gl.get_parameter_i32(glow::CURRENT_PROGRAM);
+        let previous_vertex_array = gl.get_parameter_i32(glow::VERTEX_ARRAY_BINDING);
+        let previous_active_texture = gl.get_parameter_i32(glow::ACTIVE_TEXTURE);
+        gl.active_texture(glow::TEXTURE0);
+        let previous_texture = gl.get_parameter_i32(glow::TEXTURE_BINDING_2D);
+        let mut previous_viewport = [0; 4];
+        gl.get_parameter_i32_slice(glow::VIEWPORT, &mut previous_viewport);
+        let capabilities = [
+            glow::BLEND,
+            glow::CULL_FACE,
+            glow::DEPTH_TEST,
+            glow::SCISSOR_TEST,
+            glow::STENCIL_TEST,
+        ]
+        .map(|capability| (capability, gl.is_enabled(capability)));
+
+        gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+        gl.framebuffer_texture_2d(
+            glow::FRAMEBUFFER,
+            glow::COLOR_ATTACHMENT0,
+            glow::TEXTURE_2D,
+            Some(output),
+            0,
+        );
+        gl.bind_vertex_array(Some(vertex_array));
+        for (capability, _) in capabilities {
+            gl.disable(capability);
+        }
+        gl.viewport(0, 0, width as i32, height as i32);
+
+        gl.use_program(Some(program));
+        gl.bind_texture(glow::TEXTURE_2D, Some(input));
+        gl.uniform_1_i32(gl.get_uniform_location(program, "u_input").as_ref(), 0);
+        gl.uniform_2_f32(
+            gl.get_uniform_location(program, "u_resolution").as_ref(),
+            width as f32,
+            height as f32,
+        );
+        gl.uniform_4_f32(
+            gl.get_uniform_location(program, "u_bounds").as_ref(),
+            bounds.x,
+            bounds.y,
+            bounds.w,
+            bounds.h,
+        );
+        for (name, value) in shader.uniforms.iter() {
+            let location = gl.get_uniform_location(program, name);
+            match *value {
+                ShaderUniform::Float(x) => gl.uniform_1_f32(location.as_ref(), x),
+                ShaderUniform::Vec2([x, y]) => gl.uniform_2_f32(location.as_ref(), x, y),
+                ShaderUniform::Vec3([x, y, z]) => gl.uniform_3_f32(location.as_ref(), x, y, z),
+                ShaderUniform::Vec4([x, y, z, w]) => {
+                    gl.uniform_4_f32(location.as_ref(), x, y, z, w)
+                }
+            }
+        }
+
+        gl.draw_arrays(glow::TRIANGLES, 0, 3);
+
+        // Restore the state of the renderer.
+        gl.bind_framebuffer(
+            glow::FRAMEBUFFER,
+            NonZeroU32::new(previous_framebuffer as u32).map(glow::NativeFramebuffer),
+        );
+        gl.use_program(NonZeroU32::new(previous_program as u32).map(glow::NativeProgram));
+        gl.bind_vertex_array(
+            NonZeroU32::new(previous_vertex_array as u32).map(glow::NativeVertexArray),
+        );
+        gl.bind_texture(
+            glow::TEXTURE_2D,
+            NonZeroU32::new(previous_texture as u32).map(glow::NativeTexture),
+        );
+        gl.active_texture(previous_active_texture as u32);
+        gl.viewport(
+            previous_viewport[0],
+            previous_viewport[1],
+            previous_viewport[2],
+            previous_viewport[3],
+        );
+        for (capability, enabled) in capabilities {
+            if enabled {
+                gl.enable(capability);
+            }
+        }
+
+        gl.delete_vertex_array(vertex_array);
+        gl.delete_framebuffer(framebuffer);
+
+        true
+    }
+
+    // Custom shaders are not supported on the web.
+    #[cfg(target_arch = "wasm32")]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn draw(
+        &self,
+        _: glow::Program,
+        _: &FragmentShader,
+        _: glow::Texture,
+        _: glow::Texture,
+        _: usize,
+        _: usize,
+        _: BoundingBox,
+    ) -> bool {
+        false
+    }
+}
+
+impl Drop for ShaderContext {
+    fn drop(&mut self) {
+        use glow::HasContext;
+
+        for program in self.programs.values().flatten() {
+            unsafe { self.gl.delete_program(*program) };
+        }
+    }
+}
+
+// Compiles the program of a fragment shader with the declarations of its inputs and outputs.
+unsafe fn compile_program(gl: &glow::Context, source: &str) -> Result<glow::Program, String> {
+    use glow::HasContext;
+
+    let version = gl.version();
+    let header = if version.is_embedded {
+        "#version 300 es\nprecision highp float;\n"
+    } else if (version.major, version.minor) >= (3, 2) {
+        "#version 150\n"
+    } else if version.major >= 3 {
+        "#version 130\n"
+    } else {
+        return Err("custom shaders require OpenGL 3.0 or later".to_owned());
+    };
+
+    let program = gl.create_program()?;
+
+    let stages = [
+        (glow::VERTEX_SHADER, format!("{}{}", header, VERTEX_SHADER)),
+        (glow::FRAGMENT_SHADER, format!("{}{}{}", header, FRAGMENT_DECLARATIONS, source)),
+    ];
+
+    // The shaders which have been attached to the program.
+    let mut shaders = Vec::new();
+    let mut result = Ok(());
+    for (stage, source) in stages.iter() {
+        let shader = match gl.create_shader(*stage) {
+            Ok(shader) => shader,
+            Err(err) => {
+                result = Err(err);
+                break;
+            }
+        };
+
+        gl.shader_source(shader, source);
+        gl.compile_shader(shader);
+
+        if !gl.get_shader_compile_status(shader) {
+            result = Err(gl.get_shader_info_log(shader));
+            gl.delete_shader(shader);
+            break;
+        }
+
+        gl.attach_shader(program, shader);
+        shaders.push(shader);
+    }
+
+    if result.is_ok() {
+        gl.link_program(program);
+        if !gl.get_program_link_status(program) {
+            result = Err(gl.get_program_info_log(program));
+        }
+    }
+
+    for shader in shaders {
+        gl.detach_shader(program, shader);
+        gl.delete_shader(shader);
+    }
+
+    match result {
+        Ok(()) => Ok(program),
+        Err(err) => {
+            gl.delete_program(program);
+            Err(err)
+        }
+    }
+}
@@ -113,15 +113,11 @@ impl View for Button {
                 cx.release();
             }
 
-            WindowEvent::ActionRequest(action) => match action.action {
-                Action::Default => {
-                    if let Some(callback) = &self.action {
-                        (callback)(cx);
-                    }
+            WindowEvent::ActionDefault => {
+                if let Some(callback) = &self.action {
+                    (callback)(cx);
                 }
-
-                _ => {}
-            },
+            }
 
             _ => {}
         });
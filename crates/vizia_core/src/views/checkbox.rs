@@ -220,15 +220,11 @@ impl View for Checkbox {
                 }
             }
 
-            WindowEvent::ActionRequest(action) => match action.action {
-                Action::Default => {
-                    if let Some(callback) = &self.on_toggle {
-                        (callback)(cx);
-                    }
+            WindowEvent::ActionDefault => {
+                if let Some(callback) = &self.on_toggle {
+                    (callback)(cx);
                 }
-
-                _ => {}
-            },
+            }
 
             _ => {}
         });
@@ -1,7 +1,12 @@
 use vizia_style::Url;
 
+use crate::cache::ViewportObserver;
 use crate::prelude::*;
 
+/// A view which displays an image.
+///
+/// The image is drawn as the background image of the view, so the background color of the view is
+/// shown while the image is loading.
 pub struct Image {}
 
 impl Image {
@@ -12,8 +17,41 @@ impl Image {
     }
 }
 
+impl<'a> Handle<'a, Image> {
+    /// Defers loading the image until the view is within `margin` logical pixels of the visible
+    /// area of the window, such as when it is scrolled into view within a [`ScrollView`].
+    ///
+    /// If `unload` is true then the image is also unloaded from the canvas once the view is further
+    /// away, which limits the graphics memory used by long lists of images. The decoded image is
+    /// kept until it is dropped by its retention policy, after which it is loaded again through the
+    /// image loader of the application when the view comes back near the viewport.
+    pub fn lazy(self, margin: f32, unload: bool) -> Self {
+        let margin = self.cx.logical_to_physical(margin);
+        self.cx
+            .cache
+            .viewport_observers
+            .insert(self.entity, ViewportObserver { threshold: 0.0, margin, visible: false });
+        self.cx.cache.lazy_images.insert(self.entity, unload);
+        self.cx.needs_relayout();
+
+        self
+    }
+}
+
 impl View for Image {
     fn element(&self) -> Option<&'static str> {
         Some("image")
     }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            // Lazy images are loaded or unloaded by the image system when styles are next updated.
+            WindowEvent::EnteredViewport | WindowEvent::LeftViewport => {
+                cx.style.needs_restyle();
+                cx.needs_redraw();
+            }
+
+            _ => {}
+        });
+    }
 }
@@ -10,6 +10,7 @@ static DEFAULT_DRAG_SCALAR: f32 = 0.0042;
 static DEFAULT_WHEEL_SCALAR: f32 = 0.005;
 static DEFAULT_ARROW_SCALAR: f32 = 0.1;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.04;
+static DEFAULT_PAGE_SCALAR: f32 = 0.25;
 
 use std::f32::consts::PI;
 
@@ -24,6 +25,8 @@ pub struct Knob<L> {
     drag_scalar: f32,
     wheel_scalar: f32,
     arrow_scalar: f32,
+    fine_arrow_scalar: Option<f32>,
+    page_scalar: f32,
     modifier_scalar: f32,
 
     on_changing: Option<Box<dyn Fn(&mut EventContext, f32)>>,
@@ -47,6 +50,8 @@ impl<L: Lens<Target = f32>> Knob<L> {
             drag_scalar: DEFAULT_DRAG_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
             arrow_scalar: DEFAULT_ARROW_SCALAR,
+            fine_arrow_scalar: None,
+            page_scalar: DEFAULT_PAGE_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
 
             on_changing: None,
@@ -95,6 +100,8 @@ impl<L: Lens<Target = f32>> Knob<L> {
             drag_scalar: DEFAULT_DRAG_SCALAR,
             wheel_scalar: DEFAULT_WHEEL_SCALAR,
             arrow_scalar: DEFAULT_ARROW_SCALAR,
+            fine_arrow_scalar: None,
+            page_scalar: DEFAULT_PAGE_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
 
             on_changing: None,
@@ -120,6 +127,24 @@ impl<'a, L: Lens<Target = f32>> Handle<'a, Knob<L>> {
 
         self
     }
+
+    /// Sets the amount, as a proportion of the range of the knob, that a press of an arrow key changes
+    /// the value by. Defaults to 0.1.
+    pub fn step(self, step: f32) -> Self {
+        self.modify(|knob: &mut Knob<L>| knob.arrow_scalar = step)
+    }
+
+    /// Sets the amount, as a proportion of the range of the knob, that a press of an arrow key changes
+    /// the value by while holding shift. Defaults to a tenth of the step.
+    pub fn fine_step(self, fine_step: f32) -> Self {
+        self.modify(|knob: &mut Knob<L>| knob.fine_arrow_scalar = Some(fine_step))
+    }
+
+    /// Sets the amount, as a proportion of the range of the knob, that a press of Page Up or Page Down
+    /// changes the value by. Defaults to 0.25.
+    pub fn page_step(self, page_step: f32) -> Self {
+        self.modify(|knob: &mut Knob<L>| knob.page_scalar = page_step)
+    }
 }
 
 impl<L: Lens<Target = f32>> View for Knob<L> {
@@ -136,7 +161,7 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
             }
         };
 
-        event.map(|window_event, _| match window_event {
+        event.map(|window_event, meta| match window_event {
             WindowEvent::MouseDown(button) if *button == MouseButton::Left => {
                 self.is_dragging = true;
                 self.prev_drag_y = cx.mouse.left.pos_down.1;
@@ -187,14 +212,32 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
                 move_virtual_slider(self, cx, self.default_normal);
             }
 
-            WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
-                self.continuous_normal = self.lens.get(cx);
-                move_virtual_slider(self, cx, self.continuous_normal + self.arrow_scalar);
-            }
+            // Holding shift gives finer steps for the arrow keys, and Home and End set the knob to
+            // the minimum and maximum.
+            WindowEvent::KeyDown(code, _) => {
+                let arrow_scalar = if cx.modifiers.contains(Modifiers::SHIFT) {
+                    self.fine_arrow_scalar.unwrap_or(self.arrow_scalar / 10.0)
+                } else {
+                    self.arrow_scalar
+                };
 
-            WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _) => {
                 self.continuous_normal = self.lens.get(cx);
-                move_virtual_slider(self, cx, self.continuous_normal - self.arrow_scalar);
+                let new_normal = match code {
+                    Code::ArrowUp | Code::ArrowRight => self.continuous_normal + arrow_scalar,
+                    Code::ArrowDown | Code::ArrowLeft => self.continuous_normal - arrow_scalar,
+                    Code::PageUp => self.continuous_normal + self.page_scalar,
+                    Code::PageDown => self.continuous_normal - self.page_scalar,
+                    Code::Home => 0.0,
+                    Code::End => 1.0,
+                    _ => return,
+                };
+
+                // Page Up and Page Down don't also scroll a view containing the knob.
+                if matches!(code, Code::PageUp | Code::PageDown) {
+                    meta.consume();
+                }
+
+                move_virtual_slider(self, cx, new_normal);
             }
 
             _ => {}
@@ -16,8 +16,11 @@ pub mod normalized_map;
 mod notification;
 mod picklist;
 mod popup;
+mod portal;
+mod progress_bar;
 mod radio;
 mod rating;
+mod ripple;
 mod scrollbar;
 mod scrollview;
 mod slider;
@@ -46,9 +49,13 @@ pub use menu::*;
 pub use notification::Notification;
 pub use picklist::PickList;
 pub use popup::{Popup, PopupData, PopupEvent};
+pub use portal::Portal;
+pub use progress_bar::ProgressBar;
 pub use radio::RadioButton;
 pub use rating::Rating;
+pub use ripple::Ripple;
 pub use scrollbar::Scrollbar;
+pub(crate) use scrollview::{scroll_into_view_delta, SCROLL_SENSITIVITY};
 pub use scrollview::{ScrollData, ScrollEvent, ScrollView};
 pub use slider::{NamedSlider, Slider};
 pub use spinbox::{Spinbox, SpinboxEvent, SpinboxIcons, SpinboxKind};
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+/// A view which builds its content at the root of the window, rather than within the view which
+/// declares it, so that the content is laid out and drawn outside of its layout parent.
+///
+/// The portal is positioned relative to the top-left corner of the window, and is drawn on top of
+/// the views which were built before it. Its content is still logically owned by the view which
+/// declares the portal, so lenses resolve data from the ancestors of that view, events emitted by
+/// the content propagate up through those ancestors, and the portal is removed along with it.
+///
+/// This is useful for dropdowns, tooltips and modals which should not be clipped or laid out by the
+/// views around them. Note that style rules are matched against where the portal is built, so
+/// selectors which rely on the ancestors of the declaring view do not apply to the content.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// ScrollView::new(cx, 0.0, 0.0, false, true, |cx| {
+///     Portal::new(cx, |cx| {
+///         Label::new(cx, "Not clipped by the scroll view").left(Pixels(100.0)).top(Pixels(100.0));
+///     });
+/// });
+/// ```
+pub struct Portal {}
+
+impl Portal {
+    /// Creates a new portal with the given content.
+    pub fn new(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        let owner = cx.current();
+        let window = cx.tree.window(owner);
+
+        let entity = cx.with_current(window, |cx| Self {}.build(cx, |_| {}).entity());
+
+        // The owner is set before the content is built so that bindings within the content can
+        // find the data of the ancestors of the owner.
+        cx.tree.set_owner(entity, Some(owner));
+        cx.with_current(entity, content);
+
+        Handle { entity, p: Default::default(), cx }
+            .position_type(PositionType::SelfDirected)
+            .left(Pixels(0.0))
+            .top(Pixels(0.0))
+            .size(Auto)
+    }
+}
+
+impl View for Portal {
+    fn element(&self) -> Option<&'static str> {
+        Some("portal")
+    }
+}
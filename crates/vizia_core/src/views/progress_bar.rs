@@ -0,0 +1,146 @@
+use crate::prelude::*;
+use instant::Duration;
+
+pub(crate) enum ProgressBarEvent {
+    SetPhase(f32),
+}
+
+/// A progress bar shows the progress of a task, either as a proportion which is filled in, or as an
+/// indeterminate animation when the progress of the task is not known.
+///
+/// The progress bar consists of a **track**, which is the progress bar itself, and a **fill**
+/// element with the `fill` class, so the colors of both can be changed with a stylesheet.
+///
+/// The animation of an indeterminate progress bar only runs while the progress bar is visible within
+/// the window, so a hidden progress bar does not keep the application redrawing.
+///
+/// # Examples
+///
+/// ## Determinate Progress Bar
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_derive::*;
+/// # let mut cx = &mut Context::default();
+/// # #[derive(Lens, Default)]
+/// # pub struct AppData {
+/// #     progress: f32,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData::default().build(cx);
+/// ProgressBar::new(cx, AppData::progress);
+/// ```
+///
+/// ## Indeterminate Progress Bar
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let mut cx = &mut Context::default();
+/// ProgressBar::indeterminate(cx);
+/// ```
+#[derive(Lens)]
+pub struct ProgressBar {
+    // How far through the current cycle of the indeterminate animation, between 0.0 and 1.0.
+    phase: f32,
+    indeterminate: bool,
+    period: Duration,
+    // Whether the progress bar is within the visible area of the window.
+    visible: bool,
+    // Whether a cycle of the indeterminate animation is running.
+    running: bool,
+}
+
+impl ProgressBar {
+    /// Creates a new progress bar which is filled in to the proportion, between 0.0 and 1.0, targeted
+    /// by the lens.
+    pub fn new(cx: &mut Context, lens: impl Lens<Target = f32>) -> Handle<Self> {
+        Self {
+            phase: 0.0,
+            indeterminate: false,
+            period: Duration::from_millis(1500),
+            visible: false,
+            running: false,
+        }
+        .build(cx, |cx| {
+            Element::new(cx).class("fill").bind(lens.clone(), |handle, progress| {
+                let progress = progress.get(handle.cx).clamp(0.0, 1.0);
+                handle.left(Pixels(0.0)).width(Percentage(progress * 100.0));
+            });
+        })
+        .role(Role::ProgressIndicator)
+        .numeric_value(
+            lens.clone().map(|progress| (progress.clamp(0.0, 1.0) as f64 * 100.0).round()),
+        )
+        .text_value(lens.map(|progress| format!("{}%", (progress.clamp(0.0, 1.0) * 100.0).round())))
+    }
+
+    /// Creates a new progress bar with a looping animation, for a task whose progress is not known.
+    pub fn indeterminate(cx: &mut Context) -> Handle<Self> {
+        Self {
+            phase: 0.0,
+            indeterminate: true,
+            period: Duration::from_millis(1500),
+            visible: false,
+            running: false,
+        }
+        .build(cx, |cx| {
+            // The fill moves across the track from beyond its start to beyond its end.
+            Element::new(cx).class("fill").bind(ProgressBar::phase, |handle, phase| {
+                let phase = phase.get(handle.cx);
+                handle.left(Percentage(phase * 130.0 - 30.0)).width(Percentage(30.0));
+            });
+        })
+        .class("indeterminate")
+        .role(Role::ProgressIndicator)
+        .observe_viewport(0.0)
+    }
+
+    fn start_cycle(&mut self, cx: &mut EventContext) {
+        self.running = true;
+        cx.animate(0.0, 1.0, self.period, EasingFunction::EaseInOut, |cx, phase| {
+            cx.emit(ProgressBarEvent::SetPhase(phase))
+        });
+    }
+}
+
+impl<'a> Handle<'a, ProgressBar> {
+    /// Sets how long one cycle of the animation of an indeterminate progress bar takes. Defaults to
+    /// 1.5 seconds.
+    pub fn period(self, period: Duration) -> Self {
+        self.modify(|progress_bar| progress_bar.period = period)
+    }
+}
+
+impl View for ProgressBar {
+    fn element(&self) -> Option<&'static str> {
+        Some("progress-bar")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            WindowEvent::EnteredViewport => {
+                self.visible = true;
+                if self.indeterminate && !self.running {
+                    self.start_cycle(cx);
+                }
+            }
+
+            // The current cycle is left to finish, after which no new cycle is started.
+            WindowEvent::LeftViewport => {
+                self.visible = false;
+            }
+
+            _ => {}
+        });
+
+        event.map(|progress_bar_event, _| match progress_bar_event {
+            ProgressBarEvent::SetPhase(phase) => {
+                self.phase = *phase;
+                if *phase >= 1.0 {
+                    self.running = false;
+                    if self.visible {
+                        self.start_cycle(cx);
+                    }
+                }
+            }
+        });
+    }
+}
@@ -117,15 +117,11 @@ impl View for RadioButton {
                 }
             }
 
-            WindowEvent::ActionRequest(request) => match request.action {
-                Action::Default => {
-                    if let Some(callback) = &self.on_select {
-                        (callback)(cx);
-                    }
+            WindowEvent::ActionDefault => {
+                if let Some(callback) = &self.on_select {
+                    (callback)(cx);
                 }
-
-                _ => {}
-            },
+            }
 
             _ => {}
         });
@@ -0,0 +1,131 @@
+use crate::animation::Animation;
+use crate::prelude::*;
+use crate::vg;
+use instant::{Duration, Instant};
+
+/// A press feedback effect which draws ripples expanding from the point where its parent is clicked.
+///
+/// The ripple fills its parent and should be added as the first child so that it is drawn above the
+/// background of the parent but below its content. Ripples are clipped to the rounded rectangle of
+/// the parent and are drawn with the background color of the ripple view. Multiple ripples from
+/// rapid clicks are drawn at the same time.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// Button::new(
+///     cx,
+///     |_| {},
+///     |cx| {
+///         Ripple::new(cx);
+///         Label::new(cx, "Press me")
+///     },
+/// );
+/// ```
+pub struct Ripple {
+    // Position of each ripple relative to the ripple view, in physical pixels, and its start time.
+    ripples: Vec<(f32, f32, Instant)>,
+    duration: Duration,
+    // Animation played while ripples are expanding so that the view is redrawn every frame.
+    animation: Animation,
+}
+
+impl Ripple {
+    /// Creates a new ripple effect for the parent view.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        let animation = cx.add_animation(
+            AnimationBuilder::new()
+                .keyframe(0.0, |key| key.opacity(1.0))
+                .keyframe(1.0, |key| key.opacity(1.0)),
+        );
+
+        Self { ripples: Vec::new(), duration: Duration::from_millis(600), animation }
+            .build(cx, |cx| {
+                cx.add_listener(|ripple: &mut Self, cx, event| {
+                    event.map(|window_event, meta| match window_event {
+                        WindowEvent::MouseDown(MouseButton::Left) => {
+                            if meta.origin != Entity::root() {
+                                return;
+                            }
+
+                            let parent = cx.tree.get_parent(cx.current).unwrap();
+                            if cx.hovered().is_descendant_of(cx.tree, parent) && !cx.is_disabled() {
+                                let bounds = cx.bounds();
+                                let now = Instant::now();
+                                let duration = ripple.duration;
+                                ripple
+                                    .ripples
+                                    .retain(|(_, _, start)| now.duration_since(*start) < duration);
+                                ripple.ripples.push((
+                                    cx.mouse.cursorx - bounds.x,
+                                    cx.mouse.cursory - bounds.y,
+                                    now,
+                                ));
+
+                                cx.play_animation(ripple.animation, ripple.duration);
+                                cx.needs_redraw();
+                            }
+                        }
+
+                        _ => {}
+                    });
+                });
+            })
+            .hoverable(false)
+    }
+}
+
+impl<'a> Handle<'a, Ripple> {
+    /// Sets how long a ripple takes to expand and fade out. Defaults to 600ms.
+    pub fn duration(self, duration: Duration) -> Self {
+        self.modify(|ripple| ripple.duration = duration)
+    }
+}
+
+impl View for Ripple {
+    fn element(&self) -> Option<&'static str> {
+        Some("ripple")
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let bounds = cx.bounds();
+        let color = cx.background_color();
+
+        // Build the path of the parent so that ripples are clipped to its rounded rectangle.
+        let current = cx.current;
+        cx.current = cx.tree.get_parent(current).unwrap();
+        let mut path = cx.build_path();
+        cx.current = current;
+
+        // The furthest distance from any point to a corner of the view.
+        let max_radius = (bounds.w * bounds.w + bounds.h * bounds.h).sqrt();
+
+        let now = Instant::now();
+        for (x, y, start) in self.ripples.iter() {
+            let t = now.duration_since(*start).as_secs_f32() / self.duration.as_secs_f32();
+            if t >= 1.0 {
+                continue;
+            }
+
+            // Ease out the expansion and fade out the ripple as it grows.
+            let radius = max_radius * (1.0 - (1.0 - t).powi(3));
+            let alpha = (color.a() as f32 * (1.0 - t)) as u8;
+            let inner = Color::rgba(color.r(), color.g(), color.b(), alpha);
+            let outer = Color::rgba(color.r(), color.g(), color.b(), 0);
+
+            let paint = vg::Paint::radial_gradient(
+                bounds.x + x,
+                bounds.y + y,
+                (radius - 1.0).max(0.0),
+                radius,
+                inner.into(),
+                outer.into(),
+            );
+            canvas.fill_path(&mut path, &paint);
+        }
+    }
+}
@@ -1,9 +1,14 @@
+use std::cell::Cell;
 use std::sync::Arc;
 
+use instant::Duration;
 use morphorm::PositionType;
 
+use crate::animation::Animation;
 use crate::binding::RatioLens;
 use crate::prelude::*;
+use crate::style::ImageOrGradient;
+use crate::vg;
 use crate::views::Orientation;
 
 pub(crate) const SCROLL_SENSITIVITY: f32 = 35.0;
@@ -16,14 +21,22 @@ pub struct ScrollData {
     pub child_y: f32,
     pub parent_x: f32,
     pub parent_y: f32,
+    #[lens(ignore)]
+    animation: Option<Duration>,
 
     #[lens(ignore)]
     pub on_scroll: Option<Arc<dyn Fn(&mut EventContext, f32, f32) + Send + Sync>>,
 }
 
 pub enum ScrollEvent {
+    /// Sets the horizontal scroll offset, as a proportion between 0.0 and 1.0 of the scrollable width.
     SetX(f32),
+    /// Sets the vertical scroll offset, as a proportion between 0.0 and 1.0 of the scrollable height.
     SetY(f32),
+    /// Animates the horizontal scroll offset to the given proportion over the given duration.
+    AnimateX(f32, Duration),
+    /// Animates the vertical scroll offset to the given proportion over the given duration.
+    AnimateY(f32, Duration),
     ScrollX(f32),
     ScrollY(f32),
     ScrollXPx(f32),
@@ -33,7 +46,45 @@ pub enum ScrollEvent {
     SetOnScroll(Option<Arc<dyn Fn(&mut EventContext, f32, f32) + Send + Sync>>),
 }
 
+impl Default for ScrollData {
+    fn default() -> Self {
+        Self::new(0.0, 0.0)
+    }
+}
+
 impl ScrollData {
+    /// Creates scroll data with the given initial scroll offsets, as proportions between 0.0 and 1.0
+    /// of the scrollable width and height. The sizes of the content and the view are set by the
+    /// [`ScrollView`] which the data is bound to.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// ScrollData::new(0.0, 0.5).build(cx);
+    ///
+    /// ScrollView::custom(cx, false, true, ScrollData::root, |cx| {
+    ///     Label::new(cx, "Hello").height(Pixels(1000.0));
+    /// });
+    /// ```
+    pub fn new(scroll_x: f32, scroll_y: f32) -> Self {
+        Self {
+            scroll_x,
+            scroll_y,
+            child_x: 0.0,
+            child_y: 0.0,
+            parent_x: 0.0,
+            parent_y: 0.0,
+            animation: None,
+            on_scroll: None,
+        }
+    }
+
+    /// Returns the duration over which the content animates to the last set scroll offset, if any.
+    pub fn animation(&self) -> Option<Duration> {
+        self.animation
+    }
+
     fn reset(&mut self) {
         if self.child_x == self.parent_x {
             self.scroll_x = 0.0;
@@ -43,6 +94,22 @@ impl ScrollData {
             self.scroll_y = 0.0;
         }
     }
+
+    // Sets the clamped scroll offset and notifies the scroll callback if the offset has changed.
+    fn set_scroll(&mut self, cx: &mut EventContext, x: f32, y: f32, animation: Option<Duration>) {
+        let x = x.clamp(0.0, 1.0);
+        let y = y.clamp(0.0, 1.0);
+        self.animation = animation;
+
+        if x != self.scroll_x || y != self.scroll_y {
+            self.scroll_x = x;
+            self.scroll_y = y;
+
+            if let Some(callback) = &self.on_scroll {
+                (callback)(cx, self.scroll_x, self.scroll_y);
+            }
+        }
+    }
 }
 
 impl Model for ScrollData {
@@ -50,38 +117,33 @@ impl Model for ScrollData {
         event.map(|scroll_update, meta| {
             match scroll_update {
                 ScrollEvent::ScrollX(f) => {
-                    self.scroll_x = (self.scroll_x + *f).clamp(0.0, 1.0);
-
-                    if let Some(callback) = &self.on_scroll {
-                        (callback)(cx, self.scroll_x, self.scroll_y);
-                    }
+                    self.set_scroll(cx, self.scroll_x + *f, self.scroll_y, None);
                 }
                 ScrollEvent::ScrollY(f) => {
-                    self.scroll_y = (self.scroll_y + *f).clamp(0.0, 1.0);
-                    if let Some(callback) = &self.on_scroll {
-                        (callback)(cx, self.scroll_x, self.scroll_y);
-                    }
+                    self.set_scroll(cx, self.scroll_x, self.scroll_y + *f, None);
                 }
                 ScrollEvent::SetX(f) => {
-                    self.scroll_x = *f;
-                    if let Some(callback) = &self.on_scroll {
-                        (callback)(cx, self.scroll_x, self.scroll_y);
-                    }
+                    self.set_scroll(cx, *f, self.scroll_y, None);
                 }
                 ScrollEvent::SetY(f) => {
-                    self.scroll_y = *f;
-                    if let Some(callback) = &self.on_scroll {
-                        (callback)(cx, self.scroll_x, self.scroll_y);
-                    }
+                    self.set_scroll(cx, self.scroll_x, *f, None);
+                }
+                ScrollEvent::AnimateX(f, duration) => {
+                    self.set_scroll(cx, *f, self.scroll_y, Some(*duration));
+                }
+                ScrollEvent::AnimateY(f, duration) => {
+                    self.set_scroll(cx, self.scroll_x, *f, Some(*duration));
                 }
                 ScrollEvent::ChildGeo(x, y) => {
                     self.child_x = *x;
                     self.child_y = *y;
+                    self.animation = None;
                     self.reset();
                 }
                 ScrollEvent::ParentGeo(x, y) => {
                     self.parent_x = *x;
                     self.parent_y = *y;
+                    self.animation = None;
                     self.reset();
                 }
                 ScrollEvent::ScrollXPx(delta) => {
@@ -104,6 +166,8 @@ impl Model for ScrollData {
 
 pub struct ScrollView<L> {
     data: L,
+    // The overscroll glow view, if enabled.
+    glow: Option<Entity>,
 }
 
 impl ScrollView<Wrapper<scroll_data_derived_lenses::root>> {
@@ -118,18 +182,9 @@ impl ScrollView<Wrapper<scroll_data_derived_lenses::root>> {
     where
         F: 'static + FnOnce(&mut Context),
     {
-        Self { data: ScrollData::root }
+        Self { data: ScrollData::root, glow: None }
             .build(cx, move |cx| {
-                ScrollData {
-                    scroll_x: initial_x,
-                    scroll_y: initial_y,
-                    child_x: 0.0,
-                    child_y: 0.0,
-                    parent_x: 0.0,
-                    parent_y: 0.0,
-                    on_scroll: None,
-                }
-                .build(cx);
+                ScrollData::new(initial_x, initial_y).build(cx);
 
                 Self::common_builder(cx, ScrollData::root, content, scroll_x, scroll_y);
             })
@@ -138,6 +193,8 @@ impl ScrollView<Wrapper<scroll_data_derived_lenses::root>> {
 }
 
 impl<L: Lens<Target = ScrollData>> ScrollView<L> {
+    /// Creates a scroll view bound to [`ScrollData`] built into a parent, e.g. with
+    /// [`ScrollData::new`], so that the scroll offsets can be shared with other views.
     pub fn custom<F>(
         cx: &mut Context,
         scroll_x: bool,
@@ -152,7 +209,7 @@ impl<L: Lens<Target = ScrollData>> ScrollView<L> {
             panic!("ScrollView::custom requires a ScrollData to be built into a parent");
         }
 
-        Self { data: data.clone() }.build(cx, |cx| {
+        Self { data: data.clone(), glow: None }.build(cx, |cx| {
             Self::common_builder(cx, data, content, scroll_x, scroll_y);
         })
     }
@@ -167,11 +224,21 @@ impl<L: Lens<Target = ScrollData>> ScrollView<L> {
                 let dpi_factor = handle.scale_factor();
                 if dpi_factor > 0.0 {
                     let data = data.get(handle.cx);
-                    let left = ((data.child_x - data.parent_x) * data.scroll_x).round()
-                        / handle.cx.style.dpi_factor as f32;
-                    let top = ((data.child_y - data.parent_y) * data.scroll_y).round()
-                        / handle.cx.style.dpi_factor as f32;
-                    handle.left(Units::Pixels(-left.abs())).top(Units::Pixels(-top.abs()));
+                    let left = handle.physical_to_logical(
+                        ((data.child_x - data.parent_x) * data.scroll_x).round(),
+                    );
+                    let top = handle.physical_to_logical(
+                        ((data.child_y - data.parent_y) * data.scroll_y).round(),
+                    );
+                    let left = Units::Pixels(-left.abs());
+                    let top = Units::Pixels(-top.abs());
+
+                    let handle = match data.animation {
+                        Some(duration) => ScrollContent::animate_to(handle, left, top, duration),
+                        None => handle,
+                    };
+
+                    handle.left(left).top(top);
                 }
             },
         );
@@ -210,7 +277,7 @@ impl<L: Lens<Target = ScrollData>> View for ScrollView<L> {
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, _| match window_event {
+        event.map(|window_event, meta| match window_event {
             WindowEvent::GeometryChanged(geo) => {
                 if geo.contains(GeoChanged::WIDTH_CHANGED)
                     || geo.contains(GeoChanged::HEIGHT_CHANGED)
@@ -223,20 +290,34 @@ impl<L: Lens<Target = ScrollData>> View for ScrollView<L> {
             WindowEvent::MouseScroll(x, y) => {
                 cx.set_active(true);
                 let (x, y) =
-                    if cx.modifiers.contains(Modifiers::SHIFT) { (-*y, -*x) } else { (-*x, -*y) };
+                    if meta.modifiers.contains(Modifiers::SHIFT) { (-*y, -*x) } else { (-*x, -*y) };
 
                 // what percentage of the negative space does this cross?
                 let data = self.data.get(cx);
+                if let Some(glow) = self.glow {
+                    if let Some(edge) = OverscrollEdge::from_scroll(&data, x, y) {
+                        cx.emit_to(glow, edge);
+                    }
+                }
+
+                let mut scrolled = false;
                 if x != 0.0 && data.child_x > data.parent_x {
                     let negative_space = data.child_x - data.parent_x;
                     let logical_delta = x * SCROLL_SENSITIVITY / negative_space;
                     cx.emit(ScrollEvent::ScrollX(logical_delta));
+                    scrolled = true;
                 }
                 let data = cx.data::<ScrollData>().unwrap();
                 if y != 0.0 && data.child_y > data.parent_y {
                     let negative_space = data.child_y - data.parent_y;
                     let logical_delta = y * SCROLL_SENSITIVITY / negative_space;
                     cx.emit(ScrollEvent::ScrollY(logical_delta));
+                    scrolled = true;
+                }
+
+                // Stop the scroll from also scrolling any scroll view containing this one.
+                if scrolled {
+                    meta.consume();
                 }
             }
 
@@ -244,12 +325,69 @@ impl<L: Lens<Target = ScrollData>> View for ScrollView<L> {
                 cx.set_active(false);
             }
 
+            WindowEvent::ActionScrollIntoView => {
+                if meta.target != cx.current() {
+                    let bounds = cx.bounds();
+                    let target = cx.cache.get_bounds(meta.target);
+                    let data = self.data.get(cx);
+
+                    let dx = scroll_into_view_delta(bounds.x, bounds.w, target.x, target.w);
+                    if dx != 0.0 && data.child_x > data.parent_x {
+                        cx.emit(ScrollEvent::ScrollX(dx / (data.child_x - data.parent_x)));
+                    }
+
+                    let dy = scroll_into_view_delta(bounds.y, bounds.h, target.y, target.h);
+                    if dy != 0.0 && data.child_y > data.parent_y {
+                        cx.emit(ScrollEvent::ScrollY(dy / (data.child_y - data.parent_y)));
+                    }
+                }
+            }
+
             _ => {}
         });
     }
 }
 
+// Returns the distance to scroll along one axis so that a span of a view is shown within the span of
+// the scroll view, preferring to show the start of the view if it doesn't fit.
+pub(crate) fn scroll_into_view_delta(
+    start: f32,
+    length: f32,
+    target_start: f32,
+    target_length: f32,
+) -> f32 {
+    if target_start < start {
+        target_start - start
+    } else if target_start + target_length > start + length {
+        (target_start + target_length - start - length).min(target_start - start)
+    } else {
+        0.0
+    }
+}
+
 impl<'a, L: Lens> Handle<'a, ScrollView<L>> {
+    /// Sets the horizontal scroll offset, as a proportion between 0.0 and 1.0 of the scrollable width.
+    ///
+    /// Accepts a lens so that the scroll offset can be driven from application state.
+    pub fn scroll_x(self, value: impl Res<f32>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.cx, entity, |cx, entity, x| {
+            cx.emit_to(entity, ScrollEvent::SetX(x));
+        });
+        self
+    }
+
+    /// Sets the vertical scroll offset, as a proportion between 0.0 and 1.0 of the scrollable height.
+    ///
+    /// Accepts a lens so that the scroll offset can be driven from application state.
+    pub fn scroll_y(self, value: impl Res<f32>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.cx, entity, |cx, entity, y| {
+            cx.emit_to(entity, ScrollEvent::SetY(y));
+        });
+        self
+    }
+
     pub fn on_scroll(
         self,
         callback: impl Fn(&mut EventContext, f32, f32) + 'static + Send + Sync,
@@ -259,11 +397,330 @@ impl<'a, L: Lens> Handle<'a, ScrollView<L>> {
     }
 }
 
-pub struct ScrollContent {}
+impl<'a, L: Lens<Target = ScrollData>> Handle<'a, ScrollView<L>> {
+    /// Keeps the scroll offset of the view when it is rebuilt, such as by a [`Binding`], by caching
+    /// the offset under the given key. The key should be unique to the scroll view and stay the same
+    /// across rebuilds.
+    ///
+    /// The cached offset is restored once the rebuilt view has been laid out, and is clamped if the
+    /// content has shrunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// ScrollView::new(cx, 0.0, 0.0, false, true, |cx| {
+    ///     Label::new(cx, "Content");
+    /// })
+    /// .restore_scroll("settings-list");
+    /// ```
+    pub fn restore_scroll(self, key: impl Into<String>) -> Self {
+        let key = key.into();
+        let entity = self.entity();
+        let data = self
+            .cx
+            .views
+            .get(&entity)
+            .and_then(|view_handler| view_handler.downcast_ref::<ScrollView<L>>())
+            .map(|scroll_view| scroll_view.data.clone())
+            .unwrap();
+
+        // The offset to restore, which is kept until the view has been laid out.
+        let pending = Cell::new(self.cx.scroll_offsets.get(&key).copied());
+
+        self.bind(data, move |handle, data| {
+            let data = data.get(handle.cx);
+
+            if let Some((x, y)) = pending.get() {
+                let laid_out = data.parent_x > 0.0
+                    && data.parent_y > 0.0
+                    && data.child_x > 0.0
+                    && data.child_y > 0.0;
+                if !laid_out {
+                    return;
+                }
+
+                pending.set(None);
+                let proportion = |offset: f32, child: f32, parent: f32| {
+                    if child > parent {
+                        (offset / (child - parent)).min(1.0)
+                    } else {
+                        0.0
+                    }
+                };
+                let x = proportion(x, data.child_x, data.parent_x);
+                let y = proportion(y, data.child_y, data.parent_y);
+                handle.cx.emit_to(entity, ScrollEvent::SetX(x));
+                handle.cx.emit_to(entity, ScrollEvent::SetY(y));
+                return;
+            }
+
+            handle.cx.scroll_offsets.insert(
+                key.clone(),
+                (
+                    (data.child_x - data.parent_x).max(0.0) * data.scroll_x,
+                    (data.child_y - data.parent_y).max(0.0) * data.scroll_y,
+                ),
+            );
+        })
+    }
+
+    /// Fades out the content towards the edges of the view over the given size, in logical pixels.
+    ///
+    /// Only edges with more content to scroll to beyond them are faded. The fade is applied as a
+    /// [mask image](StyleModifiers::mask_image) of the view, replacing any other mask, and so
+    /// also fades the scrollbars.
+    pub fn edge_fade(self, size: f32) -> Self {
+        let mut lens = None;
+        let handle = self.modify(|scrollview| lens = Some(scrollview.data.clone()));
+        let lens = match lens {
+            Some(lens) => lens,
+            None => return handle,
+        };
+
+        handle.bind(lens, move |handle, data| {
+            let data = data.get(handle.cx);
+            let size = handle.logical_to_physical(size);
+            let entity = handle.entity();
+
+            let mut mask = Vec::new();
+
+            if data.child_y > data.parent_y {
+                mask.push(edge_fade_gradient(
+                    VerticalPositionKeyword::Bottom.into(),
+                    size / data.parent_y,
+                    data.scroll_y > 0.0,
+                    data.scroll_y < 1.0,
+                ));
+            }
+
+            if data.child_x > data.parent_x {
+                mask.push(edge_fade_gradient(
+                    HorizontalPositionKeyword::Right.into(),
+                    size / data.parent_x,
+                    data.scroll_x > 0.0,
+                    data.scroll_x < 1.0,
+                ));
+            }
+
+            if mask.is_empty() {
+                handle.cx.style.mask_image.remove(entity);
+            } else {
+                handle.cx.style.mask_image.insert(entity, mask);
+            }
+
+            handle.cx.needs_redraw();
+        })
+    }
+
+    /// Shows a glow at an edge of the view when trying to scroll past it.
+    ///
+    /// The glow is drawn with the background color of the `overscroll_glow` class and fades out
+    /// shortly after scrolling stops. Only edges which can be scrolled show the glow.
+    pub fn overscroll_glow(self) -> Self {
+        let entity = self.entity();
+        let glow = self.cx.with_current(entity, |cx| OverscrollGlow::new(cx).entity());
+        self.modify(|scrollview| scrollview.glow = Some(glow))
+    }
+}
+
+// Builds a mask gradient in the given direction which fades out the start and/or end of the view
+// over the given proportion of its length.
+fn edge_fade_gradient(
+    direction: LineDirection,
+    proportion: f32,
+    fade_start: bool,
+    fade_end: bool,
+) -> ImageOrGradient {
+    let length = (proportion * 100.0).min(50.0);
+    let opaque = Color::black();
+    let clear = Color::rgba(0, 0, 0, 0);
+    let stop = |color, position| (color, LengthOrPercentage::Percentage(position));
+
+    let gradient = LinearGradientBuilder::with_direction(direction)
+        .add_stop(stop(if fade_start { clear } else { opaque }, 0.0))
+        .add_stop(stop(opaque, if fade_start { length } else { 0.0 }))
+        .add_stop(stop(opaque, if fade_end { 100.0 - length } else { 100.0 }))
+        .add_stop(stop(if fade_end { clear } else { opaque }, 100.0));
+
+    ImageOrGradient::Gradient(gradient.into())
+}
+
+// An edge of a scroll view which has been scrolled past.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OverscrollEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl OverscrollEdge {
+    // Returns the edge scrolled past by a scroll of the given deltas, if any.
+    fn from_scroll(data: &ScrollData, x: f32, y: f32) -> Option<Self> {
+        if y != 0.0 && data.child_y > data.parent_y {
+            if y < 0.0 && data.scroll_y <= 0.0 {
+                return Some(OverscrollEdge::Top);
+            } else if y > 0.0 && data.scroll_y >= 1.0 {
+                return Some(OverscrollEdge::Bottom);
+            }
+        }
+
+        if x != 0.0 && data.child_x > data.parent_x {
+            if x < 0.0 && data.scroll_x <= 0.0 {
+                return Some(OverscrollEdge::Left);
+            } else if x > 0.0 && data.scroll_x >= 1.0 {
+                return Some(OverscrollEdge::Right);
+            }
+        }
+
+        None
+    }
+}
+
+// Sets the strength of the glow during the fade with the given number.
+struct OverscrollGlowFade {
+    fade: u32,
+    strength: f32,
+}
+
+// A view which fills a scroll view and draws a fading glow at the edge last scrolled past.
+struct OverscrollGlow {
+    // The edge which was scrolled past.
+    edge: Option<OverscrollEdge>,
+    // The strength of the glow, which fades from 1.0 to 0.0.
+    strength: f32,
+    // The number of the latest fade, so that the values of earlier fades which are still running are
+    // ignored.
+    fade: u32,
+    duration: Duration,
+}
+
+impl OverscrollGlow {
+    fn new(cx: &mut Context) -> Handle<Self> {
+        Self { edge: None, strength: 0.0, fade: 0, duration: Duration::from_millis(400) }
+            .build(cx, |_| {})
+            .class("overscroll_glow")
+            .hoverable(false)
+    }
+}
+
+impl View for OverscrollGlow {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|edge: &OverscrollEdge, meta| {
+            self.edge = Some(*edge);
+            self.strength = 1.0;
+            self.fade = self.fade.wrapping_add(1);
+
+            let fade = self.fade;
+            cx.animate(1.0, 0.0, self.duration, EasingFunction::Linear, move |cx, strength| {
+                cx.emit(OverscrollGlowFade { fade, strength })
+            });
+
+            cx.needs_redraw();
+            meta.consume();
+        });
+
+        event.map(|glow_fade: &OverscrollGlowFade, meta| {
+            if glow_fade.fade == self.fade {
+                self.strength = glow_fade.strength;
+                cx.needs_redraw();
+            }
+
+            meta.consume();
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let edge = match self.edge {
+            Some(edge) if self.strength > 0.0 => edge,
+            _ => return,
+        };
+
+        let bounds = cx.bounds();
+        let color = cx.background_color();
+        let alpha = (color.a() as f32 * self.strength) as u8;
+        let inner = Color::rgba(color.r(), color.g(), color.b(), alpha);
+        let outer = Color::rgba(color.r(), color.g(), color.b(), 0);
+
+        // The glow extends a fixed distance into the view, with a gradient which runs from the
+        // edge towards the inside of the view.
+        let depth = cx.logical_to_physical(24.0).min(bounds.w).min(bounds.h);
+        let ((x, y, w, h), (start_x, start_y), (end_x, end_y)) = match edge {
+            OverscrollEdge::Top => (
+                (bounds.x, bounds.y, bounds.w, depth),
+                (bounds.x, bounds.y),
+                (bounds.x, bounds.y + depth),
+            ),
+            OverscrollEdge::Bottom => (
+                (bounds.x, bounds.bottom() - depth, bounds.w, depth),
+                (bounds.x, bounds.bottom()),
+                (bounds.x, bounds.bottom() - depth),
+            ),
+            OverscrollEdge::Left => (
+                (bounds.x, bounds.y, depth, bounds.h),
+                (bounds.x, bounds.y),
+                (bounds.x + depth, bounds.y),
+            ),
+            OverscrollEdge::Right => (
+                (bounds.right() - depth, bounds.y, depth, bounds.h),
+                (bounds.right(), bounds.y),
+                (bounds.right() - depth, bounds.y),
+            ),
+        };
+
+        let mut path = vg::Path::new();
+        path.rect(x, y, w, h);
+        let paint =
+            vg::Paint::linear_gradient(start_x, start_y, end_x, end_y, inner.into(), outer.into());
+        canvas.fill_path(&mut path, &paint);
+    }
+}
+
+pub struct ScrollContent {
+    // Animation of the content position while animating to a new scroll offset.
+    animation: Animation,
+}
 
 impl ScrollContent {
     pub fn new(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
-        Self {}.build(cx, content).class("scroll_content")
+        Self { animation: Animation::null() }.build(cx, content).class("scroll_content")
+    }
+
+    // Animates the content from its current position to the given position, replacing any
+    // animation which is still playing.
+    fn animate_to(
+        handle: Handle<'_, Self>,
+        left: Units,
+        top: Units,
+        duration: Duration,
+    ) -> Handle<'_, Self> {
+        let entity = handle.entity();
+        let from_left = handle.cx.style.left.get(entity).copied().unwrap_or_default();
+        let from_top = handle.cx.style.top.get(entity).copied().unwrap_or_default();
+
+        let animation = handle.cx.add_animation(
+            AnimationBuilder::new()
+                .keyframe(0.0, |key| key.left(from_left).top(from_top))
+                .keyframe(1.0, |key| key.left(left).top(top)),
+        );
+        handle.cx.style.play_animation(entity, animation, duration);
+
+        let previous = handle
+            .cx
+            .views
+            .get_mut(&entity)
+            .and_then(|view_handler| view_handler.downcast_mut::<Self>())
+            .map(|content| std::mem::replace(&mut content.animation, animation));
+
+        if let Some(previous) = previous.filter(|previous| *previous != Animation::null()) {
+            handle.cx.style.left.remove_animation(previous);
+            handle.cx.style.top.remove_animation(previous);
+            handle.cx.style.animation_manager.destroy(previous);
+        }
+
+        handle
     }
 }
 
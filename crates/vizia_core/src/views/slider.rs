@@ -1,7 +1,5 @@
 use std::ops::Range;
 
-use accesskit::ActionData;
-
 use crate::layout::cache::GeoChanged;
 use crate::prelude::*;
 use crate::views::Orientation;
@@ -76,6 +74,8 @@ pub struct Slider<L: Lens> {
     lens: L,
     is_dragging: bool,
     internal: SliderDataInternal,
+    fine_step: Option<f32>,
+    page_step: Option<f32>,
     on_changing: Option<Box<dyn Fn(&mut EventContext, f32)>>,
 }
 
@@ -116,6 +116,8 @@ where
                 keyboard_fraction: 0.1,
             },
 
+            fine_step: None,
+            page_step: None,
             on_changing: None,
         }
         .build(cx, move |cx| {
@@ -210,6 +212,8 @@ where
                 keyboard_fraction: 0.1,
             },
 
+            fine_step: None,
+            page_step: None,
             on_changing: None,
         }
         .build(cx, move |cx| {
@@ -251,7 +255,7 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
             }
         });
 
-        event.map(|window_event, _| match window_event {
+        event.map(|window_event, meta| match window_event {
             WindowEvent::GeometryChanged(_) => {
                 let current = cx.current();
                 let width = cx.cache.get_width(current);
@@ -353,69 +357,73 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 }
             }
 
-            WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
+            // The arrow keys move the value by the step, or by the fine step while holding shift, Page
+            // Up and Page Down move it by the page step, and Home and End set it to the minimum and
+            // maximum.
+            WindowEvent::KeyDown(code, _) => {
+                let min = self.internal.range.start;
+                let max = self.internal.range.end;
+                let step = if cx.modifiers.contains(Modifiers::SHIFT) {
+                    self.fine_step.unwrap_or(self.internal.step / 10.0)
+                } else {
+                    self.internal.step
+                };
+                let page_step = self.page_step.unwrap_or((max - min) / 10.0);
+                let val = self.lens.get(cx);
+
+                let val = match code {
+                    Code::ArrowUp | Code::ArrowRight => val + step,
+                    Code::ArrowDown | Code::ArrowLeft => val - step,
+                    Code::PageUp => val + page_step,
+                    Code::PageDown => val - page_step,
+                    Code::Home => min,
+                    Code::End => max,
+                    _ => return,
+                };
+
+                // Page Up and Page Down don't also scroll a view containing the slider.
+                if matches!(code, Code::PageUp | Code::PageDown) {
+                    meta.consume();
+                }
+
+                if let Some(callback) = &self.on_changing {
+                    (callback)(cx, val.clamp(min, max));
+                }
+            }
+
+            WindowEvent::ActionIncrement => {
                 let min = self.internal.range.start;
                 let max = self.internal.range.end;
                 let step = self.internal.step;
                 let mut val = self.lens.get(cx) + step;
-                // val = step * (val / step).ceil();
+                val = step * (val / step).ceil();
                 val = val.clamp(min, max);
                 if let Some(callback) = &self.on_changing {
                     (callback)(cx, val);
                 }
             }
 
-            WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _) => {
+            WindowEvent::ActionDecrement => {
                 let min = self.internal.range.start;
                 let max = self.internal.range.end;
                 let step = self.internal.step;
                 let mut val = self.lens.get(cx) - step;
-                // val = step * (val / step).ceil();
+                val = step * (val / step).ceil();
                 val = val.clamp(min, max);
                 if let Some(callback) = &self.on_changing {
                     (callback)(cx, val);
                 }
             }
 
-            WindowEvent::ActionRequest(action) => match action.action {
-                Action::Increment => {
-                    let min = self.internal.range.start;
-                    let max = self.internal.range.end;
-                    let step = self.internal.step;
-                    let mut val = self.lens.get(cx) + step;
-                    val = step * (val / step).ceil();
-                    val = val.clamp(min, max);
-                    if let Some(callback) = &self.on_changing {
-                        (callback)(cx, val);
-                    }
-                }
-
-                Action::Decrement => {
-                    let min = self.internal.range.start;
-                    let max = self.internal.range.end;
-                    let step = self.internal.step;
-                    let mut val = self.lens.get(cx) - step;
-                    val = step * (val / step).ceil();
-                    val = val.clamp(min, max);
-                    if let Some(callback) = &self.on_changing {
-                        (callback)(cx, val);
-                    }
-                }
-
-                Action::SetValue => {
-                    if let Some(ActionData::NumericValue(val)) = action.data {
-                        let min = self.internal.range.start;
-                        let max = self.internal.range.end;
-                        let mut v = val as f32;
-                        v = v.clamp(min, max);
-                        if let Some(callback) = &self.on_changing {
-                            (callback)(cx, v);
-                        }
-                    }
+            WindowEvent::ActionSetValue(val) => {
+                let min = self.internal.range.start;
+                let max = self.internal.range.end;
+                let mut v = *val as f32;
+                v = v.clamp(min, max);
+                if let Some(callback) = &self.on_changing {
+                    (callback)(cx, v);
                 }
-
-                _ => {}
-            },
+            }
 
             _ => {}
         });
@@ -483,6 +491,56 @@ impl<L: Lens> Handle<'_, Slider<L>> {
         self.modify(|slider: &mut Slider<L>| slider.internal.step = step)
     }
 
+    /// Sets the step that a press of an arrow key changes the value by while holding shift. Defaults
+    /// to a tenth of the step.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_derive::*;
+    /// # let mut cx = &mut Context::default();
+    /// # #[derive(Lens, Default)]
+    /// # pub struct AppData {
+    /// #     value: f32,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # AppData::default().build(cx);
+    /// Slider::new(cx, AppData::value)
+    ///     .step(0.05)
+    ///     .fine_step(0.001)
+    ///     .on_changing(|cx, value| {
+    ///         println!("Slider on_changing: {}", value);
+    ///     });
+    /// ```
+    pub fn fine_step(self, fine_step: f32) -> Self {
+        self.modify(|slider: &mut Slider<L>| slider.fine_step = Some(fine_step))
+    }
+
+    /// Sets the step that a press of Page Up or Page Down changes the value by. Defaults to a tenth
+    /// of the range.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_derive::*;
+    /// # let mut cx = &mut Context::default();
+    /// # #[derive(Lens, Default)]
+    /// # pub struct AppData {
+    /// #     value: f32,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # AppData::default().build(cx);
+    /// Slider::new(cx, AppData::value)
+    ///     .range(0.0..100.0)
+    ///     .page_step(20.0)
+    ///     .on_changing(|cx, value| {
+    ///         println!("Slider on_changing: {}", value);
+    ///     });
+    /// ```
+    pub fn page_step(self, page_step: f32) -> Self {
+        self.modify(|slider: &mut Slider<L>| slider.page_step = Some(page_step))
+    }
+
     /// Sets the fraction of a slider that a press of an arrow key will change.
     ///
     /// # Example
@@ -3,7 +3,10 @@ use crate::context::AccessNode;
 use crate::layout::BoundingBox;
 use crate::prelude::*;
 
-use crate::text::{enforce_text_bounds, ensure_visible, Direction, Movement};
+use crate::text::{
+    enforce_text_bounds, ensure_visible, next_word_boundary, prev_word_boundary, Direction,
+    Movement, COMMAND_MODIFIER,
+};
 use crate::views::scrollview::SCROLL_SENSITIVITY;
 use accesskit::{ActionData, ActionRequest, TextDirection, TextPosition, TextSelection};
 use cosmic_text::{Action, Attrs, Cursor, Edit, Editor, FontSystem, Shaping};
@@ -33,6 +36,10 @@ pub enum TextEvent {
     Cut,
     SetPlaceholder(String),
     Blur,
+    /// Undo the last edit to the textbox.
+    Undo,
+    /// Redo the last undone edit to the textbox.
+    Redo,
 }
 
 #[derive(Lens)]
@@ -47,6 +54,28 @@ pub struct Textbox<L: Lens> {
     on_blur: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     validate: Option<Box<dyn Fn(&String) -> bool>>,
     placeholder: String,
+    #[lens(ignore)]
+    key_bindings: TextKeyBindings,
+    #[lens(ignore)]
+    history: EditHistory,
+    // The start and end of the text being composed with an input method, if any.
+    #[lens(ignore)]
+    preedit: Option<(Cursor, Cursor)>,
+}
+
+// A snapshot of the text and cursor of a textbox, used for undo and redo.
+struct EditSnapshot {
+    text: String,
+    cursor: Cursor,
+}
+
+// The undo and redo stacks of a textbox.
+#[derive(Default)]
+struct EditHistory {
+    undo: Vec<EditSnapshot>,
+    redo: Vec<EditSnapshot>,
+    // Whether the last edit was a typed character, so that consecutive typing is undone as one edit.
+    typing: bool,
 }
 
 // Determines whether the enter key submits the text or inserts a new line.
@@ -61,10 +90,18 @@ impl<L: Lens> Textbox<L>
 where
     <L as Lens>::Target: Data + Clone + ToString,
 {
+    /// Creates a new single-line textbox.
+    ///
+    /// Pressing the enter key submits the text.
     pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
         Self::new_core(cx, lens, TextboxKind::SingleLine)
     }
 
+    /// Creates a new multi-line textbox.
+    ///
+    /// Pressing the enter key inserts a new line. If `wrap` is true then lines are wrapped to the width
+    /// of the textbox, otherwise the text scrolls horizontally. When the height of the textbox is fixed
+    /// the text scrolls vertically to keep the caret visible.
     pub fn new_multiline(cx: &mut Context, lens: L, wrap: bool) -> Handle<Self> {
         Self::new_core(
             cx,
@@ -85,6 +122,9 @@ where
             on_blur: None,
             validate: None,
             placeholder: String::from(""),
+            key_bindings: TextKeyBindings::platform_default(),
+            history: EditHistory::default(),
+            preedit: None,
         }
         .build(cx, move |cx| {
             cx.add_listener(move |textbox: &mut Self, cx, event| {
@@ -131,7 +171,7 @@ where
                 });
             });
         })
-        .toggle_class("multiline", kind == TextboxKind::MultiLineWrapped)
+        .toggle_class("multiline", kind != TextboxKind::SingleLine)
         .text_wrap(kind == TextboxKind::MultiLineWrapped)
         // .cursor(CursorIcon::Text)
         .navigable(true)
@@ -165,7 +205,8 @@ where
         bounds.h -= child_top + child_bottom;
         bounds.w -= child_left + child_right;
 
-        cx.text_context.sync_styles(cx.current, cx.style);
+        let scale_factor = cx.scale_factor();
+        cx.text_context.sync_styles(cx.current, cx.style, scale_factor);
 
         // do the computation
         let (mut tx, mut ty) = self.transform;
@@ -176,27 +217,96 @@ where
         text_bounds.y += child_top;
 
         // TODO justify????
-        if let Some((x, y, _, h)) =
-            cx.text_context.layout_caret(cx.current, text_bounds, (0., 0.), 1.0 * cx.scale_factor())
-        {
-            let caret_box = BoundingBox { x, y, w: 0.0, h };
+        let caret_box = cx
+            .text_context
+            .layout_caret(
+                cx.current,
+                text_bounds,
+                (0., 0.),
+                CaretShape::Bar,
+                1.0 * cx.scale_factor(),
+            )
+            .map(|(x, y, _, h)| BoundingBox { x, y, w: 0.0, h });
+
+        if let Some(caret_box) = &caret_box {
             bounds.x += child_left;
             bounds.y += child_top;
 
-            (tx, ty) = ensure_visible(&caret_box, &bounds, (tx, ty));
+            (tx, ty) = ensure_visible(caret_box, &bounds, (tx, ty));
         }
 
         self.transform = (tx.round(), ty.round());
+
+        // Show the candidate window of an input method just below the caret.
+        if let Some(caret_box) = caret_box {
+            if self.edit {
+                let (tx, ty) = self.transform;
+                cx.emit(WindowEvent::SetImePosition(caret_box.x + tx, caret_box.bottom() + ty));
+            }
+        }
+
+        // Keep the caret visible while it is being moved.
+        cx.text_context.reset_caret_blink();
     }
 
     pub fn insert_text(&mut self, cx: &mut EventContext, text: &str) {
+        // A single-line textbox cannot contain line breaks, e.g. from pasted text.
+        let text = if self.kind == TextboxKind::SingleLine {
+            text.replace(['\r', '\n'], " ").into()
+        } else {
+            std::borrow::Cow::Borrowed(text)
+        };
+
         cx.text_context.with_editor(cx.current, |_, buf| {
-            buf.insert_string(text, None);
+            buf.insert_string(&text, None);
         });
         cx.needs_relayout();
         cx.needs_redraw();
     }
 
+    // Replaces the text being composed with an input method, placing the cursor at the start of the
+    // byte range marked by the input method, if any.
+    fn set_preedit(&mut self, cx: &mut EventContext, text: &str, cursor: Option<(usize, usize)>) {
+        self.clear_preedit(cx);
+
+        if text.is_empty() {
+            return;
+        }
+
+        // The composed text is kept on a single line so that it can be removed again.
+        let text = text.replace(['\r', '\n'], " ");
+        let preedit = cx.text_context.with_editor(cx.current, |fs, buf| {
+            buf.delete_selection();
+            let start = buf.cursor();
+            buf.insert_string(&text, None);
+            let end = buf.cursor();
+            if let Some((begin, _)) = cursor {
+                move_to_index(fs, buf, start.index + begin);
+            }
+            (start, end)
+        });
+
+        self.preedit = Some(preedit);
+        cx.needs_relayout();
+        cx.needs_redraw();
+    }
+
+    // Removes the text being composed with an input method, if any.
+    fn clear_preedit(&mut self, cx: &mut EventContext) {
+        if let Some((start, end)) = self.preedit.take() {
+            cx.text_context.with_editor(cx.current, |fs, buf| {
+                if buf.cursor().line == start.line {
+                    buf.set_select_opt(None);
+                    move_to_index(fs, buf, end.index);
+                    buf.set_select_opt(Some(start));
+                    buf.delete_selection();
+                }
+            });
+            cx.needs_relayout();
+            cx.needs_redraw();
+        }
+    }
+
     pub fn delete_text(&mut self, cx: &mut EventContext, movement: Movement) {
         let x = |_: &mut FontSystem, buf: &mut Editor| {
             let no_selection = match (buf.cursor(), buf.select_opt()) {
@@ -225,6 +335,8 @@ where
     }
 
     pub fn move_cursor(&mut self, cx: &mut EventContext, movement: Movement, selection: bool) {
+        // Page movements scroll by the visible height of the textbox.
+        let page_height = cx.cache.get_height(cx.current) as i32;
         cx.text_context.with_editor(cx.current, |fs, buf| {
             if selection {
                 if buf.select_opt().is_none() {
@@ -234,6 +346,25 @@ where
                 buf.set_select_opt(None);
             }
 
+            if let Movement::Word(direction) = movement {
+                let cursor = buf.cursor();
+                let upstream = matches!(direction, Direction::Upstream | Direction::Left);
+                let line_text = buf.buffer().lines[cursor.line].text().to_owned();
+
+                // Word movements past the start or end of a line move onto the adjacent line.
+                if upstream && cursor.index == 0 {
+                    buf.action(fs, Action::Previous);
+                } else if !upstream && cursor.index >= line_text.len() {
+                    buf.action(fs, Action::Next);
+                } else if upstream {
+                    move_to_index(fs, buf, prev_word_boundary(&line_text, cursor.index));
+                } else {
+                    move_to_index(fs, buf, next_word_boundary(&line_text, cursor.index));
+                }
+
+                return;
+            }
+
             buf.action(
                 fs,
                 match movement {
@@ -241,19 +372,13 @@ where
                     Movement::Grapheme(Direction::Downstream) => Action::Next,
                     Movement::Grapheme(Direction::Left) => Action::Left,
                     Movement::Grapheme(Direction::Right) => Action::Right,
-                    Movement::Word(Direction::Upstream) => Action::PreviousWord,
-                    Movement::Word(Direction::Downstream) => Action::NextWord,
-                    Movement::Word(Direction::Left) => Action::LeftWord,
-                    Movement::Word(Direction::Right) => Action::RightWord,
                     Movement::Line(Direction::Upstream) => Action::Up,
                     Movement::Line(Direction::Downstream) => Action::Down,
                     Movement::LineStart => Action::Home,
                     Movement::LineEnd => Action::End,
                     Movement::Page(dir) => {
-                        let parent = cx.current.parent(cx.tree).unwrap();
-                        let parent_bounds = *cx.cache.bounds.get(parent).unwrap();
                         let sign = if let Direction::Upstream = dir { -1 } else { 1 };
-                        Action::Vertical(sign * parent_bounds.h as i32)
+                        Action::Vertical(sign * page_height)
                     }
                     Movement::Body(Direction::Upstream) => Action::BufferStart,
                     Movement::Body(Direction::Downstream) => Action::BufferEnd,
@@ -387,6 +512,75 @@ where
         cx.needs_redraw();
     }
 
+    fn snapshot(&self, cx: &mut EventContext) -> EditSnapshot {
+        let cursor = cx.text_context.with_editor(cx.current, |_, buf| buf.cursor());
+        EditSnapshot { text: self.clone_text(cx), cursor }
+    }
+
+    fn restore(&mut self, cx: &mut EventContext, snapshot: &EditSnapshot) {
+        self.select_all(cx);
+        self.insert_text(cx, &snapshot.text);
+        cx.text_context.with_editor(cx.current, |fs, buf| {
+            buf.set_select_opt(None);
+            move_to_cursor(fs, buf, snapshot.cursor);
+        });
+    }
+
+    /// Records the current state of the text so that the next edit can be undone.
+    ///
+    /// Consecutive typed characters are recorded as a single edit.
+    fn record_edit(&mut self, cx: &mut EventContext, typing: bool) {
+        if !(typing && self.history.typing) {
+            let snapshot = self.snapshot(cx);
+            self.history.undo.push(snapshot);
+        }
+        self.history.redo.clear();
+        self.history.typing = typing;
+    }
+
+    // Inserts text as an edit which can be undone, then validates the text and notifies the edit
+    // callback. Typed text is undone together with any text typed before it.
+    fn edit_text(&mut self, cx: &mut EventContext, text: &str, typing: bool) {
+        self.record_edit(cx, typing);
+        self.insert_text(cx, text);
+        self.set_caret(cx);
+
+        if let Some(validate) = &self.validate {
+            let text = self.clone_text(cx);
+            cx.set_valid(validate(&text));
+        }
+
+        if let Some(callback) = &self.on_edit {
+            let text = self.clone_text(cx);
+
+            (callback)(cx, text);
+        }
+    }
+
+    pub fn undo(&mut self, cx: &mut EventContext) -> bool {
+        if let Some(snapshot) = self.history.undo.pop() {
+            let current = self.snapshot(cx);
+            self.history.redo.push(current);
+            self.history.typing = false;
+            self.restore(cx, &snapshot);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn redo(&mut self, cx: &mut EventContext) -> bool {
+        if let Some(snapshot) = self.history.redo.pop() {
+            let current = self.snapshot(cx);
+            self.history.undo.push(current);
+            self.history.typing = false;
+            self.restore(cx, &snapshot);
+            true
+        } else {
+            false
+        }
+    }
+
     #[allow(dead_code)]
     pub fn clone_selected(&self, cx: &mut EventContext) -> Option<String> {
         cx.text_context.with_editor(cx.current, |_, buf| buf.copy_selection())
@@ -399,6 +593,40 @@ where
     }
 }
 
+/// Moves the cursor of the editor to the given cursor position.
+fn move_to_cursor(fs: &mut FontSystem, buf: &mut Editor, target: Cursor) {
+    buf.action(fs, Action::BufferStart);
+    while buf.cursor().line < target.line
+        || (buf.cursor().line == target.line && buf.cursor().index < target.index)
+    {
+        let cursor = buf.cursor();
+        buf.action(fs, Action::Next);
+        if buf.cursor() == cursor {
+            break;
+        }
+    }
+}
+
+/// Moves the cursor of the editor to the given byte index within its current line.
+fn move_to_index(fs: &mut FontSystem, buf: &mut Editor, index: usize) {
+    loop {
+        let cursor = buf.cursor();
+        let action = if cursor.index < index {
+            Action::Next
+        } else if cursor.index > index {
+            Action::Previous
+        } else {
+            break;
+        };
+
+        buf.action(fs, action);
+
+        if buf.cursor() == cursor || buf.cursor().line != cursor.line {
+            break;
+        }
+    }
+}
+
 impl<'a, L: Lens> Handle<'a, Textbox<L>> {
     /// Sets the callback triggered when a textbox is edited, i.e. text is inserted/deleted.
     ///
@@ -438,6 +666,14 @@ impl<'a, L: Lens> Handle<'a, Textbox<L>> {
         self.modify(|textbox| textbox.validate = Some(Box::new(is_valid)))
     }
 
+    /// Sets the key bindings used for editing the text of the textbox.
+    ///
+    /// Key chords in the set take priority over the default key handling of the textbox.
+    /// Defaults to [`TextKeyBindings::platform_default`].
+    pub fn key_bindings(self, key_bindings: TextKeyBindings) -> Self {
+        self.modify(|textbox| textbox.key_bindings = key_bindings)
+    }
+
     pub fn placeholder<T: ToString>(self, text: impl Res<T>) -> Self {
         text.set_or_bind(self.cx, self.entity, |cx, entity, val| {
             // self.modify(|textbox| textbox.placeholder = val.to_string());
@@ -697,153 +933,219 @@ where
                     *c != '\u{9}' && // Tab
                     *c != '\u{7f}' && // Delete
                     *c != '\u{0d}' && // Carriage return
-                    !cx.modifiers.contains(Modifiers::CTRL)
+                    !cx.modifiers.contains(Modifiers::CTRL) &&
+                    !cx.modifiers.contains(COMMAND_MODIFIER)
                 {
                     cx.emit(TextEvent::InsertText(String::from(*c)));
                 }
             }
 
-            WindowEvent::KeyDown(code, _) => match code {
-                Code::Enter => {
-                    // Finish editing
-                    if matches!(self.kind, TextboxKind::SingleLine) {
-                        cx.emit(TextEvent::Submit(true));
-                        // if let Some(source) = cx.data::<L::Source>() {
-                        //     let text = self.lens.view(source, |t| {
-                        //         if let Some(t) = t {
-                        //             t.to_string()
-                        //         } else {
-                        //             "".to_owned()
-                        //         }
-                        //     });
-
-                        //     // cx.emit(TextEvent::SelectAll);
-                        //     // cx.emit(TextEvent::InsertText(text));
-                        //     // cx.emit(TextEvent::EndEdit);
-                        // };
+            WindowEvent::ImePreedit(text, cursor) => {
+                if self.edit {
+                    self.set_preedit(cx, text, *cursor);
+                    self.set_caret(cx);
+                }
+            }
 
-                        cx.set_checked(false);
-                        cx.release();
-                    } else {
-                        cx.emit(TextEvent::InsertText("\n".to_owned()));
-                    }
+            WindowEvent::ImeCommit(text) => {
+                if self.edit {
+                    self.clear_preedit(cx);
+                    cx.emit(TextEvent::InsertText(text.clone()));
                 }
+            }
 
-                Code::ArrowLeft => {
-                    let movement = if cx.modifiers.contains(Modifiers::CTRL) {
-                        Movement::Word(Direction::Left)
-                    } else {
-                        Movement::Grapheme(Direction::Left)
-                    };
+            WindowEvent::KeyDown(code, _) => {
+                if let Some(command) = self.key_bindings.get(*cx.modifiers, *code) {
+                    match command {
+                        EditCommand::Move(movement) => cx.emit(TextEvent::MoveCursor(
+                            movement,
+                            cx.modifiers.contains(Modifiers::SHIFT),
+                        )),
+                        EditCommand::Select(movement) => {
+                            cx.emit(TextEvent::MoveCursor(movement, true))
+                        }
+                        EditCommand::Delete(movement) => cx.emit(TextEvent::DeleteText(movement)),
+                        EditCommand::SelectAll => cx.emit(TextEvent::SelectAll),
+                        EditCommand::Copy => cx.emit(TextEvent::Copy),
+                        EditCommand::Paste => cx.emit(TextEvent::Paste),
+                        EditCommand::Cut => cx.emit(TextEvent::Cut),
+                        EditCommand::Undo => cx.emit(TextEvent::Undo),
+                        EditCommand::Redo => cx.emit(TextEvent::Redo),
+                    }
 
-                    cx.emit(TextEvent::MoveCursor(
-                        movement,
-                        cx.modifiers.contains(Modifiers::SHIFT),
-                    ));
+                    return;
                 }
 
-                Code::ArrowRight => {
-                    let movement = if cx.modifiers.contains(Modifiers::CTRL) {
-                        Movement::Word(Direction::Right)
-                    } else {
-                        Movement::Grapheme(Direction::Right)
-                    };
+                match code {
+                    Code::Enter => {
+                        // Finish editing
+                        if matches!(self.kind, TextboxKind::SingleLine) {
+                            cx.emit(TextEvent::Submit(true));
+                            // if let Some(source) = cx.data::<L::Source>() {
+                            //     let text = self.lens.view(source, |t| {
+                            //         if let Some(t) = t {
+                            //             t.to_string()
+                            //         } else {
+                            //             "".to_owned()
+                            //         }
+                            //     });
+
+                            //     // cx.emit(TextEvent::SelectAll);
+                            //     // cx.emit(TextEvent::InsertText(text));
+                            //     // cx.emit(TextEvent::EndEdit);
+                            // };
+
+                            cx.set_checked(false);
+                            cx.release();
+                        } else {
+                            cx.emit(TextEvent::InsertText("\n".to_owned()));
+                        }
+                    }
 
-                    cx.emit(TextEvent::MoveCursor(
-                        movement,
-                        cx.modifiers.contains(Modifiers::SHIFT),
-                    ));
-                }
+                    Code::ArrowLeft => {
+                        let movement = if cx.modifiers.contains(Modifiers::CTRL) {
+                            Movement::Word(Direction::Left)
+                        } else {
+                            Movement::Grapheme(Direction::Left)
+                        };
 
-                Code::ArrowUp => {
-                    if self.kind != TextboxKind::SingleLine {
                         cx.emit(TextEvent::MoveCursor(
-                            Movement::Line(Direction::Upstream),
+                            movement,
                             cx.modifiers.contains(Modifiers::SHIFT),
                         ));
                     }
-                }
 
-                Code::ArrowDown => {
-                    if self.kind != TextboxKind::SingleLine {
+                    Code::ArrowRight => {
+                        let movement = if cx.modifiers.contains(Modifiers::CTRL) {
+                            Movement::Word(Direction::Right)
+                        } else {
+                            Movement::Grapheme(Direction::Right)
+                        };
+
                         cx.emit(TextEvent::MoveCursor(
-                            Movement::Line(Direction::Downstream),
+                            movement,
                             cx.modifiers.contains(Modifiers::SHIFT),
                         ));
                     }
-                }
 
-                Code::Backspace => {
-                    if cx.modifiers.contains(Modifiers::CTRL) {
-                        cx.emit(TextEvent::DeleteText(Movement::Word(Direction::Upstream)));
-                    } else {
-                        cx.emit(TextEvent::DeleteText(Movement::Grapheme(Direction::Upstream)));
+                    Code::ArrowUp => {
+                        if self.kind != TextboxKind::SingleLine {
+                            cx.emit(TextEvent::MoveCursor(
+                                Movement::Line(Direction::Upstream),
+                                cx.modifiers.contains(Modifiers::SHIFT),
+                            ));
+                        }
                     }
-                }
 
-                Code::Delete => {
-                    if cx.modifiers.contains(Modifiers::CTRL) {
-                        cx.emit(TextEvent::DeleteText(Movement::Word(Direction::Downstream)));
-                    } else {
-                        cx.emit(TextEvent::DeleteText(Movement::Grapheme(Direction::Downstream)));
+                    Code::ArrowDown => {
+                        if self.kind != TextboxKind::SingleLine {
+                            cx.emit(TextEvent::MoveCursor(
+                                Movement::Line(Direction::Downstream),
+                                cx.modifiers.contains(Modifiers::SHIFT),
+                            ));
+                        }
                     }
-                }
 
-                Code::Escape => {
-                    cx.emit(TextEvent::EndEdit);
-                    cx.set_checked(false);
-                }
+                    Code::Backspace => {
+                        if cx.modifiers.contains(Modifiers::CTRL) {
+                            cx.emit(TextEvent::DeleteText(Movement::Word(Direction::Upstream)));
+                        } else {
+                            cx.emit(TextEvent::DeleteText(Movement::Grapheme(Direction::Upstream)));
+                        }
+                    }
 
-                Code::Home => {
-                    cx.emit(TextEvent::MoveCursor(
-                        Movement::LineStart,
-                        cx.modifiers.contains(Modifiers::SHIFT),
-                    ));
-                }
+                    Code::Delete => {
+                        if cx.modifiers.contains(Modifiers::CTRL) {
+                            cx.emit(TextEvent::DeleteText(Movement::Word(Direction::Downstream)));
+                        } else {
+                            cx.emit(TextEvent::DeleteText(Movement::Grapheme(
+                                Direction::Downstream,
+                            )));
+                        }
+                    }
 
-                Code::End => {
-                    cx.emit(TextEvent::MoveCursor(
-                        Movement::LineEnd,
-                        cx.modifiers.contains(Modifiers::SHIFT),
-                    ));
-                }
+                    Code::Escape => {
+                        cx.emit(TextEvent::EndEdit);
+                        cx.set_checked(false);
+                    }
 
-                Code::PageUp | Code::PageDown => {
-                    let direction = if *code == Code::PageUp {
-                        Direction::Upstream
-                    } else {
-                        Direction::Downstream
-                    };
-                    cx.emit(TextEvent::MoveCursor(
-                        if cx.modifiers.contains(Modifiers::CTRL) {
-                            Movement::Body(direction)
+                    Code::Home => {
+                        let movement = if cx.modifiers.contains(Modifiers::CTRL) {
+                            Movement::Body(Direction::Upstream)
                         } else {
-                            Movement::Page(direction)
-                        },
-                        cx.modifiers.contains(Modifiers::SHIFT),
-                    ));
-                }
+                            Movement::LineStart
+                        };
 
-                Code::KeyA => {
-                    if cx.modifiers.contains(Modifiers::CTRL) {
-                        cx.emit(TextEvent::SelectAll);
+                        cx.emit(TextEvent::MoveCursor(
+                            movement,
+                            cx.modifiers.contains(Modifiers::SHIFT),
+                        ));
                     }
-                }
 
-                Code::KeyC if cx.modifiers == &Modifiers::CTRL => {
-                    cx.emit(TextEvent::Copy);
-                }
+                    Code::End => {
+                        let movement = if cx.modifiers.contains(Modifiers::CTRL) {
+                            Movement::Body(Direction::Downstream)
+                        } else {
+                            Movement::LineEnd
+                        };
 
-                Code::KeyV if cx.modifiers == &Modifiers::CTRL => {
-                    cx.emit(TextEvent::Paste);
-                }
+                        cx.emit(TextEvent::MoveCursor(
+                            movement,
+                            cx.modifiers.contains(Modifiers::SHIFT),
+                        ));
+                    }
 
-                Code::KeyX if cx.modifiers == &Modifiers::CTRL => {
-                    cx.emit(TextEvent::Cut);
-                }
+                    Code::PageUp | Code::PageDown => {
+                        // The caret is moved instead of scrolling a view containing the textbox.
+                        meta.consume();
+                        let direction = if *code == Code::PageUp {
+                            Direction::Upstream
+                        } else {
+                            Direction::Downstream
+                        };
+                        cx.emit(TextEvent::MoveCursor(
+                            if cx.modifiers.contains(Modifiers::CTRL) {
+                                Movement::Body(direction)
+                            } else {
+                                Movement::Page(direction)
+                            },
+                            cx.modifiers.contains(Modifiers::SHIFT),
+                        ));
+                    }
 
-                _ => {}
-            },
+                    Code::KeyA => {
+                        if cx.modifiers.contains(COMMAND_MODIFIER) {
+                            cx.emit(TextEvent::SelectAll);
+                        }
+                    }
+
+                    Code::KeyC if cx.modifiers == &COMMAND_MODIFIER => {
+                        cx.emit(TextEvent::Copy);
+                    }
+
+                    Code::KeyV if cx.modifiers == &COMMAND_MODIFIER => {
+                        cx.emit(TextEvent::Paste);
+                    }
+
+                    Code::KeyX if cx.modifiers == &COMMAND_MODIFIER => {
+                        cx.emit(TextEvent::Cut);
+                    }
+
+                    Code::KeyZ if cx.modifiers == &COMMAND_MODIFIER => {
+                        cx.emit(TextEvent::Undo);
+                    }
+
+                    Code::KeyZ if cx.modifiers == &(COMMAND_MODIFIER | Modifiers::SHIFT) => {
+                        cx.emit(TextEvent::Redo);
+                    }
+
+                    Code::KeyY if cx.modifiers == &COMMAND_MODIFIER => {
+                        cx.emit(TextEvent::Redo);
+                    }
+
+                    _ => {}
+                }
+            }
 
             WindowEvent::ActionRequest(ActionRequest {
                 action: accesskit::Action::SetTextSelection,
@@ -908,19 +1210,9 @@ where
         event.map(|text_event, _| match text_event {
             TextEvent::InsertText(text) => {
                 if self.edit {
-                    self.insert_text(cx, text);
-                    self.set_caret(cx);
-
-                    if let Some(validate) = &self.validate {
-                        let text = self.clone_text(cx);
-                        cx.set_valid(validate(&text));
-                    }
-
-                    if let Some(callback) = &self.on_edit {
-                        let text = self.clone_text(cx);
-
-                        (callback)(cx, text);
-                    }
+                    let typing =
+                        text.chars().count() == 1 && !text.starts_with(char::is_whitespace);
+                    self.edit_text(cx, text, typing);
                 }
             }
 
@@ -933,6 +1225,7 @@ where
 
             TextEvent::DeleteText(movement) => {
                 if self.edit {
+                    self.record_edit(cx, false);
                     self.delete_text(cx, *movement);
                     self.set_caret(cx);
 
@@ -962,6 +1255,7 @@ where
             TextEvent::StartEdit => {
                 if !cx.is_disabled() && !self.edit && !cx.is_read_only() {
                     self.edit = true;
+                    self.history = EditHistory::default();
                     cx.focus_with_visibility(false);
                     // cx.capture();
                     cx.set_checked(true);
@@ -990,6 +1284,7 @@ where
             TextEvent::EndEdit => {
                 self.deselect(cx);
                 self.edit = false;
+                self.preedit = None;
                 cx.set_checked(false);
                 cx.release();
 
@@ -1077,12 +1372,13 @@ where
                 }
             }
 
+            // A paste is always undone on its own, even if it is a single character.
             TextEvent::Paste =>
             {
                 #[cfg(feature = "clipboard")]
                 if self.edit {
                     if let Ok(text) = cx.get_clipboard() {
-                        cx.emit(TextEvent::InsertText(text));
+                        self.edit_text(cx, &text, false);
                     }
                 }
             }
@@ -1095,6 +1391,7 @@ where
                         if !selected_text.is_empty() {
                             cx.set_clipboard(selected_text)
                                 .expect("Failed to add text to clipboard");
+                            self.record_edit(cx, false);
                             self.delete_text(cx, Movement::Grapheme(Direction::Upstream));
                             if let Some(validate) = &self.validate {
                                 let text = self.clone_text(cx);
@@ -1110,6 +1407,30 @@ where
                     }
                 }
             }
+
+            TextEvent::Undo | TextEvent::Redo => {
+                if self.edit {
+                    let changed = if let TextEvent::Undo = text_event {
+                        self.undo(cx)
+                    } else {
+                        self.redo(cx)
+                    };
+
+                    if changed {
+                        self.set_caret(cx);
+
+                        if let Some(validate) = &self.validate {
+                            let text = self.clone_text(cx);
+                            cx.set_valid(validate(&text));
+                        }
+
+                        if let Some(callback) = &self.on_edit {
+                            let text = self.clone_text(cx);
+                            (callback)(cx, text);
+                        }
+                    }
+                }
+            }
         });
     }
 
@@ -1128,3 +1449,124 @@ where
         canvas.restore();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[derive(Lens)]
+    struct TextData {
+        text: String,
+    }
+
+    enum TextDataEvent {
+        SetText(String),
+    }
+
+    impl Model for TextData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|text_event, _| match text_event {
+                TextDataEvent::SetText(text) => self.text = text.clone(),
+            });
+        }
+    }
+
+    // Builds a textbox bound to the given text and clicks it to start editing.
+    fn edit_textbox(text: &str, multiline: bool) -> TestHarness {
+        let text = text.to_owned();
+        let mut harness = TestHarness::new(move |cx| {
+            TextData { text }.build(cx);
+
+            let textbox = if multiline {
+                Textbox::new_multiline(cx, TextData::text, false)
+            } else {
+                Textbox::new(cx, TextData::text)
+            };
+
+            textbox
+                .width(Pixels(200.0))
+                .height(Pixels(100.0))
+                .on_edit(|cx, text| cx.emit(TextDataEvent::SetText(text)))
+                .id("textbox");
+        });
+
+        let textbox = harness.entity("textbox").unwrap();
+        harness.simulate_click(textbox);
+        harness
+    }
+
+    fn press(harness: &mut TestHarness, modifiers: Modifiers, code: Code) {
+        harness.set_modifiers(modifiers);
+        harness.key_press(code, None);
+        harness.set_modifiers(Modifiers::empty());
+    }
+
+    fn text(harness: &mut TestHarness) -> String {
+        harness.context().data::<TextData>().unwrap().text.clone()
+    }
+
+    #[test]
+    fn enter_inserts_a_new_line() {
+        let mut harness = edit_textbox("", true);
+
+        harness.type_text("one");
+        press(&mut harness, Modifiers::empty(), Code::Enter);
+        harness.type_text("two");
+
+        assert_eq!(text(&mut harness), "one\ntwo");
+    }
+
+    #[test]
+    fn enter_does_not_insert_a_new_line_in_a_single_line_textbox() {
+        let mut harness = edit_textbox("", false);
+
+        harness.type_text("one");
+        press(&mut harness, Modifiers::empty(), Code::Enter);
+
+        assert_eq!(text(&mut harness), "one");
+    }
+
+    #[test]
+    fn arrow_keys_move_between_lines() {
+        let mut harness = edit_textbox("abc\ndef\nghi", true);
+
+        press(&mut harness, Modifiers::CTRL, Code::Home);
+        press(&mut harness, Modifiers::empty(), Code::ArrowDown);
+        press(&mut harness, Modifiers::empty(), Code::Home);
+        harness.type_text("1");
+        assert_eq!(text(&mut harness), "abc\n1def\nghi");
+
+        press(&mut harness, Modifiers::empty(), Code::ArrowDown);
+        press(&mut harness, Modifiers::empty(), Code::End);
+        harness.type_text("2");
+        assert_eq!(text(&mut harness), "abc\n1def\nghi2");
+
+        press(&mut harness, Modifiers::empty(), Code::ArrowUp);
+        press(&mut harness, Modifiers::empty(), Code::ArrowUp);
+        press(&mut harness, Modifiers::empty(), Code::Home);
+        harness.type_text("3");
+        assert_eq!(text(&mut harness), "3abc\n1def\nghi2");
+    }
+
+    #[test]
+    fn home_and_end_move_within_the_line_or_the_whole_text() {
+        let mut harness = edit_textbox("abc\ndef", true);
+
+        press(&mut harness, Modifiers::CTRL, Code::End);
+        harness.type_text("!");
+        assert_eq!(text(&mut harness), "abc\ndef!");
+
+        press(&mut harness, Modifiers::CTRL, Code::Home);
+        harness.type_text("^");
+        assert_eq!(text(&mut harness), "^abc\ndef!");
+
+        press(&mut harness, Modifiers::empty(), Code::End);
+        harness.type_text("$");
+        assert_eq!(text(&mut harness), "^abc$\ndef!");
+
+        press(&mut harness, Modifiers::empty(), Code::Home);
+        harness.type_text("<");
+        assert_eq!(text(&mut harness), "<^abc$\ndef!");
+    }
+}
@@ -0,0 +1,61 @@
+use vizia_style::CursorIcon;
+
+/// The mouse cursor shown while a view is hovered, either a built-in icon or a custom image.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cursor {
+    /// One of the cursor icons provided by the platform.
+    Icon(CursorIcon),
+    /// A custom cursor image.
+    Image(CursorImage),
+}
+
+impl Default for Cursor {
+    fn default() -> Self {
+        Cursor::Icon(CursorIcon::Default)
+    }
+}
+
+impl From<CursorIcon> for Cursor {
+    fn from(icon: CursorIcon) -> Self {
+        Cursor::Icon(icon)
+    }
+}
+
+impl From<CursorImage> for Cursor {
+    fn from(image: CursorImage) -> Self {
+        Cursor::Image(image)
+    }
+}
+
+/// A custom mouse cursor image.
+///
+/// If the windowing backend can't show the image, the default cursor icon is shown instead.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// // A 16x16 white square with the hotspot in its center.
+/// let image = CursorImage::new(vec![255; 16 * 16 * 4], 16, 16, 8, 8);
+/// Element::new(cx).size(Pixels(100.0)).cursor(image);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorImage {
+    /// The pixels of the image, as rows of 8-bit RGBA values from top to bottom.
+    pub rgba: Vec<u8>,
+    /// The width of the image in pixels.
+    pub width: u32,
+    /// The height of the image in pixels.
+    pub height: u32,
+    /// The x position of the point of the image which is placed at the mouse position.
+    pub hotspot_x: u32,
+    /// The y position of the point of the image which is placed at the mouse position.
+    pub hotspot_y: u32,
+}
+
+impl CursorImage {
+    /// Creates a new cursor image from RGBA pixels, with the given hotspot.
+    pub fn new(rgba: Vec<u8>, width: u32, height: u32, hotspot_x: u32, hotspot_y: u32) -> Self {
+        Self { rgba, width, height, hotspot_x, hotspot_y }
+    }
+}
@@ -1,5 +1,8 @@
 //! Window management and system events.
 
+mod cursor;
+pub use cursor::*;
+
 mod window_modifiers;
 pub use window_modifiers::*;
 
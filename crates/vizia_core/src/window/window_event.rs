@@ -1,8 +1,8 @@
 use std::path::PathBuf;
 
+use super::Cursor;
 use crate::{entity::Entity, layout::cache::GeoChanged};
 use vizia_input::{Code, Key, MouseButton};
-use vizia_style::CursorIcon;
 use vizia_window::{Position, WindowSize};
 
 #[derive(Debug, Clone)]
@@ -11,6 +11,19 @@ pub enum DropData {
     Id(Entity),
 }
 
+/// The phase of a [`WindowEvent::Touch`] event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchPhase {
+    /// A finger touched the screen.
+    Started,
+    /// A finger moved on the screen.
+    Moved,
+    /// A finger was lifted from the screen.
+    Ended,
+    /// The touch was cancelled by the system, e.g. because the window lost focus.
+    Cancelled,
+}
+
 impl From<Entity> for DropData {
     fn from(value: Entity) -> Self {
         DropData::Id(value)
@@ -28,9 +41,34 @@ impl From<PathBuf> for DropData {
 #[derive(Debug, Clone)]
 pub enum WindowEvent {
     /// Emitted when a window is closed. Can also be emitted by a view or model to close the window.
+    ///
+    /// Use [`EventContext::quit`](crate::context::EventContext::quit) to close the window from anywhere in the tree.
     WindowClose,
-    /// Emitted when a file is dragged and then dropped onto the window.
+    /// Emitted when a file is dragged and then dropped onto the window, followed by a
+    /// [`FileDrop`](WindowEvent::FileDrop) event.
+    ///
+    /// Not emitted on Windows, see [`FileDrop`](WindowEvent::FileDrop).
     Drop(DropData),
+    /// Emitted to the hovered view, and propagated up to its ancestors, when a file is dropped onto
+    /// the window. The file is also set as the drop data, so views with an
+    /// [`on_drop`](crate::modifiers::ActionModifiers::on_drop) action receive it.
+    ///
+    /// File drag and drop events are not emitted on Windows. Dropping files onto a window requires
+    /// the window to initialize COM for single-threaded use, which conflicts with libraries which
+    /// initialize it for multithreaded use, such as those used for audio and file dialogs, so it is
+    /// disabled for the windows created by the winit backend.
+    FileDrop(PathBuf),
+    /// Emitted to the hovered view, and propagated up to its ancestors, when a file is dragged over
+    /// the window, e.g. so that a view can highlight itself as a drop zone. When several files are
+    /// dragged this is emitted once for each file.
+    ///
+    /// Not emitted on Windows, see [`FileDrop`](WindowEvent::FileDrop).
+    FileHover(PathBuf),
+    /// Emitted to the hovered view, and propagated up to its ancestors, when files being dragged over
+    /// the window are dragged out of it or the drag is cancelled.
+    ///
+    /// Not emitted on Windows, see [`FileDrop`](WindowEvent::FileDrop).
+    FileHoverCancel,
     /// Emitted when a mouse button is double clicked.
     MouseDoubleClick(MouseButton),
     /// Emitted when a mouse button is triple clicked
@@ -51,30 +89,80 @@ pub enum WindowEvent {
     MouseMove(f32, f32),
     /// Emitted when the mouse scroll wheel is scrolled.
     MouseScroll(f32, f32),
+    /// Emitted when a finger touches, moves on, or is lifted from a touchscreen.
+    ///
+    /// Each finger has a distinct `id` for the duration of its touch, and the (x, y) position is in
+    /// physical window coordinates. Touch events are sent to the view under the point where the touch
+    /// started. The first finger to touch the window also acts as the left mouse button, so that a tap
+    /// presses the view under it.
+    Touch {
+        id: u64,
+        phase: TouchPhase,
+        x: f32,
+        y: f32,
+    },
+    /// Emitted with the raw (dx, dy) motion of the mouse, independent of the cursor position.
+    ///
+    /// Raw motion is reported while the cursor is grabbed, or after enabling it with
+    /// [`WindowEvent::SetRawMouseMotion`], and is not limited by the bounds of the window or screen.
+    /// This is useful for camera controls and unbounded dragging.
+    RawMouseMotion(f32, f32),
     /// Emitted when the mouse cursor enters the bounding box of an entity.
     MouseOver,
     /// Emitted when the mouse cursor leaves the bounding box of an entity.
     MouseOut,
-    /// Emitted when the mouse cursor enters an entity.
+    /// Emitted directly to a view when it becomes the hovered view, the topmost view under the mouse
+    /// cursor. This is sent after [`WindowEvent::MouseLeave`] is sent to the previously hovered view.
     MouseEnter,
-    /// Emitted when the mouse cursor leaves an entity.
+    /// Emitted directly to a view when it stops being the hovered view, including when it is hidden
+    /// or removed while hovered.
     MouseLeave,
-    // Emitted when an entity gains keyboard focus.
+    /// Emitted to a view, and propagated up to its ancestors, when a link in its text is clicked.
+    /// Links are detected in views with [`detect_links`](crate::modifiers::TextModifiers::detect_links).
+    LinkClicked(String),
+    /// Emitted to a view when it gains keyboard focus.
+    ///
+    /// This is sent whether focus was moved by the user, e.g. with the tab key or by clicking on a
+    /// view, or programmatically with [`EventContext::focus`](crate::context::EventContext::focus).
     FocusIn,
-    // Emitted when an entity loses keyboard focus.
+    /// Emitted to a view when it loses keyboard focus, before [`WindowEvent::FocusIn`] is sent to
+    /// the newly focused view.
     FocusOut,
     /// Emitted when a character is typed.
     CharInput(char),
+    /// Emitted when the text being composed with an input method changes, before it's committed.
+    ///
+    /// The second field is the byte range of the composed text which the input method marks as the
+    /// cursor, if any. An empty string clears the composition, e.g. when the input method is disabled.
+    ImePreedit(String, Option<(usize, usize)>),
+    /// Emitted when the text composed with an input method is committed, and should be inserted.
+    ImeCommit(String),
     /// Emitted when a keyboard key is pressed.
     KeyDown(Code, Option<Key>),
     /// Emitted when a keyboard key is released.
     KeyUp(Code, Option<Key>),
-    /// Sets the mouse cursor icon.
-    SetCursor(CursorIcon),
+    /// Emitted before [`KeyDown`](WindowEvent::KeyDown) with the raw hardware scancode of the
+    /// pressed key, if the windowing backend reports it.
+    ///
+    /// Scancodes depend on the platform but not on the keyboard layout, which makes them suitable
+    /// for bindings based on the physical position of a key.
+    ScancodeDown(u32),
+    /// Emitted before [`KeyUp`](WindowEvent::KeyUp) with the raw hardware scancode of the released
+    /// key, if the windowing backend reports it.
+    ScancodeUp(u32),
+    /// Sets the mouse cursor, either a built-in icon or a custom image.
+    SetCursor(Cursor),
     /// Grabs the mouse cursor, preventing it from leaving the window.
     GrabCursor(bool),
+    /// Enables or disables [`WindowEvent::RawMouseMotion`] events without grabbing the cursor.
+    SetRawMouseMotion(bool),
+    /// Sets the (x, y) factors applied to mouse wheel scrolling in the window.
+    SetScrollSensitivity(f32, f32),
     /// Sets the (x,y) position of the mouse cursor in window coordinates.
     SetCursorPosition(u32, u32),
+    /// Sets the (x,y) position, in physical window coordinates, at which the candidate window of an
+    /// input method is shown. Text input views set this to just below their text cursor.
+    SetImePosition(f32, f32),
     /// Sets the title of the window.
     SetTitle(String),
     /// Sets the size of the window.
@@ -91,12 +179,20 @@ pub enum WindowEvent {
     SetMinimized(bool),
     /// Sets whether the window is maximized.
     SetMaximized(bool),
+    /// Sets the fullscreen mode of the window, or leaves fullscreen with `None`.
+    SetFullscreen(Option<Fullscreen>),
     /// Sets whether the window is visible.
     SetVisible(bool),
     /// Sets whether the window has decorations.
     SetDecorations(bool),
     /// Sets whether the window remains on top of other windows.
     SetAlwaysOnTop(bool),
+    /// Sets whether the window remains below other windows.
+    SetAlwaysOnBottom(bool),
+    /// Sets the stacking level of the window relative to other windows.
+    SetWindowLevel(WindowLevel),
+    /// Sets whether the window is hidden from the taskbar. Only supported on Windows.
+    SetSkipTaskbar(bool),
     /// Emitted when mouse events have been captured.
     MouseCaptureEvent,
     /// Emitted when mouse events have been released.
@@ -104,6 +200,14 @@ pub enum WindowEvent {
     // TODO: check if this includes margins + borders.
     /// Emitted when an entity changes position or size.
     GeometryChanged(GeoChanged),
+    /// Emitted when a view enters the visible area of the window after layout.
+    ///
+    /// Only sent to views which observe the viewport with [`Handle::observe_viewport`](crate::view::Handle::observe_viewport).
+    EnteredViewport,
+    /// Emitted when a view leaves the visible area of the window after layout.
+    ///
+    /// Only sent to views which observe the viewport with [`Handle::observe_viewport`](crate::view::Handle::observe_viewport).
+    LeftViewport,
     /// Requests a redraw of the window contents.
     Redraw,
     /// Request a restyle.
@@ -113,6 +217,41 @@ pub enum WindowEvent {
     /// Prints the debug message to the console.
     Debug(String),
     ActionRequest(accesskit::ActionRequest),
+    /// Emitted to a view when an assistive technology requests its default action, e.g. to press a
+    /// button or toggle a checkbox.
+    ActionDefault,
+    /// Emitted to a view when an assistive technology requests that its value is incremented, e.g.
+    /// by a step of a slider.
+    ActionIncrement,
+    /// Emitted to a view when an assistive technology requests that its value is decremented.
+    ActionDecrement,
+    /// Emitted to a view when an assistive technology requests that its value is set to a number.
+    ActionSetValue(f64),
+    /// Emitted to a view, and then to its ancestors, when an assistive technology requests that it
+    /// is scrolled into view. Scroll views handle this by scrolling to show the target view.
+    ActionScrollIntoView,
     /// Reloads all application stylesheets.
     ReloadStyles,
+    /// Emitted when the application is suspended by the operating system, e.g. when an Android
+    /// application is moved to the background. The window cannot be drawn to while suspended.
+    Suspended,
+    /// Emitted when the application is resumed after being suspended.
+    Resumed,
+    /// Emitted to a window when it is fully hidden by other windows (`true`), or visible again
+    /// (`false`), on platforms which report it.
+    ///
+    /// An occluded window is not redrawn, and while every window of the application is occluded
+    /// animations are not polled, which saves power for applications in the background.
+    Occluded(bool),
+    /// Emitted to every view when the rendering context has been lost and recreated.
+    ///
+    /// Any images created directly on the canvas, for example in a custom `draw()` method, are no
+    /// longer valid and must be created again.
+    RendererReset,
+    /// Emitted to every view when a view which accepts text input gains focus (`true`), or when focus
+    /// moves to a view which does not (`false`).
+    ///
+    /// The windowing backend requests the platform's soft keyboard where supported. Applications
+    /// targeting platforms without one can respond to this event by showing an on-screen keyboard.
+    KeyboardRequested(bool),
 }
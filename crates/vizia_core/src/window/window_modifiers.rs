@@ -74,6 +74,39 @@ pub trait WindowModifiers {
     /// .run();
     /// ```
     fn position<P: Into<Position>>(self, position: P) -> Self;
+    /// Sets whether the window is centered on its monitor when it is created, instead of placing it at
+    /// a [`position`](Self::position). The size and scale factor of the monitor are taken into account.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .center(true)
+    /// .run();
+    /// ```
+    fn center(self, flag: bool) -> Self;
+    /// Remembers the position and size of the window between runs of the application.
+    ///
+    /// The position and size are saved to the file at the given path when the window is closed, and
+    /// restored from it when the window is next created, replacing any position, size, or centering
+    /// of the window. The window is placed as normal if the file does not exist yet, and the position
+    /// is not restored if the monitor the window was on is no longer available.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .center(true)
+    /// .remember_placement("window_placement.txt")
+    /// .run();
+    /// ```
+    fn remember_placement(self, path: impl Into<std::path::PathBuf>) -> Self;
     /// Sets whether the window can be resized. Accepts a boolean value, or lens to a boolean value.
     ///
     /// # Example
@@ -165,6 +198,47 @@ pub trait WindowModifiers {
     /// .run();
     /// ```
     fn always_on_top(self, flag: bool) -> Self;
+    /// Sets whether the window should be below other windows, e.g. for a desktop widget.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .always_on_bottom(true)
+    /// .run();
+    /// ```
+    fn always_on_bottom(self, flag: bool) -> Self;
+    /// Sets whether the window should be hidden from the taskbar. This is only supported on Windows
+    /// and has no effect on other platforms.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .skip_taskbar(true)
+    /// .run();
+    /// ```
+    fn skip_taskbar(self, flag: bool) -> Self;
+    /// Sets the stacking level of the window relative to other windows. This replaces any level set
+    /// with [`always_on_top`](Self::always_on_top) or [`always_on_bottom`](Self::always_on_bottom).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .window_level(WindowLevel::AlwaysOnTop)
+    /// .run();
+    /// ```
+    fn window_level(self, window_level: WindowLevel) -> Self;
     /// Sets whether the window has vsync enabled.
     ///
     /// # Example
@@ -178,6 +252,35 @@ pub trait WindowModifiers {
     /// .run();
     /// ```
     fn vsync(self, flag: bool) -> Self;
+    /// Sets how the window is cleared before each frame is drawn.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .transparent(true)
+    /// .clear_behavior(ClearBehavior::Color(Color::rgba(0, 0, 0, 128)))
+    /// .run();
+    /// ```
+    fn clear_behavior(self, clear_behavior: ClearBehavior) -> Self;
+    /// Sets the background color of the window, which overrides any background color given to the
+    /// window by a stylesheet. The alpha of the color is kept for transparent windows.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .transparent(true)
+    /// .background_color(Color::rgba(0, 0, 0, 128))
+    /// .run();
+    /// ```
+    fn background_color(self, color: impl Into<Color>) -> Self;
     /// Sets the icon used for the window.
     ///
     /// # Example
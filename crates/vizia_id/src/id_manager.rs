@@ -82,6 +82,11 @@ where
     pub fn is_alive(&self, id: I) -> bool {
         self.generation[id.index()] == id.generation()
     }
+
+    /// Returns the number of ids which are currently alive.
+    pub fn count(&self) -> usize {
+        self.generation.len() - self.free_list.len()
+    }
 }
 
 #[cfg(test)]
@@ -195,4 +200,17 @@ mod tests {
         let alive2 = id_manager.is_alive(id);
         assert_eq!(alive2, false);
     }
+
+    /// Test the count() method.
+    #[test]
+    fn count() {
+        let mut id_manager = IdManager::<Entity>::new();
+        let id1 = id_manager.create();
+        id_manager.create();
+        assert_eq!(id_manager.count(), 2);
+        id_manager.destroy(id1);
+        assert_eq!(id_manager.count(), 1);
+        id_manager.create();
+        assert_eq!(id_manager.count(), 2);
+    }
 }
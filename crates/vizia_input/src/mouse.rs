@@ -9,6 +9,10 @@ pub enum MouseButton {
     Right,
     /// The middle mouse button.
     Middle,
+    /// The back button, usually a thumb button, used to navigate backwards.
+    Back,
+    /// The forward button, usually a thumb button, used to navigate forwards.
+    Forward,
     /// Another mouse button with the associated button number.
     Other(u16),
 }
@@ -125,7 +125,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.tours.next_with(self.tree, |node, direction| match direction {
             TourDirection::Entering => {
-                if self.tree.is_ignored(*node) {
+                // Windows are laid out separately from the views of their parent window.
+                if self.tree.is_window(*node) {
+                    (None, TourStep::LeaveCurrent)
+                } else if self.tree.is_ignored(*node) {
                     (None, TourStep::EnterFirstChild)
                 } else {
                     (Some(node), TourStep::LeaveCurrent)
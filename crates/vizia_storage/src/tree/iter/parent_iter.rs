@@ -2,6 +2,9 @@ use crate::Tree;
 use vizia_id::GenerationalId;
 
 /// Iterator for iterating through the ancestors of an entity.
+///
+/// An entity with an [owner](Tree::owner) is followed by its owner rather than by its parent, so
+/// the content of a portal has the ancestors of the view which owns it.
 pub struct ParentIterator<'a, I>
 where
     I: GenerationalId,
@@ -27,10 +30,42 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(entity) = self.current {
-            self.current = self.tree.get_parent(entity);
+            self.current = self.tree.owner(entity).or_else(|| self.tree.get_parent(entity));
             Some(entity)
         } else {
             None
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TreeError;
+    use vizia_id::{
+        impl_generational_id, GenerationalId, GENERATIONAL_ID_GENERATION_MASK,
+        GENERATIONAL_ID_INDEX_BITS, GENERATIONAL_ID_INDEX_MASK,
+    };
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Entity(u32);
+
+    impl_generational_id!(Entity);
+
+    #[test]
+    fn owner_replaces_parent() -> Result<(), TreeError> {
+        let mut t = Tree::new();
+        let r = Entity::root();
+        let [a, b, c] = [1, 2, 3].map(|i| Entity::new(i, 0));
+        t.add(a, r)?;
+        t.add(b, a)?;
+        t.add(c, r)?;
+        t.set_owner(c, Some(b));
+        assert!(ParentIterator::new(&t, Some(c)).eq([c, b, a, r]));
+        assert!(t.owned_by(b).eq([c]));
+
+        t.remove(c)?;
+        assert!(t.owned_by(b).next().is_none());
+        Ok(())
+    }
+}
@@ -13,6 +13,12 @@ where
     pub prev_sibling: Vec<Option<I>>,
     pub ignored: Vec<bool>,
     pub lock_focus_within: Vec<bool>,
+    pub focus_scope: Vec<bool>,
+    pub focus_wrap: Vec<Option<bool>>,
+    pub window: Vec<bool>,
+    pub escape_clip: Vec<bool>,
+    pub owner: Vec<Option<I>>,
+    pub owned: Vec<I>,
     pub changed: bool,
     pub z_index: Vec<i32>,
 }
@@ -30,6 +36,12 @@ where
             prev_sibling: vec![None],
             ignored: vec![false],
             lock_focus_within: vec![true],
+            focus_scope: vec![false],
+            focus_wrap: vec![None],
+            window: vec![true],
+            escape_clip: vec![false],
+            owner: vec![None],
+            owned: Vec::new(),
             changed: true,
             z_index: vec![0],
         }
@@ -117,6 +129,47 @@ where
             .unwrap_or(I::root())
     }
 
+    /// Returns true if the entity has the focus_scope flag set
+    pub fn is_focus_scope(&self, entity: I) -> bool {
+        self.focus_scope.get(entity.index()).cloned().unwrap_or_default()
+    }
+
+    /// Returns the first ancestor, not including the entity itself, to have the focus_scope flag set
+    pub fn focus_scope(&self, entity: I) -> Option<I> {
+        entity.parent_iter(self).skip(1).find(|&entity| self.is_focus_scope(entity))
+    }
+
+    /// Returns whether focus navigation wraps around within the entity, if set
+    pub fn focus_wrap(&self, entity: I) -> Option<bool> {
+        self.focus_wrap.get(entity.index()).cloned().flatten()
+    }
+
+    /// Returns true if the entity is the root of a window
+    pub fn is_window(&self, entity: I) -> bool {
+        self.window.get(entity.index()).cloned().unwrap_or_default()
+    }
+
+    /// Returns the root of the window which contains the entity, which may be the entity itself
+    pub fn window(&self, entity: I) -> I {
+        entity.parent_iter(self).find(|&entity| self.is_window(entity)).unwrap_or(I::root())
+    }
+
+    /// Returns true if the entity is drawn outside the clipping of its ancestors
+    pub fn escape_clip(&self, entity: I) -> bool {
+        self.escape_clip.get(entity.index()).cloned().unwrap_or_default()
+    }
+
+    /// Returns the owner of the entity, if set, which replaces its parent as the next ancestor of the
+    /// entity when iterating through its ancestors
+    pub fn owner(&self, entity: I) -> Option<I> {
+        self.owner.get(entity.index()).cloned().flatten()
+    }
+
+    /// Returns an iterator over the entities which are owned by the given entity
+    pub fn owned_by(&self, owner: I) -> impl Iterator<Item = I> + '_ {
+        self.owned.iter().copied().filter(move |entity| self.owner(*entity) == Some(owner))
+    }
+
     /// Returns the first ancestor of an entity which is not ignored
     pub fn get_layout_parent(&self, entity: I) -> Option<I> {
         let mut i = self.get_parent(entity);
@@ -243,6 +296,12 @@ where
         self.parent[entity_index] = None;
         self.ignored[entity_index] = false;
         self.lock_focus_within[entity_index] = false;
+        self.focus_scope[entity_index] = false;
+        self.focus_wrap[entity_index] = None;
+        self.window[entity_index] = false;
+        self.escape_clip[entity_index] = false;
+        self.owner[entity_index] = None;
+        self.owned.retain(|owned| *owned != entity);
 
         // Set the changed flag
         self.changed = true;
@@ -467,6 +526,41 @@ where
         }
     }
 
+    pub fn set_focus_scope(&mut self, entity: I, flag: bool) {
+        if let Some(result) = self.focus_scope.get_mut(entity.index()) {
+            *result = flag;
+        }
+    }
+
+    pub fn set_focus_wrap(&mut self, entity: I, flag: Option<bool>) {
+        if let Some(result) = self.focus_wrap.get_mut(entity.index()) {
+            *result = flag;
+        }
+    }
+
+    pub fn set_window(&mut self, entity: I, flag: bool) {
+        if let Some(result) = self.window.get_mut(entity.index()) {
+            *result = flag;
+        }
+    }
+
+    pub fn set_escape_clip(&mut self, entity: I, flag: bool) {
+        if let Some(result) = self.escape_clip.get_mut(entity.index()) {
+            *result = flag;
+        }
+    }
+
+    pub fn set_owner(&mut self, entity: I, owner: Option<I>) {
+        if let Some(result) = self.owner.get_mut(entity.index()) {
+            *result = owner;
+
+            self.owned.retain(|owned| *owned != entity);
+            if owner.is_some() {
+                self.owned.push(entity);
+            }
+        }
+    }
+
     /// Adds an entity to the tree with the specified parent.
     pub fn add(&mut self, entity: I, parent: I) -> Result<(), TreeError> {
         if entity == I::null() || parent == I::null() {
@@ -488,6 +582,11 @@ where
             self.prev_sibling.resize(entity_index + 1, None);
             self.ignored.resize(entity_index + 1, false);
             self.lock_focus_within.resize(entity_index + 1, false);
+            self.focus_scope.resize(entity_index + 1, false);
+            self.focus_wrap.resize(entity_index + 1, None);
+            self.window.resize(entity_index + 1, false);
+            self.escape_clip.resize(entity_index + 1, false);
+            self.owner.resize(entity_index + 1, None);
             self.z_index.resize(entity_index + 1, 0);
         }
 
@@ -497,6 +596,11 @@ where
         self.prev_sibling[entity_index] = None;
         self.ignored[entity_index] = false;
         self.lock_focus_within[entity_index] = false;
+        self.focus_scope[entity_index] = false;
+        self.focus_wrap[entity_index] = None;
+        self.window[entity_index] = false;
+        self.escape_clip[entity_index] = false;
+        self.owner[entity_index] = None;
         self.z_index[entity_index] = 0;
 
         // If the parent has no first child then this entity is the first child
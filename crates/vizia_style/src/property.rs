@@ -1,10 +1,12 @@
 use crate::{
-    define_property, Angle, BackgroundImage, BackgroundSize, Border, BorderCornerShape,
-    BorderRadius, BorderWidth, BorderWidthValue, BoxShadow, ClipPath, Color, CursorIcon,
-    CustomParseError, CustomProperty, Display, Filter, FontFamily, FontSize, FontStretch,
-    FontStyle, FontWeight, LayoutType, LengthOrPercentage, Opacity, Outline, Overflow, Parse,
-    Position, PositionType, Rect, Scale, TextAlign, Transform, Transition, Translate, Units,
-    UnparsedProperty, Visibility,
+    define_property, AlignContent, AlignItems, Angle, BackgroundImage, BackgroundSize, Border,
+    BorderColor, BorderCornerShape, BorderRadius, BorderWidth, BorderWidthValue, BoxShadow,
+    BoxSizing, CaretShape, ClipPath, Color, CursorIcon, CustomParseError, CustomProperty,
+    Direction, Display, Duration, Filter, FlexWrap, FontFamily, FontHinting, FontSize,
+    FontSmoothing, FontStretch, FontStyle, FontWeight, ImageRendering, JustifyContent, LayoutType,
+    LengthOrPercentage, MaskMode, Opacity, Outline, Overflow, Parse, Position, PositionType, Rect,
+    Scale, SizeUnits, TextAlign, Transform, Transition, Translate, Units, UnparsedProperty,
+    Visibility, WillChange,
 };
 use cssparser::Parser;
 
@@ -18,21 +20,28 @@ define_property! {
         "overflow-y": OverflowY(Overflow),
         "clip-path": ClipPath(ClipPath),
         "opacity": Opacity(Opacity),
+        "will-change": WillChange(WillChange),
         "z-index": ZIndex(i32),
 
         // Positioning
         "layout-type": LayoutType(LayoutType),
         "position-type": PositionType(PositionType),
+        "direction": Direction(Direction),
+        "box-sizing": BoxSizing(BoxSizing),
 
         // Position and Size
         "space": Space(Units),
         "left": Left(Units),
-        "width": Width(Units),
+        "width": Width(SizeUnits),
         "right": Right(Units),
         "top": Top(Units),
         "size": Size(Units),
         "height": Height(Units),
         "bottom": Bottom(Units),
+        "space-inline-start": SpaceInlineStart(Units),
+        "space-inline-end": SpaceInlineEnd(Units),
+        "space-block-start": SpaceBlockStart(Units),
+        "space-block-end": SpaceBlockEnd(Units),
 
         // Constraints
         "min-space": MinSpace(Units),
@@ -61,20 +70,31 @@ define_property! {
         "child-right": ChildRight(Units),
         "child-top": ChildTop(Units),
         "child-bottom": ChildBottom(Units),
+        "child-space-inline-start": ChildSpaceInlineStart(Units),
+        "child-space-inline-end": ChildSpaceInlineEnd(Units),
+        "child-space-block-start": ChildSpaceBlockStart(Units),
+        "child-space-block-end": ChildSpaceBlockEnd(Units),
         "row-between": RowBetween(Units),
         "col-between": ColBetween(Units),
+        "gap": Gap(Units),
+        "row-gap": RowGap(Units),
+        "column-gap": ColumnGap(Units),
+        "flex-wrap": FlexWrap(FlexWrap),
+        "align-content": AlignContent(AlignContent),
+        "justify-content": JustifyContent(JustifyContent),
+        "align-items": AlignItems(AlignItems),
+        "order": Order(i32),
         // ----- Border -----
 
         // Border Shorthand
         "border": Border(Border),
 
         // Border Color
-        "border-color": BorderColor(Color),
-        // TODO: Support coloring individual borders.
-        // "border-top-color": BorderTopColor(Color),
-        // "border-right-color": BorderRightColor(Color),
-        // "border-bottom-color": BorderBottomColor(Color),
-        // "border-left-color": BorderLeftColor(Color),
+        "border-color": BorderColor(BorderColor),
+        "border-top-color": BorderTopColor(Color),
+        "border-right-color": BorderRightColor(Color),
+        "border-bottom-color": BorderBottomColor(Color),
+        "border-left-color": BorderLeftColor(Color),
 
         // Border Corner Shape
         "border-corner-shape": BorderCornerShape(Rect<BorderCornerShape>),
@@ -139,6 +159,12 @@ define_property! {
         "background-color": BackgroundColor(Color),
         "background-image": BackgroundImage(Vec<BackgroundImage<'i>>),
         "background-size": BackgroundSize(Vec<BackgroundSize>),
+        "image-rendering": ImageRendering(ImageRendering),
+        "image-tint": ImageTint(Color),
+
+        // Mask
+        "mask-image": MaskImage(Vec<BackgroundImage<'i>>),
+        "mask-mode": MaskMode(MaskMode),
 
         // Font
         "font-size": FontSize(FontSize),
@@ -147,16 +173,23 @@ define_property! {
         "font-weight": FontWeight(FontWeight),
         "font-style": FontStyle(FontStyle),
         "font-stretch": FontStretch(FontStretch),
+        "font-smoothing": FontSmoothing(FontSmoothing),
+        "font-hinting": FontHinting(FontHinting),
         "selection-color": SelectionColor(Color), // TODO: Remove this once we have the pseudoselector version.
         "caret-color": CaretColor(Color),
+        "caret-blink-rate": CaretBlinkRate(Duration),
+        "caret-shape": CaretShape(CaretShape),
+        "caret-width": CaretWidth(LengthOrPercentage),
+        "link-color": LinkColor(Color),
         "text-wrap": TextWrap(bool),
         "text-align": TextAlign(TextAlign),
 
         // Box Shadow
         "box-shadow": BoxShadow(Vec<BoxShadow>),
 
-        // Backdrop Filter
+        // Filters
         "backdrop-filter": BackdropFilter(Filter),
+        "filter": Filter(Filter),
 
         // Animations
         "transition": Transition(Vec<Transition>),
@@ -0,0 +1,23 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines how the wrapped lines of an entity are aligned along its cross axis.
+    pub enum AlignContent {
+        /// The lines are packed against the start of the cross axis.
+        "start": Start,
+        /// The lines are packed in the center of the cross axis.
+        "center": Center,
+        /// The lines are packed against the end of the cross axis.
+        "end": End,
+        /// The free space is distributed evenly between the lines.
+        "space-between": SpaceBetween,
+        /// The free space is distributed evenly around the lines.
+        "space-around": SpaceAround,
+    }
+}
+
+impl Default for AlignContent {
+    fn default() -> Self {
+        AlignContent::Start
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines how the children of a row or column entity are aligned along its cross axis.
+    pub enum AlignItems {
+        /// The children are aligned to the start of the cross axis.
+        "start": Start,
+        /// The children are aligned to the center of the cross axis.
+        "center": Center,
+        /// The children are aligned to the end of the cross axis.
+        "end": End,
+    }
+}
+
+impl Default for AlignItems {
+    fn default() -> Self {
+        AlignItems::Start
+    }
+}
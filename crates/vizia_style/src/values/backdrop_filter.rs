@@ -1,9 +1,20 @@
-use crate::{CustomParseError, Length, Parse};
+use crate::{Color, CustomParseError, Length, Parse};
 use cssparser::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Filter {
     Blur(Length),
+    /// A shadow in the shape of the drawn content, cast by its alpha channel.
+    DropShadow {
+        /// The horizontal offset of the shadow.
+        x_offset: Length,
+        /// The vertical offset of the shadow.
+        y_offset: Length,
+        /// The blur radius of the shadow.
+        blur_radius: Option<Length>,
+        /// The color of the shadow, which is black if not set.
+        color: Option<Color>,
+    },
 }
 
 impl Default for Filter {
@@ -23,6 +34,19 @@ impl<'i> Parse<'i> for Filter {
                     Ok(Filter::Blur(input.try_parse(Length::parse).unwrap_or(Length::px(0.0))))
                 },
 
+                "drop-shadow" => {
+                    // The color may be given before or after the lengths.
+                    let mut color = input.try_parse(Color::parse).ok();
+                    let x_offset = Length::parse(input)?;
+                    let y_offset = Length::parse(input)?;
+                    let blur_radius = input.try_parse(Length::parse).ok();
+                    if color.is_none() {
+                        color = input.try_parse(Color::parse).ok();
+                    }
+
+                    Ok(Filter::DropShadow { x_offset, y_offset, blur_radius, color })
+                },
+
                 _ => {
                     Err(location.new_unexpected_token_error(Token::Ident(function)))
                 }
@@ -30,3 +54,42 @@ impl<'i> Parse<'i> for Filter {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Filter, parse_filter,
+
+        custom {
+            success {
+                "blur(10px)" => Filter::Blur(Length::px(10.0)),
+                "drop-shadow(10px 20px)" => Filter::DropShadow {
+                    x_offset: Length::px(10.0),
+                    y_offset: Length::px(20.0),
+                    blur_radius: None,
+                    color: None,
+                },
+                "drop-shadow(10px 20px 30px red)" => Filter::DropShadow {
+                    x_offset: Length::px(10.0),
+                    y_offset: Length::px(20.0),
+                    blur_radius: Some(Length::px(30.0)),
+                    color: Some(Color::rgb(255, 0, 0)),
+                },
+                "drop-shadow(red 10px 20px)" => Filter::DropShadow {
+                    x_offset: Length::px(10.0),
+                    y_offset: Length::px(20.0),
+                    blur_radius: None,
+                    color: Some(Color::rgb(255, 0, 0)),
+                },
+            }
+
+            failure {
+                "test",
+                "drop-shadow(10px)",
+            }
+        }
+    }
+}
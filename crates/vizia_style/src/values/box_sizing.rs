@@ -0,0 +1,18 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether the width and height of an entity include its child space and border.
+    pub enum BoxSizing {
+        /// The width and height include the child space and border of the entity.
+        "border-box": BorderBox,
+        /// The width and height are the size of the content, with the child space and border added
+        /// around it.
+        "content-box": ContentBox,
+    }
+}
+
+impl Default for BoxSizing {
+    fn default() -> Self {
+        BoxSizing::BorderBox
+    }
+}
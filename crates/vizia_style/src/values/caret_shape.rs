@@ -0,0 +1,19 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines the shape of the text caret.
+    pub enum CaretShape {
+        /// A thin vertical bar before the character at the caret.
+        "bar": Bar,
+        /// A block covering the character at the caret, which is drawn inverted.
+        "block": Block,
+        /// A line below the character at the caret.
+        "underline": Underline,
+    }
+}
+
+impl Default for CaretShape {
+    fn default() -> Self {
+        CaretShape::Bar
+    }
+}
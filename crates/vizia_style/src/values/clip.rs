@@ -1,10 +1,44 @@
 use crate::{AutoKeyword, Length, LengthOrPercentage, Parse, Rect};
 use cssparser::*;
+use morphorm::Units;
 
+/// The region of a view which it and its descendants are clipped to.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ClipPath {
+    /// The view is not clipped by a clip path.
     Auto,
+    /// The bounds of the view inset by the top, right, bottom, and left values.
     Shape(Rect<LengthOrPercentage>),
+    /// The bounds of the view inset by the top, right, bottom, and left values, with corners rounded
+    /// by the given radius.
+    RoundedShape(Rect<LengthOrPercentage>, LengthOrPercentage),
+}
+
+impl ClipPath {
+    /// Creates a clip path which insets the bounds of a view by the given amount on each side.
+    pub fn inset(
+        top: impl Into<LengthOrPercentage>,
+        right: impl Into<LengthOrPercentage>,
+        bottom: impl Into<LengthOrPercentage>,
+        left: impl Into<LengthOrPercentage>,
+    ) -> Self {
+        ClipPath::Shape(Rect(top.into(), right.into(), bottom.into(), left.into()))
+    }
+
+    /// Creates a clip path which insets the bounds of a view by the given amount on each side, with
+    /// corners rounded by the given radius.
+    pub fn rounded_inset(
+        top: impl Into<LengthOrPercentage>,
+        right: impl Into<LengthOrPercentage>,
+        bottom: impl Into<LengthOrPercentage>,
+        left: impl Into<LengthOrPercentage>,
+        radius: impl Into<LengthOrPercentage>,
+    ) -> Self {
+        ClipPath::RoundedShape(
+            Rect(top.into(), right.into(), bottom.into(), left.into()),
+            radius.into(),
+        )
+    }
 }
 
 impl Default for ClipPath {
@@ -19,6 +53,12 @@ impl From<Rect<LengthOrPercentage>> for ClipPath {
     }
 }
 
+impl From<(Rect<LengthOrPercentage>, LengthOrPercentage)> for ClipPath {
+    fn from(value: (Rect<LengthOrPercentage>, LengthOrPercentage)) -> Self {
+        ClipPath::RoundedShape(value.0, value.1)
+    }
+}
+
 impl From<LengthOrPercentage> for ClipPath {
     fn from(value: LengthOrPercentage) -> Self {
         ClipPath::Shape(Rect(value.clone(), value.clone(), value.clone(), value))
@@ -36,6 +76,12 @@ impl From<Length> for ClipPath {
     }
 }
 
+impl From<Units> for ClipPath {
+    fn from(value: Units) -> Self {
+        LengthOrPercentage::from(value).into()
+    }
+}
+
 impl<'i> Parse<'i> for ClipPath {
     fn parse<'t>(
         input: &mut cssparser::Parser<'i, 't>,
@@ -50,7 +96,12 @@ impl<'i> Parse<'i> for ClipPath {
                 match_ignore_ascii_case! { &function,
                     "inset" | "rect" => {
                         let rect = Rect::parse(input)?;
-                        Ok(ClipPath::Shape(rect))
+                        if input.try_parse(|input| input.expect_ident_matching("round")).is_ok() {
+                            let radius = LengthOrPercentage::parse(input)?;
+                            Ok(ClipPath::RoundedShape(rect, radius))
+                        } else {
+                            Ok(ClipPath::Shape(rect))
+                        }
                     },
 
                     _ => {
@@ -61,3 +112,46 @@ impl<'i> Parse<'i> for ClipPath {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        ClipPath, parse_clip_path,
+
+        custom {
+            success {
+                "auto" => ClipPath::Auto,
+                "inset(10px)" => ClipPath::Shape(Rect(
+                    LengthOrPercentage::Length(Length::px(10.0)),
+                    LengthOrPercentage::Length(Length::px(10.0)),
+                    LengthOrPercentage::Length(Length::px(10.0)),
+                    LengthOrPercentage::Length(Length::px(10.0)),
+                )),
+                "inset(10px 20%)" => ClipPath::Shape(Rect(
+                    LengthOrPercentage::Length(Length::px(10.0)),
+                    LengthOrPercentage::Percentage(20.0),
+                    LengthOrPercentage::Length(Length::px(10.0)),
+                    LengthOrPercentage::Percentage(20.0),
+                )),
+                "inset(10px round 5px)" => ClipPath::RoundedShape(
+                    Rect(
+                        LengthOrPercentage::Length(Length::px(10.0)),
+                        LengthOrPercentage::Length(Length::px(10.0)),
+                        LengthOrPercentage::Length(Length::px(10.0)),
+                        LengthOrPercentage::Length(Length::px(10.0)),
+                    ),
+                    LengthOrPercentage::Length(Length::px(5.0)),
+                ),
+            }
+
+            failure {
+                "test",
+                "circle(10px)",
+                "inset(10px round)",
+            }
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{macros::define_enum, Parse};
 
 define_enum! {
+    /// Determines the horizontal direction in which an entity and its descendants are laid out.
     pub enum Direction {
-        /// The entity will be rendered and acted on by the layout system.
+        /// Content is laid out from left to right.
         "ltr": Ltr,
-        /// The entity will not be rendered and acted on by the layout system.
+        /// Content is laid out from right to left, mirroring the horizontal layout.
         "rtl": Rtl,
     }
 }
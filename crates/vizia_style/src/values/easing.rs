@@ -1,6 +1,10 @@
-use crate::{CustomParseError, Parse};
+use crate::{CustomParseError, Parse, StepPosition};
 use cssparser::*;
 
+/// Determines how the progress of an animation or transition is eased over its duration.
+///
+/// The named curves, such as [`EasingFunction::EaseInOutCubic`], follow the common set of easing
+/// functions referenced by designers, and can be used in CSS as `ease-in-out-cubic` and so on.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum EasingFunction {
     #[default]
@@ -9,8 +13,70 @@ pub enum EasingFunction {
     EaseIn,
     EaseOut,
     EaseInOut,
+    EaseInSine,
+    EaseOutSine,
+    EaseInOutSine,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+    EaseInQuart,
+    EaseOutQuart,
+    EaseInOutQuart,
+    EaseInQuint,
+    EaseOutQuint,
+    EaseInOutQuint,
+    EaseInExpo,
+    EaseOutExpo,
+    EaseInOutExpo,
+    EaseInCirc,
+    EaseOutCirc,
+    EaseInOutCirc,
+    EaseInBack,
+    EaseOutBack,
+    EaseInOutBack,
+    EaseInElastic,
+    EaseOutElastic,
+    EaseInOutElastic,
+    EaseInBounce,
+    EaseOutBounce,
+    EaseInOutBounce,
     CubicBezier(f32, f32, f32, f32),
-    // TODO: Steps
+    /// A spring with a unit mass, which runs until the spring comes to rest rather than for the
+    /// duration of the animation, and overshoots its target if it is underdamped.
+    Spring {
+        stiffness: f32,
+        damping: f32,
+    },
+    /// Divides the animation into a number of equal steps, holding the value between jumps.
+    Steps(u32, StepPosition),
+    /// A custom easing function, created with [`EasingFunction::custom`].
+    Custom(CustomEasing),
+}
+
+/// A function which maps the progress of an animation, from 0.0 to 1.0, to an eased progress.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomEasing(pub fn(f32) -> f32);
+
+impl PartialEq for CustomEasing {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+impl EasingFunction {
+    /// Creates an easing function from a function, or a closure which does not capture anything.
+    pub fn custom(easing: fn(f32) -> f32) -> Self {
+        EasingFunction::Custom(CustomEasing(easing))
+    }
+
+    /// Creates an easing function which divides the animation into `count` equal steps, with the
+    /// jumps between them positioned by `jump`.
+    pub fn steps(count: u32, jump: StepPosition) -> Self {
+        EasingFunction::Steps(count, jump)
+    }
 }
 
 impl<'i> Parse<'i> for EasingFunction {
@@ -23,8 +89,38 @@ impl<'i> Parse<'i> for EasingFunction {
               "ease-in" => EasingFunction::EaseIn,
               "ease-out" => EasingFunction::EaseOut,
               "ease-in-out" => EasingFunction::EaseInOut,
-            //   "step-start" => EasingFunction::Steps { count: 1, position: StepPosition::Start },
-            //   "step-end" => EasingFunction::Steps { count: 1, position: StepPosition::End },
+              "ease-in-sine" => EasingFunction::EaseInSine,
+              "ease-out-sine" => EasingFunction::EaseOutSine,
+              "ease-in-out-sine" => EasingFunction::EaseInOutSine,
+              "ease-in-quad" => EasingFunction::EaseInQuad,
+              "ease-out-quad" => EasingFunction::EaseOutQuad,
+              "ease-in-out-quad" => EasingFunction::EaseInOutQuad,
+              "ease-in-cubic" => EasingFunction::EaseInCubic,
+              "ease-out-cubic" => EasingFunction::EaseOutCubic,
+              "ease-in-out-cubic" => EasingFunction::EaseInOutCubic,
+              "ease-in-quart" => EasingFunction::EaseInQuart,
+              "ease-out-quart" => EasingFunction::EaseOutQuart,
+              "ease-in-out-quart" => EasingFunction::EaseInOutQuart,
+              "ease-in-quint" => EasingFunction::EaseInQuint,
+              "ease-out-quint" => EasingFunction::EaseOutQuint,
+              "ease-in-out-quint" => EasingFunction::EaseInOutQuint,
+              "ease-in-expo" => EasingFunction::EaseInExpo,
+              "ease-out-expo" => EasingFunction::EaseOutExpo,
+              "ease-in-out-expo" => EasingFunction::EaseInOutExpo,
+              "ease-in-circ" => EasingFunction::EaseInCirc,
+              "ease-out-circ" => EasingFunction::EaseOutCirc,
+              "ease-in-out-circ" => EasingFunction::EaseInOutCirc,
+              "ease-in-back" => EasingFunction::EaseInBack,
+              "ease-out-back" => EasingFunction::EaseOutBack,
+              "ease-in-out-back" => EasingFunction::EaseInOutBack,
+              "ease-in-elastic" => EasingFunction::EaseInElastic,
+              "ease-out-elastic" => EasingFunction::EaseOutElastic,
+              "ease-in-out-elastic" => EasingFunction::EaseInOutElastic,
+              "ease-in-bounce" => EasingFunction::EaseInBounce,
+              "ease-out-bounce" => EasingFunction::EaseOutBounce,
+              "ease-in-out-bounce" => EasingFunction::EaseInOutBounce,
+              "step-start" => EasingFunction::Steps(1, StepPosition::JumpStart),
+              "step-end" => EasingFunction::Steps(1, StepPosition::JumpEnd),
               _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
             };
             return Ok(keyword);
@@ -44,16 +140,50 @@ impl<'i> Parse<'i> for EasingFunction {
                 let y2 = input.try_parse(|input| input.expect_number())?;
                 Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
               },
-            //   "steps" => {
-            //     let count = CSSInteger::parse(input)?;
-            //     let position = input.try_parse(|input| {
-            //       input.expect_comma()?;
-            //       StepPosition::parse(input)
-            //     }).unwrap_or_default();
-            //     Ok(EasingFunction::Steps { count, position })
-            //   },
+              "steps" => {
+                let count_location = input.current_source_location();
+                let count = input.expect_integer()?;
+                let position = input.try_parse(|input| {
+                  input.expect_comma()?;
+                  StepPosition::parse(input)
+                }).unwrap_or_default();
+                // A steps function with no jumps at either end needs at least two steps.
+                let min_count = if position == StepPosition::JumpNone { 2 } else { 1 };
+                if count < min_count {
+                  return Err(count_location.new_custom_error(CustomParseError::InvalidValue));
+                }
+                Ok(EasingFunction::Steps(count as u32, position))
+              },
               _ => return Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
             }
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        EasingFunction, parse_easing_function,
+
+        success {
+            "ease-in-out" => EasingFunction::EaseInOut,
+            "ease-in-out-cubic" => EasingFunction::EaseInOutCubic,
+            "ease-out-back" => EasingFunction::EaseOutBack,
+            "ease-in-elastic" => EasingFunction::EaseInElastic,
+            "cubic-bezier(0.1, 0.7, 1.0, 0.1)" => EasingFunction::CubicBezier(0.1, 0.7, 1.0, 0.1),
+            "step-start" => EasingFunction::Steps(1, StepPosition::JumpStart),
+            "steps(4)" => EasingFunction::Steps(4, StepPosition::JumpEnd),
+            "steps(4, jump-both)" => EasingFunction::Steps(4, StepPosition::JumpBoth),
+        }
+
+        failure {
+            "ease-in-cubic-out",
+            "steps(0)",
+            "steps(1, jump-none)",
+            "steps(2.5)",
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines the shape of a radial gradient.
+    pub enum EndingShape {
+        /// The gradient is circular, reaching the farthest corner of the view.
+        "circle": Circle,
+        /// The gradient is elliptical, with the aspect ratio of the view, reaching its farthest corner.
+        "ellipse": Ellipse,
+    }
+}
+
+impl Default for EndingShape {
+    fn default() -> Self {
+        EndingShape::Ellipse
+    }
+}
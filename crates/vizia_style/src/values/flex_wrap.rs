@@ -0,0 +1,17 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether the children of a row or column flow onto multiple lines.
+    pub enum FlexWrap {
+        /// The children are laid out on a single line.
+        "nowrap": NoWrap,
+        /// The children are laid out on as many lines as needed to fit within the entity.
+        "wrap": Wrap,
+    }
+}
+
+impl Default for FlexWrap {
+    fn default() -> Self {
+        FlexWrap::NoWrap
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines whether the outlines of glyphs are hinted to align with the pixel grid.
+    pub enum FontHinting {
+        /// Uses the hinting of the application text config.
+        "auto": Auto,
+        /// Glyph outlines are hinted.
+        "full": Full,
+        /// Glyph outlines are not hinted, which keeps their shape at the cost of sharpness.
+        "none": None,
+    }
+}
+
+impl Default for FontHinting {
+    fn default() -> Self {
+        FontHinting::Auto
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines how the glyphs of text are antialiased.
+    pub enum FontSmoothing {
+        /// Uses the antialiasing of the application text config, except for rotated or skewed text
+        /// which is always antialiased in grayscale.
+        "auto": Auto,
+        /// Glyphs are antialiased with a single coverage value per pixel.
+        "grayscale": Grayscale,
+        /// Glyphs are antialiased with a coverage value per color channel, which gives sharper text
+        /// on low density displays.
+        "subpixel": Subpixel,
+    }
+}
+
+impl Default for FontSmoothing {
+    fn default() -> Self {
+        FontSmoothing::Auto
+    }
+}
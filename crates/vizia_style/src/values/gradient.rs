@@ -1,6 +1,6 @@
 use crate::{
-    Angle, Color, CustomParseError, HorizontalPositionKeyword, LengthOrPercentage, Parse, Position,
-    VerticalPositionKeyword,
+    Angle, Color, CustomParseError, DimensionPercentage, EndingShape, HorizontalPositionKeyword,
+    LengthOrPercentage, Parse, Percentage, Position, VerticalPositionKeyword,
 };
 use cssparser::*;
 
@@ -9,6 +9,7 @@ pub enum Gradient {
     None,
     Linear(LinearGradient),
     Radial(RadialGradient),
+    Conic(ConicGradient),
 }
 
 impl Default for Gradient {
@@ -23,6 +24,18 @@ impl From<LinearGradient> for Gradient {
     }
 }
 
+impl From<RadialGradient> for Gradient {
+    fn from(radial_gradient: RadialGradient) -> Self {
+        Gradient::Radial(radial_gradient)
+    }
+}
+
+impl From<ConicGradient> for Gradient {
+    fn from(conic_gradient: ConicGradient) -> Self {
+        Gradient::Conic(conic_gradient)
+    }
+}
+
 impl<'i> Parse<'i> for Gradient {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         let location = input.current_source_location();
@@ -31,6 +44,7 @@ impl<'i> Parse<'i> for Gradient {
             match_ignore_ascii_case! { &func,
               "linear-gradient" => Ok(Gradient::Linear(LinearGradient::parse(input)?)),
               "radial-gradient" => Ok(Gradient::Radial(RadialGradient::parse(input)?)),
+              "conic-gradient" => Ok(Gradient::Conic(ConicGradient::parse(input)?)),
               _ => Err(location.new_unexpected_token_error(cssparser::Token::Ident(func.clone())))
             }
         })
@@ -177,15 +191,14 @@ fn parse_items<'i, 't, D: Parse<'i>>(
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RadialGradient {
+    pub shape: EndingShape,
     pub position: Position,
     pub stops: Vec<ColorStop<LengthOrPercentage>>,
 }
 
-impl<'i> RadialGradient {
-    fn parse<'t>(
-        input: &mut Parser<'i, 't>,
-    ) -> Result<RadialGradient, ParseError<'i, CustomParseError<'i>>> {
-        // let shape = input.try_parse(EndingShape::parse).ok();
+impl<'i> Parse<'i> for RadialGradient {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let shape = input.try_parse(EndingShape::parse).ok();
         let position = input
             .try_parse(|input| {
                 input.expect_ident_matching("at")?;
@@ -193,13 +206,53 @@ impl<'i> RadialGradient {
             })
             .ok();
 
-        // if shape.is_some() || position.is_some() {
-        //     input.expect_comma()?;
-        // }
+        if shape.is_some() || position.is_some() {
+            input.expect_comma()?;
+        }
 
         let stops = parse_items(input)?;
         Ok(RadialGradient {
-            // shape: shape.unwrap_or_default(),
+            shape: shape.unwrap_or_default(),
+            position: position.unwrap_or(Position::center()),
+            stops,
+        })
+    }
+}
+
+/// A gradient whose colors transition around a center point, such as for a color wheel.
+///
+/// The positions of the color stops are angles, or percentages of a full turn, clockwise from the
+/// starting angle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient {
+    /// The angle of the start of the gradient, clockwise from the top of the view.
+    pub angle: Angle,
+    pub position: Position,
+    pub stops: Vec<ColorStop<DimensionPercentage<Angle>>>,
+}
+
+impl<'i> Parse<'i> for ConicGradient {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let angle = input
+            .try_parse(|input| {
+                input.expect_ident_matching("from")?;
+                Angle::parse(input)
+            })
+            .ok();
+        let position = input
+            .try_parse(|input| {
+                input.expect_ident_matching("at")?;
+                Position::parse(input)
+            })
+            .ok();
+
+        if angle.is_some() || position.is_some() {
+            input.expect_comma()?;
+        }
+
+        let stops = parse_items(input)?;
+        Ok(ConicGradient {
+            angle: angle.unwrap_or_default(),
             position: position.unwrap_or(Position::center()),
             stops,
         })
@@ -231,3 +284,55 @@ impl<D> From<(Color, D)> for ColorStop<D> {
         ColorStop { color: value.0, position: Some(value.1) }
     }
 }
+
+/// Creates a color stop from a position between 0.0 and 1.0 along the gradient and a color.
+impl From<(f32, Color)> for ColorStop<LengthOrPercentage> {
+    fn from(value: (f32, Color)) -> Self {
+        ColorStop {
+            color: value.1,
+            position: Some(LengthOrPercentage::Percentage(value.0.clamp(0.0, 1.0) * 100.0)),
+        }
+    }
+}
+
+/// Creates a color stop from a position between 0.0 and 1.0 around the gradient and a color.
+impl From<(f32, Color)> for ColorStop<DimensionPercentage<Angle>> {
+    fn from(value: (f32, Color)) -> Self {
+        ColorStop {
+            color: value.1,
+            position: Some(DimensionPercentage::Percentage(Percentage(
+                value.0.clamp(0.0, 1.0) * 100.0,
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Gradient, parse_gradient,
+
+        success {
+            "radial-gradient(circle, red, blue)" => Gradient::Radial(RadialGradient {
+                shape: EndingShape::Circle,
+                position: Position::center(),
+                stops: vec![Color::rgb(255, 0, 0).into(), Color::rgb(0, 0, 255).into()],
+            }),
+            "conic-gradient(from 90deg, red, blue 50%)" => Gradient::Conic(ConicGradient {
+                angle: Angle::Deg(90.0),
+                position: Position::center(),
+                stops: vec![
+                    Color::rgb(255, 0, 0).into(),
+                    (Color::rgb(0, 0, 255), DimensionPercentage::Percentage(Percentage(50.0))).into(),
+                ],
+            }),
+        }
+
+        failure {
+            "conic-gradient(from 90deg red, blue)",
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines how an image is sampled when it is drawn at a different size to its natural size.
+    pub enum ImageRendering {
+        /// The image is smoothly interpolated, which suits photographs.
+        "smooth": Smooth,
+        /// The image is scaled with nearest-neighbour sampling so that pixel art stays crisp.
+        "pixelated": Pixelated,
+    }
+}
+
+impl Default for ImageRendering {
+    fn default() -> Self {
+        ImageRendering::Smooth
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    macros::{define_enum, impl_parse},
+    Parse, Units,
+};
+
+define_enum! {
+    /// Determines how the content of an entity with an auto width is measured.
+    pub enum IntrinsicSize {
+        /// The content is as narrow as it can be, e.g. text wraps at every opportunity.
+        "min-content": MinContent,
+        /// The content is as wide as it can be, e.g. text does not wrap.
+        "max-content": MaxContent,
+    }
+}
+
+/// The width of an entity, which is either a size in units or a size measured from its content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SizeUnits {
+    /// A size in pixels, a percentage, stretch, or auto.
+    Units(Units),
+    /// An auto size which is as narrow as the content can be, e.g. text wraps at every opportunity.
+    MinContent,
+    /// An auto size which is as wide as the content can be, e.g. text does not wrap.
+    MaxContent,
+}
+
+impl Default for SizeUnits {
+    fn default() -> Self {
+        SizeUnits::Units(Units::default())
+    }
+}
+
+impl SizeUnits {
+    /// Returns the units of the size, which are auto for a size measured from the content.
+    pub fn units(&self) -> Units {
+        match self {
+            SizeUnits::Units(units) => *units,
+            SizeUnits::MinContent | SizeUnits::MaxContent => Units::Auto,
+        }
+    }
+
+    /// Returns how the content is measured, if the size is measured from the content.
+    pub fn intrinsic_size(&self) -> Option<IntrinsicSize> {
+        match self {
+            SizeUnits::Units(_) => None,
+            SizeUnits::MinContent => Some(IntrinsicSize::MinContent),
+            SizeUnits::MaxContent => Some(IntrinsicSize::MaxContent),
+        }
+    }
+}
+
+impl_parse! {
+    SizeUnits,
+
+    try_parse {
+        IntrinsicSize,
+        Units,
+    }
+}
+
+impl From<IntrinsicSize> for SizeUnits {
+    fn from(intrinsic_size: IntrinsicSize) -> Self {
+        match intrinsic_size {
+            IntrinsicSize::MinContent => SizeUnits::MinContent,
+            IntrinsicSize::MaxContent => SizeUnits::MaxContent,
+        }
+    }
+}
+
+impl From<Units> for SizeUnits {
+    fn from(units: Units) -> Self {
+        SizeUnits::Units(units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        SizeUnits, parse_size_units,
+
+        success {
+            "min-content" => SizeUnits::MinContent,
+            "max-content" => SizeUnits::MaxContent,
+            "auto" => SizeUnits::Units(Units::Auto),
+            "10px" => SizeUnits::Units(Units::Pixels(10.0)),
+        }
+
+        failure {
+            "fit-content",
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines how the children of a row or column entity are distributed along its main axis.
+    pub enum JustifyContent {
+        /// The children are packed against the start of the main axis.
+        "start": Start,
+        /// The children are packed in the center of the main axis.
+        "center": Center,
+        /// The children are packed against the end of the main axis.
+        "end": End,
+        /// The free space is distributed evenly between the children.
+        "space-between": SpaceBetween,
+        /// The free space is distributed evenly around the children.
+        "space-around": SpaceAround,
+    }
+}
+
+impl Default for JustifyContent {
+    fn default() -> Self {
+        JustifyContent::Start
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines which channel of a mask image is used to mask a view.
+    pub enum MaskMode {
+        /// The alpha channel of the mask is used, so transparent areas hide the view.
+        "alpha": Alpha,
+        /// The luminance of the mask is used, so dark areas hide the view.
+        "luminance": Luminance,
+    }
+}
+
+impl Default for MaskMode {
+    fn default() -> Self {
+        MaskMode::Alpha
+    }
+}
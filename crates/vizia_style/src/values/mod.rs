@@ -1,3 +1,5 @@
+pub mod align_content;
+pub mod align_items;
 pub mod alpha;
 pub mod angle;
 pub mod backdrop_filter;
@@ -5,7 +7,9 @@ pub mod background_size;
 pub mod basic;
 pub mod border;
 pub mod box_shadow;
+pub mod box_sizing;
 pub mod calc;
+pub mod caret_shape;
 pub mod clip;
 pub mod color;
 pub mod cursor_icon;
@@ -15,9 +19,13 @@ pub mod direction;
 pub mod display;
 pub mod duration;
 pub mod easing;
+pub mod ending_shape;
+pub mod flex_wrap;
 pub mod font_family;
+pub mod font_hinting;
 pub mod font_size;
 pub mod font_size_keyword;
+pub mod font_smoothing;
 pub mod font_stretch;
 pub mod font_style;
 pub mod font_weight;
@@ -25,11 +33,15 @@ pub mod font_weight_keyword;
 pub mod gradient;
 pub mod horizontal_position_keyword;
 pub mod image;
+pub mod image_rendering;
+pub mod intrinsic_size;
+pub mod justify_content;
 pub mod keywords;
 pub mod layout_type;
 pub mod length;
 pub mod length_or_percentage;
 pub mod length_percentage_auto;
+pub mod mask_mode;
 pub mod matrix;
 pub mod number_or_percentage;
 pub mod opacity;
@@ -40,6 +52,7 @@ pub mod position_type;
 pub mod rect;
 pub mod rotate;
 pub mod scale;
+pub mod step_position;
 pub mod stretch;
 pub mod text_align;
 pub mod text_overflow;
@@ -50,7 +63,10 @@ pub mod units;
 pub mod url;
 pub mod vertical_position_keyword;
 pub mod visibility;
+pub mod will_change;
 
+pub use align_content::*;
+pub use align_items::*;
 pub use alpha::*;
 pub use angle::*;
 pub use backdrop_filter::*;
@@ -58,7 +74,9 @@ pub use background_size::*;
 pub use basic::*;
 pub use border::*;
 pub use box_shadow::*;
+pub use box_sizing::*;
 pub use calc::*;
+pub use caret_shape::*;
 pub use clip::*;
 pub use color::*;
 pub use cursor_icon::*;
@@ -68,9 +86,13 @@ pub use direction::*;
 pub use display::*;
 pub use duration::*;
 pub use easing::*;
+pub use ending_shape::*;
+pub use flex_wrap::*;
 pub use font_family::*;
+pub use font_hinting::*;
 pub use font_size::*;
 pub use font_size_keyword::*;
+pub use font_smoothing::*;
 pub use font_stretch::*;
 pub use font_style::*;
 pub use font_weight::*;
@@ -78,11 +100,15 @@ pub use font_weight_keyword::*;
 pub use gradient::*;
 pub use horizontal_position_keyword::*;
 pub use image::*;
+pub use image_rendering::*;
+pub use intrinsic_size::*;
+pub use justify_content::*;
 pub use keywords::*;
 pub use layout_type::*;
 pub use length::*;
 pub use length_or_percentage::*;
 pub use length_percentage_auto::*;
+pub use mask_mode::*;
 pub use matrix::*;
 pub use number_or_percentage::*;
 pub use opacity::*;
@@ -93,6 +119,7 @@ pub use position_type::*;
 pub use rect::*;
 pub use rotate::*;
 pub use scale::*;
+pub use step_position::*;
 pub use stretch::*;
 pub use text_align::*;
 pub use text_overflow::*;
@@ -103,3 +130,4 @@ pub use units::*;
 pub use url::*;
 pub use vertical_position_keyword::*;
 pub use visibility::*;
+pub use will_change::*;
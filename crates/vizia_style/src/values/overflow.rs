@@ -5,12 +5,13 @@ define_enum! {
     pub enum Overflow {
         /// The overflow is not clipped and renders outside of the bounding box of the element.
         "visible": Visible,
-        /// The overflow is clipped and the content can only be scrolled programmatically.
+        /// The overflow is clipped and the content can't be scrolled.
         "hidden": Hidden,
         // /// The overflow is clipped and the content can't be scrolled at all.
         // "clip": Clip,
-        // /// The overflow is clipped and scrollbars appears to view the rest of the content.
-        // "scroll": Scroll,
+        /// The overflow is clipped and the content can be scrolled with the mouse wheel and the Page Up
+        /// and Page Down keys, as well as programmatically.
+        "scroll": Scroll,
         // /// The overflow is clipped and a scrollbar is automatically added on the axis where the overflow happens.
         // "auto": Auto,
     }
@@ -0,0 +1,21 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// Determines where the jumps of a `steps()` easing function occur.
+    pub enum StepPosition {
+        /// The first jump happens at the start of the animation.
+        "jump-start": JumpStart,
+        /// The last jump happens at the end of the animation.
+        "jump-end": JumpEnd,
+        /// There is no jump at the start or the end of the animation, so the value holds at both.
+        "jump-none": JumpNone,
+        /// There is a jump at both the start and the end of the animation.
+        "jump-both": JumpBoth,
+    }
+}
+
+impl Default for StepPosition {
+    fn default() -> Self {
+        StepPosition::JumpEnd
+    }
+}
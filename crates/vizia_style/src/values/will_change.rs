@@ -0,0 +1,18 @@
+use crate::{macros::define_enum, Parse};
+
+define_enum! {
+    /// A hint that a property of an entity is about to change, so that resources used to draw the
+    /// change can be prepared in advance. The hint does not change how the entity is drawn.
+    pub enum WillChange {
+        /// No property is expected to change.
+        "auto": Auto,
+        /// The mask image of the entity is expected to change.
+        "mask-image": MaskImage,
+    }
+}
+
+impl Default for WillChange {
+    fn default() -> Self {
+        WillChange::Auto
+    }
+}
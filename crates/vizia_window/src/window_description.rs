@@ -1,3 +1,7 @@
+use std::path::PathBuf;
+
+use vizia_style::Color;
+
 /// The logical size of an application window.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WindowSize {
@@ -23,25 +27,107 @@ impl From<(u32, u32)> for WindowSize {
 /// The logical position of a window in screen coordinates.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Position {
-    /// The x coordinate of the position.
-    pub x: u32,
-    /// The y coordinate of the position.
-    pub y: u32,
+    /// The x coordinate of the position, which is negative left of the primary monitor.
+    pub x: i32,
+    /// The y coordinate of the position, which is negative above the primary monitor.
+    pub y: i32,
 }
 
 impl Position {
     /// Creates a new window position.
-    pub fn new(x: u32, y: u32) -> Self {
+    pub fn new(x: i32, y: i32) -> Self {
         Position { x, y }
     }
 }
 
+impl From<(i32, i32)> for Position {
+    fn from(s: (i32, i32)) -> Self {
+        Position::new(s.0, s.1)
+    }
+}
+
 impl From<(u32, u32)> for Position {
     fn from(s: (u32, u32)) -> Self {
-        Position::new(s.0, s.1)
+        Position::new(s.0 as i32, s.1 as i32)
     }
 }
 
+/// How the window is cleared before each frame is drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClearBehavior {
+    /// Clears the window to transparent before the background color of the root view is drawn, so
+    /// that a transparent window shows the desktop through a translucent or rounded background. A
+    /// window with no background color of its own is cleared with that of the main window. This is
+    /// the default.
+    Background,
+    /// Clears the window with the given color. The alpha of the color is kept for transparent
+    /// windows.
+    Color(Color),
+    /// Does not clear the window, so that anything not drawn over in a frame keeps the content of
+    /// the previous frame.
+    ///
+    /// The previous content is only kept if the platform preserves the back buffer of the window
+    /// between frames, which is not the case for most double-buffered windows.
+    Preserve,
+}
+
+impl Default for ClearBehavior {
+    fn default() -> Self {
+        ClearBehavior::Background
+    }
+}
+
+/// The stacking level of a window relative to other windows.
+///
+/// Levels other than `AboveOwner` are applied by the windowing system to all windows, including
+/// those of other applications. Windows on the same level are stacked in the order they were last
+/// focused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowLevel {
+    /// The window is kept below all normal windows, e.g. for a desktop widget.
+    AlwaysOnBottom,
+    /// The window is stacked with other windows as they are focused. This is the default.
+    Normal,
+    /// The window is kept above all normal windows.
+    AlwaysOnTop,
+    /// The window is kept above its owner, the main window of the application, but not above the
+    /// windows of other applications, e.g. for a tool palette.
+    ///
+    /// Only supported for windows opened after the main window, and only on Windows and macOS,
+    /// where the window may also be moved or minimized along with its owner. It can't be set after
+    /// the window has been created. Elsewhere the window is stacked as a `Normal` window.
+    AboveOwner,
+}
+
+impl Default for WindowLevel {
+    fn default() -> Self {
+        WindowLevel::Normal
+    }
+}
+
+/// The fullscreen mode of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fullscreen {
+    /// The window covers the monitor it is on without changing the video mode of the monitor.
+    Borderless,
+    /// The window takes exclusive control of the monitor it is on, and sets its video mode.
+    Exclusive(VideoMode),
+}
+
+/// A video mode of a monitor, used for exclusive fullscreen.
+///
+/// Fields which are `None` are chosen by the windowing backend to best match the current monitor,
+/// so `VideoMode::default()` uses the size of the monitor with its highest refresh rate.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VideoMode {
+    /// The size of the video mode in physical pixels.
+    pub size: Option<WindowSize>,
+    /// The bit depth of the video mode.
+    pub bit_depth: Option<u16>,
+    /// The refresh rate of the video mode in millihertz.
+    pub refresh_rate_millihertz: Option<u32>,
+}
+
 /// Passed to the window to set initial window properties.
 pub struct WindowDescription {
     pub title: String,
@@ -51,14 +137,29 @@ pub struct WindowDescription {
     /// A scale factor applied on top of any DPI scaling, defaults to 1.0.
     pub user_scale_factor: f64,
     pub position: Option<Position>,
+    /// Whether the window is centered on its monitor when it is created, instead of using `position`.
+    pub center: bool,
+    /// A file in which the position and size of the window are saved when it is closed, and from
+    /// which they are restored when it is next created.
+    pub placement_path: Option<PathBuf>,
     pub resizable: bool,
     pub minimized: bool,
     pub maximized: bool,
     pub visible: bool,
     pub transparent: bool,
     pub decorations: bool,
+    /// The stacking level of the window, defaults to `Normal`.
+    pub window_level: WindowLevel,
+    /// Whether the window is kept above other windows. When set, this overrides `window_level`.
+    #[deprecated(note = "Use `window_level` with `WindowLevel::AlwaysOnTop` instead")]
     pub always_on_top: bool,
+    /// Whether the window is hidden from the taskbar, only supported on Windows.
+    pub skip_taskbar: bool,
     pub vsync: bool,
+    /// How the window is cleared before each frame is drawn, defaults to `Background`.
+    pub clear_behavior: ClearBehavior,
+    /// The (x, y) factors applied to mouse wheel scrolling, defaults to `(1.0, 1.0)`.
+    pub scroll_sensitivity: (f32, f32),
 
     // Change this to resource id when the resource manager is working
     pub icon: Option<Vec<u8>>,
@@ -70,6 +171,7 @@ pub struct WindowDescription {
 }
 
 impl Default for WindowDescription {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             title: "Vizia Application".to_string(),
@@ -78,14 +180,20 @@ impl Default for WindowDescription {
             max_inner_size: None,
             user_scale_factor: 1.0,
             position: None,
+            center: false,
+            placement_path: None,
             resizable: true,
             minimized: true,
             maximized: false,
             visible: true,
             transparent: false,
             decorations: true,
+            window_level: WindowLevel::Normal,
             always_on_top: false,
+            skip_taskbar: false,
             vsync: true,
+            clear_behavior: ClearBehavior::Background,
+            scroll_sensitivity: (1.0, 1.0),
 
             icon: None,
             icon_width: 0,
@@ -114,6 +222,18 @@ impl WindowDescription {
         self
     }
 
+    pub fn with_clear_behavior(mut self, clear_behavior: ClearBehavior) -> Self {
+        self.clear_behavior = clear_behavior;
+
+        self
+    }
+
+    pub fn with_scroll_sensitivity(mut self, x: f32, y: f32) -> Self {
+        self.scroll_sensitivity = (x, y);
+
+        self
+    }
+
     pub fn with_inner_size(mut self, width: u32, height: u32) -> Self {
         self.inner_size = WindowSize::new(width, height);
 
@@ -140,8 +260,38 @@ impl WindowDescription {
         self
     }
 
+    pub fn with_window_level(mut self, window_level: WindowLevel) -> Self {
+        self.window_level = window_level;
+
+        self
+    }
+
     pub fn with_always_on_top(mut self, flag: bool) -> Self {
-        self.always_on_top = flag;
+        self.window_level = if flag { WindowLevel::AlwaysOnTop } else { WindowLevel::Normal };
+
+        self
+    }
+
+    pub fn with_always_on_bottom(mut self, flag: bool) -> Self {
+        self.window_level = if flag { WindowLevel::AlwaysOnBottom } else { WindowLevel::Normal };
+
+        self
+    }
+
+    pub fn with_skip_taskbar(mut self, flag: bool) -> Self {
+        self.skip_taskbar = flag;
+
+        self
+    }
+
+    pub fn with_center(mut self, flag: bool) -> Self {
+        self.center = flag;
+
+        self
+    }
+
+    pub fn with_placement_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.placement_path = Some(path.into());
 
         self
     }
@@ -1,12 +1,22 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::placement::{center_window, restore_placement, save_placement};
 use crate::{
-    convert::{scan_code_to_code, virtual_key_code_to_code, virtual_key_code_to_key},
+    convert::{
+        mouse_button_to_button, scan_code_to_code, virtual_key_code_to_code,
+        virtual_key_code_to_key,
+    },
     window::Window,
 };
 #[cfg(not(target_arch = "wasm32"))]
-use accesskit::{Action, NodeBuilder, TreeUpdate};
+use accesskit::{Action, ActionData, TreeUpdate};
 #[cfg(not(target_arch = "wasm32"))]
 use accesskit_winit;
-use std::cell::RefCell;
+use instant::{Duration, Instant};
+use raw_window_handle::HasRawWindowHandle;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
 use vizia_core::backend::*;
 #[cfg(not(target_arch = "wasm32"))]
 use vizia_core::context::EventProxy;
@@ -14,26 +24,60 @@ use vizia_core::prelude::*;
 use vizia_id::GenerationalId;
 use vizia_window::Position;
 use winit::event_loop::EventLoopBuilder;
-#[cfg(all(
-    feature = "clipboard",
-    feature = "wayland",
-    any(
-        target_os = "linux",
-        target_os = "dragonfly",
-        target_os = "freebsd",
-        target_os = "netbsd",
-        target_os = "openbsd"
-    )
-))]
-use winit::platform::wayland::WindowExtWayland;
 use winit::{
     event::VirtualKeyCode,
     event_loop::{ControlFlow, EventLoop},
+    window::WindowId,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
 use winit::event_loop::EventLoopProxy;
 
+/// The number of consecutive late frames after which a possible event feedback loop is reported in
+/// debug builds.
+#[cfg(debug_assertions)]
+const LATE_FRAME_WARNING_FRAMES: usize = 120;
+
+/// The time a frame can take before it is late, when no minimum frame time is set.
+#[cfg(debug_assertions)]
+const DEFAULT_FRAME_TIME: Duration = Duration::from_millis(16);
+
+/// The time between frames while animations are playing in an application running without a window.
+const HEADLESS_FRAME_TIME: Duration = Duration::from_millis(16);
+
+/// The number of logical pixels scrolled by a pixel delta of the mouse wheel which is equivalent to
+/// scrolling by one line, so that both kinds of delta scroll by similar amounts.
+#[cfg(target_os = "macos")]
+const PIXELS_PER_LINE: f32 = 10.0;
+#[cfg(target_arch = "wasm32")]
+const PIXELS_PER_LINE: f32 = 100.0;
+#[cfg(not(any(target_os = "macos", target_arch = "wasm32")))]
+const PIXELS_PER_LINE: f32 = 20.0;
+
+/// An error which prevents an [`Application`] from starting.
+#[derive(Debug)]
+pub enum StartupError {
+    /// No display is available to open a window on, e.g. when running over SSH or in CI without a
+    /// display server.
+    NoDisplay(String),
+    /// The window could not be created.
+    Window(String),
+    /// The OpenGL context, surface, or renderer of the window could not be created.
+    Renderer(String),
+}
+
+impl std::fmt::Display for StartupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartupError::NoDisplay(err) => write!(f, "no display is available: {}", err),
+            StartupError::Window(err) => write!(f, "failed to create window: {}", err),
+            StartupError::Renderer(err) => write!(f, "failed to create renderer: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for StartupError {}
+
 #[derive(Debug)]
 pub enum UserEvent {
     Event(Event),
@@ -71,11 +115,16 @@ type IdleCallback = Option<Box<dyn Fn(&mut Context)>>;
 /// Calling `run()` on the `Application` causes the program to enter the event loop and for the main window to display.
 pub struct Application {
     context: Context,
-    event_loop: EventLoop<UserEvent>,
+    event_loop: Result<EventLoop<UserEvent>, StartupError>,
+    // Events sent with a `ContextProxy` when no display is available.
+    headless_events: Option<Receiver<Event>>,
+    headless_fallback: bool,
     builder: AppBuilder,
     on_idle: IdleCallback,
     window_description: WindowDescription,
     should_poll: bool,
+    animation_polling: bool,
+    min_frame_time: Option<Duration>,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -92,6 +141,18 @@ impl EventProxy for WinitEventProxy {
     }
 }
 
+struct HeadlessEventProxy(Sender<Event>);
+
+impl EventProxy for HeadlessEventProxy {
+    fn send(&self, event: Event) -> Result<(), ()> {
+        self.0.send(event).map_err(|_| ())
+    }
+
+    fn make_clone(&self) -> Box<dyn EventProxy> {
+        Box::new(HeadlessEventProxy(self.0.clone()))
+    }
+}
+
 impl Application {
     pub fn new<F>(content: F) -> Self
     where
@@ -107,21 +168,42 @@ impl Application {
         #[allow(unused_mut)]
         let mut context = Context::new(WindowSize::new(1, 1), 1.0);
 
-        let event_loop = EventLoopBuilder::with_user_event().build();
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            let mut cx = BackendContext::new(&mut context);
-            let event_proxy_obj = event_loop.create_proxy();
-            cx.set_event_proxy(Box::new(WinitEventProxy(event_proxy_obj)));
+        // Creating the event loop panics if no display is available, which is instead reported by
+        // `try_run`.
+        let event_loop = std::panic::catch_unwind(|| EventLoopBuilder::with_user_event().build())
+            .map_err(|err| StartupError::NoDisplay(panic_message(&*err)));
+
+        let mut headless_events = None;
+        match &event_loop {
+            #[cfg(not(target_arch = "wasm32"))]
+            Ok(event_loop) => {
+                let mut cx = BackendContext::new(&mut context);
+                let event_proxy_obj = event_loop.create_proxy();
+                cx.set_event_proxy(Box::new(WinitEventProxy(event_proxy_obj)));
+            }
+
+            #[cfg(target_arch = "wasm32")]
+            Ok(_) => {}
+
+            Err(_) => {
+                let (sender, receiver) = channel();
+                BackendContext::new(&mut context)
+                    .set_event_proxy(Box::new(HeadlessEventProxy(sender)));
+                headless_events = Some(receiver);
+            }
         }
 
         Self {
             context,
             event_loop,
+            headless_events,
+            headless_fallback: false,
             builder: Some(Box::new(content)),
             on_idle: None,
             window_description: WindowDescription::new(),
             should_poll: false,
+            animation_polling: true,
+            min_frame_time: None,
         }
     }
 
@@ -142,6 +224,134 @@ impl Application {
         self
     }
 
+    /// Sets whether the application should run without a window when no display is available, e.g.
+    /// when running over SSH or in CI, instead of failing to start.
+    ///
+    /// Without a window the views of the application are built and the event, data binding, style,
+    /// animation, and layout systems run as usual, but nothing is drawn and there is no user input.
+    /// Events can still be sent with a [`ContextProxy`] or from the idle callback. The application
+    /// runs until a [`WindowEvent::WindowClose`] event is sent to the root view, after which
+    /// [`try_run`](Self::try_run) returns.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .headless_fallback(true)
+    /// .run();
+    /// ```
+    pub fn headless_fallback(mut self, flag: bool) -> Self {
+        self.headless_fallback = flag;
+
+        self
+    }
+
+    /// Sets whether running animations should drive the event loop.
+    ///
+    /// By default, while any animation is playing the event loop is polled and a redraw is requested
+    /// every frame. Disabling this gives the application full control over redraw scheduling, for
+    /// example when redraws are synced to an external clock. Animations will then only advance when
+    /// the event loop is woken by some other means, such as a call to `cx.request_redraw()`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .animation_polling(false)
+    /// .run();
+    /// ```
+    pub fn animation_polling(mut self, flag: bool) -> Self {
+        self.animation_polling = flag;
+
+        self
+    }
+
+    /// Sets a minimum time between frames which are run because events were queued during the previous frame.
+    ///
+    /// By default, if event handlers or the idle callback leave events in the queue, the event loop runs
+    /// again immediately. A handler which keeps emitting events will then busy-loop a CPU core. With a
+    /// minimum frame time the event loop instead waits until the given time has passed since the start
+    /// of the previous frame.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// # use std::time::Duration;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .min_frame_time(Duration::from_millis(4))
+    /// .run();
+    /// ```
+    pub fn min_frame_time(mut self, min_frame_time: Duration) -> Self {
+        self.min_frame_time = Some(min_frame_time);
+
+        self
+    }
+
+    /// Sets the (x, y) factors applied to mouse wheel scrolling in the main window.
+    ///
+    /// Scrolling by pixels, e.g. with a touchpad, is first converted to an equivalent number of lines
+    /// for the platform, so that the factors apply to both kinds of scrolling. The factors can be
+    /// changed while the application is running with [`WindowEvent::SetScrollSensitivity`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .scroll_sensitivity(1.0, 2.0)
+    /// .run();
+    /// ```
+    pub fn scroll_sensitivity(mut self, x: f32, y: f32) -> Self {
+        self.window_description.scroll_sensitivity = (x, y);
+
+        self
+    }
+
+    /// Sets the fullscreen mode of the main window, or leaves fullscreen with `None`.
+    ///
+    /// Accepts a value or a lens, so that fullscreen can be toggled by changing the bound data. An
+    /// exclusive fullscreen [`VideoMode`] without a size, bit depth or refresh rate uses the best
+    /// matching video mode of the current monitor.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .fullscreen(Some(Fullscreen::Borderless))
+    /// .run();
+    /// ```
+    pub fn fullscreen(mut self, fullscreen: impl Res<Option<Fullscreen>>) -> Self {
+        fullscreen.set_or_bind(&mut self.context, Entity::root(), |cx, _, fullscreen| {
+            cx.emit(WindowEvent::SetFullscreen(fullscreen));
+        });
+
+        self
+    }
+
     /// Takes a closure which will be called at the end of every loop of the application.
     ///
     /// The callback provides a place to run 'idle' processing and happens at the end of each loop but before drawing.
@@ -168,18 +378,122 @@ impl Application {
         self
     }
 
+    /// Sets a callback which draws an overlay on top of the window after the views have been drawn and
+    /// before the frame is presented, e.g. for an FPS counter.
+    ///
+    /// See [`Context::set_draw_overlay`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::vg;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .on_draw_overlay(|canvas, (width, height)| {
+    ///     let mut path = vg::Path::new();
+    ///     path.rect(0.0, height - 4.0, width, 4.0);
+    ///     canvas.fill_path(&path, &vg::Paint::color(vg::Color::rgb(255, 0, 0)));
+    /// })
+    /// .run();
+    /// ```
+    pub fn on_draw_overlay<F>(mut self, overlay: F) -> Self
+    where
+        F: 'static + FnMut(&mut Canvas, (f32, f32)),
+    {
+        self.context.set_draw_overlay(overlay);
+
+        self
+    }
+
+    /// Sets a callback which is called as every event is dispatched, for debugging event propagation.
+    ///
+    /// See [`Context::set_event_tracer`] for details.
+    pub fn trace_events<F>(mut self, tracer: F) -> Self
+    where
+        F: 'static + Fn(&Event, EventPhase, Entity),
+    {
+        self.context.set_event_tracer(tracer);
+
+        self
+    }
+
     /// Returns a `ContextProxy` which can be used to send events from another thread.
     pub fn get_proxy(&self) -> ContextProxy {
         self.context.get_proxy()
     }
 
     /// Starts the application and enters the main event loop.
-    pub fn run(mut self) {
+    ///
+    /// # Panics
+    ///
+    /// Panics if the application fails to start. Use [`try_run`](Self::try_run) to handle the error
+    /// instead.
+    pub fn run(self) {
+        if let Err(err) = self.try_run() {
+            panic!("Failed to start application: {}", err);
+        }
+    }
+
+    /// Starts the application and enters the main event loop, returning an error if the application
+    /// fails to start, e.g. because no display is available or the window could not be created.
+    ///
+    /// Once the event loop has started this function does not return, unless the application is
+    /// running without a window, see [`headless_fallback`](Self::headless_fallback).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// let result = Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .try_run();
+    ///
+    /// if let Err(err) = result {
+    ///     eprintln!("{}", err);
+    /// }
+    /// ```
+    pub fn try_run(mut self) -> Result<(), StartupError> {
+        let event_loop = match self.event_loop {
+            Ok(event_loop) => event_loop,
+
+            Err(err) => {
+                return match self.headless_events.take() {
+                    Some(events) if self.headless_fallback => {
+                        run_headless(
+                            self.context,
+                            self.builder.take(),
+                            self.on_idle.take(),
+                            &self.window_description,
+                            events,
+                        );
+                        Ok(())
+                    }
+
+                    _ => Err(err),
+                };
+            }
+        };
+
         let mut context = self.context;
 
-        let event_loop = self.event_loop;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(path) = self.window_description.placement_path.clone() {
+            restore_placement(&event_loop, &mut self.window_description, &path);
+        }
 
-        let (window, canvas) = Window::new(&event_loop, &self.window_description);
+        let (window, canvas) = Window::new(&event_loop, &self.window_description, None)?;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.window_description.center {
+            center_window(window.window());
+        }
 
         #[cfg(not(target_arch = "wasm32"))]
         let event_loop_proxy = event_loop.create_proxy();
@@ -187,7 +501,7 @@ impl Application {
         let mut cx = BackendContext::new(&mut context);
 
         #[cfg(not(target_arch = "wasm32"))]
-        let root_node = NodeBuilder::new(Role::Window).build(cx.accesskit_node_classes());
+        let initial_nodes = cx.initial_access_nodes();
         #[cfg(not(target_arch = "wasm32"))]
         let accesskit = accesskit_winit::Adapter::new(
             window.window(),
@@ -198,7 +512,7 @@ impl Application {
                 let root_id = Entity::root().accesskit_id();
 
                 TreeUpdate {
-                    nodes: vec![(root_id, root_node)],
+                    nodes: initial_nodes,
                     tree: Some(Tree::new(root_id)),
                     focus: Some(Entity::root().accesskit_id()),
                 }
@@ -207,30 +521,20 @@ impl Application {
         );
 
         // Accesskit requires that the window starts invisible until accesskit has been initialised.
-        // At this point we can set the visibility based on the desired visibility from the window description.
+        // At this point the window has also been placed, so we can set the visibility based on the
+        // desired visibility from the window description.
         window.window().set_visible(self.window_description.visible);
 
-        #[cfg(all(
-            feature = "clipboard",
-            feature = "wayland",
-            any(
-                target_os = "linux",
-                target_os = "dragonfly",
-                target_os = "freebsd",
-                target_os = "netbsd",
-                target_os = "openbsd"
-            )
-        ))]
-        unsafe {
-            if let Some(display) = window.window().wayland_display() {
-                let (_, clipboard) =
-                    copypasta::wayland_clipboard::create_clipboards_from_external(display);
-                cx.set_clipboard_provider(Box::new(clipboard));
-            }
-        }
+        // The root view of each window, by the id of the window.
+        let mut window_entities = HashMap::new();
+        window_entities.insert(window.id, Entity::root());
 
         let scale_factor = window.window().scale_factor() as f32;
         cx.add_main_window(&self.window_description, canvas, scale_factor);
+        cx.set_renderer_info(window.renderer_info().clone());
+        if let Some(shader_context) = window.take_shader_context() {
+            cx.set_shader_context(Entity::root(), shader_context);
+        }
         cx.add_window(window);
 
         cx.0.remove_user_themes();
@@ -243,7 +547,11 @@ impl Application {
         let event_loop_proxy = event_loop.create_proxy();
 
         let default_should_poll = self.should_poll;
+        let animation_polling = self.animation_polling;
+        let min_frame_time = self.min_frame_time;
         let stored_control_flow = RefCell::new(ControlFlow::Poll);
+        #[cfg(not(target_arch = "wasm32"))]
+        let placement_path = self.window_description.placement_path.clone();
 
         #[cfg(not(target_arch = "wasm32"))]
         cx.process_tree_updates(|tree_updates| {
@@ -256,7 +564,14 @@ impl Application {
         let mut cursor = (0.0f32, 0.0f32);
 
         let mut main_events = false;
-        event_loop.run(move |event, _, control_flow| {
+        let mut main_window_closed = false;
+        let mut suspended = false;
+        // The windows which are fully hidden by other windows.
+        let mut occluded = HashSet::new();
+        let mut ime_allowed = false;
+        #[cfg(debug_assertions)]
+        let mut late_frames = 0;
+        event_loop.run(move |event, event_loop_target, control_flow| {
             let mut cx = BackendContext::new_with_event_manager(&mut context);
 
             match event {
@@ -267,30 +582,54 @@ impl Application {
 
                     #[cfg(not(target_arch = "wasm32"))]
                     UserEvent::AccessKitActionRequest(action_request_event) => {
-                        let node_id = action_request_event.request.target;
-
-                        if action_request_event.request.action != Action::ScrollIntoView {
-                            let entity = Entity::new(node_id.0.get() as u32 - 1, 0);
+                        let request = action_request_event.request;
+                        let entity = Entity::new(request.target.0.get() as u32 - 1, 0);
+
+                        let numeric_value = match request.data {
+                            Some(ActionData::NumericValue(value)) => Some(value),
+                            _ => None,
+                        };
+
+                        // Common actions are sent as their own events so that views can handle them
+                        // without decoding the request.
+                        let event = match (request.action, numeric_value) {
+                            (Action::Default, _) => {
+                                Event::new(WindowEvent::ActionDefault).direct(entity)
+                            }
+                            (Action::Increment, _) => {
+                                Event::new(WindowEvent::ActionIncrement).direct(entity)
+                            }
+                            (Action::Decrement, _) => {
+                                Event::new(WindowEvent::ActionDecrement).direct(entity)
+                            }
+                            (Action::SetValue, Some(value)) => {
+                                Event::new(WindowEvent::ActionSetValue(value)).direct(entity)
+                            }
+                            // Sent up the tree so that any ancestor scroll views can scroll to the view.
+                            (Action::ScrollIntoView, _) => {
+                                Event::new(WindowEvent::ActionScrollIntoView)
+                                    .target(entity)
+                                    .propagate(Propagation::Up)
+                            }
+                            _ => {
+                                // Handle focus action from screen reader
+                                if request.action == Action::Focus {
+                                    cx.0.with_current(entity, |cx| {
+                                        cx.focus();
+                                    });
+                                }
 
-                            // Handle focus action from screen reader
-                            if action_request_event.request.action == Action::Focus {
-                                cx.0.with_current(entity, |cx| {
-                                    cx.focus();
-                                });
+                                Event::new(WindowEvent::ActionRequest(request)).direct(entity)
                             }
+                        };
 
-                            cx.send_event(
-                                Event::new(WindowEvent::ActionRequest(
-                                    action_request_event.request,
-                                ))
-                                .direct(entity),
-                            );
-                        }
+                        cx.send_event(event);
                     }
                 },
 
                 winit::event::Event::MainEventsCleared => {
                     main_events = true;
+                    let frame_start = Instant::now();
 
                     *stored_control_flow.borrow_mut() =
                         if default_should_poll { ControlFlow::Poll } else { ControlFlow::Wait };
@@ -302,24 +641,78 @@ impl Application {
 
                     cx.process_events();
 
+                    // Only allow IME input while a view which accepts text input is focused. On
+                    // mobile platforms this also shows or hides the soft keyboard.
+                    if cx.keyboard_requested() != ime_allowed {
+                        ime_allowed = cx.keyboard_requested();
+                        cx.mutate_window(|_, window: &Window| {
+                            window.window().set_ime_allowed(ime_allowed);
+                        });
+                    }
+
                     cx.process_data_updates();
 
                     cx.process_style_updates();
 
-                    if cx.process_animations() {
+                    // Animations are not polled while every window is occluded. They continue from the
+                    // current time once a window is visible again.
+                    let all_occluded = window_entities.values().all(|entity| occluded.contains(entity));
+                    if cx.process_animations() && animation_polling && !all_occluded {
                         *stored_control_flow.borrow_mut() = ControlFlow::Poll;
 
                         event_loop_proxy
                             .send_event(UserEvent::Event(Event::new(WindowEvent::Redraw)))
                             .expect("Failed to send redraw event");
 
-                        cx.mutate_window(|_, window: &Window| {
-                            window.window().request_redraw();
-                        });
+                        request_redraw(&mut cx, &window_entities, &occluded);
+                    } else if let Some(next_blink) = cx.next_caret_blink() {
+                        // Sleep until the caret of the focused text input toggles.
+                        if !default_should_poll && !all_occluded {
+                            *stored_control_flow.borrow_mut() = ControlFlow::WaitUntil(next_blink);
+                        }
                     }
 
                     cx.process_visual_updates();
 
+                    // Create the windows opened during this frame.
+                    let spawned_windows = cx.take_spawned_windows();
+                    let owner = Cell::new(None);
+                    cx.mutate_window(|_, window: &Window| {
+                        owner.set(Some(window.window().raw_window_handle()));
+                    });
+                    for (entity, window_description) in spawned_windows.iter() {
+                        let (window, canvas) =
+                            match Window::new(event_loop_target, window_description, owner.get()) {
+                                Ok(window) => window,
+                                Err(err) => {
+                                    log::error!("Failed to open window: {}", err);
+                                    cx.close_window(*entity);
+                                    continue;
+                                }
+                            };
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        if window_description.center {
+                            center_window(window.window());
+                        }
+
+                        // The window is created invisible and only shown once it has been placed.
+                        window.window().set_visible(window_description.visible);
+                        window_entities.insert(window.id, *entity);
+                        cx.set_window_scale_factor(*entity, window.window().scale_factor());
+                        if let Some(shader_context) = window.take_shader_context() {
+                            cx.set_shader_context(*entity, shader_context);
+                        }
+                        cx.add_secondary_window(*entity, window, canvas);
+                    }
+
+                    // Creating a window makes its rendering context current.
+                    if !spawned_windows.is_empty() {
+                        cx.mutate_window(|_, window: &Window| {
+                            window.make_current();
+                        });
+                    }
+
                     #[cfg(not(target_arch = "wasm32"))]
                     cx.process_tree_updates(|tree_updates| {
                         for update in tree_updates.iter() {
@@ -327,49 +720,178 @@ impl Application {
                         }
                     });
 
-                    cx.mutate_window(|cx, window: &Window| {
-                        cx.style().should_redraw(|| {
-                            window.window().request_redraw();
-                        });
-                    });
+                    let mut should_redraw = false;
+                    cx.style().should_redraw(|| should_redraw = true);
+                    if should_redraw {
+                        request_redraw(&mut cx, &window_entities, &occluded);
+                    }
 
                     if let Some(idle_callback) = &on_idle {
                         cx.set_current(Entity::root());
                         (idle_callback)(cx.context());
                     }
 
+                    // Frames which are late one after another usually mean that a handler is
+                    // responding to its own events.
+                    #[cfg(debug_assertions)]
+                    {
+                        if frame_start.elapsed() > min_frame_time.unwrap_or(DEFAULT_FRAME_TIME) {
+                            late_frames += 1;
+                            if late_frames == LATE_FRAME_WARNING_FRAMES {
+                                log::warn!(
+                                    "{} consecutive frames were late, which may be caused by an event feedback loop. Queued events: {:?}",
+                                    late_frames,
+                                    cx.queued_event_types()
+                                );
+                            }
+                        } else {
+                            late_frames = 0;
+                        }
+                    }
+
                     if cx.has_queued_events() {
-                        *stored_control_flow.borrow_mut() = ControlFlow::Poll;
-                        event_loop_proxy
-                            .send_event(UserEvent::Event(Event::new(())))
-                            .expect("Failed to send event");
+                        if let Some(min_frame_time) = min_frame_time {
+                            *stored_control_flow.borrow_mut() =
+                                ControlFlow::WaitUntil(frame_start + min_frame_time);
+                        } else {
+                            *stored_control_flow.borrow_mut() = ControlFlow::Poll;
+                            event_loop_proxy
+                                .send_event(UserEvent::Event(Event::new(())))
+                                .expect("Failed to send event");
+                        }
+                    }
+
+                    // Remove the other windows which have been closed.
+                    let entities = window_entities.values().copied().collect::<Vec<_>>();
+                    for entity in entities.into_iter().filter(|entity| *entity != Entity::root()) {
+                        let should_close = Cell::new(false);
+                        cx.mutate_window_of(entity, |_, window: &Window| {
+                            should_close.set(window.should_close);
+                        });
+
+                        if should_close.get() {
+                            cx.close_window(entity);
+                        }
                     }
 
+                    if cx.dispose_removed_windows(|window: &Window| {
+                        window.make_current();
+                        if let Some(entity) = window_entities.remove(&window.id) {
+                            occluded.remove(&entity);
+                        }
+                    }) {
+                        cx.mutate_window(|_, window: &Window| {
+                            window.make_current();
+                        });
+                    }
+
+                    // The main window is hidden while other windows are still open.
+                    let should_close = Cell::new(false);
                     cx.mutate_window(|_, window: &Window| {
-                        if window.should_close {
-                            *stored_control_flow.borrow_mut() = ControlFlow::Exit;
+                        if window.should_close && !main_window_closed {
+                            #[cfg(not(target_arch = "wasm32"))]
+                            if let Some(path) = &placement_path {
+                                save_placement(window.window(), path);
+                            }
+
+                            window.window().set_visible(false);
                         }
+
+                        should_close.set(window.should_close);
                     });
+                    main_window_closed = should_close.get();
+
+                    // The application exits once the last window is closed.
+                    if main_window_closed && !cx.has_secondary_windows() {
+                        *stored_control_flow.borrow_mut() = ControlFlow::Exit;
+                    }
                 }
 
-                winit::event::Event::RedrawRequested(_) => {
-                    if main_events {
+                winit::event::Event::Suspended => {
+                    if !suspended {
+                        suspended = true;
+                        for entity in window_entities.values().copied() {
+                            cx.mutate_window_of(entity, |_, window: &Window| {
+                                window.suspend();
+                            });
+                        }
+                        cx.emit_origin(WindowEvent::Suspended);
+                    }
+                }
+
+                winit::event::Event::Resumed => {
+                    // Resumed is also emitted when the event loop starts, in which case the
+                    // renderer has already been created along with the window.
+                    if suspended {
+                        suspended = false;
+                        for entity in window_entities.values().copied() {
+                            cx.mutate_window_of(entity, |cx, window: &Window| {
+                                reset_renderer(cx, entity, window);
+                            });
+                        }
+                        cx.mutate_window(|_, window: &Window| {
+                            window.make_current();
+                        });
+                        cx.emit_origin(WindowEvent::Resumed);
+                    }
+                }
+
+                winit::event::Event::RedrawRequested(window_id) => {
+                    let window_entity = window_entities.get(&window_id).copied();
+                    if main_events && !suspended && window_entity == Some(Entity::root()) {
                         // Redraw
                         cx.draw();
+                        cx.mutate_window(|cx, window: &Window| {
+                            // Recreate the renderer if the OpenGL context was lost, e.g. after a
+                            // driver reset, and redraw with the new context.
+                            if !window.swap_buffers() {
+                                reset_renderer(cx, Entity::root(), window);
+                            }
+                        });
+                    } else if let Some(entity) = window_entity.filter(|_| main_events && !suspended) {
+                        // Each window is drawn with its own rendering context.
+                        cx.mutate_window_of(entity, |cx, window: &Window| {
+                            window.make_current();
+                            cx.draw_window(entity);
+                            if !window.swap_buffers() {
+                                reset_renderer(cx, entity, window);
+                            }
+                        });
+
                         cx.mutate_window(|_, window: &Window| {
-                            window.swap_buffers();
+                            window.make_current();
                         });
                     }
                 }
 
-                winit::event::Event::WindowEvent { window_id: _, event } => {
+                // Events of windows which have been closed are ignored.
+                winit::event::Event::WindowEvent { window_id, event }
+                    if window_entities.contains_key(&window_id) =>
+                {
+                    let window_entity = window_entities[&window_id];
                     match event {
                         winit::event::WindowEvent::CloseRequested => {
-                            cx.emit_origin(WindowEvent::WindowClose);
+                            if window_entity == Entity::root() {
+                                cx.emit_origin(WindowEvent::WindowClose);
+                            } else {
+                                cx.emit_window_event(window_entity, WindowEvent::WindowClose);
+                            }
+                        }
+
+                        winit::event::WindowEvent::Occluded(is_occluded) => {
+                            // The contents of the window may have been discarded while it was
+                            // occluded, so the whole window is redrawn once it is visible again.
+                            if is_occluded {
+                                occluded.insert(window_entity);
+                            } else if occluded.remove(&window_entity) {
+                                cx.style().needs_redraw();
+                            }
+
+                            cx.emit_window_event(window_entity, WindowEvent::Occluded(is_occluded));
                         }
 
                         winit::event::WindowEvent::Focused(is_focused) => {
-                            cx.0.window_has_focus = is_focused;
+                            cx.set_window_focus(window_entity, is_focused);
                             #[cfg(not(target_arch = "wasm32"))]
                             accesskit.update_if_active(|| TreeUpdate {
                                 nodes: vec![],
@@ -382,8 +904,10 @@ impl Application {
                             scale_factor,
                             new_inner_size,
                         } => {
-                            cx.set_scale_factor(scale_factor);
-                            cx.set_window_size(
+                            cx.set_window_scale_factor(window_entity, scale_factor);
+
+                            cx.set_window_size_of(
+                                window_entity,
                                 new_inner_size.width as f32,
                                 new_inner_size.height as f32,
                             );
@@ -391,7 +915,16 @@ impl Application {
                         }
 
                         winit::event::WindowEvent::DroppedFile(path) => {
-                            cx.emit_origin(WindowEvent::Drop(DropData::File(path)));
+                            cx.emit_origin(WindowEvent::Drop(DropData::File(path.clone())));
+                            cx.emit_origin(WindowEvent::FileDrop(path));
+                        }
+
+                        winit::event::WindowEvent::HoveredFile(path) => {
+                            cx.emit_origin(WindowEvent::FileHover(path));
+                        }
+
+                        winit::event::WindowEvent::HoveredFileCancelled => {
+                            cx.emit_origin(WindowEvent::FileHoverCancel);
                         }
 
                         #[allow(deprecated)]
@@ -402,6 +935,7 @@ impl Application {
                         } => {
                             // To avoid calling the hover system multiple times in one frame when multiple cursor moved
                             // events are received, instead we set a flag here and emit the MouseMove event during MainEventsCleared.
+                            cx.set_cursor_window(window_entity);
                             if !cursor_moved {
                                 cursor_moved = true;
                                 cursor.0 = position.x as f32;
@@ -416,12 +950,7 @@ impl Application {
                             state,
                             modifiers: _,
                         } => {
-                            let button = match button {
-                                winit::event::MouseButton::Left => MouseButton::Left,
-                                winit::event::MouseButton::Right => MouseButton::Right,
-                                winit::event::MouseButton::Middle => MouseButton::Middle,
-                                winit::event::MouseButton::Other(val) => MouseButton::Other(val),
-                            };
+                            let button = mouse_button_to_button(button);
 
                             let event = match state {
                                 winit::event::ElementState::Pressed => {
@@ -435,20 +964,47 @@ impl Application {
                             cx.emit_origin(event);
                         }
 
+                        winit::event::WindowEvent::Touch(touch) => {
+                            cx.set_cursor_window(window_entity);
+                            let phase = match touch.phase {
+                                winit::event::TouchPhase::Started => TouchPhase::Started,
+                                winit::event::TouchPhase::Moved => TouchPhase::Moved,
+                                winit::event::TouchPhase::Ended => TouchPhase::Ended,
+                                winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+                            };
+
+                            cx.emit_origin(WindowEvent::Touch {
+                                id: touch.id,
+                                phase,
+                                x: touch.location.x as f32,
+                                y: touch.location.y as f32,
+                            });
+                        }
+
                         winit::event::WindowEvent::MouseWheel { delta, phase: _, .. } => {
-                            let out_event = match delta {
-                                winit::event::MouseScrollDelta::LineDelta(x, y) => {
-                                    WindowEvent::MouseScroll(x, y)
-                                }
+                            let sensitivity = Cell::new((1.0, 1.0));
+                            let scale_factor = Cell::new(1.0);
+                            cx.mutate_window_of(window_entity, |_, window: &Window| {
+                                sensitivity.set(window.scroll_sensitivity);
+                                scale_factor.set(window.window().scale_factor() as f32);
+                            });
+
+                            let (x, y) = match delta {
+                                winit::event::MouseScrollDelta::LineDelta(x, y) => (x, y),
                                 winit::event::MouseScrollDelta::PixelDelta(pos) => {
-                                    WindowEvent::MouseScroll(
-                                        pos.x as f32 / 20.0,
-                                        pos.y as f32 / 20.0, // this number calibrated for wayland
+                                    let pixels_per_line = PIXELS_PER_LINE * scale_factor.get();
+                                    (
+                                        pos.x as f32 / pixels_per_line,
+                                        pos.y as f32 / pixels_per_line,
                                     )
                                 }
                             };
 
-                            cx.emit_origin(out_event);
+                            let (sensitivity_x, sensitivity_y) = sensitivity.get();
+                            cx.emit_origin(WindowEvent::MouseScroll(
+                                x * sensitivity_x,
+                                y * sensitivity_y,
+                            ));
                         }
 
                         winit::event::WindowEvent::KeyboardInput {
@@ -467,15 +1023,18 @@ impl Application {
                                 input.virtual_keycode.unwrap_or(VirtualKeyCode::NoConvert),
                             );
 
-                            let event = match input.state {
-                                winit::event::ElementState::Pressed => {
-                                    WindowEvent::KeyDown(code, key)
-                                }
-                                winit::event::ElementState::Released => {
-                                    WindowEvent::KeyUp(code, key)
-                                }
+                            let (scancode_event, event) = match input.state {
+                                winit::event::ElementState::Pressed => (
+                                    WindowEvent::ScancodeDown(input.scancode),
+                                    WindowEvent::KeyDown(code, key),
+                                ),
+                                winit::event::ElementState::Released => (
+                                    WindowEvent::ScancodeUp(input.scancode),
+                                    WindowEvent::KeyUp(code, key),
+                                ),
                             };
 
+                            cx.emit_origin(scancode_event);
                             cx.emit_origin(event);
                         }
 
@@ -483,12 +1042,27 @@ impl Application {
                             cx.emit_origin(WindowEvent::CharInput(character));
                         }
 
+                        winit::event::WindowEvent::Ime(ime) => match ime {
+                            winit::event::Ime::Preedit(text, cursor) => {
+                                cx.emit_origin(WindowEvent::ImePreedit(text, cursor));
+                            }
+                            winit::event::Ime::Commit(text) => {
+                                cx.emit_origin(WindowEvent::ImeCommit(text));
+                            }
+                            // Clear any composed text which wasn't committed.
+                            winit::event::Ime::Disabled => {
+                                cx.emit_origin(WindowEvent::ImePreedit(String::new(), None));
+                            }
+                            winit::event::Ime::Enabled => {}
+                        },
+
                         winit::event::WindowEvent::Resized(physical_size) => {
-                            cx.mutate_window(|_, window: &Window| {
+                            cx.mutate_window_of(window_entity, |_, window: &Window| {
                                 window.resize(physical_size);
                             });
 
-                            cx.set_window_size(
+                            cx.set_window_size_of(
+                                window_entity,
                                 physical_size.width as f32,
                                 physical_size.height as f32,
                             );
@@ -507,6 +1081,20 @@ impl Application {
                     }
                 }
 
+                winit::event::Event::DeviceEvent {
+                    device_id: _,
+                    event: winit::event::DeviceEvent::MouseMotion { delta },
+                } => {
+                    let raw_mouse_motion = Cell::new(false);
+                    cx.mutate_window(|_, window: &Window| {
+                        raw_mouse_motion.set(window.raw_mouse_motion);
+                    });
+
+                    if raw_mouse_motion.get() {
+                        cx.emit_origin(WindowEvent::RawMouseMotion(delta.0 as f32, delta.1 as f32));
+                    }
+                }
+
                 _ => {}
             }
 
@@ -515,6 +1103,126 @@ impl Application {
     }
 }
 
+// Runs the application without a window, until the root view receives a `WindowClose` event.
+fn run_headless(
+    mut context: Context,
+    builder: AppBuilder,
+    on_idle: IdleCallback,
+    window_description: &WindowDescription,
+    events: Receiver<Event>,
+) {
+    let mut cx = BackendContext::new(&mut context);
+    cx.set_window_size(
+        window_description.inner_size.width as f32,
+        window_description.inner_size.height as f32,
+    );
+    cx.style().disabled.insert(Entity::root(), false);
+    cx.add_window(HeadlessWindow::default());
+
+    cx.0.remove_user_themes();
+    if let Some(builder) = builder {
+        (builder)(cx.0);
+    }
+
+    loop {
+        let mut cx = BackendContext::new_with_event_manager(&mut context);
+
+        cx.process_events();
+        cx.process_data_updates();
+        cx.process_style_updates();
+        let animating = cx.process_animations();
+        cx.process_visual_updates();
+
+        // There are no assistive technologies to update without a window.
+        cx.process_tree_updates(|_| {});
+
+        if let Some(idle_callback) = &on_idle {
+            cx.set_current(Entity::root());
+            (idle_callback)(cx.context());
+        }
+
+        let should_close = Cell::new(false);
+        cx.mutate_window(|_, window: &HeadlessWindow| {
+            should_close.set(window.should_close);
+        });
+
+        if should_close.get() {
+            return;
+        }
+
+        if cx.has_queued_events() {
+            continue;
+        }
+
+        // Wait for an event from another thread, or until the next frame of a playing animation.
+        let event = if animating {
+            match events.recv_timeout(HEADLESS_FRAME_TIME) {
+                Ok(event) => Some(event),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        } else {
+            match events.recv() {
+                Ok(event) => Some(event),
+                Err(_) => return,
+            }
+        };
+
+        for event in event.into_iter().chain(events.try_iter()) {
+            cx.send_event(event);
+        }
+    }
+}
+
+// Stands in for the window of an application running without a display, recording when the
+// application is closed.
+#[derive(Default)]
+struct HeadlessWindow {
+    should_close: bool,
+}
+
+impl View for HeadlessWindow {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| {
+            if let WindowEvent::WindowClose = window_event {
+                self.should_close = true;
+            }
+        });
+    }
+}
+
+// Returns the message of the payload of a panic.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown error".to_string())
+}
+
+// Recreates the rendering context of the window with the given root view, e.g. after it has been
+// lost, and redraws the window with the new context.
+fn reset_renderer(cx: &mut BackendContext, entity: Entity, window: &Window) {
+    cx.reset_window_renderer(entity, window.recreate_renderer());
+    if let Some(shader_context) = window.take_shader_context() {
+        cx.set_shader_context(entity, shader_context);
+    }
+    window.window().request_redraw();
+}
+
+// Requests a redraw of every window which is not occluded.
+fn request_redraw(
+    cx: &mut BackendContext,
+    window_entities: &HashMap<WindowId, Entity>,
+    occluded: &HashSet<Entity>,
+) {
+    for entity in window_entities.values().copied().filter(|entity| !occluded.contains(entity)) {
+        cx.mutate_window_of(entity, |_, window: &Window| {
+            window.window().request_redraw();
+        });
+    }
+}
+
 impl WindowModifiers for Application {
     fn title<T: ToString>(mut self, title: T) -> Self {
         self.window_description.title = title.to_string();
@@ -546,6 +1254,18 @@ impl WindowModifiers for Application {
         self
     }
 
+    fn center(mut self, flag: bool) -> Self {
+        self.window_description.center = flag;
+
+        self
+    }
+
+    fn remember_placement(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.window_description.placement_path = Some(path.into());
+
+        self
+    }
+
     fn resizable(mut self, flag: bool) -> Self {
         self.window_description.resizable = flag;
 
@@ -583,7 +1303,26 @@ impl WindowModifiers for Application {
     }
 
     fn always_on_top(mut self, flag: bool) -> Self {
-        self.window_description.always_on_top = flag;
+        self.window_description = self.window_description.with_always_on_top(flag);
+
+        self
+    }
+
+    fn always_on_bottom(mut self, flag: bool) -> Self {
+        self.window_description = self.window_description.with_always_on_bottom(flag);
+
+        self
+    }
+
+    fn skip_taskbar(mut self, flag: bool) -> Self {
+        self.window_description.skip_taskbar = flag;
+
+        self
+    }
+
+    fn window_level(mut self, window_level: WindowLevel) -> Self {
+        self.window_description.window_level = window_level;
+
         self
     }
 
@@ -593,6 +1332,19 @@ impl WindowModifiers for Application {
         self
     }
 
+    fn clear_behavior(mut self, clear_behavior: ClearBehavior) -> Self {
+        self.window_description.clear_behavior = clear_behavior;
+
+        self
+    }
+
+    fn background_color(mut self, color: impl Into<Color>) -> Self {
+        BackendContext::new(&mut self.context)
+            .set_window_background_color(Entity::root(), color.into());
+
+        self
+    }
+
     fn icon(mut self, image: Vec<u8>, width: u32, height: u32) -> Self {
         self.window_description.icon = Some(image);
         self.window_description.icon_width = width;
@@ -1,8 +1,12 @@
 use vizia_core::prelude::CursorIcon as ViziaCursorIcon;
+use vizia_core::prelude::WindowLevel as ViziaWindowLevel;
 use vizia_input::Code as ViziaCode;
 use vizia_input::Key as ViziaKey;
+use vizia_input::MouseButton as ViziaMouseButton;
+use winit::event::MouseButton as WinitMouseButton;
 use winit::event::VirtualKeyCode as WinitVirtualKeyCode;
 use winit::window::CursorIcon as WinitCursorIcon;
+use winit::window::WindowLevel as WinitWindowLevel;
 
 pub fn cursor_icon_to_cursor_icon(cursor_icon: ViziaCursorIcon) -> Option<WinitCursorIcon> {
     match cursor_icon {
@@ -360,3 +364,46 @@ pub fn virtual_key_code_to_key(virtual_key_code: WinitVirtualKeyCode) -> Option<
         _ => None,
     }
 }
+
+/// Converts a winit mouse button to a vizia mouse button.
+///
+/// Winit reports the back and forward buttons as `Other` with a platform specific button number, so
+/// the known numbers are mapped to [`Back`](ViziaMouseButton::Back) and
+/// [`Forward`](ViziaMouseButton::Forward).
+pub fn mouse_button_to_button(button: WinitMouseButton) -> ViziaMouseButton {
+    match button {
+        WinitMouseButton::Left => ViziaMouseButton::Left,
+        WinitMouseButton::Right => ViziaMouseButton::Right,
+        WinitMouseButton::Middle => ViziaMouseButton::Middle,
+        WinitMouseButton::Other(val) => match val {
+            // XBUTTON1 and XBUTTON2
+            #[cfg(target_os = "windows")]
+            1 => ViziaMouseButton::Back,
+            #[cfg(target_os = "windows")]
+            2 => ViziaMouseButton::Forward,
+
+            #[cfg(target_os = "macos")]
+            3 => ViziaMouseButton::Back,
+            #[cfg(target_os = "macos")]
+            4 => ViziaMouseButton::Forward,
+
+            // X11 button numbers and Wayland BTN_SIDE and BTN_EXTRA codes
+            #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+            8 | 0x113 => ViziaMouseButton::Back,
+            #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+            9 | 0x114 => ViziaMouseButton::Forward,
+
+            _ => ViziaMouseButton::Other(val),
+        },
+    }
+}
+
+pub fn window_level_to_window_level(window_level: ViziaWindowLevel) -> WinitWindowLevel {
+    match window_level {
+        ViziaWindowLevel::AlwaysOnBottom => WinitWindowLevel::AlwaysOnBottom,
+        ViziaWindowLevel::Normal => WinitWindowLevel::Normal,
+        ViziaWindowLevel::AlwaysOnTop => WinitWindowLevel::AlwaysOnTop,
+        // The window is kept above its owner by setting the owner when the window is created.
+        ViziaWindowLevel::AboveOwner => WinitWindowLevel::Normal,
+    }
+}
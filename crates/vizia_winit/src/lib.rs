@@ -1,5 +1,7 @@
 pub mod application;
 mod convert;
+#[cfg(not(target_arch = "wasm32"))]
+mod placement;
 mod window;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -11,10 +13,35 @@ pub mod rwh {
 pub trait GetRawWindowHandle {
     fn raw_window_handle(&mut self) -> rwh::RawWindowHandle;
     fn mutate_window(&mut self, f: impl FnOnce(&winit::window::Window));
+    /// Calls the given closure with the underlying winit window and returns its result, for platform
+    /// specific window features which are not otherwise exposed.
+    ///
+    /// Returns `None` if the window view is not available, which is the case while the window view
+    /// itself is handling an event, e.g. from within a `WindowEvent` sent to the root view.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::GetRawWindowHandle;
+    /// # let cx = &mut Context::default();
+    /// let scale_factor = cx.with_winit_window(|window| window.scale_factor());
+    /// ```
+    fn with_winit_window<T>(&mut self, f: impl FnOnce(&winit::window::Window) -> T) -> Option<T>;
+    /// Returns the raw handle of the window with the given root view, e.g. `Entity::root()` for the
+    /// main window, so that an external renderer can be initialized against it.
+    ///
+    /// Returns `None` if the entity is not the root view of a window, or while the window view is
+    /// handling an event.
+    fn window_handle(&mut self, window: Entity) -> Option<rwh::RawWindowHandle>;
+    /// Returns the raw handle of the display of the window with the given root view.
+    ///
+    /// Returns `None` if the entity is not the root view of a window, or while the window view is
+    /// handling an event.
+    fn display_handle(&mut self, window: Entity) -> Option<rwh::RawDisplayHandle>;
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-use raw_window_handle::HasRawWindowHandle;
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 #[cfg(not(target_arch = "wasm32"))]
 use vizia_core::prelude::{Context, Entity, EventContext, GenerationalId};
 #[cfg(not(target_arch = "wasm32"))]
@@ -23,34 +50,51 @@ use window::Window;
 #[cfg(not(target_arch = "wasm32"))]
 impl<'a> GetRawWindowHandle for EventContext<'a> {
     fn raw_window_handle(&mut self) -> rwh::RawWindowHandle {
-        self.with_current(Entity::root(), |cx| {
-            cx.get_view::<Window>().map(|window| window.window().raw_window_handle())
-        })
-        .unwrap()
+        self.window_handle(Entity::root()).unwrap()
     }
 
     fn mutate_window(&mut self, f: impl FnOnce(&winit::window::Window)) {
+        self.with_winit_window(f);
+    }
+
+    fn with_winit_window<T>(&mut self, f: impl FnOnce(&winit::window::Window) -> T) -> Option<T> {
         self.with_current(Entity::root(), move |cx| {
             cx.get_view::<Window>().map(move |window| (f)(window.window()))
-        });
+        })
+    }
+
+    fn window_handle(&mut self, window: Entity) -> Option<rwh::RawWindowHandle> {
+        self.with_current(window, |cx| {
+            cx.get_view::<Window>().map(|window| window.raw_window_handle())
+        })
+    }
+
+    fn display_handle(&mut self, window: Entity) -> Option<rwh::RawDisplayHandle> {
+        self.with_current(window, |cx| {
+            cx.get_view::<Window>().map(|window| window.raw_display_handle())
+        })
     }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 impl GetRawWindowHandle for Context {
     fn raw_window_handle(&mut self) -> rwh::RawWindowHandle {
-        let mut cx = EventContext::new(self);
-        cx.with_current(Entity::root(), |cx| {
-            cx.get_view::<Window>().map(|window| window.window().raw_window_handle())
-        })
-        .unwrap()
+        self.window_handle(Entity::root()).unwrap()
     }
 
     fn mutate_window(&mut self, f: impl FnOnce(&winit::window::Window)) {
-        let mut cx = EventContext::new(self);
+        self.with_winit_window(f);
+    }
 
-        cx.with_current(Entity::root(), move |cx| {
-            cx.get_view::<Window>().map(move |window| (f)(window.window()))
-        });
+    fn with_winit_window<T>(&mut self, f: impl FnOnce(&winit::window::Window) -> T) -> Option<T> {
+        EventContext::new(self).with_winit_window(f)
+    }
+
+    fn window_handle(&mut self, window: Entity) -> Option<rwh::RawWindowHandle> {
+        EventContext::new(self).window_handle(window)
+    }
+
+    fn display_handle(&mut self, window: Entity) -> Option<rwh::RawDisplayHandle> {
+        EventContext::new(self).display_handle(window)
     }
 }
@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use vizia_window::{Position, WindowDescription, WindowSize};
+use winit::dpi::{LogicalPosition, PhysicalPosition};
+use winit::event_loop::EventLoopWindowTarget;
+
+// Centers a window on the monitor it is on, or the primary monitor if it is not on one. Both sizes
+// are in physical pixels, so monitors with different scale factors are handled.
+pub(crate) fn center_window(window: &winit::window::Window) {
+    if let Some(monitor) = window.current_monitor().or_else(|| window.primary_monitor()) {
+        let monitor_position = monitor.position();
+        let monitor_size = monitor.size();
+        let window_size = window.outer_size();
+
+        window.set_outer_position(PhysicalPosition::new(
+            monitor_position.x + (monitor_size.width as i32 - window_size.width as i32) / 2,
+            monitor_position.y + (monitor_size.height as i32 - window_size.height as i32) / 2,
+        ));
+    }
+}
+
+// Applies the position and size saved to the placement file of a window description, if the file
+// exists. A restored window is not centered. The position is only restored if the monitor the
+// window was on still exists and contains the position, otherwise only the size is restored.
+pub(crate) fn restore_placement<T>(
+    event_loop: &EventLoopWindowTarget<T>,
+    description: &mut WindowDescription,
+    path: &Path,
+) {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(_) => return,
+    };
+
+    let mut values = contents.split_whitespace();
+    let mut parse = || -> Option<(usize, i32, i32, u32, u32)> {
+        Some((
+            values.next()?.parse().ok()?,
+            values.next()?.parse().ok()?,
+            values.next()?.parse().ok()?,
+            values.next()?.parse().ok()?,
+            values.next()?.parse().ok()?,
+        ))
+    };
+
+    let (monitor, x, y, width, height) = match parse() {
+        Some(placement) => placement,
+        None => {
+            log::warn!(
+                "Failed to restore window placement from {}: invalid format",
+                path.display()
+            );
+            return;
+        }
+    };
+
+    description.inner_size = WindowSize::new(width, height);
+
+    let on_monitor = event_loop.available_monitors().nth(monitor).map_or(false, |monitor| {
+        let position = LogicalPosition::new(x, y).to_physical::<i32>(monitor.scale_factor());
+        let monitor_position = monitor.position();
+        let monitor_size = monitor.size();
+
+        position.x >= monitor_position.x
+            && position.y >= monitor_position.y
+            && position.x < monitor_position.x + monitor_size.width as i32
+            && position.y < monitor_position.y + monitor_size.height as i32
+    });
+
+    if on_monitor {
+        description.position = Some(Position::new(x, y));
+        description.center = false;
+    } else {
+        log::warn!(
+            "Saved window position in {} is not on monitor {}, which may have been disconnected",
+            path.display(),
+            monitor
+        );
+    }
+}
+
+// Saves the monitor, logical position, and inner size of a window to a placement file. Positions
+// can be negative, for monitors to the left of or above the primary monitor.
+pub(crate) fn save_placement(window: &winit::window::Window, path: &Path) {
+    let monitor = match window
+        .current_monitor()
+        .and_then(|current| window.available_monitors().position(|monitor| monitor == current))
+    {
+        Some(monitor) => monitor,
+        None => return,
+    };
+
+    let scale_factor = window.scale_factor();
+    let position = match window.outer_position() {
+        Ok(position) => position.to_logical::<f64>(scale_factor),
+        Err(_) => return,
+    };
+    let size = window.inner_size().to_logical::<f64>(scale_factor);
+
+    let contents = format!(
+        "{} {} {} {} {}",
+        monitor,
+        position.x.round() as i32,
+        position.y.round() as i32,
+        size.width.round() as u32,
+        size.height.round() as u32
+    );
+
+    if let Err(err) = std::fs::write(path, contents) {
+        log::warn!("Failed to save window placement to {}: {}", path.display(), err);
+    }
+}
@@ -1,48 +1,65 @@
-use crate::application::UserEvent;
+use crate::application::{StartupError, UserEvent};
+#[cfg(not(target_arch = "wasm32"))]
+use std::cell::RefCell;
+#[cfg(not(target_arch = "wasm32"))]
+use std::ffi::CString;
 #[cfg(not(target_arch = "wasm32"))]
 use std::num::NonZeroU32;
 
-use crate::convert::cursor_icon_to_cursor_icon;
+use crate::convert::{cursor_icon_to_cursor_icon, window_level_to_window_level};
 use femtovg::{renderer::OpenGl, Canvas, Color};
 
 #[cfg(not(target_arch = "wasm32"))]
 use glutin::surface::SwapInterval;
 #[cfg(not(target_arch = "wasm32"))]
 use glutin_winit::DisplayBuilder;
+use raw_window_handle::RawWindowHandle;
 #[cfg(not(target_arch = "wasm32"))]
-use raw_window_handle::HasRawWindowHandle;
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle, RawDisplayHandle};
 
 #[cfg(not(target_arch = "wasm32"))]
 use glutin::{
     config::ConfigTemplateBuilder,
     context::{ContextApi, ContextAttributesBuilder},
     display::GetGlDisplay,
+    error::ErrorKind,
     prelude::*,
     surface::{SurfaceAttributesBuilder, WindowSurface},
 };
 
 use vizia_core::backend::*;
 use vizia_core::prelude::*;
-use winit::event_loop::EventLoop;
-use winit::window::{CursorGrabMode, WindowBuilder, WindowLevel};
+use winit::event_loop::EventLoopWindowTarget;
+use winit::window::{CursorGrabMode, WindowBuilder};
 use winit::{dpi::*, window::WindowId};
 
 pub struct Window {
     pub id: WindowId,
     #[cfg(not(target_arch = "wasm32"))]
-    context: glutin::context::PossiblyCurrentContext,
+    context: RefCell<glutin::context::PossiblyCurrentContext>,
+    #[cfg(not(target_arch = "wasm32"))]
+    surface: RefCell<Option<glutin::surface::Surface<glutin::surface::WindowSurface>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    gl_config: glutin::config::Config,
     #[cfg(not(target_arch = "wasm32"))]
-    surface: glutin::surface::Surface<glutin::surface::WindowSurface>,
+    vsync: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    shader_context: RefCell<Option<ShaderContext>>,
     window: winit::window::Window,
+    renderer_info: RendererInfo,
     pub should_close: bool,
+    pub raw_mouse_motion: bool,
+    /// The (x, y) factors applied to mouse wheel scrolling.
+    pub scroll_sensitivity: (f32, f32),
 }
 
 #[cfg(target_arch = "wasm32")]
 impl Window {
     pub fn new(
-        events_loop: &EventLoop<UserEvent>,
+        events_loop: &EventLoopWindowTarget<UserEvent>,
         window_description: &WindowDescription,
-    ) -> (Self, Canvas<OpenGl>) {
+        owner: Option<RawWindowHandle>,
+    ) -> Result<(Self, Canvas<OpenGl>), StartupError> {
         let window_builder = WindowBuilder::new();
 
         let canvas_element = {
@@ -59,10 +76,11 @@ impl Window {
             .unwrap()
         };
 
-        let renderer =
-            OpenGl::new_from_html_canvas(&canvas_element).expect("Cannot create renderer");
+        let renderer = OpenGl::new_from_html_canvas(&canvas_element)
+            .map_err(|err| StartupError::Renderer(format!("{:?}", err)))?;
 
-        let mut canvas = Canvas::new(renderer).expect("Failed to create canvas");
+        let mut canvas =
+            Canvas::new(renderer).map_err(|err| StartupError::Renderer(format!("{:?}", err)))?;
 
         // tell winit about the above canvas
         let window_builder = {
@@ -71,43 +89,87 @@ impl Window {
         };
 
         // Apply generic WindowBuilder properties
-        let window_builder = apply_window_description(window_builder, &window_description);
+        let window_builder = apply_window_description(window_builder, &window_description, owner);
 
         // Get the window handle. this is a winit::window::Window
-        let handle = window_builder.build(&events_loop).unwrap();
+        let handle = window_builder
+            .build(&events_loop)
+            .map_err(|err| StartupError::Window(err.to_string()))?;
 
         // Build our window
-        let window = Window { id: handle.id(), window: handle, should_close: false };
+        let window = Window {
+            id: handle.id(),
+            window: handle,
+            renderer_info: RendererInfo::default(),
+            should_close: false,
+            raw_mouse_motion: false,
+            scroll_sensitivity: window_description.scroll_sensitivity,
+        };
 
         let size = window.window().inner_size();
         canvas.set_size(size.width as u32, size.height as u32, 1.0);
-        canvas.clear_rect(0, 0, size.width as u32, size.height as u32, Color::rgb(255, 80, 80));
+        canvas.clear_rect(
+            0,
+            0,
+            size.width as u32,
+            size.height as u32,
+            initial_clear_color(window_description),
+        );
 
-        (window, canvas)
+        Ok((window, canvas))
     }
 
     pub fn window(&self) -> &winit::window::Window {
         &self.window
     }
 
+    pub fn renderer_info(&self) -> &RendererInfo {
+        &self.renderer_info
+    }
+
+    pub fn take_shader_context(&self) -> Option<ShaderContext> {
+        // Custom shaders are not supported on the web.
+        None
+    }
+
     pub fn resize(&self, _size: PhysicalSize<u32>) {
         // TODO?
     }
 
-    pub fn swap_buffers(&self) {
+    pub fn swap_buffers(&self) -> bool {
         // Intentional no-op
+        true
+    }
+
+    pub fn suspend(&self) {
+        // Intentional no-op
+    }
+
+    pub fn make_current(&self) {
+        // Intentional no-op
+    }
+
+    pub fn recreate_renderer(&self) -> Canvas<OpenGl> {
+        use winit::platform::web::WindowExtWebSys;
+
+        let renderer =
+            OpenGl::new_from_html_canvas(&self.window.canvas()).expect("Cannot create renderer");
+
+        Canvas::new(renderer).expect("Failed to create canvas")
     }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 impl Window {
     pub fn new(
-        events_loop: &EventLoop<UserEvent>,
+        events_loop: &EventLoopWindowTarget<UserEvent>,
         window_description: &WindowDescription,
-    ) -> (Self, Canvas<OpenGl>) {
+        owner: Option<RawWindowHandle>,
+    ) -> Result<(Self, Canvas<OpenGl>), StartupError> {
         let window_builder = WindowBuilder::new();
 
-        //Windows COM doesn't play nicely with winit's drag and drop right now
+        // Drag and drop initializes COM for single-threaded use, which conflicts with libraries which
+        // initialize it for multithreaded use. This is documented on `WindowEvent::FileDrop`.
         #[cfg(target_os = "windows")]
         let window_builder = {
             use winit::platform::windows::WindowBuilderExtWindows;
@@ -115,21 +177,27 @@ impl Window {
         };
 
         // Apply generic WindowBuilder properties
-        let window_builder = apply_window_description(window_builder, window_description);
+        let window_builder = apply_window_description(window_builder, window_description, owner);
 
         let template = ConfigTemplateBuilder::new().with_alpha_size(8).with_transparency(true);
         let display_builder = DisplayBuilder::new().with_window_builder(Some(window_builder));
 
+        let transparent = window_description.transparent;
         let (window, gl_config) = display_builder
             .build(events_loop, template, |configs| {
                 // Find the config with the maximum number of samples, so our triangle will
                 // be smooth.
                 configs
                     .reduce(|accum, config| {
-                        let transparency_check = config.supports_transparency().unwrap_or(false)
-                            & !accum.supports_transparency().unwrap_or(false);
+                        // A transparent window needs a config which supports transparency, otherwise
+                        // the transparent parts of the window are shown as black.
+                        let config_transparency = config.supports_transparency().unwrap_or(false);
+                        let accum_transparency = accum.supports_transparency().unwrap_or(false);
+                        if transparent && config_transparency != accum_transparency {
+                            return if config_transparency { config } else { accum };
+                        }
 
-                        if transparency_check || config.num_samples() < accum.num_samples() {
+                        if config.num_samples() < accum.num_samples() {
                             config
                         } else {
                             accum
@@ -137,80 +205,193 @@ impl Window {
                     })
                     .unwrap()
             })
-            .unwrap();
+            .map_err(|err| StartupError::Window(err.to_string()))?;
 
-        let window = window.unwrap();
+        let window =
+            window.ok_or_else(|| StartupError::Window("the window was not created".to_string()))?;
 
-        let raw_window_handle = Some(window.raw_window_handle());
+        let (gl_context, surface, mut canvas, renderer_info, shader_context) =
+            create_renderer(&gl_config, &window, window_description.vsync)?;
 
-        let gl_display = gl_config.display();
+        let size = window.inner_size();
+        canvas.set_size(size.width, size.height, 1.0);
+        canvas.clear_rect(0, 0, size.width, size.height, initial_clear_color(window_description));
 
-        let context_attributes = ContextAttributesBuilder::new().build(raw_window_handle);
-        let fallback_context_attributes = ContextAttributesBuilder::new()
-            .with_context_api(ContextApi::Gles(None))
-            .build(raw_window_handle);
-        let mut not_current_gl_context = Some(unsafe {
-            gl_display.create_context(&gl_config, &context_attributes).unwrap_or_else(|_| {
-                gl_display
-                    .create_context(&gl_config, &fallback_context_attributes)
-                    .expect("failed to create context")
-            })
-        });
-
-        let (width, height): (u32, u32) = window.inner_size().into();
-        let raw_window_handle = window.raw_window_handle();
-        let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
-            raw_window_handle,
-            NonZeroU32::new(width).unwrap(),
-            NonZeroU32::new(height).unwrap(),
-        );
+        // Build our window
+        let win = Window {
+            id: window.id(),
+            context: RefCell::new(gl_context),
+            surface: RefCell::new(Some(surface)),
+            gl_config,
+            vsync: window_description.vsync,
+            shader_context: RefCell::new(Some(shader_context)),
+            window,
+            renderer_info,
+            should_close: false,
+            raw_mouse_motion: false,
+            scroll_sensitivity: window_description.scroll_sensitivity,
+        };
 
-        let surface =
-            unsafe { gl_config.display().create_window_surface(&gl_config, &attrs).unwrap() };
+        Ok((win, canvas))
+    }
 
-        let gl_context = not_current_gl_context.take().unwrap().make_current(&surface).unwrap();
+    pub fn window(&self) -> &winit::window::Window {
+        &self.window
+    }
 
-        // Build the femtovg renderer
-        let renderer = unsafe {
-            OpenGl::new_from_function_cstr(|s| gl_display.get_proc_address(s) as *const _)
+    pub fn renderer_info(&self) -> &RendererInfo {
+        &self.renderer_info
+    }
+
+    /// Takes the context used to run the shader effects of views, which is created along with the
+    /// renderer of the window.
+    pub fn take_shader_context(&self) -> Option<ShaderContext> {
+        self.shader_context.borrow_mut().take()
+    }
+
+    pub fn resize(&self, size: PhysicalSize<u32>) {
+        if size.width != 0 && size.height != 0 {
+            if let Some(surface) = self.surface.borrow().as_ref() {
+                surface.resize(
+                    &self.context.borrow(),
+                    size.width.try_into().unwrap(),
+                    size.height.try_into().unwrap(),
+                );
+            }
         }
-        .expect("Cannot create renderer");
+    }
 
-        if window_description.vsync {
-            surface
-                .set_swap_interval(&gl_context, SwapInterval::Wait(NonZeroU32::new(1).unwrap()))
-                .expect("Failed to set vsync");
+    /// Swaps the buffers of the window surface. Returns `false` if the OpenGL context has been lost.
+    pub fn swap_buffers(&self) -> bool {
+        let surface = self.surface.borrow();
+
+        // The surface does not exist while the application is suspended.
+        let Some(surface) = surface.as_ref() else {
+            return true;
+        };
+
+        match surface.swap_buffers(&self.context.borrow()) {
+            Ok(()) => true,
+            Err(err) if err.error_kind() == ErrorKind::ContextLost => false,
+            Err(err) => panic!("Failed to swap buffers: {}", err),
         }
+    }
 
-        let mut canvas = Canvas::new(renderer).expect("Failed to create canvas");
+    /// Makes the OpenGL context of the window current, so that its canvas can be drawn to or dropped.
+    pub fn make_current(&self) {
+        if let Some(surface) = self.surface.borrow().as_ref() {
+            self.context.borrow().make_current(surface).expect("Failed to make context current");
+        }
+    }
 
-        let size = window.inner_size();
-        canvas.set_size(size.width, size.height, 1.0);
-        canvas.clear_rect(0, 0, size.width, size.height, Color::rgb(255, 80, 80));
+    /// Recreates the OpenGL context and surface of the window after the context has been lost,
+    /// returning a new canvas to render into.
+    pub fn recreate_renderer(&self) -> Canvas<OpenGl> {
+        let (gl_context, surface, canvas, _, shader_context) =
+            create_renderer(&self.gl_config, &self.window, self.vsync)
+                .expect("Failed to recreate renderer");
 
-        // Build our window
-        let win =
-            Window { id: window.id(), context: gl_context, surface, window, should_close: false };
+        *self.surface.borrow_mut() = Some(surface);
+        *self.context.borrow_mut() = gl_context;
+        *self.shader_context.borrow_mut() = Some(shader_context);
 
-        (win, canvas)
+        canvas
     }
 
-    pub fn window(&self) -> &winit::window::Window {
-        &self.window
+    /// Destroys the window surface when the application is suspended. The surface is recreated with
+    /// [`recreate_renderer()`](Self::recreate_renderer) when the application is resumed.
+    pub fn suspend(&self) {
+        self.surface.borrow_mut().take();
     }
+}
 
-    pub fn resize(&self, size: PhysicalSize<u32>) {
-        if size.width != 0 && size.height != 0 {
-            self.surface.resize(
-                &self.context,
-                size.width.try_into().unwrap(),
-                size.height.try_into().unwrap(),
-            );
-        }
+#[cfg(not(target_arch = "wasm32"))]
+fn create_renderer(
+    gl_config: &glutin::config::Config,
+    window: &winit::window::Window,
+    vsync: bool,
+) -> Result<
+    (
+        glutin::context::PossiblyCurrentContext,
+        glutin::surface::Surface<WindowSurface>,
+        Canvas<OpenGl>,
+        RendererInfo,
+        ShaderContext,
+    ),
+    StartupError,
+> {
+    let raw_window_handle = Some(window.raw_window_handle());
+
+    let gl_display = gl_config.display();
+
+    let context_attributes = ContextAttributesBuilder::new().build(raw_window_handle);
+    let fallback_context_attributes = ContextAttributesBuilder::new()
+        .with_context_api(ContextApi::Gles(None))
+        .build(raw_window_handle);
+    let not_current_gl_context = unsafe {
+        gl_display
+            .create_context(gl_config, &context_attributes)
+            .or_else(|_| gl_display.create_context(gl_config, &fallback_context_attributes))
+            .map_err(|err| StartupError::Renderer(err.to_string()))?
+    };
+
+    let (width, height): (u32, u32) = window.inner_size().into();
+    let raw_window_handle = window.raw_window_handle();
+    let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
+        raw_window_handle,
+        NonZeroU32::new(width.max(1)).unwrap(),
+        NonZeroU32::new(height.max(1)).unwrap(),
+    );
+
+    let surface = unsafe { gl_display.create_window_surface(gl_config, &attrs) }
+        .map_err(|err| StartupError::Renderer(err.to_string()))?;
+
+    let gl_context = not_current_gl_context
+        .make_current(&surface)
+        .map_err(|err| StartupError::Renderer(err.to_string()))?;
+
+    // Build the femtovg renderer
+    let renderer =
+        unsafe { OpenGl::new_from_function_cstr(|s| gl_display.get_proc_address(s) as *const _) }
+            .map_err(|err| StartupError::Renderer(format!("{:?}", err)))?;
+
+    let renderer_info = unsafe {
+        RendererInfo::from_gl_loader(|s| {
+            let name = CString::new(s).unwrap();
+            gl_display.get_proc_address(&name) as *const _
+        })
+    };
+
+    let shader_context = unsafe {
+        ShaderContext::from_gl_loader(|s| {
+            let name = CString::new(s).unwrap();
+            gl_display.get_proc_address(&name) as *const _
+        })
+    };
+
+    if vsync {
+        surface
+            .set_swap_interval(&gl_context, SwapInterval::Wait(NonZeroU32::new(1).unwrap()))
+            .expect("Failed to set vsync");
     }
 
-    pub fn swap_buffers(&self) {
-        self.surface.swap_buffers(&self.context).expect("Failed to swap buffers");
+    let canvas =
+        Canvas::new(renderer).map_err(|err| StartupError::Renderer(format!("{:?}", err)))?;
+
+    Ok((gl_context, surface, canvas, renderer_info, shader_context))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+unsafe impl HasRawWindowHandle for Window {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.window.raw_window_handle()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+unsafe impl HasRawDisplayHandle for Window {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.window.raw_display_handle()
     }
 }
 
@@ -220,6 +401,15 @@ impl View for Window {
             WindowEvent::GrabCursor(flag) => {
                 let grab_mode = if *flag { CursorGrabMode::Locked } else { CursorGrabMode::None };
                 self.window().set_cursor_grab(grab_mode).expect("Failed to set cursor grab");
+                self.raw_mouse_motion = *flag;
+            }
+
+            WindowEvent::SetRawMouseMotion(flag) => {
+                self.raw_mouse_motion = *flag;
+            }
+
+            WindowEvent::SetScrollSensitivity(x, y) => {
+                self.scroll_sensitivity = (*x, *y);
             }
 
             WindowEvent::SetCursorPosition(x, y) => {
@@ -230,14 +420,28 @@ impl View for Window {
                     .expect("Failed to set cursor position");
             }
 
-            WindowEvent::SetCursor(cursor) => {
-                if let Some(icon) = cursor_icon_to_cursor_icon(*cursor) {
+            WindowEvent::SetImePosition(x, y) => {
+                self.window().set_ime_position(winit::dpi::Position::Physical(
+                    PhysicalPosition::new(*x as i32, *y as i32),
+                ));
+            }
+
+            WindowEvent::SetCursor(cursor) => match cursor {
+                Cursor::Icon(icon) => {
+                    if let Some(icon) = cursor_icon_to_cursor_icon(*icon) {
+                        self.window().set_cursor_visible(true);
+                        self.window().set_cursor_icon(icon);
+                    } else {
+                        self.window().set_cursor_visible(false);
+                    }
+                }
+
+                // Custom cursor images aren't supported by winit yet, so the default cursor is shown.
+                Cursor::Image(_) => {
                     self.window().set_cursor_visible(true);
-                    self.window().set_cursor_icon(icon);
-                } else {
-                    self.window().set_cursor_visible(false);
+                    self.window().set_cursor_icon(winit::window::CursorIcon::Default);
                 }
-            }
+            },
 
             WindowEvent::SetTitle(title) => {
                 self.window().set_title(title);
@@ -273,6 +477,22 @@ impl View for Window {
                 self.window().set_maximized(*flag);
             }
 
+            WindowEvent::SetFullscreen(fullscreen) => {
+                let fullscreen = fullscreen.map(|fullscreen| match fullscreen {
+                    Fullscreen::Borderless => winit::window::Fullscreen::Borderless(None),
+                    // Falls back to borderless fullscreen if the monitor has no video modes.
+                    Fullscreen::Exclusive(video_mode) => self
+                        .window()
+                        .current_monitor()
+                        .and_then(|monitor| best_video_mode(&monitor, &video_mode))
+                        .map_or(
+                            winit::window::Fullscreen::Borderless(None),
+                            winit::window::Fullscreen::Exclusive,
+                        ),
+                });
+                self.window().set_fullscreen(fullscreen);
+            }
+
             WindowEvent::SetVisible(flag) => {
                 self.window().set_visible(*flag);
             }
@@ -281,6 +501,27 @@ impl View for Window {
                 self.window().set_decorations(*flag);
             }
 
+            WindowEvent::SetAlwaysOnTop(flag) => {
+                let level = if *flag { WindowLevel::AlwaysOnTop } else { WindowLevel::Normal };
+                self.window().set_window_level(window_level_to_window_level(level));
+            }
+
+            WindowEvent::SetAlwaysOnBottom(flag) => {
+                let level = if *flag { WindowLevel::AlwaysOnBottom } else { WindowLevel::Normal };
+                self.window().set_window_level(window_level_to_window_level(level));
+            }
+
+            WindowEvent::SetWindowLevel(level) => {
+                self.window().set_window_level(window_level_to_window_level(*level));
+            }
+
+            // Hiding a window from the taskbar is only supported on Windows.
+            #[cfg(target_os = "windows")]
+            WindowEvent::SetSkipTaskbar(flag) => {
+                use winit::platform::windows::WindowExtWindows;
+                self.window().set_skip_taskbar(*flag);
+            }
+
             WindowEvent::ReloadStyles => {
                 cx.reload_styles().unwrap();
             }
@@ -294,9 +535,38 @@ impl View for Window {
     }
 }
 
+// Returns the video mode of a monitor which best matches the given video mode. The closest size is
+// preferred, then the requested refresh rate and bit depth, then the highest refresh rate and bit
+// depth. Without a size, the current size of the monitor is used.
+fn best_video_mode(
+    monitor: &winit::monitor::MonitorHandle,
+    video_mode: &VideoMode,
+) -> Option<winit::monitor::VideoMode> {
+    let size = video_mode
+        .size
+        .map_or_else(|| monitor.size(), |size| PhysicalSize::new(size.width, size.height));
+
+    monitor.video_modes().max_by_key(|mode| {
+        let size_difference =
+            mode.size().width.abs_diff(size.width) + mode.size().height.abs_diff(size.height);
+        (
+            std::cmp::Reverse(size_difference),
+            video_mode
+                .refresh_rate_millihertz
+                .map_or(true, |rate| mode.refresh_rate_millihertz() == rate),
+            video_mode.bit_depth.map_or(true, |depth| mode.bit_depth() == depth),
+            mode.refresh_rate_millihertz(),
+            mode.bit_depth(),
+        )
+    })
+}
+
+// Applies the window description to the builder. A window with the `AboveOwner` level is owned by
+// the given owner window where supported.
 fn apply_window_description(
     mut builder: WindowBuilder,
     description: &WindowDescription,
+    owner: Option<RawWindowHandle>,
 ) -> WindowBuilder {
     builder = builder.with_title(&description.title).with_inner_size(LogicalSize::new(
         description.inner_size.width,
@@ -317,16 +587,46 @@ fn apply_window_description(
         builder = builder.with_position(LogicalPosition::new(position.x, position.y));
     }
 
+    #[allow(deprecated)]
+    let window_level =
+        if description.always_on_top { WindowLevel::AlwaysOnTop } else { description.window_level };
+
+    // Hiding a window from the taskbar is only supported on Windows.
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::WindowBuilderExtWindows;
+        builder = builder.with_skip_taskbar(description.skip_taskbar);
+    }
+
+    // An owned window is kept above its owner on Windows, and a child window above its parent on
+    // macOS. Other platforms have no equivalent, so the window is stacked as a normal window.
+    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    if let Some(owner) = owner.filter(|_| window_level == WindowLevel::AboveOwner) {
+        #[cfg(target_os = "windows")]
+        if let RawWindowHandle::Win32(handle) = owner {
+            use winit::platform::windows::WindowBuilderExtWindows;
+            builder = builder.with_owner_window(handle.hwnd as _);
+        }
+
+        // SAFETY: The owner is the handle of the main window, which is open while this window is
+        // created.
+        #[cfg(target_os = "macos")]
+        {
+            builder = unsafe { builder.with_parent_window(Some(owner)) };
+        }
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let _ = owner;
+
     builder
         .with_resizable(description.resizable)
         .with_maximized(description.maximized)
-        // Accesskit requires that the window start invisible until accesskit is initialized.
+        // Accesskit requires that the window start invisible until accesskit is initialized. The
+        // window also stays hidden until it has been centered, so it doesn't flash at the position
+        // chosen by the platform before moving.
         .with_visible(false)
-        .with_window_level(if description.always_on_top {
-            WindowLevel::AlwaysOnTop
-        } else {
-            WindowLevel::Normal
-        })
+        .with_window_level(window_level_to_window_level(window_level))
         .with_transparent(description.transparent)
         .with_decorations(description.decorations)
         .with_window_icon(description.icon.as_ref().map(|icon| {
@@ -338,3 +638,13 @@ fn apply_window_description(
             .unwrap()
         }))
 }
+
+// Returns the color a window is cleared with before its first frame is drawn. A transparent window is
+// cleared to transparent so that it doesn't flash an opaque color before the first frame.
+fn initial_clear_color(window_description: &WindowDescription) -> Color {
+    if window_description.transparent {
+        Color::rgba(0, 0, 0, 0)
+    } else {
+        Color::rgb(255, 80, 80)
+    }
+}
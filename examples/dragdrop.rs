@@ -32,7 +32,7 @@ fn main() {
                     if let DropData::Id(id) = data {
                         let bg = ex.with_current(id, |ex| ex.background_color());
                         ex.set_background_color(bg);
-                        ex.emit(WindowEvent::SetCursor(CursorIcon::Default));
+                        ex.emit(WindowEvent::SetCursor(CursorIcon::Default.into()));
                     }
                     if let DropData::File(file) = data {
                         println!("Dropped File: {:?}", file);
@@ -40,9 +40,9 @@ fn main() {
                 })
                 .on_hover(|ex| {
                     if ex.has_drop_data() {
-                        ex.emit(WindowEvent::SetCursor(CursorIcon::Copy));
+                        ex.emit(WindowEvent::SetCursor(CursorIcon::Copy.into()));
                     } else {
-                        ex.emit(WindowEvent::SetCursor(CursorIcon::Default));
+                        ex.emit(WindowEvent::SetCursor(CursorIcon::Default.into()));
                     }
                 });
         });
@@ -0,0 +1,30 @@
+use vizia::prelude::*;
+
+#[cfg(feature = "baseview")]
+fn main() {
+    panic!("This example is not supported on baseview");
+}
+
+#[cfg(not(feature = "baseview"))]
+fn main() {
+    Application::new(|cx| {
+        Label::new(cx, "Main window");
+
+        cx.spawn_window(
+            WindowDescription::new().with_title("Second Window").with_inner_size(300, 150),
+            |cx| {
+                let window = cx.current();
+
+                Label::new(cx, "Second window");
+                Button::new(
+                    cx,
+                    move |cx| cx.emit_to(window, WindowEvent::WindowClose),
+                    |cx| Label::new(cx, "Close"),
+                );
+            },
+        );
+    })
+    .title("Multiple Windows")
+    .inner_size((400, 200))
+    .run();
+}
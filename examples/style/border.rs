@@ -39,6 +39,11 @@ const STYLE: &str = r#"
         border-radius: 30px;
         border-corner-shape: bevel round bevel round;
     }
+
+    .border_sides {
+        border-width: 2px 4px 6px 8px;
+        border-color: red green blue black;
+    }
 "#;
 
 fn main() {
@@ -49,6 +54,7 @@ fn main() {
             Element::new(cx).class("border");
             Element::new(cx).class("border_radius");
             Element::new(cx).class("border_shape");
+            Element::new(cx).class("border_sides");
         })
         .class("row");
 
@@ -68,6 +74,8 @@ fn main() {
                 BorderCornerShape::Bevel,
                 BorderCornerShape::Round,
             ));
+
+            Element::new(cx).border_bottom_width(Pixels(2.0)).border_bottom_color(Color::black());
         })
         .class("row");
     })
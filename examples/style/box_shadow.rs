@@ -148,16 +148,18 @@ fn main() {
             Element::new(cx).class("shadow-inset");
         });
 
-        Element::new(cx)
-            .box_shadow(BoxShadowBuilder::new().x_offset(5.0).y_offset(5.0).color(Color::black()))
-            .box_shadow(BoxShadow::new(
+        Element::new(cx).box_shadow((
+            BoxShadowBuilder::new().x_offset(5.0).y_offset(5.0).color(Color::black()),
+            BoxShadow::new(
                 Length::px(10.0),
                 Length::px(10.0),
                 None,
                 None,
                 Some(Color::red()),
                 false,
-            ));
+            ),
+            BoxShadowBuilder::new().blur_radius(4.0).color(Color::white()).inset(),
+        ));
     })
     .title("Box Shadows")
     .inner_size((1000, 600))
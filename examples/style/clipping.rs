@@ -41,6 +41,10 @@ const STYLE: &str = r#"
     .overflowy:over {
         overflow-y: visible;
     }
+
+    .scroll {
+        overflow: scroll;
+    }
     
     .clipping {
         size: 100%;
@@ -53,6 +57,17 @@ const STYLE: &str = r#"
         clip-path: inset(10px);
         transition: clip-path 100ms;
     }
+
+    .rounded-clipping {
+        size: 100%;
+        space: 0px;
+        clip-path: inset(30px round 30px);
+    }
+
+    .container:over .rounded-clipping {
+        clip-path: inset(10px round 10px);
+        transition: clip-path 100ms;
+    }
 "#;
 
 #[derive(Lens)]
@@ -97,10 +112,21 @@ fn main() {
             .class("container")
             .class("overflowy");
 
+            HStack::new(cx, |cx| {
+                Element::new(cx);
+            })
+            .class("container")
+            .class("scroll");
+
             HStack::new(cx, |cx| {
                 Element::new(cx).class("clipping");
             })
             .class("container");
+
+            HStack::new(cx, |cx| {
+                Element::new(cx).class("rounded-clipping");
+            })
+            .class("container");
         })
         .class("row");
     })
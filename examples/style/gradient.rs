@@ -1,5 +1,4 @@
 use vizia::prelude::*;
-use vizia_core::modifiers::LinearGradientBuilder;
 
 const STYLE: &str = r#"
 
@@ -33,6 +32,16 @@ const STYLE: &str = r#"
     .grad2 {
         background-image: radial-gradient(cyan 0%, transparent 20%, salmon 40%);
     }
+
+    .radial {
+        background-image: radial-gradient(circle at top left, #f69d3c, #3f87a6);
+        border-radius: 20px;
+    }
+
+    .conic {
+        background-image: conic-gradient(from 45deg, red, yellow, lime, aqua, blue, magenta, red);
+        border-radius: 50%;
+    }
 "#;
 
 fn main() {
@@ -41,11 +50,26 @@ fn main() {
         // Element::new(cx).class("linear-gradient");
         // Element::new(cx).class("grad2").width(Pixels(200.0));
 
-        Element::new(cx).background_gradient(
-            LinearGradientBuilder::with_direction("to right")
-                .add_stop(Color::red())
-                .add_stop(Color::blue()),
-        );
+        HStack::new(cx, |cx| {
+            Element::new(cx).background_gradient(
+                LinearGradientBuilder::with_direction("to right")
+                    .add_stop(Color::red())
+                    .add_stop(Color::blue()),
+            );
+
+            Element::new(cx).class("radial");
+            Element::new(cx).class("conic");
+
+            Element::new(cx)
+                .background(
+                    ConicGradientBuilder::new()
+                        .add_stop((0.0, Color::white()))
+                        .add_stop((0.75, Color::black()))
+                        .add_stop((1.0, Color::white())),
+                )
+                .border_radius(Pixels(20.0));
+        })
+        .col_between(Pixels(20.0));
     })
     .run();
 }
@@ -7,7 +7,7 @@ fn main() {
             .visibility(Visibility::Visible)
             .opacity(1.0)
             .overflow(Overflow::Visible)
-            // .clip_path(Pixels(0.0))
+            .clip_path(Pixels(0.0))
             .backdrop_filter(Filter::Blur(Length::px(10.0)))
             .layout_type(LayoutType::Row)
             .position_type(PositionType::ParentDirected)
@@ -41,10 +41,10 @@ fn main() {
                     .y_offset(Pixels(10.0))
                     .color(Color::limegreen()),
             )
-            // .translate(Translate::new(Pixels(50.0), Pixels(0.0)))
-            // .rotate(Angle::Deg(30.0))
-            // .scale(Scale::new(Percentage(3.0), 2.0))
-            // .transform_origin((Pixels(0.0), Pixels(0.0)))
+            .translate(Translate::new(Pixels(50.0), Pixels(0.0)))
+            .rotate(Angle::Deg(30.0))
+            .scale(Scale::new(1.5, 1.5))
+            .transform_origin((Pixels(0.0), Pixels(0.0)))
             .z_index(5)
             .cursor(CursorIcon::Grab);
     })
@@ -0,0 +1,36 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Lens)]
+struct AppData {
+    progress: f32,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetProgress(val) => self.progress = *val,
+        })
+    }
+}
+
+enum AppEvent {
+    SetProgress(f32),
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { progress: 0.4 }.build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            ProgressBar::new(cx, AppData::progress);
+            Slider::new(cx, AppData::progress)
+                .on_changing(|cx, val| cx.emit(AppEvent::SetProgress(val)));
+            ProgressBar::indeterminate(cx);
+        });
+    })
+    .title("Progress Bar")
+    .inner_size((400, 200))
+    .run();
+}
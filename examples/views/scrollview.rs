@@ -7,15 +7,7 @@ fn main() {
         ExamplePage::new(cx, |cx| {
             HStack::new(cx, |cx| {
                 // TODO: Link scrollviews to the same scroll data
-                // ScrollData {
-                //     scroll_x: 0.0,
-                //     scroll_y: 0.0,
-                //     child_x: 1000.0,
-                //     child_y: 300.0,
-                //     parent_x: 300.0,
-                //     parent_y: 300.0,
-                // }
-                // .build(cx);
+                // ScrollData::new(0.0, 0.0).build(cx);
 
                 ScrollView::new(cx, 0.0, 0.0, false, true, |cx| {
                     Label::new(cx, "Label 2")
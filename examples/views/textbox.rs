@@ -26,6 +26,10 @@ fn main() {
             Textbox::new_multiline(cx, AppData::multiline_text, true)
                 .width(Pixels(300.0))
                 .on_edit(|cx, text| cx.emit(AppDataSetter::MultilineText(text)));
+            Textbox::new_multiline(cx, AppData::multiline_text, false)
+                .width(Pixels(300.0))
+                .height(Pixels(80.0))
+                .on_edit(|cx, text| cx.emit(AppDataSetter::MultilineText(text)));
             Textbox::new(cx, AppData::non_editable_text).width(Pixels(300.0)).read_only(true);
         });
     })
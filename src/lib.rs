@@ -1,7 +1,7 @@
 //! # Vizia
 
 #[cfg(all(not(feature = "baseview"), feature = "winit"))]
-pub use vizia_winit::application::Application;
+pub use vizia_winit::application::{Application, StartupError};
 
 #[cfg(all(not(feature = "winit"), feature = "baseview"))]
 pub use vizia_baseview::{Application, ParentWindow, WindowScalePolicy};
@@ -13,7 +13,7 @@ pub mod prelude {
     pub use vizia_core::prelude::*;
 
     #[cfg(all(not(feature = "baseview"), feature = "winit"))]
-    pub use vizia_winit::application::Application;
+    pub use vizia_winit::application::{Application, StartupError};
 
     #[cfg(all(not(feature = "winit"), feature = "baseview"))]
     pub use vizia_baseview::Application;